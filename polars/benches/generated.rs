@@ -0,0 +1,93 @@
+//! Benchmarks on generated data, so they run without downloading a dataset.
+//!
+//! The number of rows is set with the env var `BENCH_SIZE` and defaults to 1_000_000.
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use lazy_static::lazy_static;
+use polars::prelude::*;
+use polars::testing::generate;
+use std::io::Cursor;
+
+const N_GROUPS: u64 = 1000;
+
+lazy_static! {
+    static ref SIZE: usize = std::env::var("BENCH_SIZE")
+        .map(|size| size.parse().expect("BENCH_SIZE is not a number"))
+        .unwrap_or(1_000_000);
+    static ref DATA: DataFrame = generate::frame(*SIZE, N_GROUPS, 0).unwrap();
+}
+
+fn csv_benchmark(c: &mut Criterion) {
+    let mut df = DATA.clone();
+    let mut buf = Vec::new();
+    CsvWriter::new(&mut buf).finish(&mut df).unwrap();
+
+    c.bench_function("csv parse", |b| {
+        b.iter_batched(
+            || Cursor::new(buf.clone()),
+            |file| CsvReader::new(file).has_header(true).finish().unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn groupby_benchmark(c: &mut Criterion) {
+    c.bench_function("groupby utf8 sum", |b| {
+        b.iter(|| {
+            DATA.groupby("id_str")
+                .unwrap()
+                .select("v_int")
+                .sum()
+                .unwrap()
+        })
+    });
+    c.bench_function("groupby int mean", |b| {
+        b.iter(|| {
+            DATA.groupby("id_int")
+                .unwrap()
+                .select("v_float")
+                .mean()
+                .unwrap()
+        })
+    });
+}
+
+fn join_benchmark(c: &mut Criterion) {
+    // a dimension table with one row per group
+    let right = DataFrame::new(vec![
+        Series::new("id_int", (0..N_GROUPS as i64).collect::<Vec<_>>()),
+        generate::floats("weight", N_GROUPS as usize, 1),
+    ])
+    .unwrap();
+
+    c.bench_function("inner join", |b| {
+        b.iter(|| DATA.inner_join(&right, "id_int", "id_int").unwrap())
+    });
+    c.bench_function("left join", |b| {
+        b.iter(|| DATA.left_join(&right, "id_int", "id_int").unwrap())
+    });
+}
+
+fn filter_benchmark(c: &mut Criterion) {
+    let v_int = DATA.column("v_int").unwrap();
+    let half = v_int.lt(50);
+    let sparse = v_int.lt(1);
+
+    c.bench_function("filter 50%", |b| b.iter(|| DATA.filter(&half).unwrap()));
+    c.bench_function("filter 1%", |b| b.iter(|| DATA.filter(&sparse).unwrap()));
+}
+
+fn arithmetic_benchmark(c: &mut Criterion) {
+    let v_int = DATA.column("v_int").unwrap();
+    let v_float = DATA.column("v_float").unwrap();
+
+    c.bench_function("add scalar", |b| b.iter(|| black_box(v_int + 1)));
+    c.bench_function("mul series", |b| b.iter(|| black_box(v_float * v_float)));
+    c.bench_function("add series with cast", |b| {
+        b.iter(|| black_box(v_int + v_float))
+    });
+}
+
+criterion_group!(name = benches;
+config = Criterion::default().sample_size(10);
+targets = csv_benchmark, groupby_benchmark, join_benchmark, filter_benchmark, arithmetic_benchmark);
+criterion_main!(benches);
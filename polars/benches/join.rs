@@ -0,0 +1,72 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars::prelude::*;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn create_df(size: usize, n_keys: u32, seed: u64) -> DataFrame {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut keys: UInt32Chunked = (0..size).map(|_| Some(rng.gen_range(0..n_keys))).collect();
+    keys.rename("key");
+    let mut values: UInt32Chunked = (0..size as u32).map(Some).collect();
+    values.rename("value");
+    DataFrame::new(vec![keys.into_series(), values.into_series()]).unwrap()
+}
+
+/// The same keys as `create_df`, as strings.
+fn create_utf8_df(size: usize, n_keys: u32, seed: u64) -> DataFrame {
+    let mut df = create_df(size, n_keys, seed);
+    let keys = df.column("key").unwrap().cast::<Utf8Type>().unwrap();
+    df.replace("key", keys).unwrap();
+    df
+}
+
+fn bench_left_join(left: &DataFrame, right: &DataFrame) {
+    criterion::black_box(left.left_join(right, "key", "key").unwrap());
+}
+
+fn bench_inner_join(left: &DataFrame, right: &DataFrame) {
+    criterion::black_box(left.inner_join(right, "key", "key").unwrap());
+}
+
+fn bench_outer_join(left: &DataFrame, right: &DataFrame) {
+    criterion::black_box(left.outer_join(right, "key", "key").unwrap());
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    // left joins around LEFT_JOIN_BUILD_LEFT_RATIO: up to a ratio of 4 the hash table is built on
+    // the right relation, above it on the left relation
+    let n_left = 100_000;
+    let left = create_df(n_left, n_left as u32, 0);
+    for ratio in &[1, 2, 3, 4, 5, 6, 8, 16] {
+        let right = create_df(n_left * ratio, (n_left * ratio) as u32, 1);
+        c.bench_function(
+            &format!("left join {} rows; right {}x larger", n_left, ratio),
+            |b| b.iter(|| bench_left_join(&left, &right)),
+        );
+    }
+
+    // utf8 keys are joined on interned u32 codes, compare them with joins on u32 keys
+    let n_rows = 100_000;
+    for n_keys in &[10_000, 100_000] {
+        let left = create_df(n_rows, *n_keys, 0);
+        let right = create_df(n_rows, *n_keys, 1);
+        let left_utf8 = create_utf8_df(n_rows, *n_keys, 0);
+        let right_utf8 = create_utf8_df(n_rows, *n_keys, 1);
+        for (dtype, left, right) in &[("u32", &left, &right), ("utf8", &left_utf8, &right_utf8)] {
+            c.bench_function(
+                &format!("inner join {} rows; {} {} keys", n_rows, n_keys, dtype),
+                |b| b.iter(|| bench_inner_join(left, right)),
+            );
+            c.bench_function(
+                &format!("left join {} rows; {} {} keys", n_rows, n_keys, dtype),
+                |b| b.iter(|| bench_left_join(left, right)),
+            );
+            c.bench_function(
+                &format!("outer join {} rows; {} {} keys", n_rows, n_keys, dtype),
+                |b| b.iter(|| bench_outer_join(left, right)),
+            );
+        }
+    }
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
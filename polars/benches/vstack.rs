@@ -0,0 +1,47 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polars::prelude::*;
+
+fn create_df(size: u32) -> DataFrame {
+    let ints = Series::new("ints", (0..size).collect::<Vec<_>>());
+    let floats = Series::new("floats", (0..size).map(|v| v as f64).collect::<Vec<_>>());
+    DataFrame::new(vec![ints, floats]).unwrap()
+}
+
+fn stack_frames(df: &DataFrame, n: usize, rechunk: bool) -> DataFrame {
+    let mut acc = df.clone();
+    for _ in 1..n {
+        acc = match rechunk {
+            true => acc.vstack_and_rechunk(df).unwrap(),
+            false => acc.vstack(df).unwrap(),
+        };
+    }
+    acc
+}
+
+fn sum_columns(df: &DataFrame) -> f64 {
+    df.get_columns().iter().flat_map(|s| s.sum::<f64>()).sum()
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let df = create_df(100_000);
+
+    c.bench_function("vstack 10 frames", |b| {
+        b.iter(|| criterion::black_box(stack_frames(&df, 10, false)))
+    });
+    c.bench_function("vstack_and_rechunk 10 frames", |b| {
+        b.iter(|| criterion::black_box(stack_frames(&df, 10, true)))
+    });
+
+    // the downstream cost of the extra chunks
+    let appended = stack_frames(&df, 10, false);
+    let copied = appended.agg_chunks();
+    c.bench_function("sum after vstack", |b| {
+        b.iter(|| criterion::black_box(sum_columns(&appended)))
+    });
+    c.bench_function("sum after vstack_and_rechunk", |b| {
+        b.iter(|| criterion::black_box(sum_columns(&copied)))
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
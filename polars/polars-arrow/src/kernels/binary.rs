@@ -0,0 +1,108 @@
+//! Binary kernels for arrays without null values. These operate directly on the value slices,
+//! so the compiler can autovectorize the loops.
+use crate::vec::AlignedVec;
+use arrow::array::*;
+use arrow::datatypes::{ArrowPrimitiveType, DataType};
+
+/// Apply a binary operation on the values of two arrays of the same length without null values.
+pub fn binary_no_null<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    op: F,
+) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    debug_assert_eq!(lhs.len(), rhs.len());
+    debug_assert_eq!(lhs.null_count() + rhs.null_count(), 0);
+
+    let mut av = AlignedVec::with_capacity_aligned(lhs.len());
+    av.extend(
+        lhs.values()
+            .iter()
+            .zip(rhs.values())
+            .map(|(&l, &r)| op(l, r)),
+    );
+    av.into_primitive_array(None)
+}
+
+/// Pack the outcomes of `op` for (at most 64) pairs of values in a single word.
+#[inline]
+fn pack_bits<N: Copy, F: Fn(N, N) -> bool>(lhs: &[N], rhs: &[N], op: &F) -> u64 {
+    lhs.iter()
+        .zip(rhs)
+        .enumerate()
+        .fold(0u64, |word, (i, (&l, &r))| word | ((op(l, r) as u64) << i))
+}
+
+/// Compare the values of two arrays of the same length without null values. The bits of the
+/// result are packed per 64 values.
+pub fn compare_no_null<T, F>(
+    lhs: &PrimitiveArray<T>,
+    rhs: &PrimitiveArray<T>,
+    op: F,
+) -> BooleanArray
+where
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native, T::Native) -> bool,
+{
+    debug_assert_eq!(lhs.len(), rhs.len());
+    debug_assert_eq!(lhs.null_count() + rhs.null_count(), 0);
+
+    let len = lhs.len();
+    let chunks_lhs = lhs.values().chunks_exact(64);
+    let chunks_rhs = rhs.values().chunks_exact(64);
+    let remainder_lhs = chunks_lhs.remainder();
+    let remainder_rhs = chunks_rhs.remainder();
+
+    let mut words = AlignedVec::with_capacity_aligned((len + 63) / 64);
+    words.extend(
+        chunks_lhs
+            .zip(chunks_rhs)
+            .map(|(lhs, rhs)| pack_bits(lhs, rhs, &op)),
+    );
+    if !remainder_lhs.is_empty() {
+        words.push(pack_bits(remainder_lhs, remainder_rhs, &op));
+    }
+
+    let data = ArrayData::builder(DataType::Boolean)
+        .len(len)
+        .add_buffer(words.into_arrow_buffer())
+        .build();
+    BooleanArray::from(data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binary_no_null() {
+        let lhs = Int32Array::from((0..100).collect::<Vec<_>>());
+        let rhs = Int32Array::from(vec![7; 100]);
+        let out = binary_no_null(&lhs, &rhs, |l, r| l % r);
+        assert_eq!(out.len(), 100);
+        assert_eq!(out.null_count(), 0);
+        assert_eq!(out.value(15), 1);
+
+        // respects the offset of sliced arrays
+        let sliced = lhs.slice(10, 5);
+        let sliced = sliced.as_any().downcast_ref::<Int32Array>().unwrap();
+        let rhs = Int32Array::from(vec![1; 5]);
+        let out = binary_no_null(sliced, &rhs, |l, r| l + r);
+        assert_eq!(out.values(), &[11, 12, 13, 14, 15]);
+    }
+
+    #[test]
+    fn test_compare_no_null() {
+        let lhs = Int32Array::from((0..130).collect::<Vec<_>>());
+        let rhs = Int32Array::from(vec![65; 130]);
+        let out = compare_no_null(&lhs, &rhs, |l, r| l > r);
+        assert_eq!(out.len(), 130);
+        assert_eq!(out.null_count(), 0);
+        for i in 0..130 {
+            assert_eq!(out.value(i), i > 65);
+        }
+    }
+}
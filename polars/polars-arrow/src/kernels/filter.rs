@@ -0,0 +1,88 @@
+use crate::builder::BooleanBufferBuilder;
+use crate::kernels::MaskedSlicesIterator;
+use crate::vec::AlignedVec;
+use arrow::array::*;
+use arrow::datatypes::ArrowPrimitiveType;
+
+/// Filter a primitive array with a mask that doesn't have null values.
+///
+/// The set bits of the mask are read 64 at a time and collected in runs. Every run of values is
+/// copied with a single `memcpy`, so this is much faster than branching on every element when the
+/// mask keeps or drops long stretches of values.
+pub fn filter_primitive<T>(array: &PrimitiveArray<T>, mask: &BooleanArray) -> PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+{
+    debug_assert_eq!(mask.null_count(), 0);
+    debug_assert_eq!(array.len(), mask.len());
+    let values = array.values();
+
+    // counting the set bits is fast and allows reserving all the needed memory
+    let mask_values = &mask.data_ref().buffers()[0];
+    let len = mask_values.count_set_bits_offset(mask.offset(), mask.len());
+    let mut av = AlignedVec::with_capacity_aligned(len);
+
+    if array.null_count() == 0 {
+        MaskedSlicesIterator::new(mask)
+            .for_each(|(lower, upper)| av.extend_from_slice(&values[lower..upper]));
+        av.into_primitive_array(None)
+    } else {
+        let mut validity = BooleanBufferBuilder::new(len);
+        MaskedSlicesIterator::new(mask).for_each(|(lower, upper)| {
+            av.extend_from_slice(&values[lower..upper]);
+            (lower..upper).for_each(|idx| validity.append(array.is_valid(idx)));
+        });
+        av.into_primitive_array(Some(validity.finish()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::UInt32Array;
+
+    #[test]
+    fn test_filter_primitive() {
+        // longer than a single 64 bit word, with runs of set and unset bits
+        let mask = BooleanArray::from(
+            (0..150)
+                .map(|v| (v > 10 && v < 100) || v % 7 == 0)
+                .collect::<Vec<bool>>(),
+        );
+        let values = (0..150).collect::<Vec<u32>>();
+        let expected = values
+            .iter()
+            .copied()
+            .filter(|v| (*v > 10 && *v < 100) || v % 7 == 0)
+            .collect::<Vec<_>>();
+
+        let arr = UInt32Array::from(values.clone());
+        let out = filter_primitive(&arr, &mask);
+        assert_eq!(out.values(), expected.as_slice());
+        assert_eq!(out.null_count(), 0);
+
+        // with null values
+        let arr = UInt32Array::from(
+            values
+                .iter()
+                .map(|v| if v % 2 == 0 { Some(*v) } else { None })
+                .collect::<Vec<_>>(),
+        );
+        let out = filter_primitive(&arr, &mask);
+        assert_eq!(out.len(), expected.len());
+        for (idx, v) in expected.iter().enumerate() {
+            assert_eq!(out.is_valid(idx), v % 2 == 0);
+            if out.is_valid(idx) {
+                assert_eq!(out.value(idx), *v);
+            }
+        }
+
+        // masks that keep nothing or everything
+        let mask = BooleanArray::from(vec![false; 150]);
+        assert_eq!(filter_primitive(&arr, &mask).len(), 0);
+        let mask = BooleanArray::from(vec![true; 150]);
+        let out = filter_primitive(&arr, &mask);
+        assert_eq!(out.len(), 150);
+        assert_eq!(out.null_count(), arr.null_count());
+    }
+}
@@ -1,6 +1,8 @@
 use arrow::array::{Array, BooleanArray};
 use arrow::util::bit_chunk_iterator::BitChunkIterator;
 use std::iter::Enumerate;
+pub mod binary;
+pub mod filter;
 pub mod set;
 
 /// Internal state of [SlicesIterator]
@@ -1,3 +1,4 @@
+#![deny(unstable_features)]
 pub mod array;
 pub mod bit_util;
 pub mod buffer;
@@ -3,7 +3,12 @@ use crate::prelude::*;
 use crate::utils::{align_chunks_binary, NoNull};
 use arrow::array::PrimitiveArray;
 use arrow::{array::ArrayRef, compute};
+use num::traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, WrappingAdd, WrappingMul,
+    WrappingSub,
+};
 use num::{Num, NumCast, ToPrimitive};
+use polars_arrow::kernels::binary::binary_no_null;
 use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::sync::Arc;
 
@@ -164,6 +169,15 @@ where
                 None => ChunkedArray::full_null(self.name(), self.len()),
                 Some(rhs) => self.apply(|val| val % rhs),
             }
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            let chunks = lhs
+                .downcast_chunks()
+                .iter()
+                .zip(rhs.downcast_chunks())
+                .map(|(lhs, rhs)| Arc::new(binary_no_null(lhs, rhs, |l, r| l % r)) as ArrayRef)
+                .collect();
+            lhs.copy_with_chunks(chunks)
         } else {
             // we will clean this mess up once there is a remainder kernel in arrow.
             apply_operand_on_chunkedarray_by_iter!(self, rhs, %)
@@ -343,6 +357,97 @@ where
     }
 }
 
+/// Apply an operation that may not have a result for every pair of values, e.g. because it
+/// overflows. Values without a result are null.
+fn checked_arithmetic_helper<T, F>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    operation: F,
+) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> Option<T::Native>,
+{
+    let mut ca: ChunkedArray<T> = match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => lhs
+            .into_iter()
+            .zip(rhs.into_iter())
+            .map(|(opt_lhs, opt_rhs)| match (opt_lhs, opt_rhs) {
+                (Some(lhs), Some(rhs)) => operation(lhs, rhs),
+                _ => None,
+            })
+            .collect(),
+        // broadcast right path
+        (_, 1) => match rhs.get(0) {
+            None => ChunkedArray::full_null(lhs.name(), lhs.len()),
+            Some(rhs) => lhs
+                .into_iter()
+                .map(|opt_lhs| opt_lhs.and_then(|lhs| operation(lhs, rhs)))
+                .collect(),
+        },
+        (1, _) => match lhs.get(0) {
+            None => ChunkedArray::full_null(lhs.name(), rhs.len()),
+            Some(lhs) => rhs
+                .into_iter()
+                .map(|opt_rhs| opt_rhs.and_then(|rhs| operation(lhs, rhs)))
+                .collect(),
+        },
+        _ => panic!("Cannot apply operation on arrays of different lengths"),
+    };
+    ca.rename(lhs.name());
+    ca
+}
+
+macro_rules! impl_checked_arithmetic {
+    ($($(#[$doc:meta])* $name:ident => |$lhs:ident, $rhs:ident| $operation:expr;)*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(&self, rhs: &ChunkedArray<T>) -> ChunkedArray<T> {
+                checked_arithmetic_helper(self, rhs, |$lhs, $rhs| $operation)
+            }
+        )*
+    };
+}
+
+/// Integer arithmetic that behaves the same in debug and release builds. The `+-*/` operators
+/// panic on overflow in debug builds and wrap around in release builds.
+///
+/// Both arrays must have the same length, or one of them must have length 1.
+impl<T> ChunkedArray<T>
+where
+    T: PolarsIntegerType,
+    T::Native: CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + WrappingAdd
+        + WrappingSub
+        + WrappingMul
+        + Saturating,
+{
+    impl_checked_arithmetic! {
+        /// Add the values, the result is null on overflow.
+        checked_add => |lhs, rhs| lhs.checked_add(&rhs);
+        /// Subtract the values, the result is null on overflow.
+        checked_sub => |lhs, rhs| lhs.checked_sub(&rhs);
+        /// Multiply the values, the result is null on overflow.
+        checked_mul => |lhs, rhs| lhs.checked_mul(&rhs);
+        /// Divide the values, the result is null on division by zero and on overflow
+        /// (e.g. `i32::MIN / -1`).
+        checked_div => |lhs, rhs| lhs.checked_div(&rhs);
+        /// Add the values, wrapping around at the bounds of the dtype.
+        wrapping_add => |lhs, rhs| Some(lhs.wrapping_add(&rhs));
+        /// Subtract the values, wrapping around at the bounds of the dtype.
+        wrapping_sub => |lhs, rhs| Some(lhs.wrapping_sub(&rhs));
+        /// Multiply the values, wrapping around at the bounds of the dtype.
+        wrapping_mul => |lhs, rhs| Some(lhs.wrapping_mul(&rhs));
+        /// Add the values, clamping the result to the bounds of the dtype.
+        saturating_add => |lhs, rhs| Some(lhs.saturating_add(rhs));
+        /// Subtract the values, clamping the result to the bounds of the dtype.
+        saturating_sub => |lhs, rhs| Some(lhs.saturating_sub(rhs));
+    }
+}
+
 fn concat_strings(l: &str, r: &str) -> String {
     // fastest way to concat strings according to https://github.com/hoodie/concatenation_benchmarks-rs
     let mut s = String::with_capacity(l.len() + r.len());
@@ -452,6 +557,8 @@ pub(crate) mod test {
         let _ = &a1 - &a2;
         let _ = &a1 / &a2;
         let _ = &a1 * &a2;
+        let rem = &a1 % &a2;
+        assert_eq!(Vec::from(&rem), &[Some(0); 6]);
 
         // with same chunks
         let _ = &a1 + &a1;
@@ -460,6 +567,76 @@ pub(crate) mod test {
         let _ = &a1 * &a1;
     }
 
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Int8Chunked::new_from_opt_slice("a", &[Some(100), Some(-100), None, Some(-128)]);
+        let b = Int8Chunked::new_from_opt_slice("b", &[Some(100), Some(-100), Some(1), Some(-1)]);
+
+        let out = a.checked_add(&b);
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[None; 4]);
+        assert_eq!(
+            Vec::from(&a.checked_sub(&b)),
+            &[Some(0), Some(0), None, Some(-127)]
+        );
+        assert_eq!(Vec::from(&a.checked_mul(&b)), &[None, None, None, None]);
+        assert_eq!(
+            Vec::from(&a.checked_div(&b)),
+            &[Some(1), Some(1), None, None]
+        );
+        let zero = Int8Chunked::new_from_slice("zero", &[0]);
+        assert_eq!(Vec::from(&a.checked_div(&zero)), &[None; 4]);
+
+        assert_eq!(
+            Vec::from(&a.wrapping_add(&b)),
+            &[Some(-56), Some(56), None, Some(127)]
+        );
+        assert_eq!(
+            Vec::from(&a.wrapping_mul(&b)),
+            &[Some(16), Some(16), None, Some(-128)]
+        );
+        assert_eq!(
+            Vec::from(&a.saturating_add(&b)),
+            &[Some(127), Some(-128), None, Some(-128)]
+        );
+        let one = UInt8Chunked::new_from_slice("one", &[1]);
+        let zeros = UInt8Chunked::new_from_slice("a", &[0, 1]);
+        assert_eq!(Vec::from(&zeros.saturating_sub(&one)), &[Some(0), Some(0)]);
+        assert_eq!(Vec::from(&zeros.wrapping_sub(&one)), &[Some(255), Some(0)]);
+    }
+
+    #[test]
+    fn test_misaligned_chunks() {
+        // chunk lengths [3, 3] and [2, 4]
+        let (a1, _) = create_two_chunked();
+        let mut a2 = Int32Chunked::new_from_slice("a", &[1, 2]);
+        a2.append(&Int32Chunked::new_from_opt_slice(
+            "a",
+            &[Some(3), None, Some(5), Some(6)],
+        ));
+
+        let (lhs, rhs) = crate::utils::align_chunks_binary(&a1, &a2);
+        assert_eq!(lhs.chunk_id(), &[2, 1, 3]);
+        assert_eq!(rhs.chunk_id(), lhs.chunk_id());
+
+        let sum = &a1 + &a2;
+        assert_eq!(
+            Vec::from(&sum),
+            &[Some(2), Some(4), Some(6), None, Some(10), Some(12)]
+        );
+        assert_eq!(
+            Vec::from(&a1.eq(&a2)),
+            &[
+                Some(true),
+                Some(true),
+                Some(true),
+                None,
+                Some(true),
+                Some(true)
+            ]
+        );
+    }
+
     #[test]
     fn test_power() {
         let a = UInt32Chunked::new_from_slice("", &[1, 2, 3]);
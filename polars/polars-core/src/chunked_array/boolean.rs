@@ -1,5 +1,26 @@
 use crate::prelude::*;
 use crate::utils::NoNull;
+use arrow::array::{Array, BooleanArray};
+use arrow::buffer::buffer_bin_and;
+
+/// Count the valid `true` values with a popcount over the value and validity bitmaps.
+fn count_true(arr: &BooleanArray) -> usize {
+    let data = arr.data_ref();
+    let values = &data.buffers()[0];
+    match data.null_bitmap() {
+        None => values.count_set_bits_offset(arr.offset(), arr.len()),
+        Some(bitmap) => {
+            let valid_values = buffer_bin_and(
+                values,
+                arr.offset(),
+                bitmap.buffer_ref(),
+                arr.offset(),
+                arr.len(),
+            );
+            valid_values.count_set_bits_offset(0, arr.len())
+        }
+    }
+}
 
 impl BooleanChunked {
     pub fn arg_true(&self) -> UInt32Chunked {
@@ -7,4 +28,58 @@ impl BooleanChunked {
         let ca: NoNull<UInt32Chunked> = (0u32..self.len() as u32).collect();
         ca.into_inner().filter(self).unwrap()
     }
+
+    /// Count the `true` values. Null values are not counted.
+    pub fn count_true(&self) -> usize {
+        self.downcast_chunks()
+            .iter()
+            .map(|arr| count_true(*arr))
+            .sum()
+    }
+
+    /// Returns true if any of the values is `true`. Null values are ignored.
+    pub fn any(&self) -> bool {
+        self.downcast_chunks()
+            .iter()
+            .any(|arr| count_true(*arr) > 0)
+    }
+
+    /// Returns true if all values are `true`. Null values are ignored, so this is also true
+    /// if all values are null.
+    pub fn all(&self) -> bool {
+        self.count_true() == self.len() - self.null_count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_any_all() {
+        let ca = BooleanChunked::new_from_opt_slice("a", &[Some(false), None, Some(true)]);
+        assert!(ca.any());
+        assert!(!ca.all());
+        assert_eq!(ca.count_true(), 1);
+        // the offset of a slice and the null values are taken into account
+        let sliced = ca.slice(1, 2).unwrap();
+        assert!(sliced.any());
+        assert!(sliced.all());
+
+        let ca = BooleanChunked::new_from_opt_slice("a", &[Some(false), None]);
+        assert!(!ca.any());
+        assert!(!ca.all());
+        let ca = BooleanChunked::new_from_opt_slice("a", &[None, None]);
+        assert!(!ca.any());
+        assert!(ca.all());
+
+        let mut ca = BooleanChunked::new_from_slice("a", &[true; 100]);
+        ca.append(&BooleanChunked::new_from_slice("a", &[true, false]));
+        assert_eq!(ca.count_true(), 101);
+        assert!(!ca.all());
+
+        let s = Series::new("a", &[true, true]);
+        assert!(s.all().unwrap());
+        assert!(Series::new("a", &[1, 2]).any().is_err());
+    }
 }
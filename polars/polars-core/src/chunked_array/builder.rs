@@ -13,6 +13,7 @@ use arrow::{
 };
 use num::Num;
 use polars_arrow::prelude::*;
+use smallvec::smallvec;
 use std::borrow::Cow;
 use std::iter::FromIterator;
 use std::marker::PhantomData;
@@ -52,10 +53,11 @@ impl ChunkedBuilder<bool, BooleanType> for BooleanChunkedBuilder {
         let len = arr.len();
         ChunkedArray {
             field: Arc::new(self.field),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -99,10 +101,11 @@ where
         let len = arr.len();
         ChunkedArray {
             field: Arc::new(self.field),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -117,6 +120,11 @@ where
             field: Field::new(name, T::get_dtype()),
         }
     }
+
+    /// Create a builder that can hold `capacity` values without reallocating.
+    pub fn with_capacity(name: &str, capacity: usize) -> Self {
+        Self::new(name, capacity)
+    }
 }
 
 pub struct CategoricalChunkedBuilder {
@@ -230,10 +238,11 @@ impl ChunkedBuilder<&str, CategoricalType> for CategoricalChunkedBuilder {
         self.reverse_mapping.shrink_to_fit();
         ChunkedArray {
             field: Arc::new(self.field),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: Some(Arc::new(self.reverse_mapping)),
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -281,10 +290,11 @@ impl Utf8ChunkedBuilder {
         let len = arr.len();
         ChunkedArray {
             field: Arc::new(self.field),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -449,12 +459,34 @@ impl<T> NewChunkedArray<T, T::Native> for ChunkedArray<T>
 where
     T: PolarsPrimitiveType,
 {
+    /// Copies the values in a single memcpy.
     fn new_from_slice(name: &str, v: &[T::Native]) -> Self {
-        Self::new_from_iter(name, v.iter().copied())
+        ChunkedArray::new_from_aligned_vec(name, AlignedVec::new_from_slice(v))
     }
 
+    /// Writes the values and the validity bitmap directly, without going through the builder.
     fn new_from_opt_slice(name: &str, opt_v: &[Option<T::Native>]) -> Self {
-        Self::new_from_opt_iter(name, opt_v.iter().copied())
+        let mut values = AlignedVec::with_capacity_aligned(opt_v.len());
+        let mut bitmap = BooleanBufferBuilder::new(opt_v.len());
+        let mut null_count = 0;
+        for opt in opt_v {
+            match opt {
+                Some(v) => {
+                    values.push(*v);
+                    bitmap.append(true);
+                }
+                None => {
+                    values.push(T::Native::default());
+                    bitmap.append(false);
+                    null_count += 1;
+                }
+            }
+        }
+        let bitmap = match null_count {
+            0 => None,
+            _ => Some(bitmap.finish()),
+        };
+        ChunkedArray::new_from_owned_with_null_bitmap(name, values, bitmap, null_count)
     }
 
     fn new_from_opt_iter(
@@ -517,10 +549,11 @@ where
 
         ChunkedArray {
             field,
-            chunks: vec![Arc::new(builder.finish())],
-            chunk_id: vec![v.len()],
+            chunks: smallvec![Arc::new(builder.finish()) as ArrayRef],
+            chunk_id: smallvec![v.len()],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 
@@ -575,10 +608,11 @@ macro_rules! finish_list_builder {
         let len = arr.len();
         ListChunked {
             field: Arc::new($self.field.clone()),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }};
 }
@@ -671,6 +705,15 @@ impl ListUtf8ChunkedBuilder {
 
         ListUtf8ChunkedBuilder { builder, field }
     }
+
+    /// Append the values of an iterator as a single list.
+    pub fn append_values_iter<'a, I: Iterator<Item = &'a str>>(&mut self, iter: I) {
+        let value_builder = self.builder.values();
+        for s in iter {
+            value_builder.append_value(s).unwrap();
+        }
+        self.builder.append(true).unwrap();
+    }
 }
 
 impl ListBuilderTrait for ListUtf8ChunkedBuilder {
@@ -805,6 +848,26 @@ mod test {
         assert_eq!(Vec::from(&ca), values);
     }
 
+    #[test]
+    fn test_new_from_slice() {
+        let ca = UInt32Chunked::new_from_slice("a", &[1, 2, 3]);
+        assert_eq!(ca.name(), "a");
+        assert_eq!(ca.null_count(), 0);
+        assert_eq!(ca.cont_slice().unwrap(), &[1, 2, 3]);
+
+        let values = &[Some(1), None, Some(2), None];
+        let ca = UInt32Chunked::new_from_opt_slice("a", values);
+        assert_eq!(ca.null_count(), 2);
+        assert_eq!(Vec::from(&ca), values);
+
+        let ca = UInt32Chunked::new_from_opt_slice("a", &[Some(1), Some(2)]);
+        assert_eq!(ca.null_count(), 0);
+        assert_eq!(ca.cont_slice().unwrap(), &[1, 2]);
+
+        let builder = PrimitiveChunkedBuilder::<Int64Type>::with_capacity("a", 0);
+        assert_eq!(builder.finish().len(), 0);
+    }
+
     #[test]
     fn test_existing_null_bitmap() {
         let mut builder = PrimitiveBuilder::<UInt32Type>::new(3);
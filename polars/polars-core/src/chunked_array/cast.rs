@@ -1,7 +1,9 @@
 //! Implementations of the ChunkCast Trait.
 use crate::chunked_array::builder::CategoricalChunkedBuilder;
 use crate::chunked_array::kernels::cast::cast;
-use crate::chunked_array::kernels::{cast_numeric_from_dtype, transmute_array_from_dtype};
+use crate::chunked_array::kernels::{
+    cast_numeric_from_dtype, transmute_array, transmute_array_from_dtype,
+};
 use crate::prelude::*;
 use crate::use_string_cache;
 use num::{NumCast, ToPrimitive};
@@ -175,6 +177,46 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    /// Reinterpret the bits of the values as another numeric type of the same size, e.g.
+    /// `Int64` as `UInt64` or the bits of a `Float64` as `UInt64`. This is zero-copy, the
+    /// memory and null bitmap are shared with `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let ca = Float64Chunked::new_from_slice("a", &[1.0]);
+    /// let bits = ca.reinterpret::<UInt64Type>().unwrap();
+    /// assert_eq!(bits.get(0), Some(1.0f64.to_bits()));
+    /// ```
+    pub fn reinterpret<N>(&self) -> Result<ChunkedArray<N>>
+    where
+        N: PolarsNumericType,
+    {
+        if std::mem::size_of::<T::Native>() != std::mem::size_of::<N::Native>() {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "cannot reinterpret {:?} as {:?}, the types differ in size",
+                    T::get_dtype(),
+                    N::get_dtype()
+                )
+                .into(),
+            ));
+        }
+        let chunks = self
+            .downcast_chunks()
+            .into_iter()
+            // Safety: all bit patterns are valid values of a numeric type of the same size
+            .map(|arr| unsafe { transmute_array::<T, N>(arr) })
+            .collect();
+        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+    }
+}
+
 macro_rules! impl_chunkcast {
     ($ca_type:ident) => {
         impl ChunkCast for $ca_type {
@@ -218,3 +260,31 @@ impl ChunkCast for Utf8Chunked {
 
 impl_chunkcast!(BooleanChunked);
 impl_chunkcast!(ListChunked);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_reinterpret() -> Result<()> {
+        let ca = Int64Chunked::new_from_opt_slice("a", &[Some(-1), None, Some(2)]);
+        let unsigned = ca.reinterpret::<UInt64Type>()?;
+        assert_eq!(unsigned.name(), "a");
+        assert_eq!(Vec::from(&unsigned), &[Some(u64::MAX), None, Some(2)]);
+        let signed = unsigned.reinterpret::<Int64Type>()?;
+        assert_eq!(Vec::from(&signed), Vec::from(&ca));
+
+        let ca = Float64Chunked::new_from_slice("a", &[1.5, -0.0]);
+        let bits = ca.reinterpret::<UInt64Type>()?;
+        assert_eq!(bits.get(1), Some((-0.0f64).to_bits()));
+        let floats = bits.reinterpret::<Float64Type>()?;
+        assert_eq!(Vec::from(&floats), &[Some(1.5), Some(-0.0)]);
+
+        // the offset of a slice is kept
+        let sliced = ca.slice(1, 1)?.reinterpret::<Int64Type>()?;
+        assert_eq!(sliced.get(0), Some(i64::MIN));
+
+        assert!(ca.reinterpret::<UInt32Type>().is_err());
+        Ok(())
+    }
+}
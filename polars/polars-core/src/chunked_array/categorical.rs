@@ -0,0 +1,288 @@
+//! Categorical dictionaries and the categorical namespace.
+use crate::prelude::*;
+use crate::use_string_cache;
+use ahash::AHashMap;
+use std::ops::Deref;
+
+/// The order of the codes of the categories when a Utf8 array is converted to a categorical
+/// array with [Utf8Chunked::to_categorical].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CategoricalOrdering {
+    /// Codes are assigned in the order in which the categories first appear. This is the order
+    /// used by `cast::<CategoricalType>()`.
+    Appearance,
+    /// The most frequent category gets code 0, the next most frequent code 1, etc. Categories
+    /// with the same count are ordered by first appearance.
+    Frequency,
+}
+
+/// The categories of a categorical array by code.
+pub(crate) type CategoricalMap = AHashMap<u32, String>;
+
+/// The categories of two categorical arrays merged into one map.
+pub(crate) enum MergedCategories {
+    /// The codes of both arrays refer to the categories in the map.
+    Shared(Arc<CategoricalMap>),
+    /// The codes of the left array refer to the categories in the map, the codes of the right
+    /// array must be translated with the remapping first.
+    Remapped(Arc<CategoricalMap>, AHashMap<u32, u32>),
+}
+
+/// Check if the codes of two categorical arrays agree: a code or a category that occurs in both
+/// maps refers to the same category or code. This is always the case for arrays that are created
+/// with the global string cache enabled.
+pub(crate) fn categories_compatible(left: &CategoricalMap, right: &CategoricalMap) -> bool {
+    let codes = left
+        .iter()
+        .map(|(code, s)| (s.as_str(), *code))
+        .collect::<AHashMap<_, _>>();
+    right.iter().all(|(code, s)| {
+        left.get(code).map_or(true, |left_s| left_s == s)
+            && codes
+                .get(s.as_str())
+                .map_or(true, |left_code| left_code == code)
+    })
+}
+
+/// Merge the categories of `right` into those of `left`. The codes of `left` stay valid,
+/// categories that only occur in `right` get new codes if the codes of the arrays don't agree.
+pub(crate) fn merge_categories(
+    left: &Arc<CategoricalMap>,
+    right: &Arc<CategoricalMap>,
+) -> MergedCategories {
+    if Arc::ptr_eq(left, right) {
+        return MergedCategories::Shared(left.clone());
+    }
+    if categories_compatible(left, right) {
+        if right.keys().all(|code| left.contains_key(code)) {
+            return MergedCategories::Shared(left.clone());
+        }
+        let mut merged = (**left).clone();
+        merged.extend(right.iter().map(|(code, s)| (*code, s.clone())));
+        return MergedCategories::Shared(Arc::new(merged));
+    }
+
+    let codes = left
+        .iter()
+        .map(|(code, s)| (s.as_str(), *code))
+        .collect::<AHashMap<_, _>>();
+    let mut merged = (**left).clone();
+    let mut next_code = left.keys().max().map_or(0, |code| code + 1);
+    let remap = right
+        .iter()
+        .map(|(code, s)| {
+            let new_code = match codes.get(s.as_str()) {
+                Some(new_code) => *new_code,
+                None => {
+                    let new_code = next_code;
+                    next_code += 1;
+                    merged.insert(new_code, s.clone());
+                    new_code
+                }
+            };
+            (*code, new_code)
+        })
+        .collect();
+    MergedCategories::Remapped(Arc::new(merged), remap)
+}
+
+impl Utf8Chunked {
+    /// Convert to a categorical array with codes assigned in the given order. Null values stay
+    /// null and are not counted as a category.
+    ///
+    /// With the global string cache enabled the codes are shared between arrays and only
+    /// [CategoricalOrdering::Appearance] is supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let ca = Utf8Chunked::new_from_slice("a", &["b", "a", "a"]);
+    /// let cat = ca.to_categorical(CategoricalOrdering::Frequency).unwrap();
+    /// assert_eq!(Vec::from(&*cat), &[Some(1), Some(0), Some(0)]);
+    /// ```
+    pub fn to_categorical(&self, ordering: CategoricalOrdering) -> Result<CategoricalChunked> {
+        match ordering {
+            CategoricalOrdering::Appearance => self.cast::<CategoricalType>(),
+            CategoricalOrdering::Frequency if use_string_cache() => {
+                Err(PolarsError::InvalidOperation(
+                    "frequency ordering of categories is not supported with the global string cache"
+                        .into(),
+                ))
+            }
+            CategoricalOrdering::Frequency => {
+                // count and first appearance per category
+                let mut counts: AHashMap<&str, (usize, usize)> = AHashMap::new();
+                for (idx, s) in self.into_iter().enumerate() {
+                    if let Some(s) = s {
+                        counts.entry(s).or_insert((0, idx)).0 += 1;
+                    }
+                }
+                let mut categories = counts.into_iter().collect::<Vec<_>>();
+                categories.sort_unstable_by_key(|(_, (count, first))| (std::cmp::Reverse(*count), *first));
+
+                let codes = categories
+                    .iter()
+                    .enumerate()
+                    .map(|(code, (s, _))| (*s, code as u32))
+                    .collect::<AHashMap<_, _>>();
+                let mut ca: UInt32Chunked = self
+                    .into_iter()
+                    .map(|opt_s| opt_s.map(|s| codes[s]))
+                    .collect();
+                ca.rename(self.name());
+                let mut ca: CategoricalChunked = ca.into();
+                ca.categorical_map = Some(Arc::new(
+                    codes
+                        .into_iter()
+                        .map(|(s, code)| (code, s.to_string()))
+                        .collect(),
+                ));
+                Ok(ca)
+            }
+        }
+    }
+}
+
+impl CategoricalChunked {
+    /// Check if the codes of both arrays refer to the same categories, so the arrays can be
+    /// compared by their codes.
+    pub(crate) fn codes_comparable(&self, other: &CategoricalChunked) -> bool {
+        match (&self.categorical_map, &other.categorical_map) {
+            (Some(left), Some(right)) => {
+                Arc::ptr_eq(left, right) || categories_compatible(left, right)
+            }
+            _ => true,
+        }
+    }
+
+    /// The categories as a Utf8 array, ordered by code. With the global string cache enabled
+    /// only the categories that occur in this array are returned.
+    pub fn get_categories(&self) -> Utf8Chunked {
+        let mut categories = match &self.categorical_map {
+            Some(map) => map.iter().collect::<Vec<_>>(),
+            None => vec![],
+        };
+        categories.sort_unstable_by_key(|(code, _)| **code);
+        let mut builder = Utf8ChunkedBuilder::new(
+            self.name(),
+            categories.len(),
+            categories.iter().map(|(_, s)| s.len()).sum(),
+        );
+        for (_, s) in categories {
+            builder.append_value(s);
+        }
+        builder.finish()
+    }
+}
+
+/// Operations on a Series of dtype Categorical. Created with
+/// [Series::cat](crate::series::Series::cat).
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// fn categories(s: &Series) -> Result<Series> {
+///     s.cat().map(|cat| cat.get_categories())
+/// }
+/// ```
+pub struct CategoricalNameSpace<'a>(pub(crate) &'a CategoricalChunked);
+
+impl<'a> CategoricalNameSpace<'a> {
+    /// The categories as a Utf8 Series, the category with code `i` is at index `i`.
+    pub fn get_categories(&self) -> Series {
+        self.0.get_categories().into_series()
+    }
+}
+
+impl<'a> Deref for CategoricalNameSpace<'a> {
+    type Target = CategoricalChunked;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_categorical_frequency_ordering() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "a",
+            &[Some("x"), Some("y"), None, Some("z"), Some("y"), Some("z")],
+        );
+
+        let cat = ca.to_categorical(CategoricalOrdering::Appearance)?;
+        assert_eq!(
+            Vec::from(&*cat),
+            &[Some(0), Some(1), None, Some(2), Some(1), Some(2)]
+        );
+
+        // y and z both occur twice, y first
+        let cat = ca.to_categorical(CategoricalOrdering::Frequency)?;
+        assert_eq!(cat.len(), 6);
+        assert_eq!(
+            Vec::from(&*cat),
+            &[Some(2), Some(0), None, Some(1), Some(0), Some(1)]
+        );
+        let s = cat.into_series();
+        let categories = s.cat()?.get_categories();
+        assert_eq!(categories.dtype(), &DataType::Utf8);
+        assert_eq!(
+            Vec::from(categories.utf8()?),
+            &[Some("y"), Some("z"), Some("x")]
+        );
+
+        // the values are unchanged
+        let back = s.cast::<Utf8Type>()?;
+        assert!(back.series_equal_missing(&ca.into_series()));
+
+        assert!(Series::new("a", &[1, 2]).cat().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_categoricals_without_string_cache() -> Result<()> {
+        // the same categories get different codes
+        let a = Utf8Chunked::new_from_slice("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let b = Utf8Chunked::new_from_opt_slice("a", &[Some("y"), None, Some("z")])
+            .cast::<CategoricalType>()?;
+        assert!(!a.codes_comparable(&b));
+
+        let mut appended = a.clone();
+        appended.append(&b);
+        let appended = appended.into_series().cast::<Utf8Type>()?;
+        assert_eq!(
+            Vec::from(appended.utf8()?),
+            &[Some("x"), Some("y"), Some("y"), None, Some("z")]
+        );
+
+        let left = DataFrame::new(vec![a.into_series()])?;
+        let right = DataFrame::new(vec![b.into_series(), Series::new("value", &[1, 2, 3])])?;
+        let joined = left.inner_join(&right, "a", "a")?;
+        assert_eq!(joined.height(), 1);
+        assert_eq!(joined.column("value")?.i32()?.get(0), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_string_cache() -> Result<()> {
+        let (a, b) = crate::with_string_cache(|| {
+            let a = Utf8Chunked::new_from_slice("a", &["x", "y"]).cast::<CategoricalType>();
+            let b = Utf8Chunked::new_from_slice("a", &["y", "z"]).cast::<CategoricalType>();
+            (a, b)
+        });
+        assert!(!crate::use_string_cache());
+        let (a, b) = (a?, b?);
+        assert!(a.codes_comparable(&b));
+        // y has the same code in both arrays
+        assert_eq!(a.get(1), b.get(0));
+        let mut appended = a;
+        appended.append(&b);
+        assert_eq!(appended.get_categorical_map().unwrap().len(), 3);
+        Ok(())
+    }
+}
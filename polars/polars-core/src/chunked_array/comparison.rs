@@ -1,4 +1,5 @@
-use crate::{prelude::*, utils::NoNull};
+use crate::prelude::*;
+use crate::utils::{align_chunks_binary, NoNull};
 use arrow::compute::*;
 use arrow::{
     array::{ArrayRef, BooleanArray, LargeStringArray, PrimitiveArray},
@@ -6,6 +7,7 @@ use arrow::{
     compute::kernels::comparison,
 };
 use num::{Num, NumCast, ToPrimitive};
+use polars_arrow::kernels::binary::compare_no_null;
 use std::ops::{BitAnd, BitOr, Not};
 use std::sync::Arc;
 
@@ -13,6 +15,35 @@ impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
 {
+    /// Compare a sorted integer array with a scalar by binary searching for the position at which
+    /// `op` flips. `op` must be monotone (e.g. `v > rhs`), so that it holds for either a prefix or
+    /// a suffix of the sorted values. Returns `None` if the fast path doesn't apply.
+    fn sorted_compare_scalar(&self, op: impl Fn(T::Native) -> bool) -> Option<BooleanChunked> {
+        // floats are excluded, NaN is sorted last but compares false
+        if self.is_sorted() == IsSorted::Not
+            || matches!(T::get_dtype(), DataType::Float32 | DataType::Float64)
+        {
+            return None;
+        }
+        let values = self.cont_slice().ok()?;
+        let first = *values.first()?;
+        let prefix = op(first);
+        // the first index at which `op` no longer equals `prefix`
+        let idx = values
+            .binary_search_by(|v| {
+                if op(*v) == prefix {
+                    std::cmp::Ordering::Less
+                } else {
+                    std::cmp::Ordering::Greater
+                }
+            })
+            .unwrap_err();
+        let mut ca: NoNull<BooleanChunked> =
+            (0..values.len()).map(|i| (i < idx) == prefix).collect();
+        ca.rename(self.name());
+        Some(ca.into_inner())
+    }
+
     /// First ensure that the chunks of lhs and rhs match and then iterates over the chunks and applies
     /// the comparison operator.
     fn comparison(
@@ -36,6 +67,23 @@ where
 
         Ok(ChunkedArray::new_from_chunks("", chunks))
     }
+
+    /// Compare arrays of equal length without null values of which the chunks don't match. The
+    /// chunks are aligned first and the comparison is done on the value slices.
+    fn comparison_no_null(
+        &self,
+        rhs: &ChunkedArray<T>,
+        op: impl Fn(T::Native, T::Native) -> bool + Copy,
+    ) -> BooleanChunked {
+        let (lhs, rhs) = align_chunks_binary(self, rhs);
+        let chunks = lhs
+            .downcast_chunks()
+            .iter()
+            .zip(rhs.downcast_chunks())
+            .map(|(lhs, rhs)| Arc::new(compare_no_null(lhs, rhs, op)) as ArrayRef)
+            .collect();
+        ChunkedArray::new_from_chunks("", chunks)
+    }
 }
 
 macro_rules! impl_eq_missing {
@@ -61,7 +109,12 @@ where
     T::Native: NumCast + NumComp + ToPrimitive,
 {
     fn eq_missing(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
-        impl_eq_missing!(self, rhs)
+        // without nulls this is the same as `eq`, which uses the comparison kernels
+        if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.eq(rhs)
+        } else {
+            impl_eq_missing!(self, rhs)
+        }
     }
 
     fn eq(&self, rhs: &ChunkedArray<T>) -> BooleanChunked {
@@ -78,6 +131,12 @@ where
             // should not fail if arrays are equal
             self.comparison(rhs, comparison::eq)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l == r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::eq)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, ==)
         }
@@ -96,6 +155,12 @@ where
         else if self.chunk_id == rhs.chunk_id {
             self.comparison(rhs, comparison::neq)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l != r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::neq)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, !=)
         }
@@ -114,6 +179,12 @@ where
         else if self.chunk_id == rhs.chunk_id {
             self.comparison(rhs, comparison::gt)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l > r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::gt)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, >)
         }
@@ -132,6 +203,12 @@ where
         else if self.chunk_id == rhs.chunk_id {
             self.comparison(rhs, comparison::gt_eq)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l >= r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::gt_eq)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, >=)
         }
@@ -150,6 +227,12 @@ where
         else if self.chunk_id == rhs.chunk_id {
             self.comparison(rhs, comparison::lt)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l < r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::lt)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, <)
         }
@@ -168,6 +251,12 @@ where
         else if self.chunk_id == rhs.chunk_id {
             self.comparison(rhs, comparison::lt_eq)
                 .expect("should not fail.")
+        } else if self.len() == rhs.len() && self.null_count() + rhs.null_count() == 0 {
+            self.comparison_no_null(rhs, |l, r| l <= r)
+        } else if self.len() == rhs.len() {
+            let (lhs, rhs) = align_chunks_binary(self, rhs);
+            lhs.comparison(&rhs, comparison::lt_eq)
+                .expect("should not fail.")
         } else {
             apply_operand_on_chunkedarray_by_iter!(self, rhs, <=)
         }
@@ -450,22 +539,38 @@ where
     }
 
     fn gt(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(ca) = self.sorted_compare_scalar(|v| v > rhs) {
+            return ca;
+        }
         self.apply_kernel_cast(|arr| Arc::new(gt_scalar(arr, rhs).unwrap()))
     }
 
     fn gt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(ca) = self.sorted_compare_scalar(|v| v >= rhs) {
+            return ca;
+        }
         self.apply_kernel_cast(|arr| Arc::new(gt_eq_scalar(arr, rhs).unwrap()))
     }
 
     fn lt(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(ca) = self.sorted_compare_scalar(|v| v < rhs) {
+            return ca;
+        }
         self.apply_kernel_cast(|arr| Arc::new(lt_scalar(arr, rhs).unwrap()))
     }
 
     fn lt_eq(&self, rhs: Rhs) -> BooleanChunked {
-        let rhs = NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        let rhs: T::Native =
+            NumCast::from(rhs).expect("could not cast to underlying chunkedarray type");
+        if let Some(ca) = self.sorted_compare_scalar(|v| v <= rhs) {
+            return ca;
+        }
         self.apply_kernel_cast(|arr| Arc::new(lt_eq_scalar(arr, rhs).unwrap()))
     }
 }
@@ -593,6 +698,9 @@ macro_rules! impl_bitwise_op  {
         if $self.chunk_id == $rhs.chunk_id {
             let result = $self.bit_operation($rhs, compute::$arrow_method);
             result.unwrap()
+        } else if $self.len() == $rhs.len() {
+            let (lhs, rhs) = align_chunks_binary($self, $rhs);
+            lhs.bit_operation(&rhs, compute::$arrow_method).unwrap()
         } else {
             let ca = $self
                 .into_iter()
@@ -955,4 +1063,23 @@ mod test {
         assert_eq!(a1.lt_eq(&a2).sum(), a2.lt_eq(&a1).sum());
         assert_eq!(a1.gt_eq(&a2).sum(), a2.gt_eq(&a1).sum());
     }
+
+    #[test]
+    fn test_compare_different_lengths() {
+        // different chunk ids and no nulls, but the lengths don't match
+        let a = Int32Chunked::new_from_slice("a", &[2]);
+        let mut b = Int32Chunked::new_from_slice("b", &[1, 2]);
+        b.append(&Int32Chunked::new_from_slice("b", &[3]));
+        assert_eq!(a.eq(&b).get(0), Some(false));
+        assert_eq!(a.neq(&b).get(0), Some(true));
+        assert_eq!(a.gt(&b).get(0), Some(true));
+        assert_eq!(a.gt_eq(&b).get(0), Some(true));
+        assert_eq!(a.lt(&b).get(0), Some(false));
+        assert_eq!(a.lt_eq(&b).get(0), Some(false));
+        // the length 1 rhs is broadcast
+        assert_eq!(
+            Vec::from(&b.gt(&a)),
+            &[Some(false), Some(false), Some(true)]
+        );
+    }
 }
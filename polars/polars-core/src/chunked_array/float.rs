@@ -24,6 +24,37 @@ impl IntegerDecode for f32 {
     }
 }
 
+/// Bit representation of a float that is equal for values that are equal in the total order
+/// used for sorting: all NaNs map to the same bits, as do `0.0` and `-0.0`.
+/// Used to hash floats in joins and groupby operations.
+pub(crate) trait ToCanonicalBits {
+    type Bits;
+
+    fn to_canonical_bits(self) -> Self::Bits;
+}
+
+macro_rules! impl_to_canonical_bits {
+    ($float:ty, $bits:ty) => {
+        impl ToCanonicalBits for $float {
+            type Bits = $bits;
+
+            #[inline]
+            fn to_canonical_bits(self) -> $bits {
+                if self.is_nan() {
+                    <$float>::NAN.to_bits()
+                } else if self == 0.0 {
+                    (0.0 as $float).to_bits()
+                } else {
+                    self.to_bits()
+                }
+            }
+        }
+    };
+}
+
+impl_to_canonical_bits!(f32, u32);
+impl_to_canonical_bits!(f64, u64);
+
 fn process_float<T>(
     val: T,
     u64_builder: &mut PrimitiveChunkedBuilder<UInt64Type>,
@@ -1,7 +1,7 @@
 use crate::datatypes::CategoricalChunked;
 use crate::prelude::{
-    BooleanChunked, ChunkedArray, Downcast, ListChunked, PolarsNumericType, Series, UnsafeValue,
-    Utf8Chunked,
+    BooleanChunked, BooleanType, ChunkedArray, Downcast, ListChunked, PolarsNumericType, Series,
+    UnsafeValue, Utf8Chunked, Utf8Type,
 };
 use arrow::array::{
     Array, ArrayDataRef, ArrayRef, BooleanArray, LargeListArray, LargeStringArray, PrimitiveArray,
@@ -39,6 +39,134 @@ pub trait IntoNoNullIterator {
     fn into_no_null_iter(self) -> Self::IntoIter;
 }
 
+/// Trait for ChunkedArrays that can be iterated by value. The iterator owns the array and yields
+/// owned values, so it can outlive the array it was created from, e.g. when it is returned from a
+/// function or moved to another thread.
+///
+/// `ChunkedArray` doesn't implement `IntoIterator` by value, as `ca.into_iter()` is used to
+/// iterate by reference.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// fn words(ca: Utf8Chunked) -> impl Iterator<Item = Option<String>> {
+///     ca.into_owned_iter()
+/// }
+/// ```
+pub trait IntoOwnedIterator {
+    type Item;
+    type IntoIter: Iterator<Item = Self::Item>;
+
+    fn into_owned_iter(self) -> Self::IntoIter;
+}
+
+/// Iterator that owns a ChunkedArray, see [IntoOwnedIterator].
+pub struct OwnedIter<T> {
+    ca: ChunkedArray<T>,
+    chunk_idx: usize,
+    idx: usize,
+    remaining: usize,
+}
+
+impl<T> OwnedIter<T> {
+    fn new(ca: ChunkedArray<T>) -> Self {
+        let remaining = ca.len();
+        OwnedIter {
+            ca,
+            chunk_idx: 0,
+            idx: 0,
+            remaining,
+        }
+    }
+
+    /// The chunk and the index in that chunk of the next value.
+    fn next_position(&mut self) -> Option<(&ArrayRef, usize)> {
+        while let Some(arr) = self.ca.chunks.get(self.chunk_idx) {
+            if self.idx < arr.len() {
+                let idx = self.idx;
+                self.idx += 1;
+                self.remaining -= 1;
+                return Some((arr, idx));
+            }
+            self.chunk_idx += 1;
+            self.idx = 0;
+        }
+        None
+    }
+}
+
+impl<T> Iterator for OwnedIter<T>
+where
+    T: PolarsNumericType,
+{
+    type Item = Option<T::Native>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (arr, idx) = self.next_position()?;
+        let arr = arr.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        if arr.is_null(idx) {
+            Some(None)
+        } else {
+            Some(Some(arr.value(idx)))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl Iterator for OwnedIter<BooleanType> {
+    type Item = Option<bool>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (arr, idx) = self.next_position()?;
+        let arr = arr.as_any().downcast_ref::<BooleanArray>().unwrap();
+        if arr.is_null(idx) {
+            Some(None)
+        } else {
+            Some(Some(arr.value(idx)))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl Iterator for OwnedIter<Utf8Type> {
+    type Item = Option<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (arr, idx) = self.next_position()?;
+        let arr = arr.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        if arr.is_null(idx) {
+            Some(None)
+        } else {
+            Some(Some(arr.value(idx).to_string()))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for OwnedIter<T> where OwnedIter<T>: Iterator {}
+
+impl<T> IntoOwnedIterator for ChunkedArray<T>
+where
+    OwnedIter<T>: Iterator,
+{
+    type Item = <OwnedIter<T> as Iterator>::Item;
+    type IntoIter = OwnedIter<T>;
+
+    fn into_owned_iter(self) -> Self::IntoIter {
+        OwnedIter::new(self)
+    }
+}
+
 /// Wrapper strunct to convert an iterator of type `T` into one of type `Option<T>`.  It is useful to make the
 /// `IntoIterator` trait, in which every iterator shall return an `Option<T>`.
 pub struct SomeIterator<I>(I)
@@ -1460,6 +1588,28 @@ impl_all_iterators!(
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_owned_iter() {
+        let mut a = Int32Chunked::new_from_opt_slice("a", &[Some(1), None]);
+        a.append(&Int32Chunked::new_from_slice("a", &[3]));
+        let it = a.clone().into_owned_iter();
+        assert_eq!(it.len(), 3);
+        assert_eq!(it.collect::<Vec<_>>(), Vec::from(&a));
+
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("x"), None, Some("y")]);
+        let handle = std::thread::spawn(move || ca.into_owned_iter().collect::<Vec<_>>());
+        assert_eq!(
+            handle.join().unwrap(),
+            &[Some("x".to_string()), None, Some("y".to_string())]
+        );
+
+        let ca = BooleanChunked::new_from_slice("a", &[true, false]);
+        assert_eq!(
+            ca.into_owned_iter().collect::<Vec<_>>(),
+            &[Some(true), Some(false)]
+        );
+    }
+
     #[test]
     fn out_of_bounds() {
         let mut a = UInt32Chunked::new_from_slice("a", &[1, 2, 3]);
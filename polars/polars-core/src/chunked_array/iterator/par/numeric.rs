@@ -7,6 +7,7 @@ use arrow::array::Array;
 use rayon::iter::plumbing::*;
 use rayon::iter::plumbing::{Consumer, ProducerCallback};
 use rayon::prelude::*;
+use std::ops::Deref;
 
 /// Generate the code for body of a parallel iterator based on the associated sequential iterator.
 /// It implements the trait methods.
@@ -656,6 +657,16 @@ where
     }
 }
 
+/// Convert a `CategoricalChunked` into a `ParallelIterator` over its `u32` codes.
+impl<'a> IntoParallelIterator for &'a CategoricalChunked {
+    type Iter = NumParIterDispatcher<'a, UInt32Type>;
+    type Item = Option<u32>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.deref().into_par_iter()
+    }
+}
+
 impl<'a, T> ParallelIterator for NumParIterDispatcher<'a, T>
 where
     T: PolarsNumericType + Send + Sync,
@@ -821,6 +832,14 @@ mod test {
     /// The size of the chunked array used in tests.
     const UINT32_CHUNKED_ARRAY_SIZE: usize = 10;
 
+    #[test]
+    fn categorical_par_iter() {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("x"), None, Some("y"), Some("x")]);
+        let ca = ca.cast::<CategoricalType>().unwrap();
+        let par_result = ca.into_par_iter().collect::<Vec<_>>();
+        assert_eq!(par_result, ca.into_iter().collect::<Vec<_>>());
+    }
+
     /// Generates a `Vec` of `u32`, where every position is the `u32` representation of its index.
     fn generate_uint32_vec(size: usize) -> Vec<u32> {
         (0..size).map(|n| n as u32).collect()
@@ -6,9 +6,20 @@ use crate::chunked_array::{
 };
 use crate::prelude::*;
 use arrow::array::{ArrayRef, PrimitiveArray};
-use chrono::{Datelike, Timelike};
+use chrono::{Datelike, NaiveDateTime, Timelike};
 use std::sync::Arc;
 
+trait WeekdayNumber {
+    /// The day of the week starting with 0 for Monday.
+    fn weekday_number(&self) -> u32;
+}
+
+impl WeekdayNumber for NaiveDateTime {
+    fn weekday_number(&self) -> u32 {
+        self.weekday().num_days_from_monday()
+    }
+}
+
 pub fn date32_as_duration(arr: &PrimitiveArray<Date32Type>) -> ArrayRef {
     let vals = arr.values();
     let (null_count, null_bit_buffer) = get_bitmap(arr);
@@ -75,6 +86,13 @@ to_temporal_unit!(
     Date32Type,
     UInt32Type
 );
+to_temporal_unit!(
+    date32_to_weekday,
+    weekday_number,
+    date32_as_datetime,
+    Date32Type,
+    UInt32Type
+);
 to_temporal_unit!(
     date32_to_ordinal,
     ordinal,
@@ -104,6 +122,13 @@ to_temporal_unit!(
     Date64Type,
     UInt32Type
 );
+to_temporal_unit!(
+    date64_to_weekday,
+    weekday_number,
+    date64_as_datetime,
+    Date64Type,
+    UInt32Type
+);
 to_temporal_unit!(
     date64_to_hour,
     hour,
@@ -2,6 +2,7 @@
 use crate::chunked_array::builder::{
     aligned_vec_to_primitive_array, build_with_existing_null_bitmap_and_slice, get_bitmap,
 };
+use crate::chunked_array::categorical::{merge_categories, MergedCategories};
 use crate::prelude::*;
 use arrow::{
     array::{
@@ -24,6 +25,7 @@ pub mod arithmetic;
 pub mod boolean;
 pub mod builder;
 pub mod cast;
+pub mod categorical;
 pub mod comparison;
 pub mod float;
 pub mod iterator;
@@ -56,16 +58,20 @@ use arrow::array::{
 use ahash::AHashMap;
 use arrow::util::bit_util::{get_bit, round_upto_power_of_2};
 use polars_arrow::array::ValueSize;
+use smallvec::{smallvec, SmallVec};
 use std::mem;
 use std::ops::{Deref, DerefMut};
 
+/// The arrays of a ChunkedArray. Most arrays have a single chunk, which is stored inline so that
+/// creating and cloning the ChunkedArray doesn't need an allocation.
+pub(crate) type Chunks = SmallVec<[ArrayRef; 1]>;
+
+/// The lengths of the chunks, stored inline for a single chunk like [Chunks].
+pub(crate) type ChunkLengths = SmallVec<[usize; 1]>;
+
 /// Get a 'hash' of the chunks in order to compare chunk sizes quickly.
-fn create_chunk_id(chunks: &[ArrayRef]) -> Vec<usize> {
-    let mut chunk_id = Vec::with_capacity(chunks.len());
-    for a in chunks {
-        chunk_id.push(a.len())
-    }
-    chunk_id
+fn create_chunk_id(chunks: &[ArrayRef]) -> ChunkLengths {
+    chunks.iter().map(|a| a.len()).collect()
 }
 
 /// # ChunkedArray
@@ -159,12 +165,38 @@ fn create_chunk_id(chunks: &[ArrayRef]) -> Vec<usize> {
 /// multiple append operations.
 pub struct ChunkedArray<T> {
     pub(crate) field: Arc<Field>,
-    pub(crate) chunks: Vec<ArrayRef>,
+    pub(crate) chunks: Chunks,
     // chunk lengths
-    chunk_id: Vec<usize>,
+    chunk_id: ChunkLengths,
     phantom: PhantomData<T>,
     /// maps categorical u32 indexes to String values
     pub(crate) categorical_map: Option<Arc<AHashMap<u32, String>>>,
+    pub(crate) sorted: IsSorted,
+}
+
+/// Whether the values of a [ChunkedArray] are known to be sorted. Null values are ignored, so
+/// an array with nulls can be flagged as sorted if its non null values are.
+///
+/// The flag is set by operations that produce sorted output, like
+/// [sort](crate::chunked_array::ops::ChunkSort::sort), and is used to take fast paths,
+/// e.g. in `min`/`max`, comparisons with a scalar and joins.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IsSorted {
+    Ascending,
+    Descending,
+    /// Not sorted or unknown.
+    Not,
+}
+
+impl IsSorted {
+    /// The flag of the array after reversing it.
+    pub fn reverse(self) -> Self {
+        match self {
+            IsSorted::Ascending => IsSorted::Descending,
+            IsSorted::Descending => IsSorted::Ascending,
+            IsSorted::Not => IsSorted::Not,
+        }
+    }
 }
 
 impl<T> ChunkedArray<T> {
@@ -173,6 +205,17 @@ impl<T> ChunkedArray<T> {
         self.chunks.iter().map(|arr| arr.data()).collect()
     }
 
+    /// Whether the values are known to be sorted.
+    pub fn is_sorted(&self) -> IsSorted {
+        self.sorted
+    }
+
+    /// Flag the values as sorted. This is not checked, setting a wrong flag leads to wrong
+    /// results of the operations that use it.
+    pub fn set_sorted(&mut self, sorted: IsSorted) {
+        self.sorted = sorted
+    }
+
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_categorical_map(&self) -> Option<&Arc<AHashMap<u32, String>>> {
         self.categorical_map.as_ref()
@@ -242,12 +285,12 @@ impl<T> ChunkedArray<T> {
     }
 
     /// Unique id representing the number of chunks
-    pub fn chunk_id(&self) -> &Vec<usize> {
+    pub fn chunk_id(&self) -> &[usize] {
         &self.chunk_id
     }
 
     /// A reference to the chunks
-    pub fn chunks(&self) -> &Vec<ArrayRef> {
+    pub fn chunks(&self) -> &[ArrayRef] {
         &self.chunks
     }
 
@@ -285,6 +328,7 @@ impl<T> ChunkedArray<T> {
         if self.field.data_type() == other.data_type() {
             self.chunks.push(other);
             self.chunk_id = create_chunk_id(&self.chunks);
+            self.sorted = IsSorted::Not;
             Ok(())
         } else {
             Err(PolarsError::DataTypeMisMatch(
@@ -299,7 +343,7 @@ impl<T> ChunkedArray<T> {
     }
 
     /// Create a new ChunkedArray from self, where the chunks are replaced.
-    fn copy_with_chunks(&self, chunks: Vec<ArrayRef>) -> Self {
+    fn copy_with_chunks(&self, chunks: Chunks) -> Self {
         let chunk_id = create_chunk_id(&chunks);
         ChunkedArray {
             field: self.field.clone(),
@@ -307,7 +351,33 @@ impl<T> ChunkedArray<T> {
             chunk_id,
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            sorted: IsSorted::Not,
+        }
+    }
+
+    /// Split the chunks such that their lengths are equal to `chunk_id`. This doesn't copy data,
+    /// so every chunk boundary of this array must also be a boundary in `chunk_id`.
+    pub(crate) fn split_chunks(&self, chunk_id: &[usize]) -> Self {
+        let mut chunks = Chunks::with_capacity(chunk_id.len());
+        let mut arrays = self.chunks.iter().filter(|arr| !arr.is_empty());
+        let mut current = arrays.next();
+        let mut offset = 0;
+        for &len in chunk_id {
+            let arr = current.expect("chunk lengths should add up to the length of the array");
+            assert!(
+                offset + len <= arr.len(),
+                "chunks can only be split at existing chunk boundaries"
+            );
+            chunks.push(arr.slice(offset, len));
+            offset += len;
+            if offset == arr.len() {
+                current = arrays.next();
+                offset = 0;
+            }
         }
+        let mut ca = self.copy_with_chunks(chunks);
+        ca.sorted = self.sorted;
+        ca
     }
 
     /// Slice the array. The chunks are reallocated the underlying data slices are zero copy.
@@ -317,7 +387,7 @@ impl<T> ChunkedArray<T> {
         }
         let mut remaining_length = length;
         let mut remaining_offset = offset;
-        let mut new_chunks = vec![];
+        let mut new_chunks = Chunks::new();
 
         for chunk in &self.chunks {
             let chunk_len = chunk.len();
@@ -339,7 +409,15 @@ impl<T> ChunkedArray<T> {
                 break;
             }
         }
-        Ok(self.copy_with_chunks(new_chunks))
+        // an empty slice keeps an empty chunk, kernels expect at least one chunk
+        if new_chunks.is_empty() {
+            if let Some(chunk) = self.chunks.first() {
+                new_chunks.push(chunk.slice(0, 0));
+            }
+        }
+        let mut ca = self.copy_with_chunks(new_chunks);
+        ca.sorted = self.sorted;
+        Ok(ca)
     }
 
     /// Get a mask of the null values.
@@ -417,18 +495,36 @@ impl<T> ChunkedArray<T> {
     where
         Self: std::marker::Sized,
     {
-        if matches!(self.dtype(), DataType::Categorical) && !self.is_empty() {
-            assert!(Arc::ptr_eq(
-                self.categorical_map.as_ref().unwrap(),
-                other.categorical_map.as_ref().unwrap()
-            ));
+        if matches!(self.dtype(), DataType::Categorical) {
+            if self.is_empty() {
+                self.categorical_map = other.categorical_map.clone();
+            } else if let (Some(left), Some(right)) =
+                (&self.categorical_map, &other.categorical_map)
+            {
+                match merge_categories(left, right) {
+                    MergedCategories::Shared(map) => self.categorical_map = Some(map),
+                    // the codes of other refer to other categories, translate them to our codes
+                    MergedCategories::Remapped(map, remap) => {
+                        self.categorical_map = Some(map);
+                        let codes = UInt32Chunked::new_from_chunks("", other.chunks.to_vec());
+                        // the value under a null slot is arbitrary and may not be a code of other
+                        let codes = codes.apply(|code| remap.get(&code).copied().unwrap_or(code));
+                        self.chunks.extend_from_slice(&codes.chunks);
+                        self.chunk_id = create_chunk_id(&self.chunks);
+                        self.sorted = IsSorted::Not;
+                        return;
+                    }
+                }
+            }
         }
 
         // replace an empty array
         if self.chunks.len() == 1 && self.is_empty() {
             self.chunks = other.chunks.clone();
+            self.sorted = other.sorted;
         } else {
-            self.chunks.extend_from_slice(&other.chunks)
+            self.chunks.extend_from_slice(&other.chunks);
+            self.sorted = IsSorted::Not;
         }
         self.chunk_id = create_chunk_id(&self.chunks);
     }
@@ -451,33 +547,18 @@ impl<T> ChunkedArray<T> {
 
 impl<T> ChunkedArray<T>
 where
-    T: PolarsDataType,
-    ChunkedArray<T>: ChunkOps,
+    ChunkedArray<T>: VecHash,
 {
-    /// Should be used to match the chunk_id of another ChunkedArray.
-    /// # Panics
-    /// It is the callers responsibility to ensure that this ChunkedArray has a single chunk.
-    pub(crate) fn match_chunks(&self, chunk_id: &[usize]) -> Self {
-        debug_assert!(self.chunks.len() == 1);
-        // Takes a ChunkedArray containing a single chunk
-        let slice = |ca: &Self| {
-            let array = &ca.chunks[0];
-
-            let mut chunks = Vec::with_capacity(chunk_id.len());
-            let mut offset = 0;
-            for len in chunk_id {
-                chunks.push(array.slice(offset, *len));
-                offset += *len;
-            }
-            Self::new_from_chunks(self.name(), chunks)
-        };
-
-        if self.chunks.len() != 1 {
-            let out = self.rechunk();
-            slice(&out)
-        } else {
-            slice(self)
-        }
+    /// Hash all values, including null values, with a hasher seeded by `seed`.
+    ///
+    /// The hashes are deterministic: the same seed and values give the same hashes on every run,
+    /// and equal values give equal hashes. This makes them suitable for custom partitioning,
+    /// sampling and deduplication. The hash function is aHash with keys derived from the seed.
+    /// The hashes may change between Polars versions and may differ between CPU architectures.
+    pub fn hash(&self, seed: u64) -> UInt64Chunked {
+        let mut ca = self.vec_hash(crate::vector_hasher::seeded_random_state(seed));
+        ca.rename(self.name());
+        ca
     }
 }
 
@@ -501,10 +582,11 @@ where
         let chunk_id = create_chunk_id(&chunks);
         ChunkedArray {
             field,
-            chunks,
+            chunks: chunks.into(),
             chunk_id,
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 
@@ -621,10 +703,11 @@ where
         ));
         ChunkedArray {
             field: Arc::new(Field::new(name, T::get_dtype())),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 
@@ -643,10 +726,11 @@ where
         ));
         ChunkedArray {
             field: Arc::new(Field::new(name, T::get_dtype())),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -806,6 +890,7 @@ impl<T> Clone for ChunkedArray<T> {
             chunk_id: self.chunk_id.clone(),
             phantom: PhantomData,
             categorical_map: self.categorical_map.clone(),
+            sorted: self.sorted,
         }
     }
 }
@@ -868,7 +953,7 @@ impl Downcast<LargeListArray> for ListChunked {
 #[cfg(feature = "object")]
 impl<T> Downcast<ObjectArray<T>> for ObjectChunked<T>
 where
-    T: 'static + std::fmt::Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn downcast_chunks(&self) -> Vec<&ObjectArray<T>> {
         self.chunks
@@ -944,6 +1029,39 @@ pub(crate) mod test {
         ChunkedArray::new_from_slice("a", &[1, 2, 3])
     }
 
+    #[test]
+    fn test_hash() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(1), Some(2)]);
+        let hashes = ca.hash(0);
+        assert_eq!(hashes.name(), "a");
+        assert_eq!(hashes.get(0), hashes.get(2));
+        assert_ne!(hashes.get(0), hashes.get(3));
+        assert!(hashes.get(1).is_some());
+        assert_eq!(Vec::from(&hashes), Vec::from(&ca.hash(0)));
+        assert_ne!(hashes.get(0), ca.hash(1).get(0));
+    }
+
+    #[test]
+    fn test_chunk_lengths() {
+        let mut ca = get_chunked_array();
+        assert_eq!(ca.chunk_id(), &[3]);
+        assert_eq!(ca.slice(1, 2).unwrap().chunk_id(), &[2]);
+        assert_eq!(ca.cast::<Float64Type>().unwrap().chunk_id(), &[3]);
+
+        ca.append(&get_chunked_array());
+        assert_eq!(ca.chunks().len(), 2);
+        assert_eq!(ca.chunk_id(), &[3, 3]);
+        assert_eq!(Vec::from(&ca.slice(2, 2).unwrap()), &[Some(3), Some(1)]);
+        let clone = ca.clone();
+        assert_eq!(clone.chunk_id(), &[3, 3]);
+        assert_eq!(Vec::from(&clone), Vec::from(&ca));
+
+        let rechunked = ca.rechunk();
+        assert_eq!(rechunked.chunks().len(), 1);
+        assert_eq!(rechunked.chunk_id(), &[6]);
+        assert_eq!(Vec::from(&rechunked), Vec::from(&ca));
+    }
+
     #[test]
     fn test_sort() {
         let a = Int32Chunked::new_from_slice("a", &[1, 9, 3, 2]);
@@ -1051,6 +1169,9 @@ pub(crate) mod test {
         assert_slice_equal(&first.slice(3, 2).unwrap(), &[3, 4]);
         assert_slice_equal(&first.slice(3, 3).unwrap(), &[3, 4, 5]);
         assert!(first.slice(3, 4).is_err());
+        let empty = first.slice(6, 0).unwrap();
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.chunks().len(), 1);
     }
 
     #[test]
@@ -1116,4 +1237,31 @@ pub(crate) mod test {
         let v: Vec<_> = ca.into_iter().collect();
         assert_eq!(v, &[Some(0), None, Some(1), Some(2)]);
     }
+
+    #[test]
+    fn test_append_categorical_with_nulls() -> Result<()> {
+        use crate::chunked_array::builder::{aligned_vec_to_primitive_array, get_bitmap};
+        use arrow::array::ArrayRef;
+        use smallvec::smallvec;
+
+        let mut a = Utf8Chunked::new_from_slice("a", &["x", "y"]).cast::<CategoricalType>()?;
+        let mut b = Utf8Chunked::new_from_opt_slice("a", &[Some("y"), None, Some("z")])
+            .cast::<CategoricalType>()?;
+        // put a value that is not a code under the null slot
+        let (null_count, null_bit_buffer) = get_bitmap(&*b.chunks[0]);
+        let codes = aligned_vec_to_primitive_array::<UInt32Type>(
+            AlignedVec::new_from_slice(&[0, 99, 1]),
+            null_bit_buffer,
+            Some(null_count),
+        );
+        b.chunks = smallvec![Arc::new(codes) as ArrayRef];
+
+        a.append(&b);
+        let appended = a.into_series().cast::<Utf8Type>()?;
+        assert_eq!(
+            Vec::from(appended.utf8()?),
+            &[Some("x"), Some("y"), Some("y"), None, Some("z")]
+        );
+        Ok(())
+    }
 }
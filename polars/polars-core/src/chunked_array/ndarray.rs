@@ -41,7 +41,7 @@ impl ListChunked {
                 let mut row = ndarray.slice_mut(s![0, ..]);
                 row.assign(&a);
 
-                for series in iter {
+                for (row_idx, series) in iter.enumerate() {
                     if series.len() != width {
                         return Err(PolarsError::ShapeMisMatch(
                             "Could not create a 2D array. Series have different lengths".into(),
@@ -50,7 +50,7 @@ impl ListChunked {
                     let series = series.cast::<N>()?;
                     let ca = series.unpack::<N>()?;
                     let a = ca.to_ndarray()?;
-                    let mut row = ndarray.slice_mut(s![0, ..]);
+                    let mut row = ndarray.slice_mut(s![row_idx + 1, ..]);
                     row.assign(&a)
                 }
                 Ok(ndarray)
@@ -107,4 +107,85 @@ impl DataFrame {
         }
         Ok(ndarr)
     }
+    /// Create a `DataFrame` from a 2D `ndarray::Array`, or a view of one. Every column of the
+    /// array becomes a column of dtype `N`. The columns are named by `names` or `column_0`,
+    /// `column_1`, etc.
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use ndarray::array;
+    /// let arr = array![[1.0, 10.0], [2.0, 8.0], [3.0, 6.0]];
+    /// let df = DataFrame::from_ndarray::<Float64Type, _>(&arr, Some(&["a", "b"])).unwrap();
+    /// assert_eq!(df.shape(), (3, 2));
+    /// ```
+    pub fn from_ndarray<N, S>(
+        array: &ArrayBase<S, Ix2>,
+        names: Option<&[&str]>,
+    ) -> Result<DataFrame>
+    where
+        N: PolarsNumericType,
+        S: ndarray::Data<Elem = N::Native>,
+    {
+        let width = array.ncols();
+        if let Some(names) = names {
+            if names.len() != width {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "{} column names were given for an array with {} columns",
+                        names.len(),
+                        width
+                    )
+                    .into(),
+                ));
+            }
+        }
+        let columns = array
+            .gencolumns()
+            .into_iter()
+            .enumerate()
+            .map(|(i, col)| {
+                let name = match names {
+                    Some(names) => names[i].to_string(),
+                    None => format!("column_{}", i),
+                };
+                ChunkedArray::<N>::new_from_iter(&name, col.iter().copied()).into_series()
+            })
+            .collect();
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_ndarray_roundtrip() -> Result<()> {
+        let df = df! {
+            "a" => &[1u32, 2, 3],
+            "b" => &[10.0, 8.0, 6.0]
+        }?;
+        let arr = df.to_ndarray::<Float64Type>()?;
+        assert_eq!(arr, array![[1.0, 10.0], [2.0, 8.0], [3.0, 6.0]]);
+
+        let out = DataFrame::from_ndarray::<Float64Type, _>(&arr, Some(&["a", "b"]))?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[Some(10.0), Some(8.0), Some(6.0)]
+        );
+        let out = DataFrame::from_ndarray::<Float64Type, _>(&arr.view(), None)?;
+        assert_eq!(out.get_column_names(), &["column_0", "column_1"]);
+        assert!(DataFrame::from_ndarray::<Float64Type, _>(&arr, Some(&["a"])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_to_ndarray() -> Result<()> {
+        let list = Series::new("a", &[Series::new("", &[1, 2]), Series::new("", &[3, 4])]);
+        let arr = list.list()?.to_ndarray::<Int32Type>()?;
+        assert_eq!(arr, array![[1, 2], [3, 4]]);
+        Ok(())
+    }
 }
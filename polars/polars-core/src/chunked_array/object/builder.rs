@@ -2,6 +2,7 @@ use super::*;
 use crate::prelude::*;
 use crate::utils::get_iter_capacity;
 use arrow::bitmap::Bitmap;
+use smallvec::smallvec;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -13,7 +14,7 @@ pub struct ObjectChunkedBuilder<T> {
 
 impl<T> ObjectChunkedBuilder<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     pub fn new(name: &str, capacity: usize) -> Self {
         ObjectChunkedBuilder {
@@ -75,17 +76,18 @@ where
         });
         ChunkedArray {
             field: Arc::new(self.field),
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
 
 impl<T> Default for ObjectChunkedBuilder<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn default() -> Self {
         ObjectChunkedBuilder::new("", 0)
@@ -94,7 +96,7 @@ where
 
 impl<T> NewChunkedArray<ObjectType<T>, T> for ObjectChunked<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn new_from_slice(name: &str, v: &[T]) -> Self {
         Self::new_from_iter(name, v.iter().cloned())
@@ -125,7 +127,7 @@ where
 
 impl<T> ObjectChunked<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     pub fn new_from_vec(name: &str, v: Vec<T>) -> Self {
         let field = Arc::new(Field::new(name, DataType::Object));
@@ -141,10 +143,11 @@ where
 
         ObjectChunked {
             field,
-            chunks: vec![arr],
-            chunk_id: vec![len],
+            chunks: smallvec![arr as ArrayRef],
+            chunk_id: smallvec![len],
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -4,9 +4,52 @@ use arrow::array::{Array, ArrayDataRef, ArrayRef, BooleanBufferBuilder, JsonEqua
 use arrow::bitmap::Bitmap;
 use serde_json::Value;
 use std::any::Any;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::sync::Arc;
 
+/// Values that can be stored in an [ObjectChunked]. This is implemented for user types to carry
+/// arbitrary Rust values through a `DataFrame`.
+///
+/// Object columns can be filtered, taken, sliced, grouped and formatted, but they cannot be
+/// converted to arrow, so they cannot be written to a file.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use std::fmt;
+///
+/// #[derive(Debug, Clone, Default)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// impl fmt::Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "({}, {})", self.x, self.y)
+///     }
+/// }
+///
+/// impl PolarsObject for Point {}
+///
+/// let points = ObjectChunked::new_from_vec("points", vec![Point { x: 1.0, y: 2.0 }]);
+/// let df = DataFrame::new(vec![points.into_series()]).unwrap();
+/// ```
+pub trait PolarsObject: Any + Debug + Display + Clone + Send + Sync + Default {}
+
+/// Object safe access to a [PolarsObject]. A value can be downcast to the concrete type with
+/// `as_any`.
+pub trait PolarsObjectSafe: Any + Debug + Display + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: PolarsObject> PolarsObjectSafe for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ObjectArray<T>
 where
@@ -114,7 +157,7 @@ where
 
 impl<T> ObjectChunked<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     pub fn get_as_any(&self, index: usize) -> &dyn Any {
         let chunks = self.downcast_chunks();
@@ -122,18 +165,89 @@ where
         let arr = unsafe { *chunks.get_unchecked(chunk_idx) };
         arr.value(idx)
     }
+
+    /// Get the value at `index`. Returns `None` if the value is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get_object(&self, index: usize) -> Option<&T> {
+        assert!(index < self.len(), "index out of bounds");
+        let chunks = self.downcast_chunks();
+        let (chunk_idx, idx) = self.index_to_chunked_index(index);
+        let arr = chunks[chunk_idx];
+        if arr.is_valid(idx) {
+            Some(arr.value(idx))
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Foo(i32);
+
+    impl Display for Foo {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "foo {}", self.0)
+        }
+    }
+
+    impl PolarsObject for Foo {}
+
     #[test]
     fn object_series() {
-        let s = ObjectChunked::new_from_opt_slice("foo", &[Some(1), None, Some(3)]);
+        let s = ObjectChunked::new_from_opt_slice("foo", &[Some(Foo(1)), None, Some(Foo(3))]);
         assert_eq!(
             Vec::from(s.is_null()),
             &[Some(false), Some(true), Some(false)]
-        )
+        );
+        assert_eq!(s.get_object(0), Some(&Foo(1)));
+        assert_eq!(s.get_object(1), None);
+    }
+
+    #[test]
+    fn object_frame() -> Result<()> {
+        let objects = ObjectChunked::new_from_opt_slice(
+            "objects",
+            &[Some(Foo(1)), None, Some(Foo(3)), Some(Foo(4))],
+        );
+        let df = DataFrame::new(vec![
+            Series::new("key", &["a", "b", "a", "b"]),
+            objects.into_series(),
+        ])?;
+
+        let mask = BooleanChunked::new_from_slice("", &[true, false, true, true]);
+        let filtered = df.filter(&mask)?;
+        let s = filtered.column("objects")?;
+        assert_eq!(s.len(), 3);
+        let value = s.get_object(1).unwrap();
+        assert_eq!(value.as_any().downcast_ref::<Foo>(), Some(&Foo(3)));
+        assert_eq!(format!("{}", value), "foo 3");
+
+        let taken = df.take(&[3usize, 1]);
+        assert!(taken.column("objects")?.get_object(1).is_none());
+        assert!(format!("{:?}", taken.column("objects")?).contains("foo 4"));
+
+        let out = df
+            .groupby("key")?
+            .select("objects")
+            .first()?
+            .sort("key", false)?;
+        let first = out.column("objects_first")?;
+        assert_eq!(
+            first.get_object(0).unwrap().as_any().downcast_ref::<Foo>(),
+            Some(&Foo(1))
+        );
+        assert!(first.get_object(1).is_none());
+
+        // objects cannot be converted to arrow
+        assert!(df.check_arrow_compatible().is_err());
+        assert!(df.select("key")?.check_arrow_compatible().is_ok());
+        Ok(())
     }
 }
@@ -1,11 +1,13 @@
 //! Implementations of the ChunkAgg trait.
 use crate::chunked_array::builder::get_list_builder;
+use crate::chunked_array::ops::sort::compare_fn_nan_max;
 use crate::chunked_array::ChunkedArray;
 use crate::datatypes::BooleanChunked;
 use crate::{datatypes::PolarsNumericType, prelude::*, utils::CustomIterTools};
+use arrow::array::{Array, PrimitiveArray};
 use arrow::compute;
 use num::{Num, NumCast, ToPrimitive, Zero};
-use std::cmp::PartialOrd;
+use std::cmp::{Ordering, PartialOrd};
 
 /// Aggregations that return Series of unit length. Those can be used in broadcasting operations.
 pub trait ChunkAggSeries {
@@ -46,39 +48,185 @@ pub trait VarAggSeries {
     }
 }
 
-macro_rules! agg_float_with_nans {
-    ($self:ident, $agg_method:ident, $precision:ty) => {{
-        if $self.null_count() == 0 {
-            $self
-                .into_no_null_iter()
-                .map(|a| -> $precision { NumCast::from(a).unwrap() })
-                .fold_first_(|a, b| a.$agg_method(b))
-                .map(|a| NumCast::from(a).unwrap())
+/// Reduce every chunk with `agg` and fold the partial results with `fold`. Chunks for which
+/// `agg` returns `None` (e.g. because they only contain nulls) are skipped.
+#[cfg(not(feature = "parallel"))]
+fn agg_chunks<T, O, A, F>(ca: &ChunkedArray<T>, agg: A, fold: F) -> Option<O>
+where
+    T: PolarsNumericType,
+    O: Send,
+    A: Fn(&PrimitiveArray<T>) -> Option<O> + Send + Sync,
+    F: Fn(O, O) -> O + Send + Sync,
+{
+    ca.downcast_chunks()
+        .iter()
+        .filter_map(|&arr| agg(arr))
+        .fold_first_(fold)
+}
+
+/// Reduce every chunk with `agg` and fold the partial results with `fold`. Chunks for which
+/// `agg` returns `None` (e.g. because they only contain nulls) are skipped.
+///
+/// Arrays with multiple chunks are reduced in parallel on the global thread pool, so `fold`
+/// must be associative.
+#[cfg(feature = "parallel")]
+fn agg_chunks<T, O, A, F>(ca: &ChunkedArray<T>, agg: A, fold: F) -> Option<O>
+where
+    T: PolarsNumericType,
+    O: Send,
+    A: Fn(&PrimitiveArray<T>) -> Option<O> + Send + Sync,
+    F: Fn(O, O) -> O + Send + Sync,
+{
+    use rayon::prelude::*;
+    let chunks = ca.downcast_chunks();
+    if chunks.len() == 1 {
+        return agg(chunks[0]);
+    }
+    crate::POOL.install(|| {
+        chunks
+            .par_iter()
+            .filter_map(|&arr| agg(arr))
+            .reduce_with(fold)
+    })
+}
+
+/// Fold the valid values of a single array.
+fn fold_valid<T, F>(arr: &PrimitiveArray<T>, fold: F) -> Option<T::Native>
+where
+    T: PolarsNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    if arr.null_count() == 0 {
+        arr.values().iter().copied().fold_first_(fold)
+    } else {
+        (0..arr.len())
+            .filter(|&i| arr.is_valid(i))
+            .map(|i| arr.value(i))
+            .fold_first_(fold)
+    }
+}
+
+/// A compensated (Kahan-Babuska) sum. The rounding error of a naive float summation grows with
+/// the number of values, the error of a compensated sum does not.
+#[derive(Copy, Clone)]
+struct KahanSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl KahanSum {
+    fn new() -> Self {
+        KahanSum {
+            sum: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    fn add(mut self, v: f64) -> Self {
+        let t = self.sum + v;
+        // the low order bits that are lost in `t`
+        if self.sum.abs() >= v.abs() {
+            self.compensation += (self.sum - t) + v;
         } else {
-            $self
-                .into_iter()
-                .filter(|opt| opt.is_some())
-                .map(|opt| opt.unwrap())
-                .map(|a| -> $precision { NumCast::from(a).unwrap() })
-                .fold_first_(|a, b| a.$agg_method(b))
-                .map(|a| NumCast::from(a).unwrap())
+            self.compensation += (v - t) + self.sum;
         }
+        self.sum = t;
+        self
+    }
+
+    fn merge(self, other: KahanSum) -> Self {
+        let mut out = self.add(other.sum);
+        out.compensation += other.compensation;
+        out
+    }
+
+    fn value(self) -> f64 {
+        // the compensation is NaN once the sum is infinite
+        if self.sum.is_finite() {
+            self.sum + self.compensation
+        } else {
+            self.sum
+        }
+    }
+}
+
+/// The compensated sum of the valid values of a float array, in f64 for both float types.
+fn kahan_sum<T>(arr: &PrimitiveArray<T>) -> Option<KahanSum>
+where
+    T: PolarsNumericType,
+    T::Native: ToPrimitive,
+{
+    if arr.len() == arr.null_count() {
+        return None;
+    }
+    let to_f64 = |v: T::Native| v.to_f64().unwrap();
+    let sum = if arr.null_count() == 0 {
+        arr.values()
+            .iter()
+            .fold(KahanSum::new(), |acc, v| acc.add(to_f64(*v)))
+    } else {
+        (0..arr.len())
+            .filter(|&i| arr.is_valid(i))
+            .fold(KahanSum::new(), |acc, i| acc.add(to_f64(arr.value(i))))
+    };
+    Some(sum)
+}
+
+/// Min/max of float arrays, according to the total order used for sorting. With this order
+/// `NaN` is the maximum: `max` returns `NaN` if the array contains a `NaN`, and `min` only
+/// returns `NaN` if all (non-null) values are `NaN`.
+macro_rules! agg_float_with_nans {
+    ($self:ident, min) => {{
+        agg_float_with_nans!($self, Ordering::Less)
+    }};
+    ($self:ident, max) => {{
+        agg_float_with_nans!($self, Ordering::Greater)
+    }};
+    ($self:ident, $keep:expr) => {{
+        let fold = |a: T::Native, b: T::Native| {
+            if compare_fn_nan_max(&b, &a) == $keep {
+                b
+            } else {
+                a
+            }
+        };
+        agg_chunks($self, |arr| fold_valid(arr, fold), fold)
     }};
 }
 
+/// The min (`min == true`) or max of an array that is flagged as sorted and doesn't contain
+/// nulls, which is its first or last value. Returns `None` if the array doesn't qualify.
+fn sorted_min_max<T>(ca: &ChunkedArray<T>, min: bool) -> Option<T::Native>
+where
+    T: PolarsNumericType,
+{
+    if ca.is_empty() || ca.null_count() > 0 {
+        return None;
+    }
+    let first = match ca.is_sorted() {
+        IsSorted::Ascending => min,
+        IsSorted::Descending => !min,
+        IsSorted::Not => return None,
+    };
+    ca.get(if first { 0 } else { ca.len() - 1 })
+}
+
 macro_rules! impl_quantile {
     ($self:expr, $quantile:expr) => {{
         let null_count = $self.null_count();
-        let opt = ChunkSort::sort($self, false)
-            .slice(
-                ((($self.len() - null_count) as f64) * $quantile + null_count as f64) as usize,
-                1,
-            )
-            .unwrap()
-            .into_iter()
-            .next()
-            .unwrap();
-        opt
+        let n_values = $self.len() - null_count;
+        if n_values == 0 {
+            None
+        } else {
+            // the sorted nulls are in front; a quantile of 1.0 is the last value
+            let idx = std::cmp::min((n_values as f64 * $quantile) as usize, n_values - 1);
+            ChunkSort::sort($self, false)
+                .slice(idx + null_count, 1)
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap()
+        }
     }};
 }
 
@@ -87,40 +235,35 @@ where
     T: PolarsNumericType,
     T::Native: PartialOrd + Num + NumCast + Zero,
 {
+    /// Floats are summed with a compensated summation, so the sum of many small values doesn't
+    /// drift, at the cost of a slower summation.
     fn sum(&self) -> Option<T::Native> {
-        self.downcast_chunks()
-            .iter()
-            .map(|&a| compute::sum(a))
-            .fold(None, |acc, v| match v {
-                Some(v) => match acc {
-                    None => Some(v),
-                    Some(acc) => Some(acc + v),
-                },
-                None => acc,
-            })
+        match T::get_dtype() {
+            DataType::Float32 | DataType::Float64 => agg_chunks(self, kahan_sum, KahanSum::merge)
+                .map(|sum| NumCast::from(sum.value()).unwrap()),
+            _ => agg_chunks(self, compute::sum, |acc, v| acc + v),
+        }
     }
 
     fn min(&self) -> Option<T::Native> {
+        if let Some(v) = sorted_min_max(self, true) {
+            return Some(v);
+        }
         match T::get_dtype() {
-            DataType::Float32 => agg_float_with_nans!(self, min, f32),
-            DataType::Float64 => agg_float_with_nans!(self, min, f64),
-            _ => self
-                .downcast_chunks()
-                .iter()
-                .filter_map(|&a| compute::min(a))
-                .fold_first_(|acc, v| if acc < v { acc } else { v }),
+            DataType::Float32 => agg_float_with_nans!(self, min),
+            DataType::Float64 => agg_float_with_nans!(self, min),
+            _ => agg_chunks(self, compute::min, |acc, v| if acc < v { acc } else { v }),
         }
     }
 
     fn max(&self) -> Option<T::Native> {
+        if let Some(v) = sorted_min_max(self, false) {
+            return Some(v);
+        }
         match T::get_dtype() {
-            DataType::Float32 => agg_float_with_nans!(self, max, f32),
-            DataType::Float64 => agg_float_with_nans!(self, max, f64),
-            _ => self
-                .downcast_chunks()
-                .iter()
-                .filter_map(|&a| compute::max(a))
-                .fold_first_(|acc, v| if acc > v { acc } else { v }),
+            DataType::Float32 => agg_float_with_nans!(self, max),
+            DataType::Float64 => agg_float_with_nans!(self, max),
+            _ => agg_chunks(self, compute::max, |acc, v| if acc > v { acc } else { v }),
         }
     }
 
@@ -146,13 +289,177 @@ where
     }
 }
 
+impl<T> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: Num + NumCast,
+{
+    /// Multiply all values as `f64`. Null values are ignored.
+    /// Returns `None` if the array is empty or only contains null values.
+    pub fn product_f64(&self) -> Option<f64> {
+        if self.null_count() == 0 {
+            self.into_no_null_iter()
+                .map(|v| v.to_f64().unwrap())
+                .fold_first_(|acc, v| acc * v)
+        } else {
+            self.into_iter()
+                .flatten()
+                .map(|v| v.to_f64().unwrap())
+                .fold_first_(|acc, v| acc * v)
+        }
+    }
+
+    /// Multiply all values in the data type of the array. Null values are ignored.
+    /// Returns `None` if the array is empty or only contains null values and an error if an
+    /// integer product overflows.
+    pub fn product_checked(&self) -> Result<Option<T::Native>> {
+        if matches!(T::get_dtype(), DataType::Float32 | DataType::Float64) {
+            let prod = if self.null_count() == 0 {
+                self.into_no_null_iter().fold_first_(|acc, v| acc * v)
+            } else {
+                self.into_iter().flatten().fold_first_(|acc, v| acc * v)
+            };
+            return Ok(prod);
+        }
+        let overflow = || {
+            PolarsError::InvalidOperation(
+                format!("product of {} overflows {:?}", self.name(), T::get_dtype()).into(),
+            )
+        };
+        // every intermediate result is checked to fit in `T::Native`, so the product of two
+        // of them cannot overflow an i128 unless it also overflows `T::Native`
+        let mut acc: Option<i128> = None;
+        let mut mul = |v: T::Native| {
+            let v = v.to_i128().unwrap();
+            let prod = match acc {
+                None => v,
+                Some(acc) => acc.checked_mul(v).ok_or_else(overflow)?,
+            };
+            if <T::Native as NumCast>::from(prod).is_none() {
+                return Err(overflow());
+            }
+            acc = Some(prod);
+            Ok(())
+        };
+        if self.null_count() == 0 {
+            self.into_no_null_iter().try_for_each(&mut mul)?;
+        } else {
+            self.into_iter().flatten().try_for_each(&mut mul)?;
+        }
+        Ok(acc.map(|v| NumCast::from(v).unwrap()))
+    }
+
+    /// Get the product of the ChunkedArray as a new Series of length 1. With
+    /// [ProductOverflow::ToFloat] the Series has dtype `Float64`, otherwise it has the dtype of
+    /// the array.
+    pub fn product_as_series(&self, overflow: ProductOverflow) -> Result<Series>
+    where
+        ChunkedArray<T>: IntoSeries,
+    {
+        let s = match overflow {
+            ProductOverflow::ToFloat => {
+                let v = self.product_f64();
+                let mut ca: Float64Chunked = [v].iter().copied().collect();
+                ca.rename(self.name());
+                ca.into_series()
+            }
+            ProductOverflow::Checked => {
+                let v = self.product_checked()?;
+                let mut ca: ChunkedArray<T> = [v].iter().copied().collect();
+                ca.rename(self.name());
+                ca.into_series()
+            }
+        };
+        Ok(s)
+    }
+
+    /// The central moments of order 2, 3 and 4 and the number of non null values.
+    fn central_moments(&self) -> Option<(f64, f64, f64, usize)> {
+        let n = self.len() - self.null_count();
+        if n == 0 {
+            return None;
+        }
+        fn moments<I: Iterator<Item = f64>>(values: impl Fn() -> I, n: usize) -> (f64, f64, f64) {
+            let mean = values().sum::<f64>() / n as f64;
+            values().fold((0.0, 0.0, 0.0), |(m2, m3, m4), v| {
+                let d = v - mean;
+                let d2 = d * d;
+                (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+            })
+        }
+        let (m2, m3, m4) = if self.null_count() == 0 {
+            moments(|| self.into_no_null_iter().map(|v| v.to_f64().unwrap()), n)
+        } else {
+            moments(
+                || self.into_iter().flatten().map(|v| v.to_f64().unwrap()),
+                n,
+            )
+        };
+        let n_f = n as f64;
+        Some((m2 / n_f, m3 / n_f, m4 / n_f, n))
+    }
+
+    /// Compute the sample skewness of the values. Null values are ignored.
+    ///
+    /// If `bias` is false the result is corrected for statistical bias, which requires at
+    /// least three values. Returns `None` if there are not enough values.
+    pub fn skew(&self, bias: bool) -> Option<f64> {
+        let (m2, m3, _, n) = self.central_moments()?;
+        let g1 = m3 / m2.powf(1.5);
+        if bias {
+            Some(g1)
+        } else if n > 2 {
+            let n = n as f64;
+            Some(g1 * (n * (n - 1.0)).sqrt() / (n - 2.0))
+        } else {
+            None
+        }
+    }
+
+    /// Compute the kurtosis of the values. Null values are ignored.
+    ///
+    /// With `fisher` the excess kurtosis is returned, such that a normal distribution has a
+    /// kurtosis of 0.0, otherwise Pearson's definition is used (3.0 for a normal distribution).
+    /// If `bias` is false the result is corrected for statistical bias, which requires at least
+    /// four values. Returns `None` if there are not enough values.
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Option<f64> {
+        let (m2, _, m4, n) = self.central_moments()?;
+        let mut excess = m4 / (m2 * m2) - 3.0;
+        if !bias {
+            if n < 4 {
+                return None;
+            }
+            let n = n as f64;
+            excess = ((n + 1.0) * excess + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0));
+        }
+        match fisher {
+            true => Some(excess),
+            false => Some(excess + 3.0),
+        }
+    }
+}
+
+/// The squared deviations from the mean are summed per chunk, so this runs in parallel with the
+/// `parallel` feature.
 macro_rules! impl_var {
     ($self:expr, $ty: ty) => {{
         let mean = $self.mean()?;
-        let ca = $self - mean;
-        let squared = &ca * &ca;
-        let opt_v = squared.sum();
-        let div = ($self.len() - 1) as $ty;
+        let squared_dev = |arr: &PrimitiveArray<_>| {
+            let dev = |v: $ty| (v - mean) * (v - mean);
+            if arr.null_count() == 0 {
+                Some(arr.values().iter().map(|&v| dev(v)).sum::<$ty>())
+            } else {
+                Some(
+                    (0..arr.len())
+                        .filter(|&i| arr.is_valid(i))
+                        .map(|i| dev(arr.value(i)))
+                        .sum::<$ty>(),
+                )
+            }
+        };
+        let opt_v = agg_chunks($self, squared_dev, |acc, v| acc + v);
+        // null values don't count as observations
+        let div = ($self.len() - $self.null_count() - 1) as $ty;
         opt_v.map(|v| v / div)
     }};
 }
@@ -462,10 +769,89 @@ impl ChunkAggSeries for ListChunked {
 #[cfg(feature = "object")]
 impl<T> ChunkAggSeries for ObjectChunked<T> {}
 
+/// Aggregations that return the index of the aggregated value.
+pub trait ArgAgg {
+    /// Get the index of the minimal value. Ties resolve to the first occurrence.
+    fn arg_min(&self) -> Option<usize> {
+        None
+    }
+    /// Get the index of the maximal value. Ties resolve to the first occurrence.
+    fn arg_max(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Find the index of the first value that compares as `keep` to all other values, according to
+/// the total order used for sorting (`NaN` is the largest value). Null values are skipped.
+fn arg_agg_helper<T, I>(iter: I, keep: Ordering) -> Option<usize>
+where
+    T: PartialOrd,
+    I: Iterator<Item = Option<T>>,
+{
+    let mut out: Option<(usize, T)> = None;
+    for (idx, opt_v) in iter.enumerate() {
+        if let Some(v) = opt_v {
+            match &out {
+                Some((_, current)) if compare_fn_nan_max(&v, current) != keep => {}
+                _ => out = Some((idx, v)),
+            }
+        }
+    }
+    out.map(|(idx, _)| idx)
+}
+
+macro_rules! impl_arg_agg {
+    () => {
+        fn arg_min(&self) -> Option<usize> {
+            arg_agg_helper(self.into_iter(), Ordering::Less)
+        }
+        fn arg_max(&self) -> Option<usize> {
+            arg_agg_helper(self.into_iter(), Ordering::Greater)
+        }
+    };
+}
+
+impl<T> ArgAgg for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    impl_arg_agg!();
+}
+
+impl ArgAgg for BooleanChunked {
+    impl_arg_agg!();
+}
+
+impl ArgAgg for Utf8Chunked {
+    impl_arg_agg!();
+}
+
+impl ArgAgg for ListChunked {}
+impl ArgAgg for CategoricalChunked {}
+#[cfg(feature = "object")]
+impl<T> ArgAgg for ObjectChunked<T> {}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
 
+    #[test]
+    fn test_compensated_float_sum() {
+        let ca = Float32Chunked::new_from_slice("a", &vec![0.1; 1_000_000]);
+        assert!((ca.sum().unwrap() - 100_000.0).abs() < 1e-3);
+        assert!((ca.mean().unwrap() - 0.1).abs() < 1e-8);
+
+        let mut ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), Some(1e100), None]);
+        ca.append(&Float64Chunked::new_from_slice("a", &[1.0, -1e100]));
+        assert_eq!(ca.sum(), Some(2.0));
+        assert_eq!(ca.mean(), Some(0.5));
+
+        let ca = Float64Chunked::new_from_slice("a", &[1.0, f64::INFINITY]);
+        assert_eq!(ca.sum(), Some(f64::INFINITY));
+        let ca = Float64Chunked::new_from_opt_slice("a", &[None, None]);
+        assert_eq!(ca.sum(), None);
+    }
+
     #[test]
     fn test_agg_float() {
         let ca1 = Float32Chunked::new_from_slice("a", &[1.0, f32::NAN]);
@@ -477,6 +863,29 @@ mod test {
         println!("{:?}", (ca1.min(), ca2.min()))
     }
 
+    #[test]
+    fn test_agg_many_chunks() {
+        let mut ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), None, Some(4.0)]);
+        ca.append(&Float64Chunked::new_from_opt_slice("a", &[None, None]));
+        ca.append(&Float64Chunked::new_from_slice("a", &[-2.0, 7.0]));
+        assert_eq!(ca.chunks().len(), 3);
+        assert_eq!(ca.sum(), Some(10.0));
+        assert_eq!(ca.min(), Some(-2.0));
+        assert_eq!(ca.max(), Some(7.0));
+        assert_eq!(ca.mean(), Some(2.5));
+
+        let mut ca = Float64Chunked::new_from_slice("a", &[1.0, 4.0]);
+        ca.append(&Float64Chunked::new_from_slice("a", &[-2.0, 7.0]));
+        // the squared deviations from the mean sum to 45.0
+        assert_eq!(ca.var(), Some(15.0));
+
+        let mut ca = Int32Chunked::new_from_slice("a", &[3, 1]);
+        ca.append(&Int32Chunked::new_from_opt_slice("a", &[None, Some(8)]));
+        assert_eq!(ca.sum(), Some(12));
+        assert_eq!(ca.min(), Some(1));
+        assert_eq!(ca.max(), Some(8));
+    }
+
     #[test]
     fn test_median() {
         let ca = UInt32Chunked::new_from_opt_slice(
@@ -501,4 +910,78 @@ mod test {
         );
         assert_eq!(ca.median(), Some(4));
     }
+
+    #[test]
+    fn test_min_max_nan() {
+        let ca = Float64Chunked::new_from_opt_slice("a", &[Some(1.0), None, Some(f64::NAN)]);
+        assert_eq!(ca.min(), Some(1.0));
+        assert!(ca.max().unwrap().is_nan());
+        let ca = Float32Chunked::new_from_slice("a", &[f32::NAN, f32::NAN]);
+        assert!(ca.min().unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_product() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(2), None, Some(-3), Some(4)]);
+        assert_eq!(ca.product_checked().unwrap(), Some(-24));
+        assert_eq!(ca.product_f64(), Some(-24.0));
+
+        let ca = UInt8Chunked::new_from_slice("a", &[16, 16]);
+        assert!(ca.product_checked().is_err());
+        assert_eq!(ca.product_f64(), Some(256.0));
+        let s = ca.product_as_series(ProductOverflow::ToFloat).unwrap();
+        assert_eq!(s.dtype(), &DataType::Float64);
+
+        let ca = Int64Chunked::new_from_slice("a", &[i64::MAX, 2]);
+        assert!(ca.product_checked().is_err());
+        let ca = Int64Chunked::new_from_slice("a", &[i64::MIN, 1]);
+        assert_eq!(ca.product_checked().unwrap(), Some(i64::MIN));
+
+        let ca = Float64Chunked::new_from_slice("a", &[1.1, 1.1]);
+        assert!((ca.product_checked().unwrap().unwrap() - 1.21).abs() < 1e-12);
+
+        let ca = Int32Chunked::new_from_opt_slice("a", &[None, None]);
+        assert_eq!(ca.product_checked().unwrap(), None);
+        assert_eq!(ca.product_f64(), None);
+    }
+
+    #[test]
+    fn test_skew_kurtosis() {
+        let ca = Float64Chunked::new_from_opt_slice(
+            "a",
+            &[Some(1.0), Some(2.0), None, Some(3.0), Some(10.0)],
+        );
+        // reference values as computed by scipy.stats
+        let eq = |a: Option<f64>, b: f64| (a.unwrap() - b).abs() < 1e-9;
+        assert!(eq(ca.skew(true), 1.0182337649086284));
+        assert!(eq(ca.skew(false), 1.763632614803888));
+        assert!(eq(ca.kurtosis(true, true), -0.7696));
+        assert!(eq(ca.kurtosis(false, true), 2.2304));
+        assert!(eq(ca.kurtosis(true, false), 3.228));
+
+        let ca = Int32Chunked::new_from_slice("a", &[1, 2]);
+        assert_eq!(ca.skew(false), None);
+        assert_eq!(ca.kurtosis(true, false), None);
+        assert!(ca.skew(true).is_some());
+    }
+
+    #[test]
+    fn test_arg_min_max() {
+        use crate::chunked_array::ops::aggregate::ArgAgg;
+
+        let ca = Int32Chunked::new_from_opt_slice("a", &[None, Some(3), Some(1), Some(5), Some(1)]);
+        assert_eq!(ca.arg_min(), Some(2));
+        assert_eq!(ca.arg_max(), Some(3));
+
+        let ca = Float64Chunked::new_from_slice("a", &[2.0, f64::NAN, -1.0, f64::NAN]);
+        assert_eq!(ca.arg_min(), Some(2));
+        assert_eq!(ca.arg_max(), Some(1));
+
+        let ca = Utf8Chunked::new_from_slice("a", &["b", "a", "c"]);
+        assert_eq!(ca.arg_min(), Some(1));
+        assert_eq!(ca.arg_max(), Some(2));
+
+        let ca = Int32Chunked::new_from_opt_slice("a", &[None, None]);
+        assert_eq!(ca.arg_min(), None);
+    }
 }
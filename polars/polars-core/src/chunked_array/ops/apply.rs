@@ -1,7 +1,9 @@
 //! Implementations of the ChunkApply Trait.
 use crate::prelude::*;
 use crate::utils::NoNull;
+use crate::POOL;
 use arrow::array::{ArrayRef, LargeStringArray, PrimitiveArray};
+use rayon::prelude::*;
 
 macro_rules! apply {
     ($self:expr, $f:expr) => {{
@@ -13,6 +15,44 @@ macro_rules! apply {
     }};
 }
 
+macro_rules! apply_on_opt {
+    ($self:expr, $f:expr) => {{
+        let mut ca: Self = $self.into_iter().map($f).collect();
+        ca.rename($self.name());
+        ca
+    }};
+}
+
+macro_rules! apply_cast_numeric {
+    ($self:expr, $f:expr) => {{
+        let mut ca: ChunkedArray<S> = if $self.null_count() == 0 {
+            $self
+                .into_no_null_iter()
+                .map($f)
+                .collect::<NoNull<_>>()
+                .into_inner()
+        } else {
+            $self.into_iter().map(|opt_v| opt_v.map($f)).collect()
+        };
+        ca.rename($self.name());
+        ca
+    }};
+}
+
+macro_rules! par_apply {
+    ($self:expr, $f:expr) => {{
+        let mut ca: Self = POOL.install(|| {
+            if $self.null_count() == 0 {
+                NoNull::new($self).into_par_iter().map($f).collect()
+            } else {
+                $self.into_par_iter().map(|opt_v| opt_v.map($f)).collect()
+            }
+        });
+        ca.rename($self.name());
+        ca
+    }};
+}
+
 macro_rules! apply_enumerate {
     ($self:expr, $f:expr) => {{
         if $self.null_count() == 0 {
@@ -75,6 +115,21 @@ where
     {
         self.into_iter().enumerate().map(f).collect()
     }
+
+    fn apply_on_opt<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(Option<T::Native>) -> Option<T::Native> + Copy,
+    {
+        apply_on_opt!(self, f)
+    }
+
+    fn apply_cast_numeric<F, S>(&'a self, f: F) -> ChunkedArray<S>
+    where
+        F: Fn(T::Native) -> S::Native + Copy,
+        S: PolarsNumericType,
+    {
+        apply_cast_numeric!(self, f)
+    }
 }
 
 impl<'a> ChunkApply<'a, bool, bool> for BooleanChunked {
@@ -97,6 +152,21 @@ impl<'a> ChunkApply<'a, bool, bool> for BooleanChunked {
     {
         self.into_iter().enumerate().map(f).collect()
     }
+
+    fn apply_on_opt<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(Option<bool>) -> Option<bool> + Copy,
+    {
+        apply_on_opt!(self, f)
+    }
+
+    fn apply_cast_numeric<F, S>(&'a self, f: F) -> ChunkedArray<S>
+    where
+        F: Fn(bool) -> S::Native + Copy,
+        S: PolarsNumericType,
+    {
+        apply_cast_numeric!(self, f)
+    }
 }
 
 impl<'a> ChunkApply<'a, &'a str, String> for Utf8Chunked {
@@ -119,6 +189,63 @@ impl<'a> ChunkApply<'a, &'a str, String> for Utf8Chunked {
     {
         self.into_iter().enumerate().map(f).collect()
     }
+
+    fn apply_on_opt<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(Option<&'a str>) -> Option<String> + Copy,
+    {
+        apply_on_opt!(self, f)
+    }
+
+    fn apply_cast_numeric<F, S>(&'a self, f: F) -> ChunkedArray<S>
+    where
+        F: Fn(&'a str) -> S::Native + Copy,
+        S: PolarsNumericType,
+    {
+        apply_cast_numeric!(self, f)
+    }
+}
+
+impl<'a, T> ChunkParApply<'a, T::Native, T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType + Send + Sync,
+{
+    fn par_apply<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(T::Native) -> T::Native + Copy + Send + Sync,
+    {
+        let mut ca: Self = POOL.install(|| {
+            if self.null_count() == 0 {
+                NoNull::new(self)
+                    .into_par_iter()
+                    .map(f)
+                    .collect::<NoNull<_>>()
+                    .into_inner()
+            } else {
+                self.into_par_iter().map(|opt_v| opt_v.map(f)).collect()
+            }
+        });
+        ca.rename(self.name());
+        ca
+    }
+}
+
+impl<'a> ChunkParApply<'a, bool, bool> for BooleanChunked {
+    fn par_apply<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(bool) -> bool + Copy + Send + Sync,
+    {
+        par_apply!(self, f)
+    }
+}
+
+impl<'a> ChunkParApply<'a, &'a str, String> for Utf8Chunked {
+    fn par_apply<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(&'a str) -> String + Copy + Send + Sync,
+    {
+        par_apply!(self, f)
+    }
 }
 
 impl<T> ChunkApplyKernel<PrimitiveArray<T>> for ChunkedArray<T>
@@ -183,4 +310,44 @@ impl<'a> ChunkApply<'a, Series, Series> for ListChunked {
     {
         self.into_iter().enumerate().map(f).collect()
     }
+
+    fn apply_on_opt<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(Option<Series>) -> Option<Series> + Copy,
+    {
+        apply_on_opt!(self, f)
+    }
+
+    fn apply_cast_numeric<F, S>(&'a self, f: F) -> ChunkedArray<S>
+    where
+        F: Fn(Series) -> S::Native + Copy,
+        S: PolarsNumericType,
+    {
+        apply_cast_numeric!(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_apply() {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3)]);
+        let out = ca.apply_on_opt(|opt_v| opt_v.map(|v| v * 2).or(Some(0)));
+        assert_eq!(Vec::from(&out), &[Some(2), Some(0), Some(6)]);
+        assert_eq!(out.name(), "a");
+
+        let out: Float64Chunked = ca.apply_cast_numeric(|v| v as f64 / 2.0);
+        assert_eq!(Vec::from(&out), &[Some(0.5), None, Some(1.5)]);
+
+        let out = ca.par_apply(|v| v + 1);
+        assert_eq!(Vec::from(&out), &[Some(2), None, Some(4)]);
+
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("a"), None]);
+        let out = ca.par_apply(|s| s.to_uppercase());
+        assert_eq!(out.into_iter().collect::<Vec<_>>(), &[Some("A"), None]);
+        let out: UInt32Chunked = ca.apply_cast_numeric(|s| s.len() as u32);
+        assert_eq!(Vec::from(&out), &[Some(1), None]);
+    }
 }
@@ -93,7 +93,7 @@ impl ChunkOps for ListChunked {
 #[cfg(feature = "object")]
 impl<T> ChunkOps for ObjectChunked<T>
 where
-    T: Any + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn rechunk(&self) -> Self
     where
@@ -26,6 +26,8 @@ where
             })
             .collect();
         ca.rename(self.name());
+        // a running maximum (minimum) doesn't decrease (increase)
+        ca.set_sorted(IsSorted::Ascending);
         if reverse {
             ca.reverse()
         } else {
@@ -50,6 +52,8 @@ where
             })
             .collect();
         ca.rename(self.name());
+        // a running maximum (minimum) doesn't decrease (increase)
+        ca.set_sorted(IsSorted::Descending);
         if reverse {
             ca.reverse()
         } else {
@@ -12,10 +12,12 @@ use std::convert::TryFrom;
 pub(crate) fn offsets_to_indexes(offsets: &[i64], capacity: usize) -> Vec<usize> {
     let mut idx = Vec::with_capacity(capacity);
 
+    // the offsets of a sliced array don't start at 0
+    let first = offsets.first().copied().unwrap_or(0);
     let mut count = 0;
     let mut last_idx = 0;
     for &offset in offsets.iter().skip(1) {
-        while count < offset {
+        while count < offset - first {
             count += 1;
             idx.push(last_idx)
         }
@@ -34,11 +36,15 @@ impl ChunkExplode for ListChunked {
         // list layout or indexes to expand the DataFrame in the same manner as the 'explode' operation
         let ca = self.rechunk();
         let listarr: &LargeListArray = ca.downcast_chunks()[0];
-        let list_data = listarr.data();
-        let values = listarr.values();
-        let offset_ptr = list_data.buffers()[0].as_ptr() as *const i64;
+        let value_offsets = listarr.value_offsets();
         // offsets in the list array. These indicate where a new list starts
-        let offsets = unsafe { std::slice::from_raw_parts(offset_ptr, self.len()) };
+        let offsets = unsafe { std::slice::from_raw_parts(value_offsets.as_ptr(), self.len()) };
+        // a sliced array only refers to a part of the values
+        let (first, last) = (
+            value_offsets[0] as usize,
+            value_offsets[self.len()] as usize,
+        );
+        let values = listarr.values().slice(first, last - first);
 
         let s = Series::try_from((self.name(), values)).unwrap();
         Ok((s, offsets))
@@ -52,17 +58,22 @@ impl ChunkExplode for Utf8Chunked {
         // list layout or indexes to expand the DataFrame in the same manner as the 'explode' operation
         let ca = self.rechunk();
         let stringarr: &LargeStringArray = ca.downcast_chunks()[0];
-        let list_data = stringarr.data();
-        let str_values_buf = stringarr.value_data();
 
         // We get the offsets of the strings in the original array
-        let offset_ptr = list_data.buffers()[0].as_ptr() as *const i64;
+        let value_offsets = stringarr.value_offsets();
         // offsets in the list array. These indicate where a new list starts
-        let offsets = unsafe { std::slice::from_raw_parts(offset_ptr, self.len()) };
+        let offsets = unsafe { std::slice::from_raw_parts(value_offsets.as_ptr(), self.len()) };
+        // a sliced array only refers to a part of the values
+        let (first, last) = (
+            value_offsets[0] as usize,
+            value_offsets[self.len()] as usize,
+        );
+        let str_values_buf = stringarr.value_data().slice(first);
 
         // Because the strings are u8 stored but really are utf8 data we need to traverse the utf8 to
         // get the chars indexes
-        let str_data = unsafe { std::str::from_utf8_unchecked(str_values_buf.as_slice()) };
+        let str_data =
+            unsafe { std::str::from_utf8_unchecked(&str_values_buf.as_slice()[..last - first]) };
         // iterator over index and chars, we take only the index
         // todo! directly create a buffer from an aligned vec or a mutable buffer
         let mut new_offsets = str_data.char_indices().map(|t| t.0 as i64).collect_vec();
@@ -85,7 +96,7 @@ impl ChunkExplode for Utf8Chunked {
             let mut last_idx = 0;
             let mut last_valid = stringarr.is_valid(last_idx);
             for &offset in offsets.iter().skip(1) {
-                while count < offset {
+                while count < offset - first as i64 {
                     count += 1;
                     bitmap_builder.append(last_valid);
                 }
@@ -4,7 +4,9 @@ use crate::prelude::*;
 use crate::utils::align_chunks_binary;
 #[cfg(feature = "object")]
 use arrow::array::Array;
+use arrow::array::ArrayRef;
 use arrow::compute::filter as filter_fn;
+use polars_arrow::kernels::filter::filter_primitive;
 use std::ops::Deref;
 
 macro_rules! check_filter_len {
@@ -45,9 +47,16 @@ where
             .downcast_chunks()
             .iter()
             .zip(filter.downcast_chunks())
-            .map(|(&left, mask)| filter_fn(left, mask).unwrap())
+            .map(|(&left, mask)| match mask.null_count() {
+                // copy the runs of set bits of the mask at once
+                0 => Arc::new(filter_primitive(left, mask)) as ArrayRef,
+                _ => filter_fn(left, mask).unwrap(),
+            })
             .collect::<Vec<_>>();
-        Ok(ChunkedArray::new_from_chunks(self.name(), chunks))
+        let mut ca = ChunkedArray::new_from_chunks(self.name(), chunks);
+        // filtering keeps the order of the values
+        ca.set_sorted(self.is_sorted());
+        Ok(ca)
     }
 }
 
@@ -129,7 +138,7 @@ impl ChunkFilter<ListType> for ListChunked {
 #[cfg(feature = "object")]
 impl<T> ChunkFilter<ObjectType<T>> for ObjectChunked<T>
 where
-    T: 'static + std::fmt::Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn filter(&self, filter: &BooleanChunked) -> Result<ChunkedArray<ObjectType<T>>>
     where
@@ -169,11 +178,26 @@ where
 }
 
 #[cfg(test)]
-#[cfg(feature = "object")]
 mod test {
     use super::*;
 
     #[test]
+    fn test_filter_numeric() -> Result<()> {
+        let ca = Int32Chunked::new_from_opt_slice("a", &[Some(1), None, Some(3), Some(4), None]);
+        let mask = BooleanChunked::new_from_slice("", &[true, true, false, true, false]);
+        let out = ca.filter(&mask)?;
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(4)]);
+
+        // null values in the mask are not selected
+        let mask =
+            BooleanChunked::new_from_opt_slice("", &[Some(true), None, None, Some(true), None]);
+        let out = ca.filter(&mask)?;
+        assert_eq!(Vec::from(&out), &[Some(1), Some(4)]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "object")]
     fn object_filter() {
         let ca = ObjectChunked::new_from_opt_slice("foo", &[Some(1), None, Some(3), None]);
         let mask = BooleanChunked::new_from_slice("", &[true, false, false, true]);
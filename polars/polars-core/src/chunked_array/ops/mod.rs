@@ -14,6 +14,7 @@ pub(crate) mod cum_agg;
 pub(crate) mod explode;
 pub(crate) mod fill_none;
 pub(crate) mod filter;
+pub(crate) mod search_sorted;
 pub(crate) mod set;
 pub(crate) mod shift;
 pub(crate) mod sort;
@@ -403,6 +404,56 @@ pub trait ChunkApply<'a, A, B> {
     fn apply_with_idx_on_opt<F>(&'a self, f: F) -> Self
     where
         F: Fn((usize, Option<A>)) -> Option<B> + Copy;
+
+    /// Apply a closure elementwise, including null values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn fill_with_zero(ca: &UInt32Chunked) -> UInt32Chunked {
+    ///     ca.apply_on_opt(|opt_v| opt_v.or(Some(0)))
+    /// }
+    /// ```
+    fn apply_on_opt<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(Option<A>) -> Option<B> + Copy;
+
+    /// Apply a closure elementwise and collect the output in a `ChunkedArray` of numeric type `S`.
+    ///
+    /// Null values remain null.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn str_lengths(ca: &Utf8Chunked) -> UInt32Chunked {
+    ///     ca.apply_cast_numeric(|s| s.len() as u32)
+    /// }
+    /// ```
+    fn apply_cast_numeric<F, S>(&'a self, f: F) -> ChunkedArray<S>
+    where
+        F: Fn(A) -> S::Native + Copy,
+        S: PolarsNumericType;
+}
+
+/// Apply a closure elementwise in parallel, on the global thread pool.
+pub trait ChunkParApply<'a, A, B> {
+    /// Parallel version of [apply](ChunkApply::apply). Useful if the closure is expensive.
+    ///
+    /// Null values remain null.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn expensive(ca: &Float64Chunked) -> Float64Chunked {
+    ///     ca.par_apply(|v| v.sin().powf(2.5))
+    /// }
+    /// ```
+    fn par_apply<F>(&'a self, f: F) -> Self
+    where
+        F: Fn(A) -> B + Copy + Send + Sync;
 }
 
 /// Aggregation operations
@@ -429,6 +480,17 @@ pub trait ChunkAgg<T> {
     fn quantile(&self, quantile: f64) -> Result<Option<T>>;
 }
 
+/// How integer overflow is handled when computing a product, see
+/// [Series::product](crate::series::Series::product).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProductOverflow {
+    /// Compute the product as `f64`. Integer overflow results in a float, which may lose
+    /// precision or be infinite.
+    ToFloat,
+    /// Compute the product in the data type of the array and return an error on overflow.
+    Checked,
+}
+
 /// Variance and standard deviation aggregation.
 pub trait ChunkVar<T> {
     /// Compute the variance of this ChunkedArray/Series.
@@ -527,6 +589,12 @@ pub trait ToDummies<T>: ChunkUnique<T> {
 }
 
 /// Sort operations on `ChunkedArray`.
+///
+/// Floats are sorted in a total order where `NaN` is larger than any other value (and equal to
+/// other `NaN`s). So in ascending order `NaN`s are placed last and with `reverse` they are
+/// placed first. Null values are placed first in ascending order and last with `reverse`.
+/// `min`/`max`, `arg_min`/`arg_max`, joins and groupby operations on floats are consistent
+/// with this order.
 pub trait ChunkSort<T> {
     /// Returned a sorted `ChunkedArray`.
     fn sort(&self, reverse: bool) -> ChunkedArray<T>;
@@ -538,6 +606,47 @@ pub trait ChunkSort<T> {
     fn argsort(&self, reverse: bool) -> Vec<usize>;
 }
 
+/// Options to sort a Series or DataFrame. See [Series::sort_with_options] and
+/// [DataFrame::sort_with_options]. The sort is stable, equal values keep their order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SortOptions {
+    /// Sort from large to small values.
+    pub descending: bool,
+    /// Place the null values after the valid values, regardless of `descending`.
+    pub nulls_last: bool,
+    /// Place the NaN values of a float column after the other valid values, regardless of
+    /// `descending`. The nulls are placed before or after the NaN values.
+    pub nans_last: bool,
+}
+
+impl Default for SortOptions {
+    fn default() -> Self {
+        SortOptions {
+            descending: false,
+            nulls_last: false,
+            nans_last: true,
+        }
+    }
+}
+
+impl SortOptions {
+    /// Whether these options place the NaN values of `dtype` elsewhere than the sort kernels,
+    /// which sort NaN as the maximum.
+    pub(crate) fn moves_nans(&self, dtype: &DataType) -> bool {
+        matches!(dtype, DataType::Float32 | DataType::Float64) && self.nans_last == self.descending
+    }
+
+    /// The options of a sort with `reverse`, which places null values first in ascending order
+    /// and last in descending order. NaN values are sorted as the maximum.
+    pub fn from_reverse(reverse: bool) -> Self {
+        SortOptions {
+            descending: reverse,
+            nulls_last: reverse,
+            nans_last: !reverse,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub enum FillNoneStrategy {
     Backward,
@@ -687,14 +796,16 @@ where
     ChunkedArray<T>: ChunkOps,
 {
     fn reverse(&self) -> ChunkedArray<T> {
-        if let Ok(slice) = self.cont_slice() {
+        let mut ca: ChunkedArray<T> = if let Ok(slice) = self.cont_slice() {
             let ca: NoNull<ChunkedArray<T>> = slice.iter().rev().copied().collect();
             let mut ca = ca.into_inner();
             ca.rename(self.name());
             ca
         } else {
             self.into_iter().rev().collect()
-        }
+        };
+        ca.set_sorted(self.is_sorted().reverse());
+        ca
     }
 }
 
@@ -815,6 +926,22 @@ pub trait ChunkShift<T> {
     fn shift(&self, periods: i64) -> ChunkedArray<T>;
 }
 
+/// Side of the insertion index returned by [search_sorted](ChunkSearchSorted::search_sorted).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSortedSide {
+    /// The index of the first value that is greater than or equal to the searched value.
+    Left,
+    /// The index of the first value that is greater than the searched value.
+    Right,
+}
+
+/// Binary search in an ascending sorted ChunkedArray.
+pub trait ChunkSearchSorted<T> {
+    /// Find the index where `value` should be inserted to maintain the order.
+    /// Returns an error if the array contains null values.
+    fn search_sorted(&self, value: T, side: SearchSortedSide) -> Result<usize>;
+}
+
 /// Combine 2 ChunkedArrays based on some predicate.
 pub trait ChunkZip<T> {
     /// Create a new ChunkedArray with values from self where the mask evaluates `true` and values
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+
+/// Binary search for the insertion index of a value. `go_right(idx)` must return `true` if the
+/// value at `idx` is located before the insertion point.
+fn binary_search<F>(len: usize, go_right: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    let mut lo = 0;
+    let mut hi = len;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if go_right(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn check_no_nulls(null_count: usize) -> Result<()> {
+    if null_count > 0 {
+        Err(PolarsError::HasNullValues(
+            "search_sorted is not supported on arrays with null values".into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! impl_search_sorted {
+    ($self:ident, $value:ident, $side:ident) => {{
+        check_no_nulls($self.null_count())?;
+        let idx = match $side {
+            SearchSortedSide::Left => binary_search($self.len(), |idx| {
+                $self.get(idx).expect("no null values") < $value
+            }),
+            SearchSortedSide::Right => binary_search($self.len(), |idx| {
+                $self.get(idx).expect("no null values") <= $value
+            }),
+        };
+        Ok(idx)
+    }};
+}
+
+impl<T> ChunkSearchSorted<T::Native> for ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PartialOrd,
+{
+    fn search_sorted(&self, value: T::Native, side: SearchSortedSide) -> Result<usize> {
+        impl_search_sorted!(self, value, side)
+    }
+}
+
+impl ChunkSearchSorted<bool> for BooleanChunked {
+    fn search_sorted(&self, value: bool, side: SearchSortedSide) -> Result<usize> {
+        impl_search_sorted!(self, value, side)
+    }
+}
+
+impl<'a> ChunkSearchSorted<&'a str> for Utf8Chunked {
+    fn search_sorted(&self, value: &'a str, side: SearchSortedSide) -> Result<usize> {
+        impl_search_sorted!(self, value, side)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_search_sorted() {
+        let ca = Int32Chunked::new_from_slice("a", &[1, 2, 2, 2, 5, 8]);
+        assert_eq!(ca.search_sorted(2, SearchSortedSide::Left).unwrap(), 1);
+        assert_eq!(ca.search_sorted(2, SearchSortedSide::Right).unwrap(), 4);
+        assert_eq!(ca.search_sorted(0, SearchSortedSide::Left).unwrap(), 0);
+        assert_eq!(ca.search_sorted(6, SearchSortedSide::Left).unwrap(), 5);
+        assert_eq!(ca.search_sorted(9, SearchSortedSide::Right).unwrap(), 6);
+
+        let ca = Utf8Chunked::new_from_slice("a", &["a", "c", "d"]);
+        assert_eq!(ca.search_sorted("b", SearchSortedSide::Left).unwrap(), 1);
+        assert_eq!(ca.search_sorted("c", SearchSortedSide::Right).unwrap(), 2);
+
+        let ca = Int32Chunked::new_from_opt_slice("a", &[None, Some(1)]);
+        assert!(ca.search_sorted(1, SearchSortedSide::Left).is_err());
+
+        let s = Series::new("a", &[1.0, 2.5, 4.0]);
+        let value = Series::new("v", &[3i32]);
+        assert_eq!(s.search_sorted(&value, SearchSortedSide::Left).unwrap(), 2);
+    }
+}
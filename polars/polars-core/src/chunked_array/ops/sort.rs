@@ -1,3 +1,4 @@
+use crate::config;
 use crate::prelude::*;
 use crate::utils::NoNull;
 use itertools::Itertools;
@@ -5,9 +6,29 @@ use rayon::prelude::*;
 use std::cmp::Ordering;
 use std::ops::{Deref, DerefMut};
 
+/// Total order for values that may not be comparable to themselves (float `NaN`):
+/// `NaN` is considered equal to `NaN` and greater than any other value.
+/// For all other values this is the natural order.
+#[inline]
+pub(crate) fn compare_fn_nan_max<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    match a.partial_cmp(b) {
+        Some(ord) => ord,
+        None => {
+            // at least one of the values is NaN, as NaN is the only value not comparable to itself
+            let a_nan = a.partial_cmp(a).is_none();
+            let b_nan = b.partial_cmp(b).is_none();
+            match (a_nan, b_nan) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                _ => Ordering::Less,
+            }
+        }
+    }
+}
+
 fn sort_partial<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Ordering {
     match (a, b) {
-        (Some(a), Some(b)) => a.partial_cmp(b).expect("could not compare"),
+        (Some(a), Some(b)) => compare_fn_nan_max(a, b),
         (None, Some(_)) => Ordering::Less,
         (Some(_), None) => Ordering::Greater,
         (None, None) => Ordering::Equal,
@@ -20,97 +41,95 @@ where
     T::Native: std::cmp::PartialOrd,
 {
     fn sort(&self, reverse: bool) -> ChunkedArray<T> {
-        if self.is_optimal_aligned()
-            && self.len()
-                > std::env::var("POLARS_PAR_SORT_BOUND")
-                    .map(|v| v.parse::<usize>().expect("could not parse"))
-                    .unwrap_or(1000000)
-        {
-            let n = self.len();
-            let vals = self.cont_slice().unwrap();
-            let vals_ptr = vals.as_ptr();
-            // allocate aligned
-            let mut new = AlignedVec::<T::Native>::with_capacity_aligned(n);
-            let new_ptr = new.as_mut_ptr();
+        let mut ca: ChunkedArray<T> =
+            if self.is_optimal_aligned() && self.len() > config::par_sort_bound() {
+                let n = self.len();
+                let vals = self.cont_slice().unwrap();
+                let vals_ptr = vals.as_ptr();
+                // allocate aligned
+                let mut new = AlignedVec::<T::Native>::with_capacity_aligned(n);
+                let new_ptr = new.as_mut_ptr();
 
-            // memcopy
-            unsafe { std::ptr::copy_nonoverlapping(vals_ptr, new_ptr, n) };
-            // set len to copied bytes
-            unsafe { new.set_len(n) };
+                // memcopy
+                unsafe { std::ptr::copy_nonoverlapping(vals_ptr, new_ptr, n) };
+                // set len to copied bytes
+                unsafe { new.set_len(n) };
 
-            if reverse {
-                new.as_mut_slice()
-                    .par_sort_by(|a, b| b.partial_cmp(a).unwrap())
-            } else {
-                new.as_mut_slice()
-                    .par_sort_by(|a, b| a.partial_cmp(b).unwrap())
-            }
-            ChunkedArray::new_from_aligned_vec(self.name(), new)
-        } else if self.null_count() == 0 {
-            if reverse {
-                let ca: NoNull<ChunkedArray<T>> = self
-                    .into_no_null_iter()
-                    .sorted_by(|a, b| b.partial_cmp(a).unwrap())
-                    .collect();
-                let mut ca = ca.into_inner();
-                ca.rename(self.name());
-                ca
+                if reverse {
+                    new.as_mut_slice()
+                        .par_sort_by(|a, b| compare_fn_nan_max(b, a))
+                } else {
+                    new.as_mut_slice()
+                        .par_sort_by(|a, b| compare_fn_nan_max(a, b))
+                }
+                ChunkedArray::new_from_aligned_vec(self.name(), new)
+            } else if self.null_count() == 0 {
+                if reverse {
+                    let ca: NoNull<ChunkedArray<T>> = self
+                        .into_no_null_iter()
+                        .sorted_by(|a, b| compare_fn_nan_max(b, a))
+                        .collect();
+                    let mut ca = ca.into_inner();
+                    ca.rename(self.name());
+                    ca
+                } else {
+                    let ca: NoNull<ChunkedArray<T>> = self
+                        .into_no_null_iter()
+                        .sorted_by(|a, b| compare_fn_nan_max(a, b))
+                        .collect();
+                    let mut ca = ca.into_inner();
+                    ca.rename(self.name());
+                    ca
+                }
+            } else if reverse {
+                self.into_iter()
+                    .sorted_by(|a, b| sort_partial(b, a))
+                    .collect()
             } else {
-                let ca: NoNull<ChunkedArray<T>> = self
-                    .into_no_null_iter()
-                    .sorted_by(|a, b| a.partial_cmp(b).unwrap())
-                    .collect();
-                let mut ca = ca.into_inner();
-                ca.rename(self.name());
-                ca
-            }
-        } else if reverse {
-            self.into_iter()
-                .sorted_by(|a, b| sort_partial(b, a))
-                .collect()
+                self.into_iter()
+                    .sorted_by(|a, b| sort_partial(a, b))
+                    .collect()
+            };
+        ca.set_sorted(if reverse {
+            IsSorted::Descending
         } else {
-            self.into_iter()
-                .sorted_by(|a, b| sort_partial(a, b))
-                .collect()
-        }
+            IsSorted::Ascending
+        });
+        ca
     }
 
     fn sort_in_place(&mut self, reverse: bool) {
         let sorted = self.sort(reverse);
         self.chunks = sorted.chunks;
+        self.sorted = sorted.sorted;
     }
 
     fn argsort(&self, reverse: bool) -> Vec<usize> {
         // if len larger than 1M we sort in paralllel
-        if self.is_optimal_aligned()
-            && self.len()
-                > std::env::var("POLARS_PAR_SORT_BOUND")
-                    .map(|v| v.parse::<usize>().expect("could not parse"))
-                    .unwrap_or(1000000)
-        {
+        if self.is_optimal_aligned() && self.len() > config::par_sort_bound() {
             let vals = self.cont_slice().unwrap();
 
             let mut vals = vals.into_par_iter().enumerate().collect::<Vec<_>>();
 
             if reverse {
                 vals.as_mut_slice()
-                    .par_sort_by(|(_idx, a), (_idx_b, b)| b.partial_cmp(a).unwrap());
+                    .par_sort_by(|(_idx, a), (_idx_b, b)| compare_fn_nan_max(b, a));
             } else {
                 vals.as_mut_slice()
-                    .par_sort_by(|(_idx, a), (_idx_b, b)| a.partial_cmp(b).unwrap());
+                    .par_sort_by(|(_idx, a), (_idx_b, b)| compare_fn_nan_max(a, b));
             }
             vals.into_par_iter().map(|(idx, _v)| idx).collect()
         } else if self.null_count() == 0 {
             if reverse {
                 self.into_no_null_iter()
                     .enumerate()
-                    .sorted_by(|(_idx_a, a), (_idx_b, b)| b.partial_cmp(a).unwrap())
+                    .sorted_by(|(_idx_a, a), (_idx_b, b)| compare_fn_nan_max(b, a))
                     .map(|(idx, _v)| idx)
                     .collect()
             } else {
                 self.into_no_null_iter()
                     .enumerate()
-                    .sorted_by(|(_idx_a, a), (_idx_b, b)| a.partial_cmp(b).unwrap())
+                    .sorted_by(|(_idx_a, a), (_idx_b, b)| compare_fn_nan_max(a, b))
                     .map(|(idx, _v)| idx)
                     .collect()
             }
@@ -236,3 +255,76 @@ impl ChunkSort<BooleanType> for BooleanChunked {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_sort_nan() {
+        let ca = Float64Chunked::new_from_opt_slice(
+            "a",
+            &[Some(1.0), Some(f64::NAN), None, Some(-1.0), Some(f64::NAN)],
+        );
+        let sorted = ca.sort(false);
+        let out: Vec<_> = sorted
+            .into_iter()
+            .map(|opt| opt.map(|v| v.is_nan()))
+            .collect();
+        assert_eq!(
+            out,
+            &[None, Some(false), Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(sorted.get(1), Some(-1.0));
+        assert_eq!(ca.argsort(false), &[2, 3, 0, 1, 4]);
+        assert_eq!(ca.argsort(true), &[1, 4, 0, 3, 2]);
+
+        let ca = Float32Chunked::new_from_slice("a", &[f32::NAN, 2.0, 1.0]);
+        assert_eq!(ca.argsort(false), &[2, 1, 0]);
+    }
+
+    #[test]
+    fn test_sorted_flag() {
+        let ca = Int32Chunked::new_from_slice("a", &[3, 1, 2, 2, 5]);
+        assert_eq!(ca.is_sorted(), IsSorted::Not);
+        let asc = ca.sort(false);
+        assert_eq!(asc.is_sorted(), IsSorted::Ascending);
+        let desc = ca.sort(true);
+        assert_eq!(desc.is_sorted(), IsSorted::Descending);
+        assert_eq!(desc.reverse().is_sorted(), IsSorted::Ascending);
+        assert_eq!(asc.slice(1, 3).unwrap().is_sorted(), IsSorted::Ascending);
+        assert_eq!(ca.cum_max(false).is_sorted(), IsSorted::Ascending);
+        assert_eq!((&asc + &asc).is_sorted(), IsSorted::Not);
+        let mut appended = asc.clone();
+        appended.append(&ca);
+        assert_eq!(appended.is_sorted(), IsSorted::Not);
+        // an empty sorted array takes the flag of the appended data
+        let mut empty = asc.filter(&asc.gt(10)).unwrap();
+        assert_eq!(empty.is_sorted(), IsSorted::Ascending);
+        empty.append(&ca);
+        assert_eq!(empty.is_sorted(), IsSorted::Not);
+        assert_eq!(empty.max(), Some(5));
+        assert_eq!(Vec::from(&empty.gt(2)), Vec::from(&ca.gt(2)));
+
+        // the fast paths give the same results as the kernels
+        for sorted in &[asc, desc] {
+            let mut plain = sorted.clone();
+            plain.set_sorted(IsSorted::Not);
+            assert_eq!(sorted.min(), Some(1));
+            assert_eq!(sorted.max(), Some(5));
+            for v in &[0, 2, 3, 6] {
+                assert_eq!(Vec::from(&sorted.gt(*v)), Vec::from(&plain.gt(*v)));
+                assert_eq!(Vec::from(&sorted.gt_eq(*v)), Vec::from(&plain.gt_eq(*v)));
+                assert_eq!(Vec::from(&sorted.lt(*v)), Vec::from(&plain.lt(*v)));
+                assert_eq!(Vec::from(&sorted.lt_eq(*v)), Vec::from(&plain.lt_eq(*v)));
+            }
+        }
+
+        let df = DataFrame::new(vec![Series::new("a", &[3, 1, 2])]).unwrap();
+        let sorted = df.sort("a", true).unwrap();
+        assert_eq!(
+            sorted.column("a").unwrap().is_sorted(),
+            IsSorted::Descending
+        );
+    }
+}
@@ -157,8 +157,16 @@ where
     ChunkedArray<T>: ChunkOps + IntoSeries,
 {
     fn unique(&self) -> Result<Self> {
-        let set = fill_set(self.into_iter(), self.len());
-        Ok(Self::new_from_opt_iter(self.name(), set.iter().copied()))
+        if self.null_count() == 0 {
+            let set = fill_set(self.into_no_null_iter(), self.len());
+            let ca: NoNull<Self> = set.iter().copied().collect();
+            let mut ca = ca.into_inner();
+            ca.rename(self.name());
+            Ok(ca)
+        } else {
+            let set = fill_set(self.into_iter(), self.len());
+            Ok(Self::new_from_opt_iter(self.name(), set.iter().copied()))
+        }
     }
 
     fn arg_unique(&self) -> Result<Vec<usize>> {
@@ -180,11 +188,18 @@ where
 
 impl ChunkUnique<Utf8Type> for Utf8Chunked {
     fn unique(&self) -> Result<Self> {
-        let set = fill_set(self.into_iter(), self.len());
-        Ok(Utf8Chunked::new_from_opt_iter(
-            self.name(),
-            set.iter().copied(),
-        ))
+        if self.null_count() == 0 {
+            let set = fill_set(self.into_no_null_iter(), self.len());
+            let mut ca: Self = set.iter().copied().collect();
+            ca.rename(self.name());
+            Ok(ca)
+        } else {
+            let set = fill_set(self.into_iter(), self.len());
+            Ok(Utf8Chunked::new_from_opt_iter(
+                self.name(),
+                set.iter().copied(),
+            ))
+        }
     }
 
     fn arg_unique(&self) -> Result<Vec<usize>> {
@@ -437,6 +452,10 @@ mod test {
             Vec::from(&ca.unique().unwrap().sort(false)),
             &[None, Some("a"), Some("b")]
         );
+        let ca = Utf8Chunked::new_from_slice("a", &["b", "a", "b"]);
+        let unique = ca.unique().unwrap();
+        assert_eq!(unique.name(), "a");
+        assert_eq!(Vec::from(&unique.sort(false)), &[Some("a"), Some("b")]);
     }
 
     #[test]
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use crate::utils::align_chunks_ternary;
 use arrow::compute::kernels::zip::zip;
+use std::borrow::Cow;
 
 fn ternary_apply<T>(predicate: bool, truthy: T, falsy: T) -> T {
     if predicate {
@@ -10,6 +11,15 @@ fn ternary_apply<T>(predicate: bool, truthy: T, falsy: T) -> T {
     }
 }
 
+/// Null values in the mask are interpreted as `false`, such that they select from `other`.
+fn prepare_mask(mask: &BooleanChunked) -> Result<Cow<BooleanChunked>> {
+    if mask.null_count() > 0 {
+        mask.fill_none_with_value(false).map(Cow::Owned)
+    } else {
+        Ok(Cow::Borrowed(mask))
+    }
+}
+
 macro_rules! impl_ternary_broadcast {
     ($self:ident, $self_len:expr, $other_len:expr, $other:expr, $mask:expr, $ty:ty) => {{
         match ($self_len, $other_len) {
@@ -56,6 +66,8 @@ where
     T: PolarsNumericType,
 {
     fn zip_with(&self, mask: &BooleanChunked, other: &ChunkedArray<T>) -> Result<ChunkedArray<T>> {
+        let mask = prepare_mask(mask)?;
+        let mask = mask.as_ref();
         // broadcasting path
         if self.len() != mask.len() || other.len() != mask.len() {
             impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, T)
@@ -78,6 +90,8 @@ where
 
 impl ChunkZip<BooleanType> for BooleanChunked {
     fn zip_with(&self, mask: &BooleanChunked, other: &BooleanChunked) -> Result<BooleanChunked> {
+        let mask = prepare_mask(mask)?;
+        let mask = mask.as_ref();
         // broadcasting path
         if self.len() != mask.len() || other.len() != mask.len() {
             impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, BooleanType)
@@ -100,6 +114,8 @@ impl ChunkZip<BooleanType> for BooleanChunked {
 
 impl ChunkZip<Utf8Type> for Utf8Chunked {
     fn zip_with(&self, mask: &BooleanChunked, other: &Utf8Chunked) -> Result<Utf8Chunked> {
+        let mask = prepare_mask(mask)?;
+        let mask = mask.as_ref();
         if self.len() != mask.len() || other.len() != mask.len() {
             impl_ternary_broadcast!(self, self.len(), other.len(), other, mask, Utf8Type)
         } else {
@@ -124,6 +140,14 @@ impl ChunkZip<ListType> for ListChunked {
         mask: &BooleanChunked,
         other: &ChunkedArray<ListType>,
     ) -> Result<ChunkedArray<ListType>> {
+        let mask = prepare_mask(mask)?;
+        let mask = mask.as_ref();
+        if self.len() != mask.len() || other.len() != mask.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "Shape of parameter `mask` and `other` could not be used in zip_with operation"
+                    .into(),
+            ));
+        }
         let (left, right, mask) = align_chunks_ternary(self, other, mask);
         let chunks = left
             .downcast_chunks()
@@ -164,3 +188,41 @@ impl<T> ChunkZip<ObjectType<T>> for ObjectChunked<T> {
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_zip_with() {
+        let a = Int32Chunked::new_from_slice("a", &[1, 2, 3]);
+        let b = Int32Chunked::new_from_opt_slice("b", &[Some(10), None, Some(30)]);
+        let mask = BooleanChunked::new_from_opt_slice("", &[Some(true), Some(false), None]);
+
+        let out = a.zip_with(&mask, &b).unwrap();
+        assert_eq!(out.name(), "a");
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(30)]);
+
+        // broadcast `other`
+        let b = Int32Chunked::new_from_slice("b", &[0]);
+        let out = a.zip_with(&mask, &b).unwrap();
+        assert_eq!(Vec::from(&out), &[Some(1), Some(0), Some(0)]);
+
+        // shapes that cannot be broadcasted
+        let b = Int32Chunked::new_from_slice("b", &[0, 1]);
+        assert!(a.zip_with(&mask, &b).is_err());
+    }
+
+    #[test]
+    fn test_zip_with_series() {
+        let a = Series::new("a", &["foo", "bar"]);
+        let b = Series::new("b", &["spam", "ham"]);
+        let mask = BooleanChunked::new_from_slice("", &[false, true]);
+        let out = a.zip_with(&mask, &b).unwrap();
+        let out: Vec<_> = out.utf8().unwrap().into_iter().collect();
+        assert_eq!(out, &[Some("spam"), Some("bar")]);
+
+        let c = Series::new("c", &[1, 2]);
+        assert!(a.zip_with(&mask, &c).is_err());
+    }
+}
@@ -1,64 +1,158 @@
+use crate::frame::select::Selection;
 use crate::prelude::*;
 use num::{Float, NumCast};
 use rand::distributions::Bernoulli;
 use rand::prelude::*;
+use rand::rngs::StdRng;
 use rand::seq::IteratorRandom;
 use rand_distr::{Distribution, Normal, StandardNormal, Uniform};
-use rayon::prelude::*;
+
+/// Draw `n` indexes of an array of length `len`. Without `shuffle` the indexes are sorted, so
+/// the sampled values keep their original order. The sample is deterministic for a given `seed`.
+fn sample_idx(
+    len: usize,
+    n: usize,
+    with_replacement: bool,
+    shuffle: bool,
+    seed: Option<u64>,
+) -> Result<Vec<usize>> {
+    if !with_replacement && n > len {
+        return Err(PolarsError::ShapeMisMatch(
+            "n is larger than the number of elements in this array".into(),
+        ));
+    }
+    if len == 0 && n > 0 {
+        return Err(PolarsError::NoData(
+            "cannot sample from an empty array".into(),
+        ));
+    }
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut idx = match with_replacement {
+        true => {
+            let dist = Uniform::new(0, len);
+            (0..n).map(|_| dist.sample(&mut rng)).collect::<Vec<_>>()
+        }
+        // the indexes are drawn in random order
+        false => rand::seq::index::sample(&mut rng, len, n).into_vec(),
+    };
+    if !shuffle {
+        idx.sort_unstable();
+    }
+    Ok(idx)
+}
+
+fn frac_to_n(len: usize, frac: f64) -> Result<usize> {
+    if frac < 0.0 {
+        return Err(PolarsError::ValueError(
+            "frac should not be negative".into(),
+        ));
+    }
+    Ok((len as f64 * frac) as usize)
+}
 
 impl<T> ChunkedArray<T>
 where
     ChunkedArray<T>: ChunkTake,
 {
     /// Sample n datapoints from this ChunkedArray.
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        if !with_replacement && n > self.len() {
-            return Err(PolarsError::ShapeMisMatch(
-                "n is larger than the number of elements in this array".into(),
-            ));
-        }
-        let len = self.len();
-        let mut rng = rand::thread_rng();
-
-        match with_replacement {
-            true => {
-                let iter = (0..n).map(|_| Uniform::new(0, len).sample(&mut rng));
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter, Some(n))) }
-            }
-            false => {
-                // TODO! prevent allocation.
-                let iter = (0..len).choose_multiple(&mut rng, n).into_iter();
-                // Safety we know that we never go out of bounds
-                debug_assert_eq!(len, self.len());
-                unsafe { Ok(self.take_unchecked(iter, Some(n))) }
-            }
-        }
+    ///
+    /// With `shuffle` the sample is in random order, otherwise the values keep their original
+    /// order. A `seed` makes the sample reproducible.
+    pub fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        let idx = sample_idx(self.len(), n, with_replacement, shuffle, seed)?;
+        // Safety we know that we never go out of bounds
+        unsafe { Ok(self.take_unchecked(idx.into_iter(), Some(n))) }
     }
 
-    /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
-        let n = (self.len() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+    /// Sample a fraction of this ChunkedArray. The fraction can only be larger than 1.0 with
+    /// replacement.
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        let n = frac_to_n(self.len(), frac)?;
+        self.sample_n(n, with_replacement, shuffle, seed)
     }
 }
 
 impl DataFrame {
-    /// Sample n datapoints from this DataFrame.
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Self> {
-        let columns = self
-            .columns
-            .par_iter()
-            .map(|s| s.sample_n(n, with_replacement))
-            .collect::<Result<_>>()?;
-        Ok(DataFrame::new_no_checks(columns))
+    /// Sample n rows from this DataFrame. The same rows are taken from every column.
+    ///
+    /// With `shuffle` the rows are in random order, otherwise they keep their original order. A
+    /// `seed` makes the sample reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3, 4], "b" => &[1, 2, 3, 4]).unwrap();
+    /// let sample = df.sample_n(2, false, false, Some(0)).unwrap();
+    /// assert!(sample.frame_equal(&df.sample_n(2, false, false, Some(0)).unwrap()));
+    /// ```
+    pub fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        let idx = sample_idx(self.height(), n, with_replacement, shuffle, seed)?;
+        Ok(self.take_iter(idx.into_iter(), Some(n)))
     }
 
-    /// Sample a fraction between 0.0-1.0 of this DataFrame.
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Self> {
-        let n = (self.height() as f64 * frac) as usize;
-        self.sample_n(n, with_replacement)
+    /// Sample a fraction of the rows of this DataFrame. See [sample_n](DataFrame::sample_n).
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Self> {
+        let n = frac_to_n(self.height(), frac)?;
+        self.sample_n(n, with_replacement, shuffle, seed)
+    }
+
+    /// Sample a fraction between 0.0-1.0 of the rows of every group in `by`, without replacement.
+    ///
+    /// Every group contributes `frac` of its rows (rounded to the nearest integer), so the
+    /// distribution of the key column(s) is maintained in the sample. The rows keep their
+    /// original order. The sample is deterministic for a given `seed`.
+    pub fn sample_stratified<'a, J, S: Selection<'a, J>>(
+        &self,
+        by: S,
+        frac: f64,
+        seed: u64,
+    ) -> Result<Self> {
+        if !(0.0..=1.0).contains(&frac) {
+            return Err(PolarsError::ValueError(
+                "frac should be between 0.0 and 1.0".into(),
+            ));
+        }
+        let gb = self.groupby_stable(by)?;
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut idx = Vec::with_capacity((self.height() as f64 * frac) as usize);
+        for (_, group) in gb.get_groups() {
+            let n = (group.len() as f64 * frac).round() as usize;
+            // the order of the indices within a group may depend on the number of threads
+            let mut group = group.clone();
+            group.sort_unstable();
+            idx.extend(group.into_iter().choose_multiple(&mut rng, n));
+        }
+        idx.sort_unstable();
+        Ok(self.take_iter(idx.iter().copied(), Some(idx.len())))
     }
 }
 
@@ -137,13 +231,81 @@ mod test {
         ]
         .unwrap();
 
-        assert!(df.sample_n(3, false).is_ok());
-        assert!(df.sample_frac(0.4, false).is_ok());
+        assert!(df.sample_n(3, false, false, None).is_ok());
+        assert!(df.sample_frac(0.4, false, false, None).is_ok());
         // without replacement can not sample more than 100%
-        assert!(df.sample_frac(2.0, false).is_err());
-        assert!(df.sample_n(3, true).is_ok());
-        assert!(df.sample_frac(0.4, true).is_ok());
+        assert!(df.sample_frac(2.0, false, false, None).is_err());
+        assert!(df.sample_n(3, true, false, None).is_ok());
+        assert!(df.sample_frac(0.4, true, false, None).is_ok());
         // with replacement can sample more than 100%
-        assert!(df.sample_frac(2.0, true).is_ok());
+        assert!(df.sample_frac(2.0, true, false, None).is_ok());
+        assert!(df.sample_frac(-0.5, true, false, None).is_err());
+    }
+
+    #[test]
+    fn test_sample_rows_aligned() {
+        let df = df![
+            "a" => (0..100).collect::<Vec<i32>>(),
+            "b" => (0..100).collect::<Vec<i32>>()
+        ]
+        .unwrap();
+        for &with_replacement in &[false, true] {
+            let sample = df.sample_n(50, with_replacement, true, Some(1)).unwrap();
+            assert_eq!(
+                Vec::from(sample.column("a").unwrap().i32().unwrap()),
+                Vec::from(sample.column("b").unwrap().i32().unwrap())
+            );
+            // the seed makes the sample reproducible
+            assert!(sample.frame_equal(&df.sample_n(50, with_replacement, true, Some(1)).unwrap()));
+        }
+
+        // without shuffle the rows keep their order
+        let sample = df.sample_frac(0.3, false, false, Some(2)).unwrap();
+        assert_eq!(sample.height(), 30);
+        let a: Vec<_> = sample
+            .column("a")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert!(a.windows(2).all(|w| w[0] < w[1]));
+
+        let s = Series::new("a", &[1, 2, 3]);
+        assert_eq!(s.sample_n(3, false, false, None).unwrap().len(), 3);
+        assert!(s.sample_n(0, true, false, None).unwrap().is_empty());
+        let empty = Series::new("a", &[0i32; 0]);
+        assert!(empty.sample_n(1, true, false, None).is_err());
+    }
+
+    #[test]
+    fn test_sample_stratified() {
+        let df = df![
+            "key" => &["a", "a", "a", "a", "b", "b", "c", "a", "b", "b"],
+            "val" => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+        ]
+        .unwrap();
+
+        let sample = df.sample_stratified("key", 0.5, 0).unwrap();
+        // groups of 5, 4 and 1 rows give 3, 2 and 1 (rounded) rows
+        assert_eq!(sample.height(), 6);
+        let keys = sample.column("key").unwrap().utf8().unwrap();
+        assert_eq!(keys.into_no_null_iter().filter(|&k| k == "a").count(), 3);
+        assert_eq!(keys.into_no_null_iter().filter(|&k| k == "b").count(), 2);
+        assert_eq!(keys.into_no_null_iter().filter(|&k| k == "c").count(), 1);
+        // rows keep their original order
+        let vals: Vec<_> = sample
+            .column("val")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        let mut sorted = vals.clone();
+        sorted.sort_unstable();
+        assert_eq!(vals, sorted);
+
+        assert!(sample.frame_equal(&df.sample_stratified("key", 0.5, 0).unwrap()));
+        assert!(df.sample_stratified("key", 1.5, 0).is_err());
     }
 }
@@ -1,6 +1,9 @@
 use crate::prelude::*;
 use crate::utils::NoNull;
+use arrow::array::LargeStringBuilder;
+use itertools::Itertools;
 use regex::Regex;
+use std::ops::Deref;
 
 macro_rules! apply_closure_to_primitive {
     ($self:expr, $f:expr) => {{
@@ -27,12 +30,27 @@ macro_rules! apply_closure {
 }
 
 impl Utf8Chunked {
-    /// Get the length of the string values.
+    /// Get the length of the string values in bytes. Same as [n_bytes](Utf8Chunked::n_bytes).
     pub fn str_lengths(&self) -> UInt32Chunked {
         let f = |s: &str| s.len() as u32;
         apply_closure_to_primitive!(self, f)
     }
 
+    /// Get the length of the string values in characters (unicode scalar values).
+    pub fn lengths(&self) -> UInt32Chunked {
+        let f = |s: &str| s.chars().count() as u32;
+        let mut ca: UInt32Chunked = apply_closure_to_primitive!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Get the length of the string values in bytes.
+    pub fn n_bytes(&self) -> UInt32Chunked {
+        let mut ca = self.str_lengths();
+        ca.rename(self.name());
+        ca
+    }
+
     /// Check if strings contain a regex pattern
     pub fn contains(&self, pat: &str) -> Result<BooleanChunked> {
         let reg = Regex::new(pat)?;
@@ -45,6 +63,22 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Check if strings start with a substring
+    pub fn starts_with(&self, sub: &str) -> BooleanChunked {
+        let f = |s: &str| s.starts_with(sub);
+        let mut ca: BooleanChunked = apply_closure!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Check if strings end with a substring
+    pub fn ends_with(&self, sub: &str) -> BooleanChunked {
+        let f = |s: &str| s.ends_with(sub);
+        let mut ca: BooleanChunked = apply_closure!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
     /// Replace the leftmost (sub)string by a regex pattern
     pub fn replace(&self, pat: &str, val: &str) -> Result<Utf8Chunked> {
         let reg = Regex::new(pat)?;
@@ -69,8 +103,239 @@ impl Utf8Chunked {
         self.apply(str::to_uppercase)
     }
 
+    /// Remove leading and trailing whitespace
+    pub fn strip(&self) -> Utf8Chunked {
+        let mut ca: Utf8Chunked = apply_closure!(self, str::trim);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Remove leading whitespace
+    pub fn lstrip(&self) -> Utf8Chunked {
+        let mut ca: Utf8Chunked = apply_closure!(self, str::trim_start);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Remove trailing whitespace
+    pub fn rstrip(&self) -> Utf8Chunked {
+        let mut ca: Utf8Chunked = apply_closure!(self, str::trim_end);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Pad the strings with zeros on the left until they are `width` characters long.
+    /// A leading sign is kept in front of the zeros, e.g. `"-1"` becomes `"-001"` for `width = 4`.
+    pub fn zfill(&self, width: usize) -> Utf8Chunked {
+        let f = |s: &str| {
+            let n_chars = s.chars().count();
+            if n_chars >= width {
+                return s.to_string();
+            }
+            let (sign, digits) = match s.chars().next() {
+                Some(c @ '-') | Some(c @ '+') => (Some(c), &s[1..]),
+                _ => (None, s),
+            };
+            let mut out = String::with_capacity(width);
+            if let Some(c) = sign {
+                out.push(c);
+            }
+            out.extend(std::iter::repeat('0').take(width - n_chars));
+            out.push_str(digits);
+            out
+        };
+        let mut ca: Utf8Chunked = apply_closure!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Pad the strings on the left with `fill_char` until they are `width` characters long.
+    pub fn pad_start(&self, width: usize, fill_char: char) -> Utf8Chunked {
+        let f = |s: &str| {
+            let n_pad = width.saturating_sub(s.chars().count());
+            let mut out: String = std::iter::repeat(fill_char).take(n_pad).collect();
+            out.push_str(s);
+            out
+        };
+        let mut ca: Utf8Chunked = apply_closure!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Pad the strings on the right with `fill_char` until they are `width` characters long.
+    pub fn pad_end(&self, width: usize, fill_char: char) -> Utf8Chunked {
+        let f = |s: &str| {
+            let n_pad = width.saturating_sub(s.chars().count());
+            let mut out = s.to_string();
+            out.extend(std::iter::repeat(fill_char).take(n_pad));
+            out
+        };
+        let mut ca: Utf8Chunked = apply_closure!(self, f);
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Split the strings by a delimiter into a List column of substrings.
+    pub fn split(&self, by: &str) -> ListChunked {
+        let value_capacity = self.get_values_size() + self.len();
+        let values_builder = LargeStringBuilder::with_capacity(value_capacity, self.len());
+        let mut builder = ListUtf8ChunkedBuilder::new(self.name(), values_builder, self.len());
+        self.into_iter().for_each(|opt_s| match opt_s {
+            Some(s) => builder.append_values_iter(s.split(by)),
+            None => builder.append_opt_series(None),
+        });
+        builder.finish()
+    }
+
+    /// Extract the capture group `group_index` of a regex pattern. Group `0` is the whole match.
+    /// Values that don't match the pattern are null.
+    pub fn extract(&self, pat: &str, group_index: usize) -> Result<Utf8Chunked> {
+        let reg = Regex::new(pat)?;
+        let mut ca: Utf8Chunked = self
+            .into_iter()
+            .map(|opt_s| {
+                opt_s
+                    .and_then(|s| reg.captures(s))
+                    .and_then(|captures| captures.get(group_index))
+                    .map(|m| m.as_str())
+            })
+            .collect();
+        ca.rename(self.name());
+        Ok(ca)
+    }
+
     /// Concat with the values from a second Utf8Chunked
     pub fn concat(&self, other: &Utf8Chunked) -> Self {
         self + other
     }
+
+    /// Concat with the values from a second Utf8Chunked, optionally separated by `sep`.
+    /// The result is null where either value is null. If `other` has a single value, it is
+    /// concatenated to every value.
+    pub fn concat_with(&self, other: &Utf8Chunked, sep: Option<&str>) -> Utf8Chunked {
+        let mut ca = match sep {
+            Some(sep) if !sep.is_empty() => &(self + sep) + other,
+            _ => self + other,
+        };
+        ca.rename(self.name());
+        ca
+    }
+
+    /// Join all values into a single string separated by `delimiter`. Null values are skipped.
+    /// This returns a Utf8Chunked of length 1 and can be used as a groupby aggregation.
+    pub fn str_concat(&self, delimiter: &str) -> Utf8Chunked {
+        let joined = self.into_iter().flatten().join(delimiter);
+        Utf8Chunked::new_from_slice(self.name(), &[joined])
+    }
+}
+
+/// String operations on a `Series` of dtype Utf8, created by [Series::str](crate::series::Series::str).
+///
+/// This dereferences to the underlying [Utf8Chunked](crate::datatypes::Utf8Chunked),
+/// so all string methods of `Utf8Chunked` are available.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// fn error_lines(log: &Series) -> Result<BooleanChunked> {
+///     Ok(log.str()?.contains(r"^\[ERROR\]")?)
+/// }
+/// ```
+pub struct StringNameSpace<'a>(pub(crate) &'a Utf8Chunked);
+
+impl<'a> Deref for StringNameSpace<'a> {
+    type Target = Utf8Chunked;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_str_namespace() -> Result<()> {
+        let s = Series::new("log", &[Some("[ERROR] disk full"), None, Some("[INFO] ok")]);
+        let ns = s.str()?;
+        assert_eq!(
+            Vec::from(&ns.contains(r"^\[ERROR\]")?),
+            &[Some(true), None, Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&ns.starts_with("[INFO]")),
+            &[Some(false), None, Some(true)]
+        );
+        assert_eq!(
+            Vec::from(&ns.ends_with("ok")),
+            &[Some(false), None, Some(true)]
+        );
+        let replaced = ns.replace_all(r"\[(\w+)\]", "$1:")?;
+        assert_eq!(replaced.get(0), Some("ERROR: disk full"));
+        assert!(Series::new("a", &[1, 2]).str().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lengths() {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("abc"), None, Some("éü")]);
+        assert_eq!(Vec::from(&ca.lengths()), &[Some(3), None, Some(2)]);
+        assert_eq!(Vec::from(&ca.n_bytes()), &[Some(3), None, Some(4)]);
+        assert_eq!(ca.lengths().name(), "a");
+    }
+
+    #[test]
+    fn test_concat() {
+        let a = Utf8Chunked::new_from_opt_slice("a", &[Some("x"), None, Some("z")]);
+        let b = Utf8Chunked::new_from_slice("b", &["1", "2", "3"]);
+        let out = a.concat_with(&b, Some("-"));
+        assert_eq!(Vec::from(&out), &[Some("x-1"), None, Some("z-3")]);
+        assert_eq!(out.name(), "a");
+        let out = a.concat_with(&Utf8Chunked::new_from_slice("b", &["!"]), None);
+        assert_eq!(Vec::from(&out), &[Some("x!"), None, Some("z!")]);
+
+        let out = a.concat(&b);
+        assert_eq!(Vec::from(&out), &[Some("x1"), None, Some("z3")]);
+
+        let out = a.str_concat(", ");
+        assert_eq!(out.len(), 1);
+        assert_eq!(out.get(0), Some("x, z"));
+    }
+
+    #[test]
+    fn test_split_and_extract() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("a,b"), None, Some("c")]);
+        let out = ca.split(",");
+        assert_eq!(out.len(), 3);
+        assert_eq!(out.null_count(), 1);
+        let first = out.get(0).unwrap();
+        assert_eq!(Vec::from(first.utf8()?), &[Some("a"), Some("b")]);
+        assert_eq!(out.get(2).unwrap().len(), 1);
+
+        let ca = Utf8Chunked::new_from_slice("a", &["id: 12", "id: x", "id: 3"]);
+        let out = ca.extract(r"id: (\d+)", 1)?;
+        assert_eq!(Vec::from(&out), &[Some("12"), None, Some("3")]);
+        assert_eq!(ca.extract(r"(\d+)", 2)?.null_count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_and_pad() {
+        let ca = Utf8Chunked::new_from_opt_slice("a", &[Some("  Foo "), None, Some("-1")]);
+        assert_eq!(Vec::from(&ca.strip()), &[Some("Foo"), None, Some("-1")]);
+        assert_eq!(ca.lstrip().get(0), Some("Foo "));
+        assert_eq!(ca.rstrip().get(0), Some("  Foo"));
+        assert_eq!(ca.strip().to_uppercase().get(0), Some("FOO"));
+        assert_eq!(ca.strip().to_lowercase().get(0), Some("foo"));
+        assert_eq!(
+            Vec::from(&ca.zfill(4)),
+            &[Some("  Foo "), None, Some("-001")]
+        );
+        assert_eq!(ca.pad_start(4, '*').get(2), Some("**-1"));
+        assert_eq!(ca.pad_end(4, '*').get(2), Some("-1**"));
+        assert_eq!(ca.pad_end(4, '*').null_count(), 1);
+        assert_eq!(ca.strip().name(), "a");
+    }
 }
@@ -1,8 +1,9 @@
 use super::*;
 use crate::chunked_array::kernels::temporal::{
-    date32_as_duration, date32_to_day, date32_to_month, date32_to_ordinal, date32_to_year,
-    date64_as_duration, date64_to_day, date64_to_hour, date64_to_minute, date64_to_month,
-    date64_to_nanosecond, date64_to_ordinal, date64_to_second,
+    date32_as_duration, date32_to_day, date32_to_month, date32_to_ordinal, date32_to_weekday,
+    date32_to_year, date64_as_duration, date64_to_day, date64_to_hour, date64_to_minute,
+    date64_to_month, date64_to_nanosecond, date64_to_ordinal, date64_to_second, date64_to_weekday,
+    date64_to_year,
 };
 use crate::prelude::*;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
@@ -201,6 +202,11 @@ impl Utf8Chunked {
         if reg.is_match(val) {
             return Ok("%Y/%m/%d %H:%M:%S");
         }
+        let pat = r"^\d{4}-\d{1,2}-\d{1,2}T\d{2}:\d{2}:\d{2}\s*$";
+        let reg = Regex::new(pat).expect("wrong regex");
+        if reg.is_match(val) {
+            return Ok("%Y-%m-%dT%H:%M:%S");
+        }
         Err(PolarsError::Other(
             "Could not find an appropriate format to parse dates, please define a fmt".into(),
         ))
@@ -223,13 +229,18 @@ impl Utf8Chunked {
         let pat = r"^\d{4}/\d{1,2}/\d{1,2}\s*$";
         let reg = Regex::new(pat).expect("wrong regex");
         if reg.is_match(val) {
-            return Ok("%Y/%m/%d %H:%M:%S");
+            return Ok("%Y/%m/%d");
         }
         Err(PolarsError::Other(
             "Could not find an appropriate format to parse dates, please define a fmt".into(),
         ))
     }
 
+    /// Parse the strings to dates with a chrono `fmt` rule, e.g. `"%Y-%m-%d"`.
+    /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// If `fmt` is `None`, the format is inferred from the first non null value.
+    /// Values that cannot be parsed become null.
     pub fn as_date32(&self, fmt: Option<&str>) -> Result<Date32Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
@@ -258,6 +269,11 @@ impl Utf8Chunked {
         Ok(ca)
     }
 
+    /// Parse the strings to datetimes with a chrono `fmt` rule, e.g. `"%Y-%m-%d %H:%M:%S"`.
+    /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    ///
+    /// If `fmt` is `None`, the format is inferred from the first non null value.
+    /// Values that cannot be parsed become null.
     pub fn as_date64(&self, fmt: Option<&str>) -> Result<Date64Chunked> {
         let fmt = match fmt {
             Some(fmt) => fmt,
@@ -289,13 +305,30 @@ impl Utf8Chunked {
         ca.rename(self.name());
         Ok(ca)
     }
+
+    /// Parse the strings to datetimes. Same as [as_date64](Utf8Chunked::as_date64).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let ca = Utf8Chunked::new_from_opt_slice(
+    ///     "timestamps",
+    ///     &[Some("2021-03-01 12:30:00"), Some("not a date"), None],
+    /// );
+    /// let dt = ca.as_datetime(Some("%Y-%m-%d %H:%M:%S")).unwrap();
+    /// assert_eq!(dt.null_count(), 2);
+    /// ```
+    pub fn as_datetime(&self, fmt: Option<&str>) -> Result<Date64Chunked> {
+        self.as_date64(fmt)
+    }
 }
 
 impl Date64Chunked {
-    /// Extract month from underlying NaiveDateTime representation.
+    /// Extract year from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
     pub fn year(&self) -> Int32Chunked {
-        self.apply_kernel_cast::<_, Int32Type>(date64_to_month)
+        self.apply_kernel_cast::<_, Int32Type>(date64_to_year)
     }
 
     /// Extract month from underlying NaiveDateTime representation.
@@ -313,6 +346,14 @@ impl Date64Chunked {
     pub fn day(&self) -> UInt32Chunked {
         self.apply_kernel_cast::<_, UInt32Type>(date64_to_day)
     }
+
+    /// Returns the day of the week starting from 0 for Monday.
+    ///
+    /// The return value ranges from 0 to 6.
+    pub fn weekday(&self) -> UInt32Chunked {
+        self.apply_kernel_cast::<_, UInt32Type>(date64_to_weekday)
+    }
+
     /// Extract hour from underlying NaiveDateTime representation.
     /// Returns the hour number from 0 to 23.
     pub fn hour(&self) -> UInt32Chunked {
@@ -354,7 +395,7 @@ impl Date64Chunked {
 }
 
 impl Date32Chunked {
-    /// Extract month from underlying NaiveDateTime representation.
+    /// Extract year from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
     pub fn year(&self) -> Int32Chunked {
         self.apply_kernel_cast::<_, Int32Type>(date32_to_year)
@@ -376,6 +417,13 @@ impl Date32Chunked {
         self.apply_kernel_cast::<_, UInt32Type>(date32_to_day)
     }
 
+    /// Returns the day of the week starting from 0 for Monday.
+    ///
+    /// The return value ranges from 0 to 6.
+    pub fn weekday(&self) -> UInt32Chunked {
+        self.apply_kernel_cast::<_, UInt32Type>(date32_to_weekday)
+    }
+
     /// Returns the day of year starting from 1.
     ///
     /// The return value ranges from 1 to 366. (The last day of year differs by years.)
@@ -1,17 +1,107 @@
 //! Traits and utilities for temporal data.
 pub mod conversion;
 pub(crate) mod conversions_utils;
+mod truncate;
 pub use self::conversion::{
     AsDuration, AsNaiveDate, AsNaiveDateTime, AsNaiveTime, FromNaiveDate, FromNaiveDateTime,
     FromNaiveTime,
 };
 pub(crate) use self::conversions_utils::*;
+use crate::prelude::*;
 use chrono::NaiveDateTime;
 
 pub fn unix_time() -> NaiveDateTime {
     NaiveDateTime::from_timestamp(0, 0)
 }
 
+/// Temporal operations on a Series of dtype Date32 or Date64. Created with
+/// [Series::dt](crate::series::Series::dt).
+///
+/// The time units `hour`, `minute`, `second` and `nanosecond` are only defined for Date64.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// fn months(s: &Series) -> Result<Series> {
+///     s.dt()?.month()
+/// }
+/// ```
+pub struct DateTimeNameSpace<'a>(pub(crate) &'a Series);
+
+impl<'a> DateTimeNameSpace<'a> {
+    /// The year number in the calendar date as Int32.
+    pub fn year(&self) -> Result<Series> {
+        self.0.year()
+    }
+
+    /// The month number starting from 1 as UInt32.
+    pub fn month(&self) -> Result<Series> {
+        self.0.month()
+    }
+
+    /// The day of month starting from 1 as UInt32.
+    pub fn day(&self) -> Result<Series> {
+        self.0.day()
+    }
+
+    /// The day of year starting from 1 as UInt32.
+    pub fn ordinal_day(&self) -> Result<Series> {
+        self.0.ordinal_day()
+    }
+
+    /// The day of the week starting from 0 for Monday as UInt32.
+    pub fn weekday(&self) -> Result<Series> {
+        self.0.weekday()
+    }
+
+    /// The hour number from 0 to 23 as UInt32.
+    pub fn hour(&self) -> Result<Series> {
+        self.0.hour()
+    }
+
+    /// The minute number from 0 to 59 as UInt32.
+    pub fn minute(&self) -> Result<Series> {
+        self.0.minute()
+    }
+
+    /// The second number from 0 to 59 as UInt32.
+    pub fn second(&self) -> Result<Series> {
+        self.0.second()
+    }
+
+    /// The number of nanoseconds since the whole second as UInt32.
+    pub fn nanosecond(&self) -> Result<Series> {
+        self.0.nanosecond()
+    }
+
+    /// Truncate the values to a multiple of the interval `every`, e.g. `"1h"` or `"15m"`.
+    ///
+    /// The units are `ms`, `s`, `m`, `h`, `d` and `w` and can be combined, e.g. `"1h30m"`.
+    /// For Date32 the interval must be a whole number of days.
+    pub fn truncate(&self, every: &str) -> Result<Series> {
+        match self.0.dtype() {
+            DataType::Date32 => Ok(self.0.date32()?.truncate(every)?.into_series()),
+            _ => Ok(self.0.date64()?.truncate(every)?.into_series()),
+        }
+    }
+
+    /// Round the values to the nearest multiple of the interval `every`.
+    /// See [truncate](DateTimeNameSpace::truncate) for the format of the interval.
+    pub fn round(&self, every: &str) -> Result<Series> {
+        match self.0.dtype() {
+            DataType::Date32 => Ok(self.0.date32()?.round(every)?.into_series()),
+            _ => Ok(self.0.date64()?.round(every)?.into_series()),
+        }
+    }
+
+    /// Format the values with a `fmt` rule.
+    /// See [chrono strftime/strptime](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html).
+    pub fn strftime(&self, fmt: &str) -> Result<Series> {
+        self.0.datetime_str_fmt(fmt)
+    }
+}
+
 #[cfg(all(test, feature = "temporal"))]
 mod test {
     use crate::prelude::*;
@@ -63,4 +153,75 @@ mod test {
             ca.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_utf8() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "dates",
+            &[
+                Some("2020/08/21"),
+                None,
+                Some("2020/13/40"),
+                Some("2020/08/23"),
+            ],
+        );
+        let dates = ca.as_date32(None)?;
+        assert_eq!(dates.name(), "dates");
+        assert_eq!(Vec::from(&dates), &[Some(18495), None, None, Some(18497)]);
+
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "datetimes",
+            &[Some("1970-01-01T00:00:01"), Some("foo"), None],
+        );
+        let datetimes = ca.as_datetime(None)?;
+        assert_eq!(Vec::from(&datetimes), &[Some(1000), None, None]);
+        let datetimes = ca.as_datetime(Some("%d-%m-%Y"))?;
+        assert_eq!(datetimes.null_count(), 3);
+
+        let ca = Utf8Chunked::new_from_opt_slice("nulls", &[None, None]);
+        assert!(ca.as_date32(None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dt_namespace() -> Result<()> {
+        // 2021-03-01 is a Monday, 2021-03-07 a Sunday
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "dt",
+            &[
+                Some("2021-03-01 13:05:09"),
+                None,
+                Some("2021-03-07 00:00:00"),
+            ],
+        );
+        let s = ca.as_date64(None)?.into_series();
+        let dt = s.dt()?;
+        assert_eq!(
+            Vec::from(dt.year()?.i32()?),
+            &[Some(2021), None, Some(2021)]
+        );
+        assert_eq!(Vec::from(dt.month()?.u32()?), &[Some(3), None, Some(3)]);
+        assert_eq!(Vec::from(dt.day()?.u32()?), &[Some(1), None, Some(7)]);
+        assert_eq!(
+            Vec::from(dt.ordinal_day()?.u32()?),
+            &[Some(60), None, Some(66)]
+        );
+        assert_eq!(Vec::from(dt.weekday()?.u32()?), &[Some(0), None, Some(6)]);
+        assert_eq!(Vec::from(dt.hour()?.u32()?), &[Some(13), None, Some(0)]);
+        assert_eq!(Vec::from(dt.minute()?.u32()?), &[Some(5), None, Some(0)]);
+        assert_eq!(Vec::from(dt.second()?.u32()?), &[Some(9), None, Some(0)]);
+
+        let ca =
+            Utf8Chunked::new_from_opt_slice("dt", &[Some("2021-03-01"), None, Some("2021-03-07")]);
+        let s = ca.as_date32(None)?.into_series();
+        let dt = s.dt()?;
+        assert_eq!(Vec::from(dt.weekday()?.u32()?), &[Some(0), None, Some(6)]);
+        assert_eq!(
+            Vec::from(dt.year()?.i32()?),
+            &[Some(2021), None, Some(2021)]
+        );
+
+        assert!(Series::new("a", &[1, 2]).dt().is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,161 @@
+use crate::prelude::*;
+
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Parse an interval like `"15m"` or `"1h30m"` to milliseconds.
+///
+/// The units are `ms` (milliseconds), `s` (seconds), `m` (minutes), `h` (hours), `d` (days) and
+/// `w` (weeks).
+pub(crate) fn parse_interval(interval: &str) -> Result<i64> {
+    let invalid = || {
+        PolarsError::ValueError(
+            format!(
+                "invalid interval '{}', expected e.g. '500ms', '15m' or '1h30m'",
+                interval
+            )
+            .into(),
+        )
+    };
+
+    let mut total = 0i64;
+    let mut chars = interval.chars().peekable();
+    if chars.peek().is_none() {
+        return Err(invalid());
+    }
+    while chars.peek().is_some() {
+        let mut n = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+            n.push(*c);
+            chars.next();
+        }
+        let mut unit = String::new();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_alphabetic()) {
+            unit.push(*c);
+            chars.next();
+        }
+        let n: i64 = n.parse().map_err(|_| invalid())?;
+        let unit_ms = match unit.as_str() {
+            "ms" => 1,
+            "s" => 1_000,
+            "m" => 60_000,
+            "h" => 3_600_000,
+            "d" => MILLISECONDS_IN_DAY,
+            "w" => 7 * MILLISECONDS_IN_DAY,
+            _ => return Err(invalid()),
+        };
+        total += n * unit_ms;
+    }
+    if total <= 0 {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+fn truncate_ms(t: i64, every: i64) -> i64 {
+    t - t.rem_euclid(every)
+}
+
+fn round_ms(t: i64, every: i64) -> i64 {
+    truncate_ms(t + every / 2, every)
+}
+
+impl Date64Chunked {
+    /// Truncate the datetimes to a multiple of the interval `every`, e.g. `"1h"`.
+    /// The intervals start at the Unix epoch.
+    pub fn truncate(&self, every: &str) -> Result<Date64Chunked> {
+        let every = parse_interval(every)?;
+        Ok(self.apply(|t| truncate_ms(t, every)))
+    }
+
+    /// Round the datetimes to the nearest multiple of the interval `every`, e.g. `"15m"`.
+    /// Ties are rounded up.
+    pub fn round(&self, every: &str) -> Result<Date64Chunked> {
+        let every = parse_interval(every)?;
+        Ok(self.apply(|t| round_ms(t, every)))
+    }
+}
+
+impl Date32Chunked {
+    fn every_days(every: &str) -> Result<i64> {
+        let every = parse_interval(every)?;
+        if every % MILLISECONDS_IN_DAY != 0 {
+            return Err(PolarsError::ValueError(
+                "the interval to truncate or round a Date32 must be a whole number of days".into(),
+            ));
+        }
+        Ok(every / MILLISECONDS_IN_DAY)
+    }
+
+    /// Truncate the dates to a multiple of the interval `every`, e.g. `"1w"`.
+    /// The interval must be a whole number of days.
+    pub fn truncate(&self, every: &str) -> Result<Date32Chunked> {
+        let every = Self::every_days(every)?;
+        Ok(self.apply(|t| truncate_ms(t as i64, every) as i32))
+    }
+
+    /// Round the dates to the nearest multiple of the interval `every`.
+    /// The interval must be a whole number of days.
+    pub fn round(&self, every: &str) -> Result<Date32Chunked> {
+        let every = Self::every_days(every)?;
+        Ok(self.apply(|t| round_ms(t as i64, every) as i32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("15m").unwrap(), 15 * 60_000);
+        assert_eq!(parse_interval("1h30m").unwrap(), 90 * 60_000);
+        assert_eq!(parse_interval("2d").unwrap(), 2 * MILLISECONDS_IN_DAY);
+        assert_eq!(parse_interval("250ms").unwrap(), 250);
+        for invalid in &["", "m", "15", "1y", "0s"] {
+            assert!(parse_interval(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn test_truncate_and_round() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "dt",
+            &[
+                Some("2021-03-01 13:05:09"),
+                None,
+                Some("2021-03-01 13:52:30"),
+            ],
+        );
+        let ca = ca.as_date64(None)?;
+        let truncated = ca.truncate("15m")?.str_fmt("%H:%M:%S");
+        assert_eq!(
+            Vec::from(&truncated),
+            &[Some("13:00:00"), None, Some("13:45:00")]
+        );
+        // 13:52:30 is a tie
+        let rounded = ca.round("15m")?.str_fmt("%H:%M:%S");
+        assert_eq!(
+            Vec::from(&rounded),
+            &[Some("13:00:00"), None, Some("14:00:00")]
+        );
+        let rounded = ca.round("1h")?.str_fmt("%H:%M:%S");
+        assert_eq!(
+            Vec::from(&rounded),
+            &[Some("13:00:00"), None, Some("14:00:00")]
+        );
+        assert_eq!(ca.truncate("1h")?.name(), "dt");
+
+        let dates = Date32Chunked::new_from_slice("d", &[18687, 18690]);
+        // 18687 (2021-03-01) is a multiple of 3 and 18690 of 2
+        assert_eq!(
+            Vec::from(&dates.truncate("3d")?),
+            &[Some(18687), Some(18690)]
+        );
+        assert_eq!(
+            Vec::from(&dates.truncate("2d")?),
+            &[Some(18686), Some(18690)]
+        );
+        assert!(dates.truncate("12h").is_err());
+        Ok(())
+    }
+}
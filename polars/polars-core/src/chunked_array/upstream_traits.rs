@@ -21,6 +21,7 @@ impl<T> Default for ChunkedArray<T> {
             chunk_id: Default::default(),
             phantom: PhantomData,
             categorical_map: None,
+            sorted: IsSorted::Not,
         }
     }
 }
@@ -284,6 +285,24 @@ impl FromParallelIterator<bool> for BooleanChunked {
     }
 }
 
+impl FromParallelIterator<Option<bool>> for BooleanChunked {
+    fn from_par_iter<I: IntoParallelIterator<Item = Option<bool>>>(iter: I) -> Self {
+        let vectors = collect_into_linked_list(iter);
+
+        let capacity: usize = get_capacity_from_par_results(&vectors);
+
+        let mut builder = BooleanChunkedBuilder::new("", capacity);
+        // Unpack all these results and append them single threaded
+        vectors.iter().for_each(|vec| {
+            for opt_val in vec {
+                builder.append_option(*opt_val);
+            }
+        });
+
+        builder.finish()
+    }
+}
+
 impl<Ptr> FromParallelIterator<Ptr> for Utf8Chunked
 where
     Ptr: PolarsAsRef<str> + Send + Sync,
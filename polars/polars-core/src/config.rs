@@ -0,0 +1,261 @@
+//! Runtime configuration.
+//!
+//! All tunables can be set with an environment variable or programmatically with the
+//! corresponding `set_*` function. A programmatically set value takes precedence over the
+//! environment variable. Passing `None` to a `set_*` function restores the environment variable
+//! or default value.
+//!
+//! | setting                  | environment variable          | default           |
+//! |--------------------------|-------------------------------|-------------------|
+//! | `fmt_max_cols`           | `POLARS_FMT_MAX_COLS`         | 8                 |
+//! | `fmt_max_rows`           | `POLARS_FMT_MAX_ROWS`         | 8                 |
+//! | `fmt_max_series_rows`    | `POLARS_FMT_MAX_SERIES_ROWS`  | 10                |
+//! | `fmt_table_width`        | `POLARS_TABLE_WIDTH`          | 100               |
+//! | `fmt_str_len`            | `POLARS_FMT_STR_LEN`          | 32                |
+//! | `fmt_float_precision`    | `POLARS_FMT_FLOAT_PRECISION`  | not set           |
+//! | `fmt_show_dtypes`        | `POLARS_FMT_SHOW_DTYPES`      | true              |
+//! | `max_threads`            | `POLARS_MAX_THREADS`          | number of cpus    |
+//! | `par_sort_bound`         | `POLARS_PAR_SORT_BOUND`       | 1_000_000         |
+//! | `par_groupby_bound`      | `POLARS_PAR_GROUPBY_BOUND`    | 1_000             |
+//! | `infer_schema_length`    | `POLARS_INFER_SCHEMA_LENGTH`  | 128               |
+//! | `chunk_size`             | `POLARS_CHUNK_SIZE`           | 65_536            |
+//! | `verbose`                | `POLARS_VERBOSE`              | false             |
+//! | `string_cache`           | `POLARS_STRING_CACHE`         | false             |
+//!
+//! An environment variable that is not a valid number is ignored.
+//!
+//! The thread pool is created on first use, so `max_threads` must be set before any parallel
+//! operation runs to have an effect on it.
+//!
+//! # Example
+//!
+//! ```
+//! use polars_core::config;
+//!
+//! config::set_fmt_max_rows(Some(20));
+//! assert_eq!(config::fmt_max_rows(), 20);
+//! config::set_fmt_max_rows(None);
+//! ```
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const UNSET: usize = usize::MAX;
+
+struct Setting {
+    env_var: &'static str,
+    default: usize,
+    value: AtomicUsize,
+}
+
+/// Resolve a setting from its programmatically set `value`, its environment variable `env` and
+/// its `default`. Returns an error with the environment variable if it is not a valid number.
+fn resolve(value: usize, env: Option<&str>, default: usize) -> Result<usize, String> {
+    match (value, env) {
+        (UNSET, None) => Ok(default),
+        (UNSET, Some(env)) => env.trim().parse().map_err(|_| env.to_string()),
+        (v, _) => Ok(v),
+    }
+}
+
+impl Setting {
+    const fn new(env_var: &'static str, default: usize) -> Self {
+        Setting {
+            env_var,
+            default,
+            value: AtomicUsize::new(UNSET),
+        }
+    }
+
+    fn get_or(&self, default: usize) -> usize {
+        let env = std::env::var(self.env_var).ok();
+        // an invalid environment variable is ignored
+        resolve(self.value.load(Ordering::Relaxed), env.as_deref(), default).unwrap_or(default)
+    }
+
+    fn get(&self) -> usize {
+        self.get_or(self.default)
+    }
+
+    fn set(&self, value: Option<usize>) {
+        self.value.store(value.unwrap_or(UNSET), Ordering::Relaxed)
+    }
+}
+
+static FMT_MAX_COLS: Setting = Setting::new("POLARS_FMT_MAX_COLS", 8);
+static FMT_MAX_ROWS: Setting = Setting::new("POLARS_FMT_MAX_ROWS", 8);
+static FMT_MAX_SERIES_ROWS: Setting = Setting::new("POLARS_FMT_MAX_SERIES_ROWS", 10);
+static FMT_TABLE_WIDTH: Setting = Setting::new("POLARS_TABLE_WIDTH", 100);
+static FMT_STR_LEN: Setting = Setting::new("POLARS_FMT_STR_LEN", 32);
+// not set by default, floats are then rounded to 3 decimals
+static FMT_FLOAT_PRECISION: Setting = Setting::new("POLARS_FMT_FLOAT_PRECISION", UNSET);
+// 0 is false, any other value is true
+static FMT_SHOW_DTYPES: Setting = Setting::new("POLARS_FMT_SHOW_DTYPES", 1);
+// the default is determined at runtime
+static MAX_THREADS: Setting = Setting::new("POLARS_MAX_THREADS", UNSET);
+static PAR_SORT_BOUND: Setting = Setting::new("POLARS_PAR_SORT_BOUND", 1_000_000);
+static PAR_GROUPBY_BOUND: Setting = Setting::new("POLARS_PAR_GROUPBY_BOUND", 1_000);
+static INFER_SCHEMA_LENGTH: Setting = Setting::new("POLARS_INFER_SCHEMA_LENGTH", 128);
+static CHUNK_SIZE: Setting = Setting::new("POLARS_CHUNK_SIZE", 1 << 16);
+// 0 is false, any other value is true
+static VERBOSE: Setting = Setting::new("POLARS_VERBOSE", 0);
+
+/// Maximum number of columns shown when formatting a DataFrame.
+pub fn fmt_max_cols() -> usize {
+    FMT_MAX_COLS.get()
+}
+
+pub fn set_fmt_max_cols(n: Option<usize>) {
+    FMT_MAX_COLS.set(n)
+}
+
+/// Maximum number of rows shown when formatting a DataFrame.
+pub fn fmt_max_rows() -> usize {
+    FMT_MAX_ROWS.get()
+}
+
+pub fn set_fmt_max_rows(n: Option<usize>) {
+    FMT_MAX_ROWS.set(n)
+}
+
+/// Maximum number of values shown when formatting a Series or ChunkedArray.
+pub fn fmt_max_series_rows() -> usize {
+    FMT_MAX_SERIES_ROWS.get()
+}
+
+pub fn set_fmt_max_series_rows(n: Option<usize>) {
+    FMT_MAX_SERIES_ROWS.set(n)
+}
+
+/// Width in characters of a formatted DataFrame table.
+pub fn fmt_table_width() -> u16 {
+    std::cmp::min(FMT_TABLE_WIDTH.get(), u16::MAX as usize) as u16
+}
+
+pub fn set_fmt_table_width(width: Option<u16>) {
+    FMT_TABLE_WIDTH.set(width.map(|w| w as usize))
+}
+
+/// Maximum number of characters shown of a string value, longer strings are cut off with `...`.
+pub fn fmt_str_len() -> usize {
+    FMT_STR_LEN.get()
+}
+
+pub fn set_fmt_str_len(n: Option<usize>) {
+    FMT_STR_LEN.set(n)
+}
+
+/// Number of decimals shown of a float value. If not set, floats are rounded to 3 decimals and
+/// very small or large values are shown in scientific notation.
+pub fn fmt_float_precision() -> Option<usize> {
+    match FMT_FLOAT_PRECISION.get() {
+        UNSET => None,
+        n => Some(n),
+    }
+}
+
+pub fn set_fmt_float_precision(precision: Option<usize>) {
+    FMT_FLOAT_PRECISION.set(precision)
+}
+
+/// Show the data types below the column names of a formatted DataFrame.
+pub fn fmt_show_dtypes() -> bool {
+    FMT_SHOW_DTYPES.get() != 0
+}
+
+pub fn set_fmt_show_dtypes(show: Option<bool>) {
+    FMT_SHOW_DTYPES.set(show.map(|v| v as usize))
+}
+
+/// Maximum number of threads used by parallel operations.
+pub fn max_threads() -> usize {
+    match MAX_THREADS.get() {
+        UNSET => num_cpus::get(),
+        n => std::cmp::max(n, 1),
+    }
+}
+
+pub fn set_max_threads(n: Option<usize>) {
+    MAX_THREADS.set(n)
+}
+
+/// Minimal array length for which sorting is done in parallel.
+pub fn par_sort_bound() -> usize {
+    PAR_SORT_BOUND.get()
+}
+
+pub fn set_par_sort_bound(n: Option<usize>) {
+    PAR_SORT_BOUND.set(n)
+}
+
+/// Minimal number of rows for which the groups of a groupby are determined in parallel. The
+/// rows are partitioned by the hash of their key over the threads and every thread groups its
+/// partition, so this pays off for many rows with many distinct keys.
+pub fn par_groupby_bound() -> usize {
+    PAR_GROUPBY_BOUND.get()
+}
+
+pub fn set_par_groupby_bound(n: Option<usize>) {
+    PAR_GROUPBY_BOUND.set(n)
+}
+
+/// Default number of rows used to infer the schema of a file.
+pub fn infer_schema_length() -> usize {
+    INFER_SCHEMA_LENGTH.get()
+}
+
+pub fn set_infer_schema_length(n: Option<usize>) {
+    INFER_SCHEMA_LENGTH.set(n)
+}
+
+/// Default number of rows in a chunk when data is split in batches, e.g. when streaming
+/// record batches.
+pub fn chunk_size() -> usize {
+    std::cmp::max(CHUNK_SIZE.get(), 1)
+}
+
+pub fn set_chunk_size(n: Option<usize>) {
+    CHUNK_SIZE.set(n)
+}
+
+/// Print information about the executed operations to stdout.
+///
+/// If not set programmatically, this is enabled if `POLARS_VERBOSE` is set to any value.
+pub fn verbose() -> bool {
+    match VERBOSE.value.load(Ordering::Relaxed) {
+        UNSET => std::env::var(VERBOSE.env_var).is_ok(),
+        v => v != 0,
+    }
+}
+
+pub fn set_verbose(verbose: Option<bool>) {
+    VERBOSE.set(verbose.map(|v| v as usize))
+}
+
+/// Whether the global string cache is enabled on a new thread.
+pub(crate) fn string_cache_default() -> bool {
+    std::env::var("POLARS_STRING_CACHE")
+        .map(|v| v != "0")
+        .unwrap_or(false)
+}
+
+/// Toggle the global string cache for the current thread.
+/// See [toggle_string_cache](crate::toggle_string_cache).
+pub fn set_string_cache(toggle: bool) {
+    crate::toggle_string_cache(toggle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_config_resolve() {
+        assert_eq!(resolve(UNSET, None, 100), Ok(100));
+        assert_eq!(resolve(UNSET, Some("20"), 100), Ok(20));
+        assert_eq!(resolve(UNSET, Some(" 20 "), 100), Ok(20));
+        // a programmatically set value takes precedence over the environment variable
+        assert_eq!(resolve(5, Some("20"), 100), Ok(5));
+        assert_eq!(resolve(5, None, 100), Ok(5));
+        assert_eq!(resolve(UNSET, Some("many"), 100), Err("many".to_string()));
+        assert_eq!(resolve(UNSET, Some("-1"), 100), Err("-1".to_string()));
+    }
+}
@@ -185,6 +185,30 @@ pub trait PolarsFloatType: PolarsNumericType {}
 impl PolarsFloatType for Float32Type {}
 impl PolarsFloatType for Float64Type {}
 
+/// Rust numbers that can be used as a scalar in arithmetic with a Series, e.g. `&s * 0.5`.
+pub trait NumericNative: num::Num + num::NumCast + Copy {
+    /// The dtype of a Series with values of this type.
+    fn dtype() -> DataType;
+}
+
+macro_rules! impl_numeric_native {
+    ($($native:ty => $dtype:ident),*) => {
+        $(
+            impl NumericNative for $native {
+                fn dtype() -> DataType {
+                    DataType::$dtype
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_native!(
+    u8 => UInt8, u16 => UInt16, u32 => UInt32, u64 => UInt64, usize => UInt64,
+    i8 => Int8, i16 => Int16, i32 => Int32, i64 => Int64,
+    f32 => Float32, f64 => Float64
+);
+
 #[derive(Debug, Clone)]
 pub enum AnyValue<'a> {
     Null,
@@ -261,6 +285,32 @@ impl Display for DataType {
     }
 }
 
+impl<'a> AnyValue<'a> {
+    /// Cast a numeric, temporal or boolean value to `T`. Returns `None` for other values and
+    /// values that don't fit in `T`.
+    pub(crate) fn extract<T: num::NumCast>(&self) -> Option<T> {
+        use AnyValue::*;
+        match self {
+            Boolean(v) => num::NumCast::from(*v as u8),
+            UInt8(v) => num::NumCast::from(*v),
+            UInt16(v) => num::NumCast::from(*v),
+            UInt32(v) => num::NumCast::from(*v),
+            UInt64(v) => num::NumCast::from(*v),
+            Int8(v) => num::NumCast::from(*v),
+            Int16(v) => num::NumCast::from(*v),
+            Int32(v) => num::NumCast::from(*v),
+            Int64(v) => num::NumCast::from(*v),
+            Float32(v) => num::NumCast::from(*v),
+            Float64(v) => num::NumCast::from(*v),
+            Date32(v) => num::NumCast::from(*v),
+            Date64(v) => num::NumCast::from(*v),
+            Time64(v, _) => num::NumCast::from(*v),
+            Duration(v, _) => num::NumCast::from(*v),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> PartialEq for AnyValue<'a> {
     // Everything of Any is slow. Don't use.
     fn eq(&self, other: &Self) -> bool {
@@ -346,6 +396,14 @@ impl DataType {
             Categorical => ArrowDataType::UInt16,
         }
     }
+    /// Check if this is an integer or float type.
+    pub fn is_numeric(&self) -> bool {
+        use DataType::*;
+        matches!(
+            self,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+        )
+    }
 }
 
 impl PartialEq<ArrowDataType> for DataType {
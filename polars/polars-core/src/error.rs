@@ -15,6 +15,8 @@ pub enum PolarsError {
     DataTypeMisMatch(ErrString),
     #[error("Not found: {0}")]
     NotFound(String),
+    #[error("Schemas don't match: {0}")]
+    SchemaMisMatch(ErrString),
     #[error("Lengths don't match: {0}")]
     ShapeMisMatch(ErrString),
     #[error("{0}")]
@@ -35,7 +37,7 @@ pub enum PolarsError {
     #[cfg(feature = "random")]
     #[error("{0}")]
     RandError(String),
-    #[error("This operation requires data without Null values")]
+    #[error("This operation requires data without Null values: {0}")]
     HasNullValues(ErrString),
     #[error("{0}")]
     UnknownSchema(ErrString),
@@ -48,6 +50,8 @@ pub enum PolarsError {
     Regex(#[from] regex::Error),
     #[error("DuplicateError: {0}")]
     Duplicate(ErrString),
+    #[error("Cancelled: {0}")]
+    Cancelled(ErrString),
 }
 
 pub type Result<T> = std::result::Result<T, PolarsError>;
@@ -1,3 +1,4 @@
+use crate::config;
 use crate::prelude::*;
 
 #[cfg(feature = "temporal")]
@@ -12,7 +13,6 @@ use std::{
     fmt,
     fmt::{Debug, Display, Formatter},
 };
-const LIMIT: usize = 10;
 
 /// Some unit functions that just pass the integer values if we don't want all chrono functionality
 #[cfg(not(feature = "temporal"))]
@@ -85,11 +85,7 @@ macro_rules! format_utf8_array {
                 write!($f, "\tnull\n").ok();
             }
             Some(s) => {
-                if s.len() >= $limit {
-                    write!($f, "\t\"{}...\"\n", &s[..$limit]).ok();
-                } else {
-                    write!($f, "\t\"{}\"\n", &s).ok();
-                }
+                write!($f, "\t{}\n", fmt_str_val(s, config::fmt_str_len())).ok();
             }
         });
         write![$f, "]"]
@@ -122,10 +118,9 @@ fn format_object_array(
     write![f, "{}: '{}' [object]\n[\n", array_type, name]?;
 
     for i in 0..limit {
-        let v = object.get(i);
-        match v {
-            AnyValue::Null => writeln!(f, "\tnull")?,
-            _ => writeln!(f, "\tobject")?,
+        match object.get_object(i) {
+            None => writeln!(f, "\tnull")?,
+            Some(v) => writeln!(f, "\t{}", v)?,
         }
     }
 
@@ -138,10 +133,9 @@ macro_rules! format_object_array {
         write![$f, "{}: '{}' [object]\n[\n", $array_type, $name]?;
 
         for i in 0..$limit {
-            let v = $object.get_any_value(i);
-            match v {
-                AnyValue::Null => writeln!($f, "\tnull")?,
-                _ => writeln!($f, "\tobject")?,
+            match $object.get_object(i) {
+                None => writeln!($f, "\tnull")?,
+                Some(v) => writeln!($f, "\t{}", v)?,
             }
         }
 
@@ -151,7 +145,7 @@ macro_rules! format_object_array {
 
 macro_rules! set_limit {
     ($self:ident) => {
-        std::cmp::min($self.len(), LIMIT)
+        std::cmp::min($self.len(), config::fmt_max_series_rows())
     };
 }
 
@@ -198,7 +192,7 @@ impl Debug for CategoricalChunked {
 #[cfg(feature = "object")]
 impl<T> Debug for ObjectChunked<T>
 where
-    T: 'static + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let limit = set_limit!(self);
@@ -322,20 +316,23 @@ impl Debug for DataFrame {
     }
 }
 
-fn prepare_row(row: Vec<AnyValue>, n_first: usize, n_last: usize) -> Vec<String> {
-    fn make_str_val(v: &AnyValue) -> String {
-        let string_limit = 32;
-        if let AnyValue::Utf8(s) = v {
-            if s.len() > string_limit {
-                format!("\"{}...\"", &s[..string_limit])
-            } else {
-                format!("\"{}\"", s)
-            }
-        } else {
-            format!("{}", v)
-        }
+/// Quote a string, strings longer than `max_len` characters are cut off.
+fn fmt_str_val(s: &str, max_len: usize) -> String {
+    match s.char_indices().nth(max_len) {
+        Some((idx, _)) => format!("\"{}...\"", &s[..idx]),
+        None => format!("\"{}\"", s),
+    }
+}
+
+fn make_str_val(v: &AnyValue) -> String {
+    if let AnyValue::Utf8(s) = v {
+        fmt_str_val(s, config::fmt_str_len())
+    } else {
+        format!("{}", v)
     }
+}
 
+fn prepare_row(row: Vec<AnyValue>, n_first: usize, n_last: usize) -> Vec<String> {
     let reduce_columns = n_first + n_last < row.len();
     let mut row_str = Vec::with_capacity(n_first + n_last + reduce_columns as usize);
     for v in row[0..n_first].iter() {
@@ -352,14 +349,8 @@ fn prepare_row(row: Vec<AnyValue>, n_first: usize, n_last: usize) -> Vec<String>
 
 impl Display for DataFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let max_n_cols = std::env::var("POLARS_FMT_MAX_COLS")
-            .unwrap_or_else(|_| "8".to_string())
-            .parse()
-            .unwrap_or(8);
-        let max_n_rows = std::env::var("POLARS_FMT_MAX_ROWS")
-            .unwrap_or_else(|_| "8".to_string())
-            .parse()
-            .unwrap_or(8);
+        let max_n_cols = config::fmt_max_cols();
+        let max_n_rows = config::fmt_max_rows();
 
         let (n_first, n_last) = if self.width() > max_n_cols {
             ((max_n_cols + 1) / 2, max_n_cols / 2)
@@ -368,33 +359,26 @@ impl Display for DataFrame {
         };
         let reduce_columns = n_first + n_last < self.width();
 
-        let field_to_str = |f: &Field| format!("{}\n---\n{}", f.name(), f.data_type());
+        let show_dtypes = config::fmt_show_dtypes();
 
         let mut names = Vec::with_capacity(n_first + n_last + reduce_columns as usize);
         let schema = self.schema();
         let fields = schema.fields();
         for field in fields[0..n_first].iter() {
-            names.push(field_to_str(field))
+            names.push(fmt_field(field, show_dtypes))
         }
         if reduce_columns {
             names.push("...".to_string())
         }
         for field in fields[self.width() - n_last..].iter() {
-            names.push(field_to_str(field))
+            names.push(fmt_field(field, show_dtypes))
         }
         let mut table = Table::new();
         table
             .load_preset(UTF8_FULL)
             .set_content_arrangement(ContentArrangement::Dynamic)
             .apply_modifier(UTF8_ROUND_CORNERS)
-            .set_table_width(
-                std::env::var("POLARS_TABLE_WIDTH")
-                    .map(|s| {
-                        s.parse::<u16>()
-                            .expect("could not parse table width argument")
-                    })
-                    .unwrap_or(100),
-            )
+            .set_table_width(config::fmt_table_width())
             .set_header(names);
         let mut rows = Vec::with_capacity(max_n_rows);
         if self.height() > max_n_rows {
@@ -427,6 +411,65 @@ impl Display for DataFrame {
     }
 }
 
+impl DataFrame {
+    /// Get a vertical preview of the `DataFrame`, with one line per column that shows the name,
+    /// the data type and the first values of the column. This is more readable than the table
+    /// representation for `DataFrame`s with many columns.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2, 3],
+    ///     "b" => &["x", "y", "z"]
+    /// }
+    /// .unwrap();
+    /// println!("{}", df.glimpse());
+    /// // Rows: 3
+    /// // Columns: 2
+    /// // $ a <i32> 1, 2, 3
+    /// // $ b <str> "x", "y", "z"
+    /// ```
+    pub fn glimpse(&self) -> String {
+        self.glimpse_with_limit(config::fmt_max_series_rows())
+    }
+
+    /// [glimpse](DataFrame::glimpse) showing at most `limit` values per column.
+    fn glimpse_with_limit(&self, limit: usize) -> String {
+        let name_width = self
+            .get_column_names()
+            .iter()
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let dtypes = self
+            .columns
+            .iter()
+            .map(|s| format!("<{}>", s.dtype()))
+            .collect::<Vec<_>>();
+        let dtype_width = dtypes.iter().map(|dt| dt.len()).max().unwrap_or(0);
+
+        let mut out = format!("Rows: {}\nColumns: {}\n", self.height(), self.width());
+        for (s, dtype) in self.columns.iter().zip(dtypes) {
+            let n_values = std::cmp::min(s.len(), limit);
+            let mut values = (0..n_values)
+                .map(|idx| make_str_val(&s.get(idx)))
+                .collect::<Vec<_>>();
+            if s.len() > n_values {
+                values.push("...".to_string());
+            }
+            out.push_str(&format!(
+                "$ {:<name_width$} {:<dtype_width$} {}\n",
+                s.name(),
+                dtype,
+                values.join(", "),
+                name_width = name_width,
+                dtype_width = dtype_width
+            ));
+        }
+        out
+    }
+}
+
 fn fmt_integer<T: Num + NumCast + Display>(
     f: &mut Formatter<'_>,
     width: usize,
@@ -435,8 +478,30 @@ fn fmt_integer<T: Num + NumCast + Display>(
     write!(f, "{:>width$}", v, width = width)
 }
 
-fn fmt_float<T: Num + NumCast>(f: &mut Formatter<'_>, width: usize, v: T) -> fmt::Result {
+/// The header of a column, with its dtype below the name if `show_dtypes` is set.
+fn fmt_field(field: &Field, show_dtypes: bool) -> String {
+    match show_dtypes {
+        true => format!("{}\n---\n{}", field.name(), field.data_type()),
+        false => field.name().to_string(),
+    }
+}
+
+fn fmt_float<T: Num + NumCast, W: fmt::Write>(
+    f: &mut W,
+    width: usize,
+    v: T,
+    precision: Option<usize>,
+) -> fmt::Result {
     let v: f64 = NumCast::from(v).unwrap();
+    if let Some(precision) = precision {
+        return write!(
+            f,
+            "{:>width$.precision$}",
+            v,
+            width = width,
+            precision = precision
+        );
+    }
     let v = (v * 1000.).round() / 1000.;
     if v == 0.0 {
         write!(f, "{:>width$.1}", v, width = width)
@@ -460,8 +525,8 @@ impl Display for AnyValue<'_> {
             AnyValue::Int16(v) => fmt_integer(f, width, *v),
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
-            AnyValue::Float32(v) => fmt_float(f, width, *v),
-            AnyValue::Float64(v) => fmt_float(f, width, *v),
+            AnyValue::Float32(v) => fmt_float(f, width, *v, config::fmt_float_precision()),
+            AnyValue::Float64(v) => fmt_float(f, width, *v, config::fmt_float_precision()),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
             AnyValue::Utf8(v) => write!(f, "{}", format!("\"{}\"", v)),
             AnyValue::Date32(v) => write!(f, "{}", date32_as_datetime(*v).date()),
@@ -553,14 +618,21 @@ impl FmtList for CategoricalChunked {
 }
 
 #[cfg(feature = "object")]
-impl<T> FmtList for ObjectChunked<T> {
+impl<T: PolarsObject> FmtList for ObjectChunked<T> {
     fn fmt_list(&self) -> String {
-        todo!()
+        let values = (0..self.len())
+            .map(|i| match self.get_object(i) {
+                Some(v) => format!("{}", v),
+                None => "null".to_string(),
+            })
+            .collect::<Vec<_>>();
+        format!("[{}]", values.join(", "))
     }
 }
 
 #[cfg(all(test, feature = "temporal"))]
 mod test {
+    use crate::config;
     use crate::prelude::*;
     use polars_arrow::prelude::PrimitiveArrayBuilder;
 
@@ -645,6 +717,60 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fmt_helpers() {
+        // strings are cut off at a character, not a byte
+        assert_eq!(fmt_str_val("abcéd", 4), "\"abcé...\"");
+        assert_eq!(fmt_str_val("ab", 4), "\"ab\"");
+
+        let fmt = |v: f64, precision| {
+            let mut out = String::new();
+            fmt_float(&mut out, 0, v, precision).unwrap();
+            out
+        };
+        assert_eq!(fmt(2.5, Some(2)), "2.50");
+        assert_eq!(fmt(2.5, None), "2.5");
+        assert_eq!(fmt(1.23456, None), "1.235");
+
+        let field = Field::new("value", DataType::Float64);
+        assert_eq!(fmt_field(&field, true), "value\n---\nf64");
+        assert_eq!(fmt_field(&field, false), "value");
+    }
+
+    #[test]
+    fn test_glimpse() {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "nämé" => &["x", "é漢字", "z"],
+            "flag" => &[true, false, true]
+        }
+        .unwrap();
+        // names and dtypes are padded to the widest one, in characters
+        assert_eq!(
+            df.glimpse_with_limit(3),
+            "Rows: 3
+Columns: 3
+$ a    <i32>  1, 2, 3
+$ nämé <str>  \"x\", \"é漢字\", \"z\"
+$ flag <bool> true, false, true
+"
+        );
+        // the values are truncated at the limit
+        assert_eq!(
+            df.glimpse_with_limit(2),
+            "Rows: 3
+Columns: 3
+$ a    <i32>  1, 2, ...
+$ nämé <str>  \"x\", \"é漢字\", ...
+$ flag <bool> true, false, ...
+"
+        );
+        assert_eq!(
+            df.glimpse(),
+            df.glimpse_with_limit(config::fmt_max_series_rows())
+        );
+    }
+
     #[test]
     fn test_series() {
         let s = Series::new("foo", &["Somelongstringto eeat wit me oundaf"]);
@@ -0,0 +1,439 @@
+use crate::frame::hash_join::drop_key;
+use crate::prelude::*;
+use num::ToPrimitive;
+
+/// Which row of the right DataFrame an asof join takes for a left key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AsofStrategy {
+    /// The last right row with a key smaller than or equal to the left key.
+    Backward,
+    /// The first right row with a key larger than or equal to the left key.
+    Forward,
+    /// The right row with the key closest to the left key. On a tie the backward match is taken.
+    Nearest,
+}
+
+/// The non-null values of an asof key with their row index. The key must be sorted.
+fn sorted_key<T>(ca: &ChunkedArray<T>) -> Result<Vec<(usize, T::Native)>>
+where
+    T: PolarsNumericType,
+{
+    let values = ca
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, v)| v.map(|v| (idx, v)))
+        .collect::<Vec<_>>();
+    if values.windows(2).any(|w| w[0].1 > w[1].1) {
+        return Err(PolarsError::InvalidOperation(
+            format!(
+                "the asof key '{}' must be sorted in ascending order",
+                ca.name()
+            )
+            .into(),
+        ));
+    }
+    Ok(values)
+}
+
+/// The distance between two asof keys, in a type that holds it without overflow.
+trait AsofDistance: Copy {
+    type Distance: PartialOrd + ToPrimitive;
+
+    fn distance(self, other: Self) -> Self::Distance;
+}
+
+macro_rules! impl_asof_distance {
+    ($distance:ty, $($native:ty),*) => {
+        $(
+            impl AsofDistance for $native {
+                type Distance = $distance;
+
+                fn distance(self, other: Self) -> $distance {
+                    (self as $distance - other as $distance).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_asof_distance!(i128, u8, u16, u32, u64, i8, i16, i32, i64);
+impl_asof_distance!(f64, f32);
+
+impl AsofDistance for f64 {
+    type Distance = f64;
+
+    fn distance(self, other: Self) -> f64 {
+        (self - other).abs()
+    }
+}
+
+/// Match every left key with a right key by merging both sorted keys. Returns the position in
+/// `right` of the match for every left key.
+fn asof_matches<N>(
+    left: &[(usize, N)],
+    right: &[(usize, N)],
+    strategy: AsofStrategy,
+    tolerance: Option<f64>,
+) -> Vec<Option<usize>>
+where
+    N: PartialOrd + AsofDistance,
+{
+    // right[..end_le] has keys <= l and right[..end_lt] keys < l
+    let mut end_le = 0;
+    let mut end_lt = 0;
+    left.iter()
+        .map(|&(_, l)| {
+            while end_le < right.len() && right[end_le].1 <= l {
+                end_le += 1;
+            }
+            while end_lt < right.len() && right[end_lt].1 < l {
+                end_lt += 1;
+            }
+            let backward = end_le.checked_sub(1);
+            let forward = if end_lt < right.len() {
+                Some(end_lt)
+            } else {
+                None
+            };
+            let distance = |pos: usize| right[pos].1.distance(l);
+            let pos = match strategy {
+                AsofStrategy::Backward => backward,
+                AsofStrategy::Forward => forward,
+                AsofStrategy::Nearest => match (backward, forward) {
+                    (Some(b), Some(f)) if distance(f) < distance(b) => Some(f),
+                    (Some(b), _) => Some(b),
+                    (None, f) => f,
+                },
+            };
+            pos.filter(|&pos| {
+                tolerance.map_or(true, |tol| {
+                    distance(pos).to_f64().map_or(false, |dist| dist <= tol)
+                })
+            })
+        })
+        .collect()
+}
+
+/// The row of `right` that matches every row of `left`. With `groups` only the rows in a pair
+/// of matching groups are joined, the row indexes of a group must be ascending.
+fn asof_join_idx<T>(
+    left: &ChunkedArray<T>,
+    right: &ChunkedArray<T>,
+    strategy: AsofStrategy,
+    tolerance: Option<f64>,
+    groups: Option<&[(&[usize], &[usize])]>,
+) -> Result<Vec<Option<usize>>>
+where
+    T: PolarsNumericType,
+    T::Native: AsofDistance,
+{
+    let left_key = sorted_key(left)?;
+    let right_key = sorted_key(right)?;
+
+    let mut right_idx = vec![None; left.len()];
+    let mut join_sorted = |left: Vec<(usize, T::Native)>, right: Vec<(usize, T::Native)>| {
+        let matches = asof_matches(&left, &right, strategy, tolerance);
+        for ((idx, _), pos) in left.iter().zip(matches) {
+            right_idx[*idx] = pos.map(|pos| right[pos].0);
+        }
+    };
+
+    match groups {
+        None => join_sorted(left_key, right_key),
+        Some(groups) => {
+            // the row indexes of a group are ascending, so the keys of a group are sorted
+            let mut values_left = vec![None; left.len()];
+            left_key
+                .iter()
+                .for_each(|&(idx, v)| values_left[idx] = Some(v));
+            let mut values_right = vec![None; right.len()];
+            right_key
+                .iter()
+                .for_each(|&(idx, v)| values_right[idx] = Some(v));
+            let group_key = |idx: &[usize], values: &[Option<T::Native>]| {
+                idx.iter()
+                    .filter_map(|&idx| values[idx].map(|v| (idx, v)))
+                    .collect::<Vec<_>>()
+            };
+            for (group_left, group_right) in groups {
+                join_sorted(
+                    group_key(group_left, &values_left),
+                    group_key(group_right, &values_right),
+                );
+            }
+        }
+    }
+    Ok(right_idx)
+}
+
+/// The physical representation of an asof key, the keys are compared in this type.
+fn physical_key(s: &Series) -> Result<Series> {
+    match s.dtype() {
+        DataType::Date32 => s.cast::<Int32Type>(),
+        DataType::Date64 => s.cast::<Int64Type>(),
+        dt if dt.is_numeric() => Ok(s.clone()),
+        dt => Err(PolarsError::DataTypeMisMatch(
+            format!("cannot asof join on a key of dtype {:?}", dt).into(),
+        )),
+    }
+}
+
+impl DataFrame {
+    /// Join every row with the row of `other` whose key is nearest to its own key, e.g. to
+    /// align trades with the last quote before them. The result has the rows of `self` and
+    /// the columns of `other` are null for rows without a match.
+    ///
+    /// * `strategy` - which right row matches, see [AsofStrategy].
+    /// * `tolerance` - the maximum distance between the keys of a match. The distance is in the
+    ///   units of the key, milliseconds for Date64 and days for Date32 keys.
+    /// * `by` - only join rows that have the same value in this column of both DataFrames.
+    ///
+    /// The keys must be numeric or temporal, of the same dtype and sorted in ascending order.
+    /// Rows with a null key are never matched. The right key and `by` column are not part of
+    /// the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let trades = df! {
+    ///     "time" => &[2, 5, 9],
+    ///     "quantity" => &[10, 20, 30]
+    /// }
+    /// .unwrap();
+    /// let quotes = df! {
+    ///     "time" => &[1, 4, 5, 8],
+    ///     "price" => &[1.0, 1.5, 2.0, 2.5]
+    /// }
+    /// .unwrap();
+    /// let joined = trades
+    ///     .join_asof(&quotes, "time", "time", AsofStrategy::Backward, None, None)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     Vec::from(joined.column("price").unwrap().f64().unwrap()),
+    ///     &[Some(1.0), Some(2.0), Some(2.5)]
+    /// );
+    /// ```
+    pub fn join_asof(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        strategy: AsofStrategy,
+        tolerance: Option<f64>,
+        by: Option<&str>,
+    ) -> Result<DataFrame> {
+        let s_left = self.column(left_on)?;
+        let s_right = other.column(right_on)?;
+        if s_left.dtype() != s_right.dtype() {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "the asof keys have different dtypes: {:?} and {:?}",
+                    s_left.dtype(),
+                    s_right.dtype()
+                )
+                .into(),
+            ));
+        }
+        let key_left = physical_key(s_left)?;
+        let key_right = physical_key(s_right)?;
+
+        let mut df_right = drop_key(other, s_right);
+        let gb = match by {
+            None => None,
+            Some(by) => {
+                df_right = drop_key(&df_right, other.column(by)?);
+                Some((self.groupby(by)?, other.groupby(by)?))
+            }
+        };
+        let groups = match (by, &gb) {
+            (Some(by), Some((gb_left, gb_right))) => {
+                let groups_left = gb_left.get_groups();
+                let groups_right = gb_right.get_groups();
+
+                // the groups are unique, so a left group matches at most one right group
+                let first = |groups: &[(usize, Vec<usize>)]| {
+                    groups.iter().map(|(first, _)| *first).collect::<Vec<_>>()
+                };
+                let by_left = self.column(by)?.take(&first(groups_left));
+                let by_right = other.column(by)?.take(&first(groups_right));
+                Some(
+                    by_left
+                        .hash_join_inner(&by_right)
+                        .into_iter()
+                        .map(|(l, r)| (groups_left[l].1.as_slice(), groups_right[r].1.as_slice()))
+                        .collect::<Vec<_>>(),
+                )
+            }
+            _ => None,
+        };
+
+        macro_rules! asof_join_idx {
+            ($method:ident) => {
+                asof_join_idx(
+                    key_left.$method()?,
+                    key_right.$method()?,
+                    strategy,
+                    tolerance,
+                    groups.as_deref(),
+                )?
+            };
+        }
+        let right_idx = match key_left.dtype() {
+            DataType::UInt8 => asof_join_idx!(u8),
+            DataType::UInt16 => asof_join_idx!(u16),
+            DataType::UInt32 => asof_join_idx!(u32),
+            DataType::UInt64 => asof_join_idx!(u64),
+            DataType::Int8 => asof_join_idx!(i8),
+            DataType::Int16 => asof_join_idx!(i16),
+            DataType::Int32 => asof_join_idx!(i32),
+            DataType::Int64 => asof_join_idx!(i64),
+            DataType::Float32 => asof_join_idx!(f32),
+            _ => asof_join_idx!(f64),
+        };
+
+        let df_right = unsafe {
+            df_right.take_opt_iter_unchecked_bounds(right_idx.into_iter(), Some(self.height()))
+        };
+        self.finish_join(self.clone(), df_right)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_join_asof() -> Result<()> {
+        let left = df! {
+            "time" => &[Some(1), Some(3), Some(5), None, Some(10)],
+            "a" => &[1, 2, 3, 4, 5]
+        }?;
+        let right = df! {
+            "time" => &[2, 3, 3, 7],
+            "b" => &[20, 30, 31, 70]
+        }?;
+        let join = |strategy, tolerance| -> Result<Vec<Option<i32>>> {
+            let joined = left.join_asof(&right, "time", "time", strategy, tolerance, None)?;
+            assert_eq!(joined.get_column_names(), &["time", "a", "b"]);
+            Ok(Vec::from(joined.column("b")?.i32()?))
+        };
+        // a match on an equal key takes the last of the equal right keys going backward and
+        // the first going forward
+        assert_eq!(
+            join(AsofStrategy::Backward, None)?,
+            &[None, Some(31), Some(31), None, Some(70)]
+        );
+        assert_eq!(
+            join(AsofStrategy::Forward, None)?,
+            &[Some(20), Some(30), Some(70), None, None]
+        );
+        assert_eq!(
+            join(AsofStrategy::Nearest, None)?,
+            &[Some(20), Some(31), Some(31), None, Some(70)]
+        );
+        assert_eq!(
+            join(AsofStrategy::Backward, Some(2.0))?,
+            &[None, Some(31), Some(31), None, None]
+        );
+
+        let unsorted = df! {
+            "time" => &[2, 1]
+        }?;
+        assert!(left
+            .join_asof(
+                &unsorted,
+                "time",
+                "time",
+                AsofStrategy::Backward,
+                None,
+                None
+            )
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_asof_by() -> Result<()> {
+        let trades = df! {
+            "time" => &[1, 2, 3, 4],
+            "ticker" => &["a", "b", "a", "c"],
+            "quantity" => &[10, 20, 30, 40]
+        }?;
+        let quotes = df! {
+            "time" => &[0, 1, 2, 3],
+            "ticker" => &["a", "b", "b", "a"],
+            "price" => &[1.0, 2.0, 2.5, 1.5]
+        }?;
+        let joined = trades.join_asof(
+            &quotes,
+            "time",
+            "time",
+            AsofStrategy::Backward,
+            None,
+            Some("ticker"),
+        )?;
+        assert_eq!(
+            joined.get_column_names(),
+            &["time", "ticker", "quantity", "price"]
+        );
+        assert_eq!(
+            Vec::from(joined.column("price")?.f64()?),
+            &[Some(1.0), Some(2.5), Some(1.5), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_asof_large_keys() -> Result<()> {
+        // keys above 2^53 are not exactly representable as f64
+        let base = 1i64 << 53;
+        let left = df! {
+            "time" => &[base + 1, base + 3]
+        }?;
+        let right = df! {
+            "time" => &[base, base + 1, base + 2],
+            "b" => &[0, 1, 2]
+        }?;
+        let joined = left.join_asof(&right, "time", "time", AsofStrategy::Backward, None, None)?;
+        assert_eq!(Vec::from(joined.column("b")?.i32()?), &[Some(1), Some(2)]);
+
+        let left = df! {
+            "time" => &[u64::MAX - 1]
+        }?;
+        let right = df! {
+            "time" => &[u64::MAX - 2, u64::MAX],
+            "b" => &[0, 1]
+        }?;
+        let joined = left.join_asof(&right, "time", "time", AsofStrategy::Forward, None, None)?;
+        assert_eq!(Vec::from(joined.column("b")?.i32()?), &[Some(1)]);
+
+        // the distance between keys of opposite sign doesn't fit in an i64
+        let left = df! {
+            "time" => &[0i64]
+        }?;
+        let right = df! {
+            "time" => &[i64::MIN, i64::MAX],
+            "b" => &[0, 1]
+        }?;
+        let joined = left.join_asof(&right, "time", "time", AsofStrategy::Nearest, None, None)?;
+        assert_eq!(Vec::from(joined.column("b")?.i32()?), &[Some(1)]);
+        let right = df! {
+            "time" => &[i64::MIN],
+            "b" => &[0]
+        }?;
+        let left = df! {
+            "time" => &[i64::MAX]
+        }?;
+        let joined = left.join_asof(
+            &right,
+            "time",
+            "time",
+            AsofStrategy::Backward,
+            Some(1e3),
+            None,
+        )?;
+        assert_eq!(Vec::from(joined.column("b")?.i32()?), &[None]);
+        Ok(())
+    }
+}
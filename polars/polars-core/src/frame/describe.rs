@@ -0,0 +1,202 @@
+use crate::prelude::*;
+
+const DEFAULT_PERCENTILES: [f64; 3] = [0.25, 0.5, 0.75];
+
+fn percentile_name(p: f64) -> String {
+    format!("{}%", (p * 1000.0).round() / 10.0)
+}
+
+/// Statistics of a numeric column. The order matches the rows of [DataFrame::describe].
+fn describe_numeric(s: &Series, percentiles: &[f64]) -> Result<Float64Chunked> {
+    let s = s.cast::<Float64Type>()?;
+    let ca = s.f64()?;
+
+    let mut stats = Vec::with_capacity(percentiles.len() + 9);
+    stats.push(Some((ca.len() - ca.null_count()) as f64));
+    stats.push(Some(ca.null_count() as f64));
+    stats.push(ca.mean());
+    stats.push(ca.std());
+    stats.push(ca.min());
+    for p in percentiles {
+        stats.push(ca.quantile(*p)?);
+    }
+    stats.push(ca.max());
+    stats.push(Some(ca.n_unique()? as f64));
+    // top and freq are only defined for non numeric columns
+    stats.push(None);
+    stats.push(None);
+    Ok(Float64Chunked::new_from_opt_slice(s.name(), &stats))
+}
+
+/// The most frequent value and its count.
+fn top_and_freq(s: &Series) -> Result<(Option<String>, Option<String>)> {
+    let counts = s.drop_nulls().value_counts()?;
+    if counts.height() == 0 {
+        return Ok((None, None));
+    }
+    let top = match counts.get_columns()[0].get(0) {
+        AnyValue::Utf8(v) => v.to_string(),
+        av => format!("{}", av),
+    };
+    let freq = format!("{}", counts.column("counts")?.get(0));
+    Ok((Some(top), Some(freq)))
+}
+
+/// Statistics of a non numeric column, formatted as strings. The order matches the rows of
+/// [DataFrame::describe].
+fn describe_other(s: &Series, percentiles: &[f64]) -> Result<Utf8Chunked> {
+    let mut min = None;
+    let mut max = None;
+    let mut unique = None;
+    let mut top = None;
+    let mut freq = None;
+
+    match s.dtype() {
+        DataType::Utf8 | DataType::Categorical => {
+            let s = match s.dtype() {
+                DataType::Categorical => s.cast_with_datatype(&DataType::Utf8)?,
+                _ => s.clone(),
+            };
+            let ca = s.utf8()?;
+            min = ca.into_iter().flatten().min().map(|v| v.to_string());
+            max = ca.into_iter().flatten().max().map(|v| v.to_string());
+            unique = Some(format!("{}", ca.n_unique()?));
+            let (t, f) = top_and_freq(&s)?;
+            top = t;
+            freq = f;
+        }
+        DataType::Boolean => {
+            unique = Some(format!("{}", s.n_unique()?));
+            let (t, f) = top_and_freq(s)?;
+            top = t;
+            freq = f;
+        }
+        DataType::Date32 | DataType::Date64 => {
+            let fmt_agg = |agg: Series| match agg.get(0) {
+                AnyValue::Null => None,
+                av => Some(format!("{}", av)),
+            };
+            min = fmt_agg(s.min_as_series());
+            max = fmt_agg(s.max_as_series());
+            unique = Some(format!("{}", s.n_unique()?));
+        }
+        _ => {}
+    }
+
+    let mut stats = Vec::with_capacity(percentiles.len() + 9);
+    stats.push(Some(format!("{}", s.len() - s.null_count())));
+    stats.push(Some(format!("{}", s.null_count())));
+    // mean and std
+    stats.push(None);
+    stats.push(None);
+    stats.push(min);
+    stats.extend(percentiles.iter().map(|_| None));
+    stats.push(max);
+    stats.push(unique);
+    stats.push(top);
+    stats.push(freq);
+    Ok(Utf8Chunked::new_from_opt_slice(s.name(), &stats))
+}
+
+impl DataFrame {
+    /// Summary statistics of the columns.
+    ///
+    /// The first column `describe` names the statistics: `count`, `null_count`, `mean`, `std`,
+    /// `min`, one row per percentile (e.g. `25%`), `max`, `unique`, `top` and `freq`. The rows
+    /// don't depend on the dtypes of the columns, statistics that don't apply to a column are null.
+    ///
+    /// Numeric columns are described by a `Float64` column. All other columns are described by a
+    /// `Utf8` column in which `min` and `max` are set for strings and dates and `top` and `freq`
+    /// hold the most frequent value and its count for strings, categoricals and booleans.
+    ///
+    /// `percentiles` must be between 0 and 1 and default to `[0.25, 0.5, 0.75]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2, 3],
+    ///     "b" => &["x", "y", "y"]
+    /// }
+    /// .unwrap();
+    /// let summary = df.describe(Some(&[0.1, 0.9])).unwrap();
+    /// assert_eq!(summary.height(), 11);
+    /// ```
+    pub fn describe(&self, percentiles: Option<&[f64]>) -> Result<DataFrame> {
+        let percentiles = percentiles.unwrap_or(&DEFAULT_PERCENTILES);
+        if let Some(p) = percentiles.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return Err(PolarsError::ValueError(
+                format!("percentile {} is not between 0 and 1", p).into(),
+            ));
+        }
+
+        let mut names = vec![
+            "count".to_string(),
+            "null_count".to_string(),
+            "mean".to_string(),
+            "std".to_string(),
+            "min".to_string(),
+        ];
+        names.extend(percentiles.iter().map(|p| percentile_name(*p)));
+        names.extend(
+            ["max", "unique", "top", "freq"]
+                .iter()
+                .map(|name| name.to_string()),
+        );
+
+        let mut columns = Vec::with_capacity(self.width() + 1);
+        columns.push(Utf8Chunked::new_from_slice("describe", &names).into_series());
+        for s in &self.columns {
+            let described = if s.dtype().is_numeric() {
+                describe_numeric(s, percentiles)?.into_series()
+            } else {
+                describe_other(s, percentiles)?.into_series()
+            };
+            columns.push(described);
+        }
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_describe() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(1.0), Some(2.0), Some(3.0), None],
+            "b" => &[Some("x"), Some("y"), Some("y"), None]
+        }?;
+        let out = df.describe(None)?;
+        assert_eq!(out.shape(), (12, 3));
+        assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+        assert_eq!(out.column("b")?.dtype(), &DataType::Utf8);
+
+        let stat = out.column("describe")?.utf8()?;
+        assert_eq!(stat.get(6), Some("50%"));
+        let a = out.column("a")?.f64()?;
+        assert_eq!(a.get(0), Some(3.0));
+        assert_eq!(a.get(1), Some(1.0));
+        assert_eq!(a.get(2), Some(2.0));
+        assert_eq!(a.get(4), Some(1.0));
+        assert_eq!(a.get(8), Some(3.0));
+        assert_eq!(a.get(10), None);
+
+        let b = out.column("b")?.utf8()?;
+        assert_eq!(b.get(0), Some("3"));
+        assert_eq!(b.get(2), None);
+        assert_eq!(b.get(4), Some("x"));
+        assert_eq!(b.get(8), Some("y"));
+        assert_eq!(b.get(10), Some("y"));
+        assert_eq!(b.get(11), Some("2"));
+
+        // the schema does not depend on the data
+        let empty = df.head(Some(0)).describe(None)?;
+        assert_eq!(empty.schema(), out.schema());
+
+        assert!(df.describe(Some(&[1.5])).is_err());
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::chunked_array::ops::explode::offsets_to_indexes;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::utils::get_supertype;
 use std::collections::VecDeque;
 
 fn get_exploded(series: &Series) -> Result<(Series, &[i64])> {
@@ -11,9 +12,19 @@ fn get_exploded(series: &Series) -> Result<(Series, &[i64])> {
     }
 }
 
+/// The number of values of every row but the last. The offsets of a sliced array don't start at 0,
+/// so they can't be compared directly.
+fn row_lengths(offsets: &[i64]) -> impl Iterator<Item = i64> + '_ {
+    offsets.windows(2).map(|w| w[1] - w[0])
+}
+
 impl DataFrame {
     /// Explode `DataFrame` to long format by exploding a column with Lists.
     ///
+    /// Every element of the list becomes a row and the values of the other columns are repeated
+    /// for every element. Utf8 columns are exploded into their characters. When multiple columns
+    /// are exploded, the lists in every row must have the same length in all of them.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -71,7 +82,12 @@ impl DataFrame {
     ///  +-----+-----+-----+
     /// ```
     pub fn explode<'a, J, S: Selection<'a, J>>(&self, columns: S) -> Result<DataFrame> {
-        let columns = self.select_series(columns)?;
+        // the offsets point into the arrow array, so the columns need to be a single chunk
+        let columns = self
+            .select_series(columns)?
+            .iter()
+            .map(|s| s.rechunk())
+            .collect::<Vec<_>>();
 
         // first remove all the exploded columns
         let mut df = self.clone();
@@ -79,14 +95,31 @@ impl DataFrame {
             df = df.drop(s.name())?;
         }
 
-        for (i, s) in columns.iter().enumerate() {
+        let mut first_offsets: Option<&[i64]> = None;
+        for s in columns.iter() {
             if let Ok((exploded, offsets)) = get_exploded(s) {
                 let col_idx = self.name_to_idx(s.name())?;
 
-                // expand all the other columns based the exploded first column
-                if i == 0 {
-                    let row_idx = offsets_to_indexes(offsets, exploded.len());
-                    df = unsafe { df.take_iter_unchecked(row_idx.into_iter(), None) };
+                match first_offsets {
+                    // expand all the other columns based the exploded first column
+                    None => {
+                        let row_idx = offsets_to_indexes(offsets, exploded.len());
+                        df = unsafe { df.take_iter_unchecked(row_idx.into_iter(), None) };
+                        first_offsets = Some(offsets);
+                    }
+                    // every row must explode to the same number of values in all columns. The
+                    // length of the last row follows from the length of the exploded column.
+                    Some(first) if !row_lengths(first).eq(row_lengths(offsets)) => {
+                        return Err(PolarsError::ShapeMisMatch(
+                            format!(
+                                "The lists of the exploded columns don't have the same lengths. Column {} differs from column {}",
+                                exploded.name(),
+                                columns[0].name()
+                            )
+                            .into(),
+                        ));
+                    }
+                    Some(_) => {}
                 }
                 if exploded.len() == df.height() {
                     df.columns.insert(col_idx, exploded);
@@ -112,7 +145,11 @@ impl DataFrame {
     /// # Arguments
     ///
     /// * `id_vars` - String slice that represent the columns to use as id variables.
-    /// * `value_vars` - String slice that represent the columns to use as value variables.
+    /// * `value_vars` - String slice that represent the columns to use as value variables. If
+    ///   empty, all columns that are not in `id_vars` are used.
+    ///
+    /// The value columns are cast to their common supertype, so e.g. an `i32` and an `f64` column
+    /// are melted into an `f64` `value` column.
     ///
     /// ```rust
     ///
@@ -166,15 +203,32 @@ impl DataFrame {
         id_vars: SelId,
         value_vars: SelValue,
     ) -> Result<Self> {
-        let ids = self.select(id_vars)?;
+        let id_vars = id_vars.to_selection_vec();
+        let ids = self.select(&id_vars)?;
         let value_vars = value_vars.to_selection_vec();
+        let value_vars: Vec<&str> = if value_vars.is_empty() {
+            self.columns
+                .iter()
+                .map(|s| s.name())
+                .filter(|name| !id_vars.iter().any(|id| id == name))
+                .collect()
+        } else {
+            value_vars
+        };
         let len = self.height();
 
+        let value_cols = self.select_series(&value_vars)?;
+        let mut dtypes = value_cols.iter().map(|s| s.dtype());
+        let dtype = match dtypes.next() {
+            Some(first) => dtypes.try_fold(first.clone(), |acc, dt| get_supertype(&acc, dt))?,
+            None => return Err(PolarsError::NoData("No data in melt operation".into())),
+        };
+
         let mut dataframe_chunks = VecDeque::with_capacity(value_vars.len());
 
-        for value_column_name in value_vars {
-            let variable_col = Utf8Chunked::full("variable", value_column_name, len).into_series();
-            let mut value_col = self.column(value_column_name)?.clone();
+        for value_col in value_cols {
+            let variable_col = Utf8Chunked::full("variable", value_col.name(), len).into_series();
+            let mut value_col = value_col.cast_with_datatype(&dtype)?;
             value_col.rename("value");
 
             let mut df_chunk = ids.clone();
@@ -231,6 +285,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_explode_multiple() -> Result<()> {
+        let list_a = Series::new("a", &[Series::new("", &[1, 2]), Series::new("", &[3])]);
+        let list_b = Series::new("b", &[Series::new("", &[4, 5]), Series::new("", &[6])]);
+        let id = Series::new("id", &[1, 2]);
+        let df = DataFrame::new(vec![id, list_a, list_b.clone()])?;
+        let exploded = df.explode(&["a", "b"])?;
+        assert_eq!(exploded.get_column_names(), &["id", "a", "b"]);
+        assert_eq!(
+            Vec::from(exploded.column("id")?.i32()?),
+            &[Some(1), Some(1), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("b")?.i32()?),
+            &[Some(4), Some(5), Some(6)]
+        );
+
+        // the lists of "c" have other lengths than those of "b"
+        let list_c = Series::new("c", &[Series::new("", &[7]), Series::new("", &[8, 9])]);
+        let df = DataFrame::new(vec![list_b, list_c])?;
+        assert!(df.explode(&["b", "c"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_explode_sliced() -> Result<()> {
+        let list_a = Series::new(
+            "a",
+            &[
+                Series::new("", &[1]),
+                Series::new("", &[2, 3]),
+                Series::new("", &[4, 5, 6]),
+            ],
+        );
+        let list_b = Series::new(
+            "b",
+            &[
+                Series::new("", &[7, 8]),
+                Series::new("", &[9, 10]),
+                Series::new("", &[11, 12, 13]),
+            ],
+        );
+        let s = Series::new("s", &["x", "yz", "abc"]);
+        let id = Series::new("id", &[1, 2, 3]);
+        let df = DataFrame::new(vec![id, list_a, list_b, s])?;
+
+        // the offsets of the sliced lists don't start at 0, but the lists have equal lengths
+        let sliced = df.slice(1, 2)?;
+        let exploded = sliced.explode(&["a", "b"])?;
+        assert_eq!(
+            Vec::from(exploded.column("id")?.i32()?),
+            &[Some(2), Some(2), Some(3), Some(3), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("a")?.i32()?),
+            &[Some(2), Some(3), Some(4), Some(5), Some(6)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("b")?.i32()?),
+            &[Some(9), Some(10), Some(11), Some(12), Some(13)]
+        );
+
+        let exploded = sliced.explode("s")?;
+        assert_eq!(
+            Vec::from(exploded.column("s")?.utf8()?),
+            &[Some("y"), Some("z"), Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("id")?.i32()?),
+            &[Some(2), Some(2), Some(3), Some(3), Some(3)]
+        );
+
+        // the first row of "a" differs from that of "b"
+        assert!(df.slice(0, 2)?.explode(&["a", "b"]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_melt() {
         let df = df!("A" => &["a", "b", "a"],
@@ -244,6 +375,23 @@ mod test {
         assert_eq!(
             Vec::from(melted.column("value").unwrap().i32().unwrap()),
             &[Some(10), Some(11), Some(12), Some(2), Some(4), Some(6)]
+        );
+
+        // default to all other columns and unify the value dtypes
+        let df = df!("A" => &["a", "b"],
+         "C" => &[10, 11],
+         "D" => &[0.5, 1.5]
         )
+        .unwrap();
+        let melted = df.melt("A", Vec::new()).unwrap();
+        assert_eq!(melted.shape(), (4, 3));
+        assert_eq!(
+            Vec::from(melted.column("value").unwrap().f64().unwrap()),
+            &[Some(10.0), Some(11.0), Some(0.5), Some(1.5)]
+        );
+        assert_eq!(
+            Vec::from(melted.column("variable").unwrap().utf8().unwrap()),
+            &[Some("C"), Some("C"), Some("D"), Some("D")]
+        );
     }
 }
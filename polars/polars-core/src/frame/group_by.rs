@@ -1,7 +1,12 @@
-use crate::chunked_array::{builder::PrimitiveChunkedBuilder, float::IntegerDecode};
+use crate::chunked_array::{
+    builder::PrimitiveChunkedBuilder,
+    float::{IntegerDecode, ToCanonicalBits},
+};
+use crate::config;
 use crate::frame::row::Row;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::progress::Progress;
 use crate::utils::{accumulate_dataframes_vertical, split_ca, split_df, NoNull};
 use crate::vector_hasher::{
     create_hash_and_keys_threaded_vectorized, df_rows_to_hashes, df_rows_to_hashes_threaded,
@@ -24,6 +29,9 @@ use std::{
 pub trait VecHash {
     /// Compute the hase for all values in the array.
     ///
+    /// The hash of a value doesn't depend on the presence of null values in the array, so arrays
+    /// with and without nulls can be compared by their hashes.
+    ///
     /// This currently only works with the ahash RandomState hasher builder.
     fn vec_hash(&self, _random_state: RandomState) -> UInt64Chunked {
         unimplemented!()
@@ -46,11 +54,15 @@ where
                 .collect::<NoNull<_>>()
                 .into_inner()
         } else {
+            let null_h = random_state.build_hasher().finish();
             self.into_iter()
-                .map(|opt_v| {
-                    let mut hasher = random_state.build_hasher();
-                    opt_v.hash(&mut hasher);
-                    hasher.finish()
+                .map(|opt_v| match opt_v {
+                    Some(v) => {
+                        let mut hasher = random_state.build_hasher();
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    }
+                    None => null_h,
                 })
                 .collect::<NoNull<_>>()
                 .into_inner()
@@ -70,11 +82,15 @@ impl VecHash for Utf8Chunked {
                 .collect::<NoNull<_>>()
                 .into_inner()
         } else {
+            let null_h = random_state.build_hasher().finish();
             self.into_iter()
-                .map(|opt_v| {
-                    let mut hasher = random_state.build_hasher();
-                    opt_v.hash(&mut hasher);
-                    hasher.finish()
+                .map(|opt_v| match opt_v {
+                    Some(v) => {
+                        let mut hasher = random_state.build_hasher();
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    }
+                    None => null_h,
                 })
                 .collect::<NoNull<_>>()
                 .into_inner()
@@ -94,11 +110,15 @@ impl VecHash for BooleanChunked {
                 .collect::<NoNull<_>>()
                 .into_inner()
         } else {
+            let null_h = random_state.build_hasher().finish();
             self.into_iter()
-                .map(|opt_v| {
-                    let mut hasher = random_state.build_hasher();
-                    opt_v.hash(&mut hasher);
-                    hasher.finish()
+                .map(|opt_v| match opt_v {
+                    Some(v) => {
+                        let mut hasher = random_state.build_hasher();
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    }
+                    None => null_h,
                 })
                 .collect::<NoNull<_>>()
                 .into_inner()
@@ -111,7 +131,7 @@ impl VecHash for Float32Chunked {
         if self.null_count() == 0 {
             self.into_no_null_iter()
                 .map(|v| {
-                    let v = v.to_bits();
+                    let v = v.to_canonical_bits();
                     let mut hasher = random_state.build_hasher();
                     v.hash(&mut hasher);
                     hasher.finish()
@@ -119,12 +139,16 @@ impl VecHash for Float32Chunked {
                 .collect::<NoNull<_>>()
                 .into_inner()
         } else {
+            let null_h = random_state.build_hasher().finish();
             self.into_iter()
-                .map(|opt_v| {
-                    let opt_v = opt_v.map(|v| v.to_bits());
-                    let mut hasher = random_state.build_hasher();
-                    opt_v.hash(&mut hasher);
-                    hasher.finish()
+                .map(|opt_v| match opt_v {
+                    Some(v) => {
+                        let v = v.to_canonical_bits();
+                        let mut hasher = random_state.build_hasher();
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    }
+                    None => null_h,
                 })
                 .collect::<NoNull<_>>()
                 .into_inner()
@@ -136,7 +160,7 @@ impl VecHash for Float64Chunked {
         if self.null_count() == 0 {
             self.into_no_null_iter()
                 .map(|v| {
-                    let v = v.to_bits();
+                    let v = v.to_canonical_bits();
                     let mut hasher = random_state.build_hasher();
                     v.hash(&mut hasher);
                     hasher.finish()
@@ -144,12 +168,16 @@ impl VecHash for Float64Chunked {
                 .collect::<NoNull<_>>()
                 .into_inner()
         } else {
+            let null_h = random_state.build_hasher().finish();
             self.into_iter()
-                .map(|opt_v| {
-                    let opt_v = opt_v.map(|v| v.to_bits());
-                    let mut hasher = random_state.build_hasher();
-                    opt_v.hash(&mut hasher);
-                    hasher.finish()
+                .map(|opt_v| match opt_v {
+                    Some(v) => {
+                        let v = v.to_canonical_bits();
+                        let mut hasher = random_state.build_hasher();
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    }
+                    None => null_h,
                 })
                 .collect::<NoNull<_>>()
                 .into_inner()
@@ -357,16 +385,46 @@ pub trait IntoGroupTuples {
     }
 }
 
-fn group_multithreaded<T>(ca: &ChunkedArray<T>) -> bool {
-    // TODO! change to something sensible
-    ca.len() > 1000
+/// Group an array that is flagged as sorted and doesn't contain nulls by splitting it in runs of
+/// equal values, instead of hashing. Returns `None` if the array doesn't qualify.
+fn groupby_sorted<T>(ca: &ChunkedArray<T>) -> Option<Vec<(usize, Vec<usize>)>>
+where
+    T: PolarsNumericType,
+{
+    if ca.is_sorted() == IsSorted::Not || ca.null_count() > 0 {
+        return None;
+    }
+    let mut groups = Vec::new();
+    let mut iter = ca.into_no_null_iter().enumerate();
+    let (mut first, mut current) = match iter.next() {
+        Some(first) => first,
+        None => return Some(groups),
+    };
+    for (idx, v) in iter {
+        if v != current {
+            groups.push((first, (first..idx).collect()));
+            first = idx;
+            current = v;
+        }
+    }
+    groups.push((first, (first..ca.len()).collect()));
+    Some(groups)
+}
+
+/// Check if the groups of `len` rows should be determined in parallel.
+fn group_multithreaded(len: usize) -> bool {
+    len >= config::par_groupby_bound() && n_groupby_threads() > 1
+}
+
+/// The number of partitions of a parallel groupby, one per thread of the pool.
+fn n_groupby_threads() -> usize {
+    POOL.current_num_threads()
 }
 
 macro_rules! group_tuples {
     ($ca: expr, $multithreaded: expr) => {{
-        // TODO! choose a splitting len
-        if $multithreaded && group_multithreaded($ca) {
-            let n_threads = num_cpus::get();
+        if $multithreaded && group_multithreaded($ca.len()) {
+            let n_threads = n_groupby_threads();
             let splitted = split_ca($ca, n_threads).unwrap();
 
             if $ca.null_count() == 0 {
@@ -395,6 +453,9 @@ where
     T::Native: Eq + Hash + Send,
 {
     fn group_tuples(&self, multithreaded: bool) -> Vec<(usize, Vec<usize>)> {
+        if let Some(groups) = groupby_sorted(self) {
+            return groups;
+        }
         group_tuples!(self, multithreaded)
     }
 }
@@ -410,37 +471,73 @@ impl IntoGroupTuples for Utf8Chunked {
     }
 }
 
+/// Group a categorical array by putting the rows in a bucket per code, instead of hashing the
+/// codes. Returns `None` if there are more codes than rows, then most buckets would stay empty.
+/// The groups are ordered by code, the null values form the last group.
+fn groupby_categorical_codes(ca: &CategoricalChunked) -> Option<Vec<(usize, Vec<usize>)>> {
+    let n_codes = *ca.get_categorical_map()?.keys().max()? as usize + 1;
+    if n_codes > ca.len() {
+        return None;
+    }
+    let mut buckets = vec![vec![]; n_codes];
+    let mut nulls = vec![];
+    for (idx, opt_code) in ca.into_iter().enumerate() {
+        match opt_code {
+            Some(code) => buckets.get_mut(code as usize)?.push(idx),
+            None => nulls.push(idx),
+        }
+    }
+    buckets.push(nulls);
+    Some(
+        buckets
+            .into_iter()
+            .filter(|idx| !idx.is_empty())
+            .map(|idx| (idx[0], idx))
+            .collect(),
+    )
+}
+
 impl IntoGroupTuples for CategoricalChunked {
     fn group_tuples(&self, multithreaded: bool) -> Vec<(usize, Vec<usize>)> {
+        if let Some(groups) = groupby_categorical_codes(self) {
+            return groups;
+        }
         group_tuples!(self, multithreaded)
     }
 }
 
 macro_rules! impl_into_group_tpls_float {
     ($self: ident, $multithreaded:expr) => {
-        if $multithreaded && group_multithreaded($self) {
-            let n_threads = num_cpus::get();
+        if $multithreaded && group_multithreaded($self.len()) {
+            let n_threads = n_groupby_threads();
             let splitted = split_ca($self, n_threads).unwrap();
             match $self.null_count() {
                 0 => {
                     let iters = splitted
                         .iter()
-                        .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                        .map(|ca| ca.into_no_null_iter().map(|v| v.to_canonical_bits()))
                         .collect_vec();
                     groupby_threaded_flat(iters)
                 }
                 _ => {
                     let iters = splitted
                         .iter()
-                        .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                        .map(|ca| {
+                            ca.into_iter()
+                                .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+                        })
                         .collect_vec();
                     groupby_threaded_flat(iters)
                 }
             }
         } else {
             match $self.null_count() {
-                0 => groupby($self.into_no_null_iter().map(|v| v.to_bits())),
-                _ => groupby($self.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits()))),
+                0 => groupby($self.into_no_null_iter().map(|v| v.to_canonical_bits())),
+                _ => groupby(
+                    $self
+                        .into_iter()
+                        .map(|opt_v| opt_v.map(|v| v.to_canonical_bits())),
+                ),
             }
         }
     };
@@ -576,6 +673,7 @@ impl DataFrame {
                 "the Series used as keys should have the same length as the DataFrame".into(),
             ));
         };
+        let progress = Progress::start("groupby", Some(self.height()));
 
         // make sure that categorical is used as uint32 in value type
         let keys_df = DataFrame::new(
@@ -593,20 +691,21 @@ impl DataFrame {
                 series.group_tuples(multithreaded)
             }
             _ => {
-                if multithreaded {
-                    let n_threads = num_cpus::get();
-                    groupby_threaded_multiple_keys_flat(keys_df, n_threads)
+                if multithreaded && group_multithreaded(keys_df.height()) {
+                    groupby_threaded_multiple_keys_flat(keys_df, n_groupby_threads())
                 } else {
                     groupby_multiple_keys(keys_df)
                 }
             }
         };
+        progress.update(self.height())?;
 
         Ok(GroupBy {
             df: self,
             selected_keys: by,
             groups,
             selected_agg: None,
+            group_keys: None,
         })
     }
 
@@ -627,12 +726,91 @@ impl DataFrame {
         self.groupby_with_series(selected_keys, true)
     }
 
+    /// Split the `DataFrame` into one `DataFrame` per group of the `by` columns. The partitions
+    /// are ordered by the first row of their group and keep the order of the rows. With
+    /// `include_key` false, the `by` columns are dropped from the partitions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "key" => &["a", "b", "a"],
+    ///     "value" => &[1, 2, 3]
+    /// }
+    /// .unwrap();
+    /// let parts = df.partition_by(&["key"], false).unwrap();
+    /// assert_eq!(parts.len(), 2);
+    /// assert_eq!(parts[0].get_column_names(), &["value"]);
+    /// assert_eq!(parts[0].height(), 2);
+    /// ```
+    pub fn partition_by<'g, J, S: Selection<'g, J>>(
+        &self,
+        by: S,
+        include_key: bool,
+    ) -> Result<Vec<DataFrame>> {
+        let by = by.to_selection_vec();
+        let mut df = self.clone();
+        if !include_key {
+            for name in &by {
+                df = df.drop(name)?;
+            }
+        }
+        let gb = self.groupby_stable(by)?;
+        let parts = POOL.install(|| {
+            gb.get_groups()
+                .par_iter()
+                .map(|(_, idx)| unsafe {
+                    df.take_iter_unchecked(idx.iter().copied(), Some(idx.len()))
+                })
+                .collect()
+        });
+        Ok(parts)
+    }
+
+    /// Reshape from long to wide format. Every unique value of the `columns` column becomes a
+    /// column that holds the `values` of the rows with that value, one row per unique `index`
+    /// key. Values that end up in the same cell are combined with `agg`.
+    ///
+    /// The pivoted columns are ordered by the first appearance of their value. This is a
+    /// shorthand for `df.groupby(index)?.pivot(columns, values)` followed by the aggregation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "foo" => &["A", "A", "B"],
+    ///     "bar" => &["k", "l", "k"],
+    ///     "N" => &[1, 2, 3]
+    /// }
+    /// .unwrap();
+    /// let pivoted = df.pivot("N", "foo", "bar", PivotAgg::First).unwrap();
+    /// assert_eq!(pivoted.get_column_names(), &["foo", "k", "l"]);
+    /// ```
+    pub fn pivot<'g, J, S: Selection<'g, J>>(
+        &self,
+        values: &str,
+        index: S,
+        columns: &str,
+        agg: PivotAgg,
+    ) -> Result<DataFrame> {
+        let gb = self.groupby_stable(index)?;
+        let pivot = gb.pivot(columns, values);
+        match agg {
+            PivotAgg::First => pivot.first(),
+            PivotAgg::Sum => pivot.sum(),
+            PivotAgg::Min => pivot.min(),
+            PivotAgg::Max => pivot.max(),
+            PivotAgg::Mean => pivot.mean(),
+            PivotAgg::Median => pivot.median(),
+        }
+    }
+
     /// Group DataFrame using a Series column.
     /// The groups are ordered by their smallest row index.
     pub fn groupby_stable<'g, J, S: Selection<'g, J>>(&self, by: S) -> Result<GroupBy> {
-        let mut gb = self.groupby(by)?;
-        gb.groups.sort();
-        Ok(gb)
+        Ok(self.groupby(by)?.maintain_order(true))
     }
 }
 
@@ -693,6 +871,29 @@ pub struct GroupBy<'df, 'selection_str> {
     pub(crate) groups: Vec<(usize, Vec<usize>)>,
     // columns selected for aggregation
     selected_agg: Option<Vec<&'selection_str str>>,
+    // keys per group, for groups whose keys can't be taken from their first row, e.g. time
+    // windows where a row can be part of multiple groups
+    group_keys: Option<Vec<Series>>,
+}
+
+/// `sum(value * weight) / sum(weight)` of the pairs where both are not null. `None` if there are
+/// no such pairs or the weights sum to zero.
+pub(crate) fn weighted_mean<I>(pairs: I) -> Option<f64>
+where
+    I: Iterator<Item = (Option<f64>, Option<f64>)>,
+{
+    let (sum, weight_sum) = pairs
+        .filter_map(|pair| match pair {
+            (Some(v), Some(w)) => Some((v * w, w)),
+            _ => None,
+        })
+        .fold((0.0, 0.0), |(sum, weight_sum), (vw, w)| {
+            (sum + vw, weight_sum + w)
+        });
+    match weight_sum {
+        w if w == 0.0 => None,
+        w => Some(sum / w),
+    }
 }
 
 pub(crate) trait NumericAggSync {
@@ -738,7 +939,9 @@ where
                 } else {
                     let take = unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
                     let opt_sum: Option<T::Native> = take.sum();
-                    opt_sum.map(|sum| sum.to_f64().unwrap() / idx.len() as f64)
+                    // null values are not counted
+                    let n = idx.len() - take.null_count();
+                    opt_sum.map(|sum| sum.to_f64().unwrap() / n as f64)
                 }
             })
             .collect();
@@ -799,36 +1002,34 @@ where
         )
     }
     fn agg_var(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
-        Some(
-            groups
-                .par_iter()
-                .map(|(_first, idx)| {
-                    let take = unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
-                    take.into_series()
-                        .var_as_series()
-                        .unpack::<T>()
-                        .unwrap()
-                        .get(0)
-                })
-                .collect::<ChunkedArray<T>>()
-                .into_series(),
-        )
+        Some(agg_float_helper(self, groups, |s| s.var_as_series()))
     }
     fn agg_std(&self, groups: &[(usize, Vec<usize>)]) -> Option<Series> {
-        Some(
-            groups
-                .par_iter()
-                .map(|(_first, idx)| {
-                    let take = unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
-                    take.into_series()
-                        .std_as_series()
-                        .unpack::<T>()
-                        .unwrap()
-                        .get(0)
-                })
-                .collect::<ChunkedArray<T>>()
-                .into_series(),
-        )
+        Some(agg_float_helper(self, groups, |s| s.std_as_series()))
+    }
+}
+
+/// Aggregate every group to a float with `agg`, which returns a Series of length 1. The result
+/// is Float32 for Float32 arrays and Float64 otherwise.
+fn agg_float_helper<T, F>(ca: &ChunkedArray<T>, groups: &[(usize, Vec<usize>)], agg: F) -> Series
+where
+    T: PolarsNumericType + Sync,
+    ChunkedArray<T>: IntoSeries,
+    F: Fn(Series) -> Series + Send + Sync,
+{
+    let out: Float64Chunked = groups
+        .par_iter()
+        .map(|(_first, idx)| {
+            let take = unsafe { ca.take_unchecked(idx.iter().copied(), Some(idx.len())) };
+            agg(take.into_series())
+                .cast::<Float64Type>()
+                .ok()
+                .and_then(|s| s.f64().ok().and_then(|ca| ca.get(0)))
+        })
+        .collect();
+    match T::get_dtype() {
+        DataType::Float32 => out.cast::<Float32Type>().unwrap().into_series(),
+        _ => out.into_series(),
     }
 }
 
@@ -885,9 +1086,13 @@ impl AggFirst for CategoricalChunked {
 }
 
 #[cfg(feature = "object")]
-impl<T> AggFirst for ObjectChunked<T> {
-    fn agg_first(&self, _groups: &[(usize, Vec<usize>)]) -> Series {
-        todo!()
+impl<T: PolarsObject> AggFirst for ObjectChunked<T> {
+    fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.take(
+            groups.iter().map(|(first, _idx)| *first),
+            Some(groups.len()),
+        )
+        .into_series()
     }
 }
 
@@ -944,9 +1149,13 @@ impl AggLast for ListChunked {
 }
 
 #[cfg(feature = "object")]
-impl<T> AggLast for ObjectChunked<T> {
-    fn agg_last(&self, _groups: &[(usize, Vec<usize>)]) -> Series {
-        todo!()
+impl<T: PolarsObject> AggLast for ObjectChunked<T> {
+    fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.take(
+            groups.iter().map(|(_first, idx)| idx[idx.len() - 1]),
+            Some(groups.len()),
+        )
+        .into_series()
     }
 }
 
@@ -992,9 +1201,25 @@ where
     }
 }
 
-// todo! could use mantissa method here
-impl AggNUnique for Float32Chunked {}
-impl AggNUnique for Float64Chunked {}
+// floats are compared by their canonical bits, so all NaNs are one unique value
+impl AggNUnique for Float32Chunked {
+    fn agg_n_unique(&self, groups: &[(usize, Vec<usize>)]) -> Option<UInt32Chunked> {
+        let bits: UInt32Chunked = self
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+            .collect();
+        bits.agg_n_unique(groups)
+    }
+}
+impl AggNUnique for Float64Chunked {
+    fn agg_n_unique(&self, groups: &[(usize, Vec<usize>)]) -> Option<UInt32Chunked> {
+        let bits: UInt64Chunked = self
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+            .collect();
+        bits.agg_n_unique(groups)
+    }
+}
 impl AggNUnique for ListChunked {}
 impl AggNUnique for CategoricalChunked {
     fn agg_n_unique(&self, groups: &[(usize, Vec<usize>)]) -> Option<UInt32Chunked> {
@@ -1075,6 +1300,12 @@ where
         }
 
         let s = self.clone().into_series();
+        match s.dtype() {
+            // the lists get the categories as values
+            DataType::Categorical => return s.cast::<Utf8Type>().ok()?.agg_list(groups),
+            DataType::List(_) | DataType::Object => return None,
+            _ => {}
+        }
         Some(match_arrow_data_type_apply_macro!(
             s.dtype(),
             impl_gb,
@@ -1099,8 +1330,10 @@ impl<T> AggQuantile for ChunkedArray<T>
 where
     T: PolarsNumericType + Sync,
     T::Native: PartialEq,
-    ChunkedArray<T>: IntoSeries,
+    ChunkedArray<T>: IntoSeries + ChunkAgg<T::Native>,
 {
+    /// The quantile of every group, determined like [ChunkAgg::quantile]: null values are
+    /// ignored and a group with only null values gets a null value.
     fn agg_quantile(&self, groups: &[(usize, Vec<usize>)], quantile: f64) -> Option<Series> {
         Some(
             groups
@@ -1108,10 +1341,7 @@ where
                 .map(|(_first, idx)| {
                     let group_vals =
                         unsafe { self.take_unchecked(idx.iter().copied(), Some(idx.len())) };
-                    let sorted_idx = group_vals.argsort(false);
-                    let quant_idx = (quantile * (sorted_idx.len() - 1) as f64) as usize;
-                    let value_idx = sorted_idx[quant_idx];
-                    group_vals.get(value_idx)
+                    group_vals.quantile(quantile).ok().flatten()
                 })
                 .collect::<ChunkedArray<T>>()
                 .into_series(),
@@ -1127,11 +1357,56 @@ impl AggQuantile for CategoricalChunked {}
 impl<T> AggQuantile for ObjectChunked<T> {}
 
 impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
+    /// Create a GroupBy with precomputed keys per group. The `by` columns are excluded from the
+    /// aggregations, `group_keys` must have a value for every group.
+    pub(crate) fn new_with_group_keys(
+        df: &'df DataFrame,
+        by: Vec<Series>,
+        groups: Vec<(usize, Vec<usize>)>,
+        group_keys: Vec<Series>,
+    ) -> Self {
+        debug_assert!(group_keys.iter().all(|s| s.len() == groups.len()));
+        GroupBy {
+            df,
+            selected_keys: by,
+            groups,
+            selected_agg: None,
+            group_keys: Some(group_keys),
+        }
+    }
+
+    /// Output the groups in the order their keys first appear in the `DataFrame`. Without this
+    /// the order of the groups is arbitrary, because the groups are found by hashing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "shop" => ["b", "a", "b", "c"],
+    ///     "sales" => [1, 2, 3, 4]
+    /// }
+    /// .unwrap();
+    /// let out = df.groupby("shop").unwrap().maintain_order(true).select("sales").sum().unwrap();
+    /// assert_eq!(
+    ///     Vec::from(out.column("shop").unwrap().utf8().unwrap()),
+    ///     &[Some("b"), Some("a"), Some("c")]
+    /// );
+    /// ```
+    pub fn maintain_order(mut self, maintain_order: bool) -> Self {
+        // the groups of time windows are already in order
+        if maintain_order && self.group_keys.is_none() {
+            self.groups.sort_unstable_by_key(|(first, _)| *first);
+        }
+        self
+    }
+
     /// Select the column(s) that should be aggregated.
     /// You can select a single column or a slice of columns.
     ///
     /// Note that making a selection with this method is not required. If you
     /// skip it all columns (except for the keys) will be selected for aggregation.
+    /// Numerical aggregations like `mean` and `sum` then skip the columns that are not numeric.
     pub fn select<S, J>(mut self, selection: S) -> Self
     where
         S: Selection<'selection_str, J>,
@@ -1148,7 +1423,35 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         &self.groups
     }
 
+    /// Keep only the groups for which `predicate` returns true, e.g. to aggregate a subset of the
+    /// groups. The predicate gets the groups in the representation of
+    /// [get_groups](GroupBy::get_groups).
+    pub fn retain_groups<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&(usize, Vec<usize>)) -> bool,
+    {
+        let mut keep = Vec::with_capacity(self.groups.len());
+        let mut i = 0;
+        self.groups.retain(|group| {
+            let retain = predicate(group);
+            if retain {
+                keep.push(i);
+            }
+            i += 1;
+            retain
+        });
+        // the keys of time window groupbys are computed per group up front
+        if let Some(keys) = &mut self.group_keys {
+            for s in keys.iter_mut() {
+                *s = s.take(&keep);
+            }
+        }
+    }
+
     pub fn keys(&self) -> Vec<Series> {
+        if let Some(keys) = &self.group_keys {
+            return keys.clone();
+        }
         // Keys will later be appended with the aggregation columns, so we already allocate extra space
         let size;
         if let Some(sel) = &self.selected_agg {
@@ -1189,6 +1492,9 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
     /// Aggregate grouped series and compute the mean per group.
     ///
+    /// Without a [select](GroupBy::select) the mean of every numeric column that is not a key is
+    /// computed, other columns are left out of the result.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1343,6 +1649,9 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
     /// Aggregate grouped `Series` and find the first value per group.
     ///
+    /// This is the value of the first row of the group in the order of the `DataFrame`, also if
+    /// that value is null.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1379,6 +1688,9 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
     /// Aggregate grouped `Series` and return the last value per group.
     ///
+    /// This is the value of the last row of the group in the order of the `DataFrame`, also if
+    /// that value is null.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1413,6 +1725,43 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
+    /// Aggregate grouped `Series` and return the `n`th value per group. A negative `n` counts
+    /// from the end of the group, e.g. `-1` is the last value. Groups with too few values get a
+    /// null value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// /// The second purchase of every customer.
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("customer")?.select("amount").nth(1)
+    /// }
+    /// ```
+    pub fn nth(&self, n: i64) -> Result<DataFrame> {
+        let (mut cols, agg_cols) = self.prepare_agg()?;
+        let take_idx = self
+            .groups
+            .iter()
+            .map(|(_first, idx)| {
+                let len = idx.len() as i64;
+                let i = if n < 0 { len + n } else { n };
+                if (0..len).contains(&i) {
+                    Some(idx[i as usize])
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        for agg_col in agg_cols {
+            let mut agg =
+                agg_col.take_opt_iter(&mut take_idx.iter().copied(), Some(take_idx.len()));
+            agg.rename(&format!("{}_nth", agg_col.name()));
+            cols.push(agg);
+        }
+        DataFrame::new(cols)
+    }
+
     /// Aggregate grouped `Series` by counting the number of unique values.
     ///
     /// # Example
@@ -1570,6 +1919,54 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
+    /// Compute the mean of `values` weighted by `weights` per group: `sum(value * weight) /
+    /// sum(weight)`. Rows where the value or the weight is null are ignored. The result is a
+    /// Float64 column named `{values}_wmean`, null for groups without weights or with weights that
+    /// sum to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// fn revenue_weighted_price(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("product")?.wmean("price", "revenue")
+    /// }
+    /// ```
+    pub fn wmean(&self, values: &str, weights: &str) -> Result<DataFrame> {
+        let values_s = self.df.column(values)?;
+        let weights_s = self.df.column(weights)?;
+        for s in &[values_s, weights_s] {
+            if !s.dtype().is_numeric() {
+                return Err(PolarsError::InvalidOperation(
+                    format!("weighted mean is not supported for dtype {:?}", s.dtype()).into(),
+                ));
+            }
+        }
+        let values_s = values_s.cast::<Float64Type>()?;
+        let weights_s = weights_s.cast::<Float64Type>()?;
+        let values_ca = values_s.f64()?;
+        let weights_ca = weights_s.f64()?;
+
+        let mut ca: Float64Chunked = POOL.install(|| {
+            self.groups
+                .par_iter()
+                .map(|(_first, idx)| {
+                    let values_taker = values_ca.take_rand();
+                    let weights_taker = weights_ca.take_rand();
+                    weighted_mean(
+                        idx.iter()
+                            .map(|&i| (values_taker.get(i), weights_taker.get(i))),
+                    )
+                })
+                .collect()
+        });
+        ca.rename(&format!("{}_wmean", values));
+
+        let mut cols = self.keys();
+        cols.push(ca.into_series());
+        DataFrame::new(cols)
+    }
+
     /// Get the groupby group indexes.
     ///
     /// # Example
@@ -1613,7 +2010,44 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
         DataFrame::new(cols)
     }
 
-    /// Combine different aggregations on columns
+    /// Aggregate `agg_col` per group with `method`. The result is named with
+    /// [fmt_groupby_column]. Returns `None` if the aggregation is not supported for the dtype.
+    fn agg_series(&self, agg_col: &Series, method: GroupByMethod) -> Option<Series> {
+        use GroupByMethod::*;
+        let groups = &self.groups;
+        let mut agg = match method {
+            Min => agg_col.agg_min(groups),
+            Max => agg_col.agg_max(groups),
+            Mean => agg_col.agg_mean(groups),
+            Sum => agg_col.agg_sum(groups),
+            First => Some(agg_col.agg_first(groups)),
+            Last => Some(agg_col.agg_last(groups)),
+            NUnique => agg_col.agg_n_unique(groups).map(|ca| ca.into_series()),
+            Median => agg_col.agg_median(groups),
+            Quantile(quantile) => agg_col.agg_quantile(groups, quantile),
+            Std => agg_col.agg_std(groups),
+            Var => agg_col.agg_var(groups),
+            List => agg_col.agg_list(groups),
+            Count => {
+                let mut builder = PrimitiveChunkedBuilder::<UInt32Type>::new("", groups.len());
+                for (_first, idx) in groups {
+                    builder.append_value(idx.len() as u32);
+                }
+                Some(builder.finish().into_series())
+            }
+            Groups => None,
+        }?;
+        agg.rename(&fmt_groupby_column(agg_col.name(), method));
+        Some(agg)
+    }
+
+    /// Combine different aggregations on columns. The groups are computed once and shared by
+    /// all aggregations.
+    ///
+    /// The output has the key columns followed by a column per aggregation, in the order of
+    /// `column_to_agg`, named `{column}_{aggregation}` (`{column}_agg_list` for `"list"`).
+    /// Aggregations that are not supported for the dtype of a column are skipped, an unknown
+    /// aggregation or column is an error.
     ///
     /// ## Operations
     ///
@@ -1625,6 +2059,10 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// * max
     /// * mean
     /// * median
+    /// * n_unique
+    /// * std
+    /// * var
+    /// * list
     ///
     /// # Example
     ///
@@ -1652,67 +2090,40 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     ///
     pub fn agg<Column, S, Slice>(&self, column_to_agg: &[(Column, Slice)]) -> Result<DataFrame>
     where
-        S: AsRef<str>,
         S: AsRef<str>,
         Slice: AsRef<[S]>,
         Column: AsRef<str>,
     {
-        // create a mapping from columns to aggregations on that column
-        let mut map = HashMap::with_capacity_and_hasher(column_to_agg.len(), RandomState::new());
-        column_to_agg.iter().for_each(|(column, aggregations)| {
-            map.insert(column.as_ref(), aggregations.as_ref());
-        });
-
-        macro_rules! finish_agg_opt {
-            ($self:ident, $name_fmt:expr, $agg_fn:ident, $agg_col:ident, $cols:ident) => {{
-                let new_name = format![$name_fmt, $agg_col.name()];
-                let opt_agg = $agg_col.$agg_fn(&$self.groups);
-                if let Some(mut agg) = opt_agg {
-                    agg.rename(&new_name);
-                    $cols.push(agg.into_series());
-                }
-            }};
-        }
-        macro_rules! finish_agg {
-            ($self:ident, $name_fmt:expr, $agg_fn:ident, $agg_col:ident, $cols:ident) => {{
-                let new_name = format![$name_fmt, $agg_col.name()];
-                let mut agg = $agg_col.$agg_fn(&$self.groups);
-                agg.rename(&new_name);
-                $cols.push(agg.into_series());
-            }};
-        }
-
-        let (mut cols, agg_cols) = self.prepare_agg()?;
-        for agg_col in &agg_cols {
-            if let Some(&aggregations) = map.get(agg_col.name()) {
-                for aggregation_f in aggregations {
-                    match aggregation_f.as_ref() {
-                        "min" => finish_agg_opt!(self, "{}_min", agg_min, agg_col, cols),
-                        "max" => finish_agg_opt!(self, "{}_max", agg_max, agg_col, cols),
-                        "mean" => finish_agg_opt!(self, "{}_mean", agg_mean, agg_col, cols),
-                        "sum" => finish_agg_opt!(self, "{}_sum", agg_sum, agg_col, cols),
-                        "first" => finish_agg!(self, "{}_first", agg_first, agg_col, cols),
-                        "last" => finish_agg!(self, "{}_last", agg_last, agg_col, cols),
-                        "n_unique" => {
-                            finish_agg_opt!(self, "{}_n_unique", agg_n_unique, agg_col, cols)
-                        }
-                        "median" => finish_agg_opt!(self, "{}_median", agg_median, agg_col, cols),
-                        "std" => finish_agg_opt!(self, "{}_std", agg_std, agg_col, cols),
-                        "var" => finish_agg_opt!(self, "{}_var", agg_var, agg_col, cols),
-                        "count" => {
-                            let new_name = format!["{}_count", agg_col.name()];
-                            let mut builder = PrimitiveChunkedBuilder::<UInt32Type>::new(
-                                &new_name,
-                                self.groups.len(),
-                            );
-                            for (_first, idx) in &self.groups {
-                                builder.append_value(idx.len() as u32);
-                            }
-                            let ca = builder.finish();
-                            cols.push(ca.into_series());
-                        }
-                        a => panic!("aggregation: {:?} is not supported", a),
+        use GroupByMethod::*;
+        let mut cols = self.keys();
+        for (column, aggregations) in column_to_agg {
+            let agg_col = self.df.column(column.as_ref())?;
+            for aggregation_f in aggregations.as_ref() {
+                let method = match aggregation_f.as_ref() {
+                    "min" => Min,
+                    "max" => Max,
+                    "mean" => Mean,
+                    "sum" => Sum,
+                    "first" => First,
+                    "last" => Last,
+                    "n_unique" => NUnique,
+                    "median" => Median,
+                    "std" => Std,
+                    "var" => Var,
+                    "count" => Count,
+                    "list" => List,
+                    a => {
+                        return Err(PolarsError::InvalidOperation(
+                            format!("aggregation: {:?} is not supported", a).into(),
+                        ))
                     }
+                };
+                if let Some(mut agg) = self.agg_series(agg_col, method) {
+                    // std and var are named `{column}_agg_std` elsewhere
+                    if matches!(method, Std | Var) {
+                        agg.rename(&format!("{}_{}", agg_col.name(), aggregation_f.as_ref()));
+                    }
+                    cols.push(agg);
                 }
             }
         }
@@ -1721,6 +2132,10 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
 
     /// Aggregate the groups of the groupby operation into lists.
     ///
+    /// Categorical columns are aggregated to lists of their categories, list and object columns
+    /// are skipped. The lists can be turned back into rows with
+    /// [DataFrame::explode](crate::frame::DataFrame::explode).
+    ///
     /// # Example
     ///
     /// ```rust
@@ -1758,6 +2173,23 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     }
 
     /// Apply a closure over the groups as a new DataFrame.
+    ///
+    /// Every group is passed to `f` as a `DataFrame` with the key columns and the selected
+    /// columns, or all columns if there is no selection. The groups are processed in parallel and
+    /// the results are stacked vertically, so they must have the same schema. The order of the
+    /// groups in the output is the order of [get_groups](GroupBy::get_groups). If there are no
+    /// groups, `f` is called once with an empty `DataFrame` to determine the output schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// /// The two largest sales of every shop.
+    /// fn example(df: DataFrame) -> Result<DataFrame> {
+    ///     df.groupby("shop")?
+    ///         .apply(|sub_df| sub_df.sort("amount", true)?.slice(0, 2))
+    /// }
+    /// ```
     pub fn apply<F>(&self, f: F) -> Result<DataFrame>
     where
         F: Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
@@ -1786,6 +2218,9 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
                 f(sub_df)
             })
             .collect::<Result<Vec<_>>>()?;
+        if dfs.is_empty() {
+            return f(df.slice(0, 0)?);
+        }
 
         let mut df = accumulate_dataframes_vertical(dfs)?;
         df.as_single_chunk();
@@ -1799,12 +2234,16 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// * max
     /// * mean
     /// * median
+    /// * count
     ///
     /// The pivot operation consists of a group by one, or multiple collumns (these will be the new
     /// y-axis), column that will be pivoted (this will be the new x-axis) and an aggregation.
     ///
-    /// # Panics
-    /// If the values column is not a numerical type, the code will panic.
+    /// The cells are aggregated directly from the groups of this `GroupBy`, so the same grouping
+    /// can be used for multiple pivots and aggregations.
+    ///
+    /// # Errors
+    /// Only `count` is supported for values columns that are not numerical.
     ///
     /// # Example
     ///
@@ -1858,13 +2297,10 @@ impl<'df, 'selection_str> GroupBy<'df, 'selection_str> {
     /// +-----+------+------+------+------+------+
     /// ```
     pub fn pivot(
-        &mut self,
+        &self,
         pivot_column: &'selection_str str,
         values_column: &'selection_str str,
     ) -> Pivot {
-        // same as select method
-        self.selected_agg = Some(vec![pivot_column, values_column]);
-
         Pivot {
             gb: self,
             pivot_column,
@@ -1945,6 +2381,16 @@ pub(crate) trait ChunkPivot {
     }
 }
 
+/// The unique pivot values in order of first appearance. This is the order of the pivoted columns.
+fn pivot_column_order<'a>(pivot_vec: &'a [Option<Groupable<'a>>]) -> Vec<&'a Groupable<'a>> {
+    let mut seen = HashSet::with_hasher(RandomState::new());
+    pivot_vec
+        .iter()
+        .flatten()
+        .filter(|v| seen.insert(*v))
+        .collect()
+}
+
 /// Create a hashmap that maps column/keys names to values. This is not yet the result of the aggregation.
 fn create_column_values_map<'a, T>(
     pivot_vec: &'a [Option<Groupable>],
@@ -2044,9 +2490,9 @@ where
         let mut cols = keys;
         cols.reserve_exact(columns_agg_map_main.len());
 
-        for (_, builder) in columns_agg_map_main {
-            let ca = builder.finish();
-            cols.push(ca.into_series());
+        for k in pivot_column_order(&pivot_vec) {
+            let builder = columns_agg_map_main.remove(k).unwrap();
+            cols.push(builder.finish().into_series());
         }
 
         DataFrame::new(cols)
@@ -2100,9 +2546,9 @@ fn pivot_count_impl<'a, CA: TakeRandom>(
     let mut cols = keys;
     cols.reserve_exact(columns_agg_map_main.len());
 
-    for (_, builder) in columns_agg_map_main {
-        let ca = builder.finish();
-        cols.push(ca.into_series());
+    for k in pivot_column_order(&pivot_vec) {
+        let builder = columns_agg_map_main.remove(k).unwrap();
+        cols.push(builder.finish().into_series());
     }
 
     DataFrame::new(cols)
@@ -2146,6 +2592,8 @@ impl ChunkPivot for ListChunked {}
 #[cfg(feature = "object")]
 impl<T> ChunkPivot for ObjectChunked<T> {}
 
+/// The aggregation of the values that end up in the same cell of a pivoted `DataFrame`.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PivotAgg {
     First,
     Sum,
@@ -2318,11 +2766,176 @@ impl<'df, 'sel_str> Pivot<'df, 'sel_str> {
 
 #[cfg(test)]
 mod test {
-    use crate::frame::group_by::{groupby, groupby_threaded_flat};
+    use crate::frame::group_by::{groupby, groupby_threaded_flat, IntoGroupTuples};
     use crate::prelude::*;
     use crate::utils::split_ca;
     use itertools::Itertools;
 
+    #[test]
+    fn test_groupby_sorted() -> Result<()> {
+        let df = df! {
+            "a" => &[3, 1, 2, 1, 3, 3],
+            "b" => &[1, 2, 3, 4, 5, 6]
+        }?;
+        let expected = df.groupby("a")?.sum()?.sort("a", false)?;
+        let sorted = df.sort("a", false)?;
+        assert_eq!(sorted.column("a")?.is_sorted(), IsSorted::Ascending);
+
+        let groups = sorted.column("a")?.i32()?.group_tuples(true);
+        assert_eq!(groups, &[(0, vec![0, 1]), (2, vec![2]), (3, vec![3, 4, 5])]);
+        let out = sorted.groupby("a")?.sum()?.sort("a", false)?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_nth() -> Result<()> {
+        let df = df! {
+            "customer" => &["a", "b", "a", "a", "b", "c"],
+            "amount" => &[1, 2, 3, 4, 5, 6]
+        }?;
+        let gb = df.groupby("customer")?;
+
+        let out = gb.nth(1)?.sort("customer", false)?;
+        assert_eq!(out.get_column_names(), &["customer", "amount_nth"]);
+        assert_eq!(
+            Vec::from(out.column("amount_nth")?.i32()?),
+            &[Some(3), Some(5), None]
+        );
+
+        let out = gb.nth(-1)?.sort("customer", false)?;
+        assert_eq!(
+            Vec::from(out.column("amount_nth")?.i32()?),
+            &[Some(4), Some(5), Some(6)]
+        );
+
+        let out = gb.nth(-3)?.sort("customer", false)?;
+        assert_eq!(
+            Vec::from(out.column("amount_nth")?.i32()?),
+            &[Some(1), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_float_aggregations() -> Result<()> {
+        let df = df! {
+            "g" => &["a", "b", "a", "c", "b", "a", "c", "c"],
+            "int" => &[Some(1), Some(2), Some(3), None, Some(4), Some(5), Some(6), Some(8)],
+            "float" => &[1.0f32, 2.0, 1.0, 3.0, 2.0, 1.5, f32::NAN, f32::NAN]
+        }?;
+        let gb = df.groupby("g")?;
+
+        let out = gb.select("int").var()?.sort("g", false)?;
+        assert_eq!(out.column("int_agg_var")?.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.column("int_agg_var")?.f64()?),
+            &[Some(4.0), Some(2.0), Some(2.0)]
+        );
+        let out = gb.select("int").std()?.sort("g", false)?;
+        assert_eq!(out.column("int_agg_std")?.f64()?.get(0), Some(2.0));
+        let out = gb.select("float").var()?;
+        assert_eq!(out.column("float_agg_var")?.dtype(), &DataType::Float32);
+
+        // null values are ignored
+        let out = gb.select("int").median()?.sort("g", false)?;
+        assert_eq!(
+            Vec::from(out.column("int_median")?.i32()?),
+            &[Some(3), Some(4), Some(8)]
+        );
+        let out = gb.select("int").quantile(0.0)?.sort("g", false)?;
+        assert_eq!(
+            Vec::from(out.column("int_quantile_0.00")?.i32()?),
+            &[Some(1), Some(2), Some(6)]
+        );
+
+        let out = gb.select("float").n_unique()?.sort("g", false)?;
+        assert_eq!(
+            Vec::from(out.column("float_n_unique")?.u32()?),
+            &[Some(2), Some(1), Some(2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_first_last_order() -> Result<()> {
+        // enough rows to use the threaded groupby
+        let n = 10_000;
+        let day = (0..n).map(|i| (i % 7) as i32).collect::<Vec<_>>();
+        let hour = (0..n).map(|i| (i % 3) as i32).collect::<Vec<_>>();
+        let price = (0..n)
+            .map(|i| if i == 2 { None } else { Some(i as u32) })
+            .collect::<Vec<_>>();
+        let df = df! {
+            "day" => &day,
+            "hour" => &hour,
+            "price" => &price
+        }?;
+
+        let first = df
+            .groupby("day")?
+            .select("price")
+            .first()?
+            .sort("day", false)?;
+        assert_eq!(
+            Vec::from(first.column("price_first")?.u32()?),
+            &[Some(0), Some(1), None, Some(3), Some(4), Some(5), Some(6)]
+        );
+        let last = df
+            .groupby("day")?
+            .select("price")
+            .last()?
+            .sort("day", false)?;
+        assert_eq!(last.column("price_last")?.u32()?.get(0), Some(9996));
+        assert_eq!(last.column("price_last")?.u32()?.get(6), Some(9995));
+
+        let first = df.groupby(&["day", "hour"])?.select("price").first()?;
+        let last = df.groupby(&["day", "hour"])?.select("price").last()?;
+        assert_eq!(first.height(), 21);
+        // the first row of a group (day, hour) is its own index: i < 21 and i % 7 == day
+        let first_sum: Option<u32> = first.column("price_first")?.u32()?.sum();
+        assert_eq!(first_sum, Some((0..21).sum::<u32>() - 2));
+        let last_min: Option<u32> = last.column("price_last")?.u32()?.min();
+        assert_eq!(last_min, Some(n as u32 - 21));
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_agg_list() -> Result<()> {
+        let mut df = df! {
+            "g" => &["a", "b", "a", "b", "a"],
+            "v" => &[Some(1), Some(2), None, Some(4), Some(5)],
+            "c" => &["x", "y", "z", "x", "y"]
+        }?;
+        df.may_apply("c", |s| s.cast::<CategoricalType>())?;
+
+        let out = df.groupby("g")?.agg_list()?.sort("g", false)?;
+        assert_eq!(out.get_column_names(), &["g", "v_agg_list", "c_agg_list"]);
+        assert_eq!(
+            out.column("v_agg_list")?.dtype(),
+            &DataType::List(ArrowDataType::Int32)
+        );
+        assert_eq!(
+            out.column("c_agg_list")?.dtype(),
+            &DataType::List(ArrowDataType::LargeUtf8)
+        );
+
+        let exploded = out.explode(&["v_agg_list", "c_agg_list"])?;
+        assert_eq!(
+            Vec::from(exploded.column("v_agg_list")?.i32()?),
+            &[Some(1), None, Some(5), Some(2), Some(4)]
+        );
+        assert_eq!(
+            Vec::from(exploded.column("c_agg_list")?.utf8()?),
+            &[Some("x"), Some("z"), Some("y"), Some("y"), Some("x")]
+        );
+
+        // lists of lists are not supported
+        let out = out.groupby("g")?.select("v_agg_list").agg_list()?;
+        assert_eq!(out.width(), 1);
+        Ok(())
+    }
+
     #[test]
     fn test_group_by() {
         let s0 = Date32Chunked::parse_from_str_slice(
@@ -2427,6 +3040,53 @@ mod test {
         println!("{:?}", df.groupby("date").unwrap().groups().unwrap());
     }
 
+    #[test]
+    fn test_groupby_agg_many() -> Result<()> {
+        let df = df! {
+            "shop" => &["a", "b", "a", "b", "a"],
+            "price" => &[1.0, 2.0, 3.0, 4.0, 5.0],
+            "qty" => &[10, 20, 30, 40, 50],
+            "item" => &["x", "y", "z", "x", "y"]
+        }?;
+        let gb = df.groupby("shop")?;
+        let out = gb
+            .agg(&[
+                ("qty", vec!["max"]),
+                ("price", vec!["sum", "mean", "std"]),
+                ("item", vec!["first", "sum", "count"]),
+            ])?
+            .sort("shop", false)?;
+        // utf8 columns can't be summed
+        assert_eq!(
+            out.get_column_names(),
+            &[
+                "shop",
+                "qty_max",
+                "price_sum",
+                "price_mean",
+                "price_std",
+                "item_first",
+                "item_count"
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("price_sum")?.f64()?),
+            &[Some(9.0), Some(6.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("qty_max")?.i32()?),
+            &[Some(50), Some(40)]
+        );
+        assert_eq!(
+            Vec::from(out.column("item_count")?.u32()?),
+            &[Some(3), Some(2)]
+        );
+
+        assert!(gb.agg(&[("qty", vec!["maximum"])]).is_err());
+        assert!(gb.agg(&[("quantity", vec!["max"])]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_pivot() {
         let s0 = Series::new("foo", ["A", "A", "B", "B", "C"].as_ref());
@@ -2467,6 +3127,211 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_groupby_categorical_codes() -> Result<()> {
+        let ca = Utf8Chunked::new_from_opt_slice(
+            "cat",
+            &[Some("b"), Some("a"), None, Some("b"), Some("c"), None],
+        )
+        .cast::<CategoricalType>()?;
+        let groups = ca.group_tuples(true);
+        assert_eq!(
+            groups,
+            &[(0, vec![0, 3]), (1, vec![1]), (4, vec![4]), (2, vec![2, 5])]
+        );
+        let mut hashed = ca.cast::<UInt32Type>()?.group_tuples(false);
+        hashed.sort();
+        let mut groups = groups;
+        groups.sort();
+        assert_eq!(groups, hashed);
+
+        // more categories than rows are grouped by hashing
+        let ca = Utf8Chunked::new_from_slice("cat", &["x", "y", "z"]).cast::<CategoricalType>()?;
+        let sliced = ca.slice(1, 1)?;
+        assert_eq!(sliced.group_tuples(true), &[(0, vec![0])]);
+
+        let df = DataFrame::new(vec![ca.into_series(), Series::new("value", &[1, 2, 3])])?;
+        let out = df.groupby_stable("cat")?.select("value").sum()?;
+        assert_eq!(
+            Vec::from(out.column("value_sum")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_wmean() -> Result<()> {
+        let df = df! {
+            "product" => ["a", "a", "b", "b", "c"],
+            "price" => [Some(10), Some(20), Some(5), None, Some(1)],
+            "revenue" => [Some(1.0), Some(3.0), Some(2.0), Some(8.0), Some(0.0)]
+        }?;
+        let out = df.groupby_stable("product")?.wmean("price", "revenue")?;
+        assert_eq!(out.get_column_names(), &["product", "price_wmean"]);
+        // the null price of b is ignored, the weights of c sum to zero
+        assert_eq!(
+            Vec::from(out.column("price_wmean")?.f64()?),
+            &[Some(17.5), Some(5.0), None]
+        );
+        assert!(df.groupby("price")?.wmean("product", "revenue").is_err());
+        assert_eq!(
+            df.column("price")?.weighted_mean(df.column("revenue")?)?,
+            Some(80.0 / 6.0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_maintain_order() -> Result<()> {
+        // enough rows and keys for the multithreaded groupby
+        let keys = (0..10_000).map(|i| (i * 7919) % 1000).collect::<Vec<i32>>();
+        let df = df! {
+            "a" => &keys,
+            "b" => &keys
+        }?;
+        let first_seen = keys.iter().unique().map(|k| Some(*k)).collect::<Vec<_>>();
+        for by in vec![vec!["a"], vec!["a", "b"]] {
+            let out = df.groupby(by)?.maintain_order(true).select("a").count()?;
+            assert_eq!(Vec::from(out.column("a")?.i32()?), first_seen);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_groups() -> Result<()> {
+        let df = df! {
+            "key" => ["a", "b", "a", "c", "b"],
+            "value" => [1, 2, 3, 4, 5]
+        }?;
+        let mut gb = df.groupby_stable("key")?;
+        assert_eq!(
+            gb.get_groups(),
+            &[(0, vec![0, 2]), (1, vec![1, 4]), (3, vec![3])]
+        );
+        let groups = gb.groups()?;
+        assert_eq!(groups.get_column_names(), &["key", "groups"]);
+        let idx = groups.column("groups")?.list()?;
+        assert_eq!(Vec::from(idx.get(1).unwrap().u32()?), &[Some(1), Some(4)]);
+
+        // aggregate only the groups with more than one row
+        gb.retain_groups(|(_, idx)| idx.len() > 1);
+        let out = gb.select("value").sum()?;
+        assert_eq!(
+            Vec::from(out.column("key")?.utf8()?),
+            &[Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("value_sum")?.i32()?),
+            &[Some(4), Some(7)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_aggregate_all_columns() -> Result<()> {
+        let df = df! {
+            "key" => ["a", "b", "a", "a"],
+            "int" => [1, 2, 3, 5],
+            "name" => ["x", "y", "z", "x"],
+            "float" => [Some(1.0), None, Some(2.0), None],
+            "flag" => [true, false, true, false]
+        }?;
+        let gb = df.groupby_stable("key")?;
+        let mean = gb.mean()?;
+        assert_eq!(mean.get_column_names(), &["key", "int_mean", "float_mean"]);
+        assert_eq!(
+            Vec::from(mean.column("int_mean")?.f64()?),
+            &[Some(3.0), Some(2.0)]
+        );
+        // null values are not counted
+        assert_eq!(
+            Vec::from(mean.column("float_mean")?.f64()?),
+            &[Some(1.5), None]
+        );
+        let sum = gb.sum()?;
+        assert_eq!(sum.get_column_names(), &["key", "int_sum", "float_sum"]);
+        assert_eq!(
+            Vec::from(sum.column("float_sum")?.f64()?),
+            &[Some(3.0), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_reuse_groupby() -> Result<()> {
+        let df = df! {
+            "foo" => ["A", "A", "B", "B", "C"],
+            "N" => [1, 2, 2, 4, 2],
+            "bar" => ["k", "l", "m", "m", "l"]
+        }?;
+        let gb = df.groupby_stable("foo")?;
+        let sum = gb.pivot("bar", "N").sum()?;
+        let first = gb.pivot("bar", "N").first()?;
+        assert_eq!(sum.get_column_names(), &["foo", "k", "l", "m"]);
+        assert_eq!(Vec::from(sum.column("m")?.i32()?), &[None, Some(6), None]);
+        assert_eq!(Vec::from(first.column("m")?.i32()?), &[None, Some(2), None]);
+        // the grouping is not changed by the pivots
+        let mean = gb.select("N").mean()?;
+        assert_eq!(
+            Vec::from(mean.column("N_mean")?.f64()?),
+            &[Some(1.5), Some(3.0), Some(2.0)]
+        );
+
+        assert!(gb.pivot("N", "bar").sum().is_err());
+        let count = gb.pivot("N", "bar").count()?;
+        assert_eq!(
+            Vec::from(count.column("2")?.u32()?),
+            &[Some(1), Some(1), Some(1)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_partition_by() -> Result<()> {
+        let df = df! {
+            "a" => ["x", "y", "x", "z", "y"],
+            "b" => [1, 2, 3, 4, 5]
+        }?;
+        let parts = df.partition_by("a", true)?;
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].get_column_names(), &["a", "b"]);
+        let values = parts
+            .iter()
+            .map(|df| Vec::from(df.column("b").unwrap().i32().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            &[
+                vec![Some(1), Some(3)],
+                vec![Some(2), Some(5)],
+                vec![Some(4)]
+            ]
+        );
+        let parts = df.partition_by(&["a"], false)?;
+        assert_eq!(parts[1].get_column_names(), &["b"]);
+        assert!(df.partition_by("c", true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pivot_frame() -> Result<()> {
+        let df = df! {
+            "foo" => ["A", "A", "B", "B", "C"],
+            "N" => [1, 2, 2, 4, 2],
+            "bar" => ["k", "l", "m", "m", "l"]
+        }?;
+        let pvt = df.pivot("N", "foo", "bar", PivotAgg::Sum)?;
+        assert_eq!(pvt.get_column_names(), &["foo", "k", "l", "m"]);
+        assert_eq!(
+            Vec::from(pvt.column("foo")?.utf8()?),
+            &[Some("A"), Some("B"), Some("C")]
+        );
+        assert_eq!(Vec::from(pvt.column("m")?.i32()?), &[None, Some(6), None]);
+        let pvt = df.pivot("N", "foo", "bar", PivotAgg::First)?;
+        assert_eq!(Vec::from(pvt.column("m")?.i32()?), &[None, Some(2), None]);
+        Ok(())
+    }
+
     #[test]
     fn test_static_groupby_by_12_columns() {
         // Build GroupBy DataFrame.
@@ -2581,6 +3446,42 @@ mod test {
 
         let out = df.groupby("a").unwrap().apply(|df| Ok(df)).unwrap();
         assert!(out.sort("b", false).unwrap().frame_equal(&df));
+
+        // the largest b per group, with the size of the group
+        let out = df
+            .groupby("a")
+            .unwrap()
+            .apply(|sub_df| {
+                let n = sub_df.height() as u32;
+                let mut top = sub_df.sort("b", true)?.slice(0, 1)?;
+                top.add_column(Series::new("n", &[n]))?;
+                Ok(top)
+            })
+            .unwrap()
+            .sort("a", false)
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.column("b").unwrap().i32().unwrap()),
+            &[Some(2), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("n").unwrap().u32().unwrap()),
+            &[Some(2), Some(3)]
+        );
+
+        // results with a different schema can't be stacked
+        let out = df.groupby("a").unwrap().apply(|sub_df| {
+            if sub_df.height() == 2 {
+                sub_df.select("a")
+            } else {
+                Ok(sub_df)
+            }
+        });
+        assert!(out.is_err());
+
+        let empty = df.slice(0, 0).unwrap();
+        let out = empty.groupby("a").unwrap().apply(|df| Ok(df)).unwrap();
+        assert_eq!(out.shape(), (0, 2));
     }
 
     #[test]
@@ -2602,4 +3503,35 @@ mod test {
             assert_eq!(a, b);
         }
     }
+    #[test]
+    fn test_groupby_partitioned_high_cardinality() -> Result<()> {
+        let n = 20_000;
+        let key = (0..n)
+            .map(|i| if i % 13 == 0 { None } else { Some(i % 5000) })
+            .collect::<Vec<_>>();
+        let key2 = (0..n).map(|i| (i % 7) as u8).collect::<Vec<_>>();
+        let df = df! {
+            "key" => &key,
+            "key2" => &key2
+        }?;
+
+        for by in &[vec!["key"], vec!["key", "key2"]] {
+            let by = df.select_series(by.clone())?;
+            let threaded = df
+                .groupby_with_series(by.clone(), true)?
+                .groups
+                .into_iter()
+                .sorted()
+                .collect_vec();
+            let single = df
+                .groupby_with_series(by, false)?
+                .groups
+                .into_iter()
+                .sorted()
+                .collect_vec();
+            assert_eq!(threaded.len(), single.len());
+            assert_eq!(threaded, single);
+        }
+        Ok(())
+    }
 }
@@ -1,13 +1,16 @@
+use crate::chunked_array::float::ToCanonicalBits;
+use crate::config;
 use crate::frame::select::Selection;
 use crate::prelude::*;
+use crate::progress::Progress;
 use crate::utils::{split_ca, NoNull};
 use crate::vector_hasher::{
-    create_hash_and_keys_threaded_vectorized, prepare_hashed_relation,
-    prepare_hashed_relation_threaded,
+    create_hash_and_keys_threaded_vectorized, df_rows_to_hashes, prepare_hashed_relation,
+    prepare_hashed_relation_threaded, IdBuildHasher, IdxHash,
 };
 use crate::POOL;
 use ahash::RandomState;
-use hashbrown::HashMap;
+use hashbrown::{hash_map::RawEntryMut, HashMap};
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashSet;
@@ -34,6 +37,141 @@ macro_rules! det_hash_prone_order {
     }};
 }
 
+/// A left join builds its hash table on the right relation. If the right relation is more than
+/// this many times larger than the left relation, the table is built on the left relation instead.
+///
+/// This is decided here at execution time rather than by the lazy optimizer from estimated plan
+/// sizes: the exact lengths are known at this point and eager joins benefit as well.
+///
+/// Building on the left relation saves hashing and inserting the surplus right rows, but the
+/// tuples then have to be sorted back into the left order (see `left_join_from_inner`). A table
+/// insert costs more than a probe, while the sort is over the left rows and their matches only,
+/// so the switch pays off once the right relation is a few times larger. The value is a
+/// conservative estimate; the `join` bench in `polars/benches` measures left joins around this
+/// ratio and should be used to retune it.
+const LEFT_JOIN_BUILD_LEFT_RATIO: usize = 4;
+
+fn left_join_builds_left(n_left: usize, n_right: usize) -> bool {
+    n_right > n_left.saturating_mul(LEFT_JOIN_BUILD_LEFT_RATIO)
+}
+
+/// Create the join tuples of a left join from the join tuples of the inner join. The inner join
+/// builds its table on the shorter (left) relation. The unmatched left rows are added and the
+/// tuples are restored to the left order. The sort is stable, so the right indices of a left row
+/// stay in the order of the right relation.
+fn left_join_from_inner(inner: Vec<(usize, usize)>, n_left: usize) -> Vec<(usize, Option<usize>)> {
+    let mut matched = vec![false; n_left];
+    let mut tuples = Vec::with_capacity(inner.len() + n_left);
+    tuples.extend(inner.into_iter().map(|(idx_left, idx_right)| {
+        matched[idx_left] = true;
+        (idx_left, Some(idx_right))
+    }));
+    tuples.extend(
+        matched
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !**matched)
+            .map(|(idx_left, _)| (idx_left, None)),
+    );
+    POOL.install(|| tuples.par_sort_by_key(|(idx_left, _)| *idx_left));
+    tuples
+}
+
+/// Inner join of two arrays that are flagged as sorted in the same direction and don't contain
+/// nulls, by walking both arrays at once instead of building a hash table. The tuples are in the
+/// sort order of the keys. Returns `None` if the arrays don't qualify.
+fn sorted_join_inner<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<Vec<(usize, usize)>>
+where
+    T: PolarsNumericType,
+{
+    let sorted = a.is_sorted();
+    if sorted == IsSorted::Not || sorted != b.is_sorted() {
+        return None;
+    }
+    let a = a.cont_slice().ok()?;
+    let b = b.cont_slice().ok()?;
+
+    let mut tuples = Vec::with_capacity(std::cmp::min(a.len(), b.len()));
+    let mut idx_a = 0;
+    let mut idx_b = 0;
+    while idx_a < a.len() && idx_b < b.len() {
+        let ord = a[idx_a]
+            .partial_cmp(&b[idx_b])
+            .unwrap_or(std::cmp::Ordering::Equal);
+        let ord = match sorted {
+            IsSorted::Descending => ord.reverse(),
+            _ => ord,
+        };
+        match ord {
+            std::cmp::Ordering::Less => idx_a += 1,
+            std::cmp::Ordering::Greater => idx_b += 1,
+            std::cmp::Ordering::Equal => {
+                // join the run of equal values in `a` with the run of equal values in `b`
+                let v = a[idx_a];
+                let end_b = idx_b + b[idx_b..].iter().take_while(|w| **w == v).count();
+                while idx_a < a.len() && a[idx_a] == v {
+                    tuples.extend((idx_b..end_b).map(|idx_b| (idx_a, idx_b)));
+                    idx_a += 1;
+                }
+                idx_b = end_b;
+            }
+        }
+    }
+    Some(tuples)
+}
+
+/// Outer join of two arrays that are flagged as sorted in the same direction and don't contain
+/// nulls, see [sorted_join_inner]. The tuples are in the sort order of the keys.
+fn sorted_join_outer<T>(
+    a: &ChunkedArray<T>,
+    b: &ChunkedArray<T>,
+) -> Option<Vec<(Option<usize>, Option<usize>)>>
+where
+    T: PolarsNumericType,
+{
+    let sorted = a.is_sorted();
+    if sorted == IsSorted::Not || sorted != b.is_sorted() {
+        return None;
+    }
+    let a = a.cont_slice().ok()?;
+    let b = b.cont_slice().ok()?;
+
+    let mut tuples = Vec::with_capacity(std::cmp::max(a.len(), b.len()));
+    let mut idx_a = 0;
+    let mut idx_b = 0;
+    while idx_a < a.len() && idx_b < b.len() {
+        let ord = a[idx_a]
+            .partial_cmp(&b[idx_b])
+            .unwrap_or(std::cmp::Ordering::Equal);
+        let ord = match sorted {
+            IsSorted::Descending => ord.reverse(),
+            _ => ord,
+        };
+        match ord {
+            std::cmp::Ordering::Less => {
+                tuples.push((Some(idx_a), None));
+                idx_a += 1
+            }
+            std::cmp::Ordering::Greater => {
+                tuples.push((None, Some(idx_b)));
+                idx_b += 1
+            }
+            std::cmp::Ordering::Equal => {
+                let v = a[idx_a];
+                let end_b = idx_b + b[idx_b..].iter().take_while(|w| **w == v).count();
+                while idx_a < a.len() && a[idx_a] == v {
+                    tuples.extend((idx_b..end_b).map(|idx_b| (Some(idx_a), Some(idx_b))));
+                    idx_a += 1;
+                }
+                idx_b = end_b;
+            }
+        }
+    }
+    tuples.extend((idx_a..a.len()).map(|idx_a| (Some(idx_a), None)));
+    tuples.extend((idx_b..b.len()).map(|idx_b| (None, Some(idx_b))));
+    Some(tuples)
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum JoinType {
     Left,
@@ -41,6 +179,49 @@ pub enum JoinType {
     Outer,
 }
 
+/// The cardinality a join is expected to have. A join that doesn't have it would duplicate rows
+/// unexpectedly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinValidation {
+    /// The keys are unique in both DataFrames.
+    OneToOne,
+    /// The keys are unique in the left DataFrame.
+    OneToMany,
+    /// The keys are unique in the right DataFrame.
+    ManyToOne,
+}
+
+impl JoinValidation {
+    /// Check the keys of a join against the expected cardinality. Returns a
+    /// [Duplicate](PolarsError::Duplicate) error if a key that should be unique isn't.
+    pub fn validate(&self, left_on: &[Series], right_on: &[Series]) -> Result<()> {
+        let keys_unique = |keys: &[Series]| -> Result<bool> {
+            let height = keys.first().map_or(0, |s| s.len());
+            let df = DataFrame::new_no_checks(keys.to_vec());
+            let gb = df.groupby_with_series(keys.to_vec(), true)?;
+            Ok(gb.get_groups().len() == height)
+        };
+        let (left, right) = match self {
+            JoinValidation::OneToOne => (true, true),
+            JoinValidation::OneToMany => (true, false),
+            JoinValidation::ManyToOne => (false, true),
+        };
+        for (check, keys, side) in [(left, left_on, "left"), (right, right_on, "right")].iter() {
+            if *check && !keys_unique(keys)? {
+                return Err(PolarsError::Duplicate(
+                    format!(
+                        "the join keys of the {} DataFrame are not unique, as required by {:?}",
+                        side, self
+                    )
+                    .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The hash table of [prepare_hashed_relation_threaded] that contains the keys with hash `h`.
 unsafe fn get_hash_tbl<T>(
     h: u64,
     hash_tables: &[HashMap<T, Vec<usize>, RandomState>],
@@ -49,13 +230,8 @@ unsafe fn get_hash_tbl<T>(
 where
     T: Send + Hash + Eq + Sync + Copy,
 {
-    let mut idx = 0;
-    for i in 0..len {
-        if (h + i) % len == 0 {
-            idx = i as usize;
-        }
-    }
-    hash_tables.get_unchecked(idx)
+    // the tables are partitioned with `this_thread`
+    hash_tables.get_unchecked((h % len) as usize)
 }
 
 #[allow(clippy::needless_collect)]
@@ -94,8 +270,9 @@ where
             .map(|(probe_hashes, offset)| {
                 // local reference
                 let hash_tbls = &hash_tbls;
-                let mut results =
-                    Vec::with_capacity(probe_hashes.len() / POOL.current_num_threads());
+                // every probe key matches at least once in a left join and on a join on a
+                // foreign key
+                let mut results = Vec::with_capacity(probe_hashes.len());
                 let local_offset = offset;
                 // code duplication is to hoist swap out of the inner loop.
                 if swap {
@@ -169,8 +346,9 @@ where
             .map(|(probe_hashes, offset)| {
                 // local reference
                 let hash_tbls = &hash_tbls;
-                let mut results =
-                    Vec::with_capacity(probe_hashes.len() / POOL.current_num_threads());
+                // every probe key matches at least once in a left join and on a join on a
+                // foreign key
+                let mut results = Vec::with_capacity(probe_hashes.len());
 
                 probe_hashes.iter().enumerate().for_each(|(idx_a, (h, k))| {
                     let idx_a = idx_a + offset;
@@ -268,19 +446,26 @@ where
 {
     let mut results = Vec::with_capacity(a.size_hint().0 + b.size_hint().0);
 
-    // prepare hash table
-    let mut hash_tbl = prepare_hashed_relation(b);
+    // prepare hash table. Every entry gets a flag that is set once it is matched, so that more
+    // rows of a with the same key still match and the unmatched rows of b can be found.
+    let mut hash_tbl: HashMap<T, (bool, Vec<usize>), RandomState> = {
+        let hash_tbl = prepare_hashed_relation(b);
+        let mut flagged =
+            HashMap::with_capacity_and_hasher(hash_tbl.len(), hash_tbl.hasher().clone());
+        flagged.extend(hash_tbl.into_iter().map(|(k, v)| (k, (false, v))));
+        flagged
+    };
 
     // probe the hash table.
     // Note: indexes from b that are not matched will be None, Some(idx_b)
-    // Therefore we remove the matches and the remaining will be joined from the right
 
     // code duplication is because we want to only do the swap check once
     if swap {
         a.enumerate().for_each(|(idx_a, key)| {
-            match hash_tbl.remove(&key) {
+            match hash_tbl.get_mut(&key) {
                 // left and right matches
-                Some(indexes_b) => {
+                Some((matched, indexes_b)) => {
+                    *matched = true;
                     results.extend(indexes_b.iter().map(|&idx_b| (Some(idx_b), Some(idx_a))))
                 }
                 // only left values, right = null
@@ -289,15 +474,19 @@ where
                 }
             }
         });
-        hash_tbl.iter().for_each(|(_k, indexes_b)| {
-            // remaining joined values from the right table
-            results.extend(indexes_b.iter().map(|&idx_b| (Some(idx_b), None)))
-        });
+        hash_tbl
+            .values()
+            .filter(|(matched, _)| !matched)
+            .for_each(|(_, indexes_b)| {
+                // remaining joined values from the right table
+                results.extend(indexes_b.iter().map(|&idx_b| (Some(idx_b), None)))
+            });
     } else {
         a.enumerate().for_each(|(idx_a, key)| {
-            match hash_tbl.remove(&key) {
+            match hash_tbl.get_mut(&key) {
                 // left and right matches
-                Some(indexes_b) => {
+                Some((matched, indexes_b)) => {
+                    *matched = true;
                     results.extend(indexes_b.iter().map(|&idx_b| (Some(idx_a), Some(idx_b))))
                 }
                 // only left values, right = null
@@ -306,15 +495,96 @@ where
                 }
             }
         });
-        hash_tbl.iter().for_each(|(_k, indexes_b)| {
-            // remaining joined values from the right table
-            results.extend(indexes_b.iter().map(|&idx_b| (None, Some(idx_b))))
-        });
+        hash_tbl
+            .values()
+            .filter(|(matched, _)| !matched)
+            .for_each(|(_, indexes_b)| {
+                // remaining joined values from the right table
+                results.extend(indexes_b.iter().map(|&idx_b| (None, Some(idx_b))))
+            });
     };
 
     results
 }
 
+/// Outer join tuples for any number of key columns. The rows of the keys are hashed and the
+/// rows with equal hashes are compared by value. Slower than zipping the keys, but not limited
+/// in the number of keys.
+///
+/// The tuples follow the order of the left rows, the unmatched right rows come last.
+fn hash_join_tuples_multiple_keys(
+    left_keys: &DataFrame,
+    right_keys: &DataFrame,
+) -> Result<Vec<(Option<usize>, Option<usize>)>> {
+    if let Some(s) = left_keys
+        .get_columns()
+        .iter()
+        .chain(right_keys.get_columns())
+        .find(|s| matches!(s.dtype(), DataType::List(_) | DataType::Object))
+    {
+        return Err(PolarsError::InvalidOperation(
+            format!(
+                "cannot join on column '{}' of dtype {:?}",
+                s.name(),
+                s.dtype()
+            )
+            .into(),
+        ));
+    }
+    if left_keys.height() == 0 || right_keys.height() == 0 {
+        return Ok((0..left_keys.height())
+            .map(|idx| (Some(idx), None))
+            .chain((0..right_keys.height()).map(|idx| (None, Some(idx))))
+            .collect());
+    }
+    let (left_hashes, random_state) = df_rows_to_hashes(left_keys, None);
+    let (right_hashes, _) = df_rows_to_hashes(right_keys, Some(random_state));
+
+    // two row containers to amortize allocations
+    let mut row_1 = right_keys.get_row(0);
+    let mut row_2 = row_1.clone();
+
+    let mut hash_tbl: HashMap<IdxHash, (bool, Vec<usize>), IdBuildHasher> =
+        HashMap::with_capacity_and_hasher(right_hashes.len(), IdBuildHasher::default());
+    for (idx, h) in right_hashes.into_no_null_iter().enumerate() {
+        unsafe { right_keys.get_row_amortized_unchecked(idx, &mut row_2) };
+        let entry = hash_tbl.raw_entry_mut().from_hash(h, |idx_hash| {
+            unsafe { right_keys.get_row_amortized_unchecked(idx_hash.idx, &mut row_1) };
+            row_1 == row_2
+        });
+        match entry {
+            RawEntryMut::Vacant(entry) => {
+                entry.insert_hashed_nocheck(h, IdxHash::new(idx, h), (false, vec![idx]));
+            }
+            RawEntryMut::Occupied(mut entry) => entry.get_mut().1.push(idx),
+        }
+    }
+
+    let mut results = Vec::with_capacity(left_hashes.len());
+    for (idx, h) in left_hashes.into_no_null_iter().enumerate() {
+        unsafe { left_keys.get_row_amortized_unchecked(idx, &mut row_2) };
+        let entry = hash_tbl.raw_entry_mut().from_hash(h, |idx_hash| {
+            unsafe { right_keys.get_row_amortized_unchecked(idx_hash.idx, &mut row_1) };
+            row_1 == row_2
+        });
+        match entry {
+            RawEntryMut::Occupied(mut entry) => {
+                let (matched, indexes_b) = entry.get_mut();
+                *matched = true;
+                results.extend(indexes_b.iter().map(|&idx_b| (Some(idx), Some(idx_b))));
+            }
+            RawEntryMut::Vacant(_) => results.push((Some(idx), None)),
+        }
+    }
+    hash_tbl
+        .values()
+        .filter(|(matched, _)| !matched)
+        .for_each(|(_, indexes_b)| {
+            results.extend(indexes_b.iter().map(|&idx_b| (None, Some(idx_b))))
+        });
+    Ok(results)
+}
+
 pub(crate) trait HashJoin<T> {
     fn hash_join_inner(&self, _other: &ChunkedArray<T>) -> Vec<(usize, usize)> {
         unimplemented!()
@@ -341,28 +611,37 @@ macro_rules! impl_float_hash_join {
                     (0, 0) => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_canonical_bits()))
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_canonical_bits()))
                             .collect_vec();
                         hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
                     }
                     _ => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+                            })
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+                            })
                             .collect_vec();
                         hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
                     }
                 }
             }
             fn hash_join_left(&self, other: &$ca) -> Vec<(usize, Option<usize>)> {
+                if left_join_builds_left(self.len(), other.len()) {
+                    return left_join_from_inner(self.hash_join_inner(other), self.len());
+                }
                 let n_threads = n_join_threads();
 
                 let a = self;
@@ -374,22 +653,28 @@ macro_rules! impl_float_hash_join {
                     (0, 0) => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_canonical_bits()))
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_bits()))
+                            .map(|ca| ca.into_no_null_iter().map(|v| v.to_canonical_bits()))
                             .collect_vec();
                         hash_join_tuples_left_threaded(iters_a, iters_b)
                     }
                     _ => {
                         let iters_a = splitted_a
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+                            })
                             .collect_vec();
                         let iters_b = splitted_b
                             .iter()
-                            .map(|ca| ca.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())))
+                            .map(|ca| {
+                                ca.into_iter()
+                                    .map(|opt_v| opt_v.map(|v| v.to_canonical_bits()))
+                            })
                             .collect_vec();
                         hash_join_tuples_left_threaded(iters_a, iters_b)
                     }
@@ -400,13 +685,15 @@ macro_rules! impl_float_hash_join {
 
                 match (a.null_count() == 0, b.null_count() == 0) {
                     (true, true) => hash_join_tuples_outer(
-                        a.into_no_null_iter().map(|v| v.to_bits()),
-                        b.into_no_null_iter().map(|v| v.to_bits()),
+                        a.into_no_null_iter().map(|v| v.to_canonical_bits()),
+                        b.into_no_null_iter().map(|v| v.to_canonical_bits()),
                         swap,
                     ),
                     _ => hash_join_tuples_outer(
-                        a.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())),
-                        b.into_iter().map(|opt_v| opt_v.map(|v| v.to_bits())),
+                        a.into_iter()
+                            .map(|opt_v| opt_v.map(|v| v.to_canonical_bits())),
+                        b.into_iter()
+                            .map(|opt_v| opt_v.map(|v| v.to_canonical_bits())),
                         swap,
                     ),
                 }
@@ -419,23 +706,33 @@ impl_float_hash_join!(Float32Type, Float32Chunked);
 impl_float_hash_join!(Float64Type, Float64Chunked);
 
 impl HashJoin<ListType> for ListChunked {}
+/// Categoricals are joined on their codes if these refer to the same categories, e.g. when both
+/// are created with the global string cache. Otherwise they are joined on their categories.
+macro_rules! impl_categorical_join {
+    ($self:expr, $other:expr, $method:ident) => {{
+        if $self.codes_comparable($other) {
+            $self.deref().$method(&$other.cast().unwrap())
+        } else {
+            let left: Utf8Chunked = $self.cast().unwrap();
+            left.$method(&$other.cast().unwrap())
+        }
+    }};
+}
+
 impl HashJoin<CategoricalType> for CategoricalChunked {
     fn hash_join_inner(&self, other: &CategoricalChunked) -> Vec<(usize, usize)> {
-        self.deref().hash_join_inner(&other.cast().unwrap())
+        impl_categorical_join!(self, other, hash_join_inner)
     }
     fn hash_join_left(&self, other: &CategoricalChunked) -> Vec<(usize, Option<usize>)> {
-        self.deref().hash_join_left(&other.cast().unwrap())
+        impl_categorical_join!(self, other, hash_join_left)
     }
     fn hash_join_outer(&self, other: &CategoricalChunked) -> Vec<(Option<usize>, Option<usize>)> {
-        self.deref().hash_join_outer(&other.cast().unwrap())
+        impl_categorical_join!(self, other, hash_join_outer)
     }
 }
 
 fn n_join_threads() -> usize {
-    let max = std::env::var("POLARS_MAX_THREADS")
-        .map(|s| s.parse::<usize>().expect("integer"))
-        .unwrap_or(usize::MAX);
-    std::cmp::min(num_cpus::get(), max)
+    std::cmp::min(num_cpus::get(), config::max_threads())
 }
 
 impl<T> HashJoin<T> for ChunkedArray<T>
@@ -444,6 +741,9 @@ where
     T::Native: Eq + Hash,
 {
     fn hash_join_inner(&self, other: &ChunkedArray<T>) -> Vec<(usize, usize)> {
+        if let Some(tuples) = sorted_join_inner(self, other) {
+            return tuples;
+        }
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         let n_threads = n_join_threads();
@@ -471,6 +771,12 @@ where
     }
 
     fn hash_join_left(&self, other: &ChunkedArray<T>) -> Vec<(usize, Option<usize>)> {
+        if let Some(tuples) = sorted_join_inner(self, other) {
+            return left_join_from_inner(tuples, self.len());
+        }
+        if left_join_builds_left(self.len(), other.len()) {
+            return left_join_from_inner(self.hash_join_inner(other), self.len());
+        }
         let n_threads = n_join_threads();
 
         let a = self;
@@ -499,6 +805,9 @@ where
     }
 
     fn hash_join_outer(&self, other: &ChunkedArray<T>) -> Vec<(Option<usize>, Option<usize>)> {
+        if let Some(tuples) = sorted_join_outer(self, other) {
+            return tuples;
+        }
         let (a, b, swap) = det_hash_prone_order!(self, other);
 
         match (a.null_count() == 0, b.null_count() == 0) {
@@ -543,70 +852,51 @@ impl HashJoin<BooleanType> for BooleanChunked {
     }
 }
 
-impl HashJoin<Utf8Type> for Utf8Chunked {
-    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(usize, usize)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
+/// Map the values of both arrays to `u32` codes using a shared interning table, such that equal
+/// strings get equal codes. Every string is hashed once to build the table, after which the join
+/// hashes and compares `u32` codes instead of strings.
+fn intern_utf8_pair(a: &Utf8Chunked, b: &Utf8Chunked) -> (UInt32Chunked, UInt32Chunked) {
+    let capacity = std::cmp::min(a.len(), b.len());
+    let mut table = HashMap::with_capacity_and_hasher(capacity, RandomState::default());
+    let a = intern_utf8(a, &mut table);
+    let b = intern_utf8(b, &mut table);
+    (a, b)
+}
 
-        let n_threads = n_join_threads();
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
+fn intern_utf8<'a>(
+    ca: &'a Utf8Chunked,
+    table: &mut HashMap<&'a str, u32, RandomState>,
+) -> UInt32Chunked {
+    let mut intern = |s: &'a str| {
+        let code = table.len() as u32;
+        *table.entry(s).or_insert(code)
+    };
+    if ca.null_count() == 0 {
+        ca.into_no_null_iter()
+            .map(intern)
+            .collect::<NoNull<_>>()
+            .into_inner()
+    } else {
+        ca.into_iter()
+            .map(|opt_s| opt_s.map(|s| intern(s)))
+            .collect()
+    }
+}
 
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_inner_threaded(iters_a, iters_b, swap)
-            }
-        }
+impl HashJoin<Utf8Type> for Utf8Chunked {
+    fn hash_join_inner(&self, other: &Utf8Chunked) -> Vec<(usize, usize)> {
+        let (a, b) = intern_utf8_pair(self, other);
+        a.hash_join_inner(&b)
     }
 
     fn hash_join_left(&self, other: &Utf8Chunked) -> Vec<(usize, Option<usize>)> {
-        let n_threads = n_join_threads();
-
-        let a = self;
-        let b = other;
-        let splitted_a = split_ca(a, n_threads).unwrap();
-        let splitted_b = split_ca(b, n_threads).unwrap();
-
-        match (a.null_count(), b.null_count()) {
-            (0, 0) => {
-                let iters_a = splitted_a
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                let iters_b = splitted_b
-                    .iter()
-                    .map(|ca| ca.into_no_null_iter())
-                    .collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
-            }
-            _ => {
-                let iters_a = splitted_a.iter().map(|ca| ca.into_iter()).collect_vec();
-                let iters_b = splitted_b.iter().map(|ca| ca.into_iter()).collect_vec();
-                hash_join_tuples_left_threaded(iters_a, iters_b)
-            }
-        }
+        let (a, b) = intern_utf8_pair(self, other);
+        a.hash_join_left(&b)
     }
 
     fn hash_join_outer(&self, other: &Utf8Chunked) -> Vec<(Option<usize>, Option<usize>)> {
-        let (a, b, swap) = det_hash_prone_order!(self, other);
-        match (a.null_count() == 0, b.null_count() == 0) {
-            (true, true) => {
-                hash_join_tuples_outer(a.into_no_null_iter(), b.into_no_null_iter(), swap)
-            }
-            _ => hash_join_tuples_outer(a.into_iter(), b.into_iter(), swap),
-        }
+        let (a, b) = intern_utf8_pair(self, other);
+        a.hash_join_outer(&b)
     }
 }
 
@@ -622,7 +912,7 @@ pub trait ZipOuterJoinColumn {
 
 impl<T> ZipOuterJoinColumn for ChunkedArray<T>
 where
-    T: PolarsIntegerType,
+    T: PolarsNumericType,
     ChunkedArray<T>: IntoSeries,
 {
     fn zip_outer_join_column(
@@ -637,26 +927,32 @@ where
 
         opt_join_tuples
             .iter()
-            .map(|(opt_left_idx, opt_right_idx)| {
-                if let Some(left_idx) = opt_left_idx {
-                    unsafe { left_rand_access.get_unchecked(*left_idx) }
-                } else {
-                    unsafe {
-                        let right_idx = opt_right_idx.unsafe_unwrap();
-                        right_rand_access.get_unchecked(right_idx)
-                    }
-                }
+            .map(|(opt_left_idx, opt_right_idx)| match opt_left_idx {
+                Some(left_idx) => left_rand_access.get(*left_idx),
+                None => right_rand_access.get(unsafe { opt_right_idx.unsafe_unwrap() }),
             })
-            .collect::<NoNull<ChunkedArray<T>>>()
-            .into_inner()
+            .collect::<ChunkedArray<T>>()
             .into_series()
     }
 }
 
-impl ZipOuterJoinColumn for Float32Chunked {}
-impl ZipOuterJoinColumn for Float64Chunked {}
 impl ZipOuterJoinColumn for ListChunked {}
-impl ZipOuterJoinColumn for CategoricalChunked {}
+
+impl ZipOuterJoinColumn for CategoricalChunked {
+    /// The keys are zipped as Utf8 and cast back, because the codes of both sides may refer to
+    /// different categories.
+    fn zip_outer_join_column(
+        &self,
+        right_column: &Series,
+        opt_join_tuples: &[(Option<usize>, Option<usize>)],
+    ) -> Series {
+        let left: Utf8Chunked = self.cast().unwrap();
+        let right = right_column.cast::<Utf8Type>().unwrap();
+        left.zip_outer_join_column(&right, opt_join_tuples)
+            .cast::<CategoricalType>()
+            .unwrap()
+    }
+}
 #[cfg(feature = "object")]
 impl<T> ZipOuterJoinColumn for ObjectChunked<T> {}
 
@@ -675,15 +971,9 @@ macro_rules! impl_zip_outer_join {
 
                 opt_join_tuples
                     .iter()
-                    .map(|(opt_left_idx, opt_right_idx)| {
-                        if let Some(left_idx) = opt_left_idx {
-                            unsafe { left_rand_access.get_unchecked(*left_idx) }
-                        } else {
-                            unsafe {
-                                let right_idx = opt_right_idx.unsafe_unwrap();
-                                right_rand_access.get_unchecked(right_idx)
-                            }
-                        }
+                    .map(|(opt_left_idx, opt_right_idx)| match opt_left_idx {
+                        Some(left_idx) => left_rand_access.get(*left_idx),
+                        None => right_rand_access.get(unsafe { opt_right_idx.unsafe_unwrap() }),
                     })
                     .collect::<$chunkedtype>()
                     .into_series()
@@ -694,9 +984,23 @@ macro_rules! impl_zip_outer_join {
 impl_zip_outer_join!(BooleanChunked);
 impl_zip_outer_join!(Utf8Chunked);
 
+/// Drop the column with the name of a join key, if the DataFrame has one.
+pub(crate) fn drop_key(df: &DataFrame, key: &Series) -> DataFrame {
+    match df.drop(key.name()) {
+        Ok(df) => df,
+        Err(_) => df.clone(),
+    }
+}
+
 impl DataFrame {
-    /// Utility method to finish a join.
-    fn finish_join(&self, mut df_left: DataFrame, mut df_right: DataFrame) -> Result<DataFrame> {
+    /// Utility method to finish a join. Right columns whose name is in the left DataFrame get a
+    /// `"_right"` suffix, if that name is taken as well the column is renamed according to
+    /// [DuplicatePolicy::Suffix].
+    pub(crate) fn finish_join(
+        &self,
+        mut df_left: DataFrame,
+        mut df_right: DataFrame,
+    ) -> Result<DataFrame> {
         let mut left_names = HashSet::with_capacity_and_hasher(df_left.width(), RandomState::new());
 
         df_left.columns.iter().for_each(|series| {
@@ -715,7 +1019,7 @@ impl DataFrame {
             df_right.rename(&name, &format!("{}_right", name))?;
         }
 
-        df_left.hstack_mut(&df_right.columns)?;
+        df_left.hstack_mut_with_policy(&df_right.columns, DuplicatePolicy::Suffix)?;
         Ok(df_left)
     }
 
@@ -728,7 +1032,18 @@ impl DataFrame {
         }
     }
 
-    /// Generic join method. Can be used to join on multiple columns.
+    /// Generic join method. Can be used to join on multiple columns. The key columns may have
+    /// different names in both DataFrames. Up to six keys are hashed as tuples of values, more
+    /// keys are joined by hashing the rows of the keys, which is slower.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     left.join(right, &["year", "city"], &["yr", "town"], JoinType::Inner)
+    /// }
+    /// ```
     pub fn join<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
         &self,
         other: &DataFrame,
@@ -738,18 +1053,191 @@ impl DataFrame {
     ) -> Result<DataFrame> {
         let selected_left = self.select_series(left_on)?;
         let selected_right = other.select_series(right_on)?;
-        assert_eq!(selected_right.len(), selected_left.len());
+        self.join_with_series(other, &selected_left, &selected_right, how)
+    }
+
+    /// Join like [join](DataFrame::join), but add `suffix` instead of `"_right"` to the names of
+    /// the columns of `other` that clash with a column of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let trades = df! {
+    ///     "ticker" => &["a", "b"],
+    ///     "price" => &[1.0, 2.0]
+    /// }
+    /// .unwrap();
+    /// let quotes = df! {
+    ///     "ticker" => &["a", "b"],
+    ///     "price" => &[1.1, 1.9]
+    /// }
+    /// .unwrap();
+    /// let joined = trades
+    ///     .join_with_suffix(&quotes, "ticker", "ticker", JoinType::Inner, "_quotes")
+    ///     .unwrap();
+    /// assert_eq!(joined.get_column_names(), &["ticker", "price", "price_quotes"]);
+    /// ```
+    pub fn join_with_suffix<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        how: JoinType,
+        suffix: &str,
+    ) -> Result<DataFrame> {
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        self.join_with_series_and_suffix(other, &selected_left, &selected_right, how, suffix)
+    }
+
+    /// Join on key Series like [join_with_series](DataFrame::join_with_series), with the suffix
+    /// of [join_with_suffix](DataFrame::join_with_suffix).
+    pub fn join_with_series_and_suffix(
+        &self,
+        other: &DataFrame,
+        left_on: &[Series],
+        right_on: &[Series],
+        how: JoinType,
+        suffix: &str,
+    ) -> Result<DataFrame> {
+        // rename the clashing columns up front, so that they don't get the default suffix
+        let mut renamed = other.clone();
+        for s in other.get_columns() {
+            let name = s.name();
+            if self.column(name).is_ok() && !right_on.iter().any(|key| key.name() == name) {
+                renamed.rename(name, &format!("{}{}", name, suffix))?;
+            }
+        }
+        self.join_with_series(&renamed, left_on, right_on, how)
+    }
+
+    /// Join like [join](DataFrame::join), but first check that the join has the expected
+    /// cardinality. This catches joins that duplicate rows because a key that was assumed to be
+    /// unique isn't.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let orders = df! {
+    ///     "customer" => &[1, 1, 2],
+    ///     "amount" => &[10, 20, 30]
+    /// }
+    /// .unwrap();
+    /// let customers = df! {
+    ///     "customer" => &[1, 2, 2],
+    ///     "name" => &["a", "b", "c"]
+    /// }
+    /// .unwrap();
+    /// // every order should have one customer
+    /// assert!(orders
+    ///     .join_with_validation(
+    ///         &customers,
+    ///         "customer",
+    ///         "customer",
+    ///         JoinType::Left,
+    ///         JoinValidation::ManyToOne
+    ///     )
+    ///     .is_err());
+    /// ```
+    pub fn join_with_validation<'a, J, S1: Selection<'a, J>, S2: Selection<'a, J>>(
+        &self,
+        other: &DataFrame,
+        left_on: S1,
+        right_on: S2,
+        how: JoinType,
+        validation: JoinValidation,
+    ) -> Result<DataFrame> {
+        let selected_left = self.select_series(left_on)?;
+        let selected_right = other.select_series(right_on)?;
+        validation.validate(&selected_left, &selected_right)?;
+        self.join_with_series(other, &selected_left, &selected_right, how)
+    }
+
+    /// Join on key Series that don't have to be columns of the DataFrames, e.g. keys that are
+    /// normalized before the join. The keys must have the same length as their DataFrame.
+    ///
+    /// A column of `other` with the same name as a right key is dropped from the result, just
+    /// like the key columns in [join](DataFrame::join).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// // join rows whose ids only differ in case
+    /// fn join_case_insensitive(left: &DataFrame, right: &DataFrame) -> Result<DataFrame> {
+    ///     let lowercase = |s: &Series| -> Result<Series> {
+    ///         let ca: Utf8Chunked = s
+    ///             .utf8()?
+    ///             .into_iter()
+    ///             .map(|opt_v| opt_v.map(|v| v.to_lowercase()))
+    ///             .collect();
+    ///         Ok(ca.into_series())
+    ///     };
+    ///     let left_key = lowercase(left.column("id")?)?;
+    ///     let right_key = lowercase(right.column("id")?)?;
+    ///     left.join_with_series(right, &[left_key], &[right_key], JoinType::Left)
+    /// }
+    /// ```
+    pub fn join_with_series(
+        &self,
+        other: &DataFrame,
+        left_on: &[Series],
+        right_on: &[Series],
+        how: JoinType,
+    ) -> Result<DataFrame> {
+        if left_on.len() != right_on.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the number of left keys: {} does not match the number of right keys: {}",
+                    left_on.len(),
+                    right_on.len()
+                )
+                .into(),
+            ));
+        }
+        if left_on.is_empty() {
+            return Err(PolarsError::ValueError(
+                "a join needs at least one key".into(),
+            ));
+        }
+        if let Some((l, r)) = left_on
+            .iter()
+            .zip(right_on)
+            .find(|(l, r)| l.dtype() != r.dtype())
+        {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!(
+                    "cannot join the key '{}' of dtype {:?} with the key '{}' of dtype {:?}",
+                    l.name(),
+                    l.dtype(),
+                    r.name(),
+                    r.dtype()
+                )
+                .into(),
+            ));
+        }
+        if left_on.iter().any(|s| s.len() != self.height())
+            || right_on.iter().any(|s| s.len() != other.height())
+        {
+            return Err(PolarsError::ShapeMisMatch(
+                "the join keys must have the same length as their DataFrame".into(),
+            ));
+        }
+        let selected_left = left_on;
+        let selected_right = right_on;
 
         if selected_left.len() == 1 {
             return match how {
                 JoinType::Inner => {
-                    self.inner_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.inner_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
                 JoinType::Left => {
-                    self.left_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.left_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
                 JoinType::Outer => {
-                    self.outer_join(other, selected_left[0].name(), selected_right[0].name())
+                    self.outer_join_from_series(other, &selected_left[0], &selected_right[0])
                 }
             };
         }
@@ -772,15 +1260,28 @@ impl DataFrame {
             }};
         }
 
+        // More keys than can be zipped are joined by hashing the rows of the keys. Categoricals
+        // are compared as strings, because their codes may refer to different categories.
+        let multiple_keys_tuples = || {
+            let key_df = |keys: &[Series]| -> Result<DataFrame> {
+                let columns = keys
+                    .iter()
+                    .map(|s| match s.dtype() {
+                        DataType::Categorical => s.cast::<Utf8Type>(),
+                        _ => Ok(s.clone()),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataFrame::new_no_checks(columns))
+            };
+            hash_join_tuples_multiple_keys(&key_df(selected_left)?, &key_df(selected_right)?)
+        };
+
         fn remove_selected(df: &DataFrame, selected: &[Series]) -> DataFrame {
-            let mut new = None;
+            let mut new = df.clone();
             for s in selected {
-                new = match new {
-                    None => Some(df.drop(s.name()).unwrap()),
-                    Some(new) => Some(new.drop(s.name()).unwrap()),
-                }
+                new = drop_key(&new, s);
             }
-            new.unwrap()
+            new
         }
 
         match how {
@@ -816,7 +1317,10 @@ impl DataFrame {
                         let (a, b, swap) = det_hash_prone_order2!(a, b);
                         hash_join_tuples_inner(a, b, swap)
                     }
-                    _ => todo!(),
+                    _ => multiple_keys_tuples()?
+                        .into_iter()
+                        .filter_map(|(left, right)| Some((left?, right?)))
+                        .collect(),
                 };
 
                 let (df_left, df_right) = POOL.join(
@@ -858,7 +1362,10 @@ impl DataFrame {
                         let b = static_zip!(selected_right, 5);
                         hash_join_tuples_left(a, b)
                     }
-                    _ => todo!(),
+                    _ => multiple_keys_tuples()?
+                        .into_iter()
+                        .filter_map(|(left, right)| Some((left?, right)))
+                        .collect(),
                 };
 
                 let (df_left, df_right) = POOL.join(
@@ -905,7 +1412,7 @@ impl DataFrame {
                         let (a, b, swap) = det_hash_prone_order2!(a, b);
                         hash_join_tuples_outer(a, b, swap)
                     }
-                    _ => todo!(),
+                    _ => multiple_keys_tuples()?,
                 };
 
                 // Take the left and right dataframes by join tuples
@@ -960,18 +1467,18 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
+        let n_rows = s_left.len() + s_right.len();
+        let progress = Progress::start("join", Some(n_rows));
         let join_tuples = s_left.hash_join_inner(s_right);
+        progress.update(n_rows)?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&join_tuples),
             || unsafe {
-                other
-                    .drop(s_right.name())
-                    .unwrap()
-                    .take_iter_unchecked_bounds(
-                        join_tuples.iter().map(|(_left, right)| *right),
-                        Some(join_tuples.len()),
-                    )
+                drop_key(other, s_right).take_iter_unchecked_bounds(
+                    join_tuples.iter().map(|(_left, right)| *right),
+                    Some(join_tuples.len()),
+                )
             },
         );
         self.finish_join(df_left, df_right)
@@ -998,24 +1505,29 @@ impl DataFrame {
         s_left: &Series,
         s_right: &Series,
     ) -> Result<DataFrame> {
+        let n_rows = s_left.len() + s_right.len();
+        let progress = Progress::start("join", Some(n_rows));
         let opt_join_tuples = s_left.hash_join_left(s_right);
+        progress.update(n_rows)?;
 
         let (df_left, df_right) = POOL.join(
             || self.create_left_df(&opt_join_tuples),
             || unsafe {
-                other
-                    .drop(s_right.name())
-                    .unwrap()
-                    .take_opt_iter_unchecked_bounds(
-                        opt_join_tuples.iter().map(|(_left, right)| *right),
-                        Some(opt_join_tuples.len()),
-                    )
+                drop_key(other, s_right).take_opt_iter_unchecked_bounds(
+                    opt_join_tuples.iter().map(|(_left, right)| *right),
+                    Some(opt_join_tuples.len()),
+                )
             },
         );
         self.finish_join(df_left, df_right)
     }
 
     /// Perform an outer join on two DataFrames
+    ///
+    /// The result holds the union of the keys of both sides. The columns of the side that has no
+    /// match for a key are null. The key columns are merged into one column with the name of the
+    /// left key. Null keys are joined like any other key.
+    ///
     /// # Example
     ///
     /// ```
@@ -1041,26 +1553,24 @@ impl DataFrame {
         s_right: &Series,
     ) -> Result<DataFrame> {
         // Get the indexes of the joined relations
+        let n_rows = s_left.len() + s_right.len();
+        let progress = Progress::start("join", Some(n_rows));
         let opt_join_tuples = s_left.hash_join_outer(s_right);
+        progress.update(n_rows)?;
 
         // Take the left and right dataframes by join tuples
         let (mut df_left, df_right) = POOL.join(
             || unsafe {
-                self.drop(s_left.name())
-                    .unwrap()
-                    .take_opt_iter_unchecked_bounds(
-                        opt_join_tuples.iter().map(|(left, _right)| *left),
-                        Some(opt_join_tuples.len()),
-                    )
+                drop_key(self, s_left).take_opt_iter_unchecked_bounds(
+                    opt_join_tuples.iter().map(|(left, _right)| *left),
+                    Some(opt_join_tuples.len()),
+                )
             },
             || unsafe {
-                other
-                    .drop(s_right.name())
-                    .unwrap()
-                    .take_opt_iter_unchecked_bounds(
-                        opt_join_tuples.iter().map(|(_left, right)| *right),
-                        Some(opt_join_tuples.len()),
-                    )
+                drop_key(other, s_right).take_opt_iter_unchecked_bounds(
+                    opt_join_tuples.iter().map(|(_left, right)| *right),
+                    Some(opt_join_tuples.len()),
+                )
             },
         );
         let mut s = s_left.zip_outer_join_column(s_right, &opt_join_tuples);
@@ -1072,9 +1582,55 @@ impl DataFrame {
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::prelude::*;
     use crate::toggle_string_cache;
 
+    #[test]
+    fn test_left_join_build_left() {
+        let left = Int32Chunked::new_from_opt_slice("a", &[Some(3), None, Some(1), Some(7)]);
+        let right = Int32Chunked::new_from_opt_slice(
+            "a",
+            &[
+                Some(1),
+                Some(2),
+                Some(3),
+                None,
+                Some(1),
+                Some(5),
+                Some(3),
+                Some(6),
+                Some(8),
+                Some(9),
+                Some(1),
+                Some(4),
+                Some(2),
+                Some(0),
+                Some(3),
+                Some(2),
+                Some(5),
+            ],
+        );
+        assert!(left_join_builds_left(left.len(), right.len()));
+        let expected = hash_join_tuples_left(left.into_iter(), right.into_iter());
+        assert_eq!(left.hash_join_left(&right), expected);
+
+        let left = Float64Chunked::new_from_slice("a", &[2.0, 1.0]);
+        let right = Float64Chunked::new_from_slice(
+            "a",
+            &[1.0, 1.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 1.0],
+        );
+        let expected = hash_join_tuples_left(
+            left.into_no_null_iter().map(|v| v.to_canonical_bits()),
+            right.into_no_null_iter().map(|v| v.to_canonical_bits()),
+        );
+        assert_eq!(left.hash_join_left(&right), expected);
+        assert_eq!(
+            expected,
+            [(0, None), (1, Some(0)), (1, Some(1)), (1, Some(9))]
+        );
+    }
+
     fn create_frames() -> (DataFrame, DataFrame) {
         let s0 = Series::new("days", &[0, 1, 2]);
         let s1 = Series::new("temp", &[22.1, 19.9, 7.]);
@@ -1087,6 +1643,79 @@ mod test {
         (temp, rain)
     }
 
+    #[test]
+    fn test_join_threaded() -> Result<()> {
+        // enough rows to be split over the threads
+        let keys = (0..10_000).map(|i| i % 1000).collect::<Vec<i64>>();
+        let left = df! {
+            "key" => &keys
+        }?;
+        let right = df! {
+            "key" => &(0..1000).rev().collect::<Vec<i64>>(),
+            "value" => &(0..1000).rev().map(|i| 2 * i).collect::<Vec<i64>>()
+        }?;
+        let joined = left.inner_join(&right, "key", "key")?;
+        assert_eq!(joined.height(), 10_000);
+        let key = joined.column("key")?.cast::<Float64Type>()?;
+        let expected = &key * 2.0;
+        assert!(joined
+            .column("value")?
+            .cast::<Float64Type>()?
+            .series_equal(&expected));
+        let joined = left.left_join(&right.slice(0, 500)?, "key", "key")?;
+        assert_eq!(joined.height(), 10_000);
+        assert_eq!(joined.column("value")?.null_count(), 5000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sorted_join() {
+        let a = Int32Chunked::new_from_slice("a", &[4, 2, 1, 5, 2]);
+        let b = Int32Chunked::new_from_slice("b", &[5, 0, 2, 3, 2, 5]);
+        for reverse in &[false, true] {
+            let a = a.sort(*reverse);
+            let b = b.sort(*reverse);
+            let mut a_plain = a.clone();
+            a_plain.set_sorted(IsSorted::Not);
+
+            let mut inner = a.hash_join_inner(&b);
+            let mut expected = a_plain.hash_join_inner(&b);
+            assert_eq!(inner.len(), 6);
+            inner.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(inner, expected);
+
+            let mut left = a.hash_join_left(&b);
+            let mut expected = a_plain.hash_join_left(&b);
+            assert_eq!(left.len(), 8);
+            left.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(left, expected);
+
+            // the merge gives the outer join in key order
+            let outer = a.hash_join_outer(&b);
+            let keys = outer
+                .iter()
+                .map(|(idx_a, idx_b)| match idx_a {
+                    Some(idx_a) => a.get(*idx_a),
+                    None => b.get(idx_b.unwrap()),
+                })
+                .collect::<Vec<_>>();
+            let mut sorted_keys = keys.clone();
+            sorted_keys.sort_unstable();
+            if *reverse {
+                sorted_keys.reverse();
+            }
+            assert_eq!(keys, sorted_keys);
+            let mut outer = outer;
+            let mut expected = a_plain.hash_join_outer(&b);
+            assert_eq!(outer.len(), 10);
+            outer.sort_unstable();
+            expected.sort_unstable();
+            assert_eq!(outer, expected);
+        }
+    }
+
     #[test]
     fn test_inner_join() {
         let (temp, rain) = create_frames();
@@ -1158,6 +1787,43 @@ mod test {
         assert_eq!(joined.column("days").unwrap().sum::<i32>(), Some(7));
     }
 
+    #[test]
+    fn test_outer_join_key_types() -> Result<()> {
+        let left = df! {
+            "key" => &[Some(1.0), None, Some(3.0)],
+            "a" => &[1, 2, 3]
+        }?;
+        let right = df! {
+            "key" => &[Some(3.0), Some(4.0)],
+            "b" => &[30, 40]
+        }?;
+        let joined = left.outer_join(&right, "key", "key")?;
+        assert_eq!(joined.height(), 4);
+        // the null key stays null, the key that is only on the right is taken from the right
+        let key = joined.column("key")?;
+        assert_eq!(key.null_count(), 1);
+        assert_eq!(key.sum::<f64>(), Some(8.0));
+        assert_eq!(joined.column("a")?.null_count(), 1);
+        assert_eq!(joined.column("b")?.null_count(), 2);
+
+        let mut left = left;
+        left.replace(
+            "key",
+            Series::new("key", &["x", "y", "z"]).cast::<CategoricalType>()?,
+        )?;
+        let mut right = right;
+        right.replace(
+            "key",
+            Series::new("key", &["z", "w"]).cast::<CategoricalType>()?,
+        )?;
+        let joined = left.outer_join(&right, "key", "key")?;
+        assert_eq!(joined.height(), 4);
+        let key = joined.column("key")?;
+        assert_eq!(key.dtype(), &DataType::Categorical);
+        assert_eq!(key.cast::<Utf8Type>()?.n_unique()?, 4);
+        Ok(())
+    }
+
     #[test]
     fn test_join_with_nulls() {
         let dts = &[20, 21, 22, 23, 24, 25, 27, 28];
@@ -1183,6 +1849,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_join_utf8_interned() {
+        let a = Utf8Chunked::new_from_opt_slice("a", &[Some("x"), None, Some("y"), Some("z")]);
+        let b = Utf8Chunked::new_from_opt_slice("b", &[Some("y"), Some("x"), None]);
+        let (codes_a, codes_b) = intern_utf8_pair(&a, &b);
+        assert_eq!(Vec::from(&codes_a), &[Some(0), None, Some(1), Some(2)]);
+        assert_eq!(Vec::from(&codes_b), &[Some(1), Some(0), None]);
+
+        let mut tuples = a.hash_join_inner(&b);
+        tuples.sort_unstable();
+        assert_eq!(tuples, &[(0, 1), (1, 2), (2, 0)]);
+        let mut tuples = a.hash_join_left(&b);
+        tuples.sort_unstable();
+        assert_eq!(
+            tuples,
+            &[(0, Some(1)), (1, Some(2)), (2, Some(0)), (3, None)]
+        );
+        let mut tuples = a.hash_join_outer(&b);
+        tuples.sort_unstable();
+        assert_eq!(
+            tuples,
+            &[
+                (Some(0), Some(1)),
+                (Some(1), Some(2)),
+                (Some(2), Some(0)),
+                (Some(3), None)
+            ]
+        );
+    }
+
     fn get_dfs() -> (DataFrame, DataFrame) {
         let df_a = df! {
             "a" => &[1, 2, 1, 1],
@@ -1267,6 +1963,124 @@ mod test {
             .series_equal_missing(joined_outer.column("ham").unwrap()));
     }
 
+    #[test]
+    fn test_join_many_keys() -> Result<()> {
+        // more keys than can be zipped
+        let names = (0..7).map(|i| format!("k{}", i)).collect::<Vec<_>>();
+        let names = names.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let frame = |keys: &[i32], last_key: &[i32], name: &str, values: &[i32]| {
+            let mut columns = names[..6]
+                .iter()
+                .map(|name| Series::new(name, keys))
+                .collect::<Vec<_>>();
+            columns.push(Series::new(names[6], last_key));
+            columns.push(Series::new(name, values));
+            DataFrame::new(columns)
+        };
+        let left = frame(&[1, 1, 2, 3], &[1, 2, 2, 3], "a", &[1, 2, 3, 4])?;
+        let right = frame(&[1, 1, 2, 4], &[1, 1, 2, 4], "b", &[10, 11, 20, 40])?;
+
+        let inner = left.join(&right, names.clone(), names.clone(), JoinType::Inner)?;
+        assert_eq!(inner.shape(), (3, 9));
+        assert_eq!(inner.column("b")?.sum::<i32>(), Some(41));
+
+        let joined = left.join(&right, names.clone(), names.clone(), JoinType::Left)?;
+        assert_eq!(joined.height(), 5);
+        assert_eq!(
+            Vec::from(joined.column("a")?.i32()?),
+            &[Some(1), Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(joined.column("b")?.null_count(), 2);
+
+        let outer = left.join(&right, names.clone(), names, JoinType::Outer)?;
+        assert_eq!(outer.height(), 6);
+        assert_eq!(outer.column("a")?.null_count(), 1);
+        assert_eq!(outer.column("k6")?.null_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_outer_join_duplicate_keys() -> Result<()> {
+        let left = df! {
+            "key" => &[1, 1, 2],
+            "a" => &[1, 2, 3]
+        }?;
+        let right = df! {
+            "key" => &[1],
+            "b" => &[10]
+        }?;
+        // every left row with key 1 matches
+        let joined = left.outer_join(&right, "key", "key")?;
+        assert_eq!(joined.height(), 3);
+        assert_eq!(joined.column("b")?.null_count(), 1);
+        let joined = left.join(&right, ("key", "a"), ("key", "b"), JoinType::Outer)?;
+        assert_eq!(joined.height(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_with_suffix() -> Result<()> {
+        let left = df! {
+            "id" => &[1, 2],
+            "x" => &[1, 2],
+            "x_right" => &[3, 4]
+        }?;
+        let right = df! {
+            "id" => &[1, 2],
+            "x" => &[10, 20]
+        }?;
+        let joined = left.join_with_suffix(&right, "id", "id", JoinType::Left, "_other")?;
+        assert_eq!(
+            joined.get_column_names(),
+            &["id", "x", "x_right", "x_other"]
+        );
+        assert_eq!(
+            Vec::from(joined.column("x_other")?.i32()?),
+            &[Some(10), Some(20)]
+        );
+        // the suffixed name clashes with a column of the left DataFrame and gets the default suffix
+        let joined = left.join_with_suffix(&right, "id", "id", JoinType::Left, "_right")?;
+        assert_eq!(
+            joined.get_column_names(),
+            &["id", "x", "x_right", "x_right_right"]
+        );
+
+        // the default suffix clashes as well, so the duplicate policy resolves the name
+        let joined = left.left_join(&right, "id", "id")?;
+        assert_eq!(
+            joined.get_column_names(),
+            &["id", "x", "x_right", "x_right_1"]
+        );
+        assert_eq!(
+            Vec::from(joined.column("x_right_1")?.i32()?),
+            &[Some(10), Some(20)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_validation() -> Result<()> {
+        let left = df! {
+            "a" => &[1, 1, 2],
+            "b" => &[1, 2, 1]
+        }?;
+        let right = df! {
+            "a" => &[1, 2, 2],
+            "b" => &[1, 1, 2],
+            "c" => &[10, 20, 30]
+        }?;
+        let validate = |on: Vec<&str>, validation| {
+            left.join_with_validation(&right, on.clone(), on, JoinType::Inner, validation)
+        };
+        assert!(validate(vec!["a"], JoinValidation::OneToOne).is_err());
+        assert!(validate(vec!["a"], JoinValidation::OneToMany).is_err());
+        assert!(validate(vec!["a"], JoinValidation::ManyToOne).is_err());
+        // the rows are unique on both keys
+        let joined = validate(vec!["a", "b"], JoinValidation::OneToOne)?;
+        assert_eq!(joined.height(), 2);
+        Ok(())
+    }
+
     #[test]
     fn test_join_categorical() {
         toggle_string_cache(true);
@@ -1293,4 +2107,67 @@ mod test {
 
         assert_eq!(Vec::from(ca), correct_ham);
     }
+
+    #[test]
+    fn test_join_with_series() -> Result<()> {
+        let df_a = df! {
+            "id" => &["A", "b", "C"],
+            "a" => &[1, 2, 3]
+        }?;
+        let df_b = df! {
+            "id" => &["c", "a"],
+            "b" => &[30, 10]
+        }?;
+        let lowercase = |s: &Series| -> Result<Series> {
+            let ca: Utf8Chunked = s
+                .utf8()?
+                .into_iter()
+                .map(|opt_v| opt_v.map(|v| v.to_lowercase()))
+                .collect();
+            let mut s = ca.into_series();
+            s.rename("key");
+            Ok(s)
+        };
+        let key_a = lowercase(df_a.column("id")?)?;
+        let key_b = lowercase(df_b.column("id")?)?;
+
+        let out = df_a
+            .join_with_series(&df_b, &[key_a.clone()], &[key_b.clone()], JoinType::Inner)?
+            .sort("a", false)?;
+        // the keys are not columns, so the original columns are all kept
+        assert_eq!(out.get_column_names(), &["id", "a", "id_right", "b"]);
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(3)]);
+        assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(10), Some(30)]);
+
+        let out = df_a
+            .join_with_series(&df_b, &[key_a.clone()], &[key_b.clone()], JoinType::Left)?
+            .sort("a", false)?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(10), None, Some(30)]
+        );
+
+        // the keys must match the height of their DataFrame
+        let short = key_b.head(Some(1));
+        assert!(df_a
+            .join_with_series(&df_b, &[key_a.clone()], &[short], JoinType::Inner)
+            .is_err());
+        assert!(df_a
+            .join_with_series(&df_b, &[key_a], &[], JoinType::Inner)
+            .is_err());
+
+        // keys of different dtypes are an error that names both keys
+        match df_a.join(&df_b, "a", "id", JoinType::Inner) {
+            Err(PolarsError::DataTypeMisMatch(msg)) => {
+                assert!(msg.contains("'a' of dtype Int32"));
+                assert!(msg.contains("'id' of dtype Utf8"));
+            }
+            _ => panic!("expected a dtype error"),
+        }
+        match df_a.column("c") {
+            Err(PolarsError::NotFound(msg)) => assert!(msg.contains("[\"id\", \"a\"]")),
+            _ => panic!("expected a missing column"),
+        }
+        Ok(())
+    }
 }
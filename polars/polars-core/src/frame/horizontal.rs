@@ -0,0 +1,172 @@
+use crate::prelude::*;
+use crate::utils::get_supertype;
+
+/// Combine two Series of the same dtype with `f`. Where one of both values is null, the other
+/// value is taken, so null values are skipped.
+fn combine_skip_nulls<F>(acc: Series, s: &Series, f: F) -> Result<Series>
+where
+    F: Fn(&Series, &Series) -> Result<Series>,
+{
+    let combined = f(&acc, s)?;
+    let combined = combined.zip_with(&s.is_not_null(), &acc)?;
+    combined.zip_with(&acc.is_not_null(), s)
+}
+
+impl DataFrame {
+    /// Reduce the columns to a single Series by applying `f` to the accumulated Series and the
+    /// next column. The first column is the initial value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2],
+    ///     "b" => &[3, 4]
+    /// }
+    /// .unwrap();
+    /// let product = df.fold(|acc, s| Ok(&acc * s)).unwrap();
+    /// assert_eq!(Vec::from(product.i32().unwrap()), &[Some(3), Some(8)]);
+    /// ```
+    pub fn fold<F>(&self, mut f: F) -> Result<Series>
+    where
+        F: FnMut(Series, &Series) -> Result<Series>,
+    {
+        let mut iter = self.columns.iter();
+        let first = iter
+            .next()
+            .ok_or_else(|| PolarsError::NoData("cannot fold a DataFrame without columns".into()))?;
+        iter.try_fold(first.clone(), |acc, s| f(acc, s))
+    }
+
+    /// Cast the columns to their supertype and fold them with `f`, skipping null values.
+    fn fold_skip_nulls<F>(&self, name: &str, numeric: bool, f: F) -> Result<Series>
+    where
+        F: Fn(&Series, &Series) -> Result<Series>,
+    {
+        let mut dtypes = self.columns.iter().map(|s| s.dtype());
+        let dtype = match dtypes.next() {
+            Some(first) => dtypes.try_fold(first.clone(), |acc, dt| get_supertype(&acc, dt))?,
+            None => {
+                return Err(PolarsError::NoData(
+                    "cannot fold a DataFrame without columns".into(),
+                ))
+            }
+        };
+        let supported = match numeric {
+            true => dtype.is_numeric(),
+            false => dtype == DataType::Boolean,
+        };
+        if !supported {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot fold columns of dtype {:?} horizontally", dtype).into(),
+            ));
+        }
+        let cols = self
+            .columns
+            .iter()
+            .map(|s| s.cast_with_datatype(&dtype))
+            .collect::<Result<Vec<_>>>()?;
+        let mut out = DataFrame::new_no_checks(cols)
+            .fold(|acc, s| combine_skip_nulls(acc, s, |a, b| f(a, b)))?;
+        out.rename(name);
+        Ok(out)
+    }
+
+    /// Sum the numeric columns per row. Null values are skipped, a row of only null values sums
+    /// to null. The result has the supertype of the columns and is named `"sum"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[Some(1), None],
+    ///     "b" => &[Some(2.5), Some(4.0)]
+    /// }
+    /// .unwrap();
+    /// let sum = df.sum_horizontal().unwrap();
+    /// assert_eq!(Vec::from(sum.f64().unwrap()), &[Some(3.5), Some(4.0)]);
+    /// ```
+    pub fn sum_horizontal(&self) -> Result<Series> {
+        self.fold_skip_nulls("sum", true, |a, b| Ok(a + b))
+    }
+
+    /// The minimum of the numeric columns per row, skipping null values. The result is named
+    /// `"min"`.
+    pub fn min_horizontal(&self) -> Result<Series> {
+        self.fold_skip_nulls("min", true, |a, b| a.zip_with(&a.lt_eq(b), b))
+    }
+
+    /// The maximum of the numeric columns per row, skipping null values. The result is named
+    /// `"max"`.
+    pub fn max_horizontal(&self) -> Result<Series> {
+        self.fold_skip_nulls("max", true, |a, b| a.zip_with(&a.gt_eq(b), b))
+    }
+
+    /// Check per row if all values of the boolean columns are `true`, skipping null values.
+    /// The result is named `"all"`.
+    pub fn all_horizontal(&self) -> Result<Series> {
+        self.fold_skip_nulls("all", false, |a, b| {
+            Ok((a.bool()? & b.bool()?).into_series())
+        })
+    }
+
+    /// Check per row if any value of the boolean columns is `true`, skipping null values.
+    /// The result is named `"any"`.
+    pub fn any_horizontal(&self) -> Result<Series> {
+        self.fold_skip_nulls("any", false, |a, b| {
+            Ok((a.bool()? | b.bool()?).into_series())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_horizontal_folds() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(1), None, None, Some(4)],
+            "b" => &[Some(3.0), Some(2.0), None, Some(1.0)],
+            "c" => &[Some(2), Some(5), None, None]
+        }?;
+        let sum = df.sum_horizontal()?;
+        assert_eq!(sum.name(), "sum");
+        assert_eq!(
+            Vec::from(sum.f64()?),
+            &[Some(6.0), Some(7.0), None, Some(5.0)]
+        );
+        assert_eq!(
+            Vec::from(df.min_horizontal()?.f64()?),
+            &[Some(1.0), Some(2.0), None, Some(1.0)]
+        );
+        assert_eq!(
+            Vec::from(df.max_horizontal()?.f64()?),
+            &[Some(3.0), Some(5.0), None, Some(4.0)]
+        );
+
+        let masks = df! {
+            "a" => &[Some(true), Some(true), None, Some(false)],
+            "b" => &[Some(true), Some(false), Some(true), None]
+        }?;
+        assert_eq!(
+            Vec::from(masks.all_horizontal()?.bool()?),
+            &[Some(true), Some(false), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(masks.any_horizontal()?.bool()?),
+            &[Some(true), Some(true), Some(true), Some(false)]
+        );
+        assert!(masks.sum_horizontal().is_err());
+        assert!(df.all_horizontal().is_err());
+
+        let product = df.select(("a", "c"))?.fold(|acc, s| Ok(&acc * s))?;
+        assert_eq!(Vec::from(product.i32()?), &[Some(2), None, None, None]);
+        assert!(DataFrame::new_no_checks(vec![])
+            .fold(|acc, _| Ok(acc))
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+
+/// Bytes allocated by the arrays of a Series, including the validity bitmaps.
+fn series_bytes(s: &Series) -> u64 {
+    s.chunks()
+        .iter()
+        .map(|arr| arr.get_array_memory_size() as u64)
+        .sum()
+}
+
+impl DataFrame {
+    /// Estimated number of bytes used by the columns. See [Series::estimated_size].
+    pub fn estimated_size(&self) -> usize {
+        self.columns.iter().map(|s| s.estimated_size()).sum()
+    }
+
+    /// Memory usage per column.
+    ///
+    /// The report has one row per column with the columns `column`, `dtype`, `chunks`, `bytes`
+    /// and `null_count`. `bytes` are the bytes allocated by the arrow arrays of the column, so
+    /// columns that are slices of a larger array report the size of the whole array.
+    ///
+    /// The last column, `n_unique`, is only set for Utf8 columns. A Utf8 column with few unique
+    /// values usually takes far less memory as a Categorical column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2, 3],
+    ///     "b" => &["x", "y", "x"]
+    /// }
+    /// .unwrap();
+    /// let report = df.memory_report().unwrap();
+    /// assert_eq!(report.shape(), (2, 6));
+    /// ```
+    pub fn memory_report(&self) -> Result<DataFrame> {
+        let names = self.get_column_names();
+        let dtypes = self
+            .columns
+            .iter()
+            .map(|s| format!("{:?}", s.dtype()))
+            .collect::<Vec<_>>();
+        let chunks = self
+            .columns
+            .iter()
+            .map(|s| s.n_chunks() as u32)
+            .collect::<Vec<_>>();
+        let bytes = self.columns.iter().map(series_bytes).collect::<Vec<_>>();
+        let null_counts = self
+            .columns
+            .iter()
+            .map(|s| s.null_count() as u32)
+            .collect::<Vec<_>>();
+        let n_unique = self
+            .columns
+            .iter()
+            .map(|s| match s.dtype() {
+                DataType::Utf8 => s.n_unique().map(|n| Some(n as u32)),
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        DataFrame::new(vec![
+            Utf8Chunked::new_from_slice("column", &names).into_series(),
+            Utf8Chunked::new_from_slice("dtype", &dtypes).into_series(),
+            UInt32Chunked::new_from_slice("chunks", &chunks).into_series(),
+            UInt64Chunked::new_from_slice("bytes", &bytes).into_series(),
+            UInt32Chunked::new_from_slice("null_count", &null_counts).into_series(),
+            UInt32Chunked::new_from_opt_slice("n_unique", &n_unique).into_series(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_memory_report() -> Result<()> {
+        let mut df = df! {
+            "a" => &[Some(1i64), None, Some(3)],
+            "b" => &["x", "y", "x"]
+        }?;
+        let other = df.clone();
+        df.vstack_mut(&other)?;
+        let report = df.memory_report()?;
+        assert_eq!(report.shape(), (2, 6));
+        assert_eq!(
+            Vec::from(report.column("column")?.utf8()?),
+            &[Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(report.column("chunks")?.u32()?),
+            &[Some(2), Some(2)]
+        );
+        assert_eq!(
+            Vec::from(report.column("null_count")?.u32()?),
+            &[Some(2), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(report.column("n_unique")?.u32()?),
+            &[None, Some(2)]
+        );
+        // at least the 6 values of 8 bytes
+        assert!(report.column("bytes")?.u64()?.get(0).unwrap() >= 48);
+
+        // 6 values of 8 bytes + 2 validity bitmaps of 1 byte, 2 * 4 offsets + 6 bytes
+        assert_eq!(df.estimated_size(), 48 + 2 + 2 * 4 * 8 + 6);
+        Ok(())
+    }
+}
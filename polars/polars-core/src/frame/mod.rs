@@ -2,31 +2,149 @@
 use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::frame::select::Selection;
 use crate::prelude::*;
-use crate::utils::{accumulate_dataframes_horizontal, accumulate_dataframes_vertical, NoNull};
+use crate::utils::{
+    accumulate_dataframes_horizontal, accumulate_dataframes_vertical, IntoVec, NoNull,
+};
 use ahash::RandomState;
 use arrow::record_batch::RecordBatch;
 use itertools::Itertools;
 use rayon::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::Iterator;
 use std::mem;
 use std::sync::Arc;
 
 mod arithmetic;
+pub mod asof_join;
+mod describe;
 pub mod explode;
 pub mod group_by;
 pub mod hash_join;
+mod horizontal;
+mod memory;
 pub mod resample;
 pub mod row;
 pub mod select;
+mod transpose;
 mod upstream_traits;
 
+/// Determines what happens when columns with the same name end up in a single `DataFrame`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Return a `PolarsError::Duplicate`.
+    Error,
+    /// Rename the duplicated column by appending a suffix: `"{name}_{n}"`, where `n` is the
+    /// smallest number that leads to a unique name.
+    Suffix,
+}
+
+impl Default for DuplicatePolicy {
+    fn default() -> Self {
+        DuplicatePolicy::Error
+    }
+}
+
+/// Determines which row of a set of duplicated rows is kept by [DataFrame::unique].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UniqueKeepStrategy {
+    /// Keep the first row.
+    First,
+    /// Keep the last row.
+    Last,
+    /// Keep one of the rows. The order of the rows is not maintained, which makes this the
+    /// fastest strategy.
+    Any,
+    /// Drop all rows that have a duplicate.
+    None,
+}
+
+/// Find a name that is not yet in `names`, by appending `_{n}` to `name`.
+fn dedup_name(name: &str, names: &HashSet<String, RandomState>) -> String {
+    let mut n = 1;
+    loop {
+        let new_name = format!("{}_{}", name, n);
+        if !names.contains(&new_name) {
+            return new_name;
+        }
+        n += 1;
+    }
+}
+
+/// A two dimensional table of [Series] of equal length.
+///
+/// Cloning a DataFrame is cheap: the columns are reference counted and only copied when a column
+/// of a clone is mutated in place (copy on write). DataFrames are `Send + Sync`, so they can be
+/// shared with or moved to other threads.
 #[derive(Clone)]
 pub struct DataFrame {
     pub(crate) columns: Vec<Series>,
 }
 
+/// Shift a Series and fill with `fill_value` if it can be cast to the dtype of the Series.
+fn shift_and_fill_series(s: &Series, periods: i64, fill_value: &AnyValue) -> Series {
+    macro_rules! primitive {
+        ($type:ty) => {{
+            match fill_value.extract::<<$type as ArrowPrimitiveType>::Native>() {
+                Some(v) => s
+                    .unpack::<$type>()
+                    .unwrap()
+                    .shift_and_fill(periods, Some(v))
+                    .into_series(),
+                None => s.shift(periods),
+            }
+        }};
+    }
+    macro_rules! utf8 {
+        () => {{
+            match fill_value {
+                AnyValue::Utf8(v) => s
+                    .utf8()
+                    .unwrap()
+                    .shift_and_fill(periods, Some(*v))
+                    .into_series(),
+                _ => s.shift(periods),
+            }
+        }};
+    }
+    macro_rules! bool {
+        () => {{
+            match fill_value {
+                AnyValue::Boolean(v) => s
+                    .bool()
+                    .unwrap()
+                    .shift_and_fill(periods, Some(*v))
+                    .into_series(),
+                _ => s.shift(periods),
+            }
+        }};
+    }
+    match s.dtype() {
+        DataType::List(_) | DataType::Categorical | DataType::Object => s.shift(periods),
+        dt => match_arrow_data_type_apply_macro!(dt, primitive, utf8, bool),
+    }
+}
+
+/// Check if `fill_none` with the given strategy is implemented for columns of this dtype.
+fn fill_none_supported(dtype: &DataType, strategy: FillNoneStrategy) -> bool {
+    use FillNoneStrategy::*;
+    match dtype {
+        DataType::List(_) | DataType::Categorical | DataType::Object => false,
+        DataType::Utf8 => matches!(strategy, Forward | Backward),
+        DataType::Boolean => !matches!(strategy, Mean),
+        _ => true,
+    }
+}
+
 impl DataFrame {
+    /// The error of a missing column, it names the columns of the DataFrame.
+    fn column_not_found(&self, name: &str) -> PolarsError {
+        PolarsError::NotFound(format!(
+            "column '{}', the DataFrame has the columns {:?}",
+            name,
+            self.get_column_names()
+        ))
+    }
+
     /// Get the index of the column.
     fn name_to_idx(&self, name: &str) -> Result<usize> {
         let mut idx = 0;
@@ -37,7 +155,7 @@ impl DataFrame {
             idx += 1;
         }
         if idx == self.columns.len() {
-            Err(PolarsError::NotFound(name.into()))
+            Err(self.column_not_found(name))
         } else {
             Ok(idx)
         }
@@ -72,13 +190,32 @@ impl DataFrame {
     /// let df = DataFrame::new(vec![s0, s1]).unwrap();
     /// ```
     pub fn new<S: IntoSeries>(columns: Vec<S>) -> Result<Self> {
+        DataFrame::new_with_policy(columns, DuplicatePolicy::Error)
+    }
+
+    /// Create a DataFrame from a Vector of Series and resolve duplicate column names according
+    /// to the given `DuplicatePolicy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s0 = Series::new("a", [0, 1, 2].as_ref());
+    /// let s1 = Series::new("a", [22.1, 19.9, 7.].as_ref());
+    /// let df = DataFrame::new_with_policy(vec![s0, s1], DuplicatePolicy::Suffix).unwrap();
+    /// assert_eq!(df.get_column_names(), &["a", "a_1"]);
+    /// ```
+    pub fn new_with_policy<S: IntoSeries>(
+        columns: Vec<S>,
+        policy: DuplicatePolicy,
+    ) -> Result<Self> {
         let mut first_len = None;
         let mut series_cols = Vec::with_capacity(columns.len());
         let mut names = HashSet::with_capacity_and_hasher(columns.len(), RandomState::default());
 
         // check for series length equality and convert into series in one pass
         for s in columns {
-            let series = s.into_series();
+            let mut series = s.into_series();
             match first_len {
                 Some(len) => {
                     if series.len() != len {
@@ -87,12 +224,21 @@ impl DataFrame {
                 }
                 None => first_len = Some(series.len()),
             }
-            let name = series.name().to_string();
+            let mut name = series.name().to_string();
 
             if names.contains(&name) {
-                return Err(PolarsError::Duplicate(
-                    format!("Column with name: '{}' has more than one occurences", name).into(),
-                ));
+                match policy {
+                    DuplicatePolicy::Error => {
+                        return Err(PolarsError::Duplicate(
+                            format!("Column with name: '{}' has more than one occurences", name)
+                                .into(),
+                        ))
+                    }
+                    DuplicatePolicy::Suffix => {
+                        name = dedup_name(&name, &names);
+                        series.rename(&name);
+                    }
+                }
             }
 
             names.insert(name);
@@ -139,6 +285,101 @@ impl DataFrame {
         Schema::new(fields)
     }
 
+    /// Check that the `DataFrame` has exactly the columns of `schema` with the same dtypes. The
+    /// order of the columns does not matter. The error lists every column that is missing, has
+    /// another dtype or is not in the schema.
+    pub fn validate_schema(&self, schema: &Schema) -> Result<()> {
+        let mut mismatches = vec![];
+        for field in schema.fields() {
+            match self.column(field.name()) {
+                Ok(s) if s.dtype() != field.data_type() => mismatches.push(format!(
+                    "column '{}' has dtype {:?}, expected {:?}",
+                    s.name(),
+                    s.dtype(),
+                    field.data_type()
+                )),
+                Ok(_) => {}
+                Err(_) => mismatches.push(format!("column '{}' is missing", field.name())),
+            }
+        }
+        for s in &self.columns {
+            if schema.column_with_name(s.name()).is_none() {
+                mismatches.push(format!("column '{}' is not in the schema", s.name()));
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(PolarsError::SchemaMisMatch(mismatches.join("; ").into()))
+        }
+    }
+
+    /// Cast the columns to the dtypes of `schema`. Every field of the schema must be a column of
+    /// the `DataFrame`, other columns are left as is. The error lists every column that is
+    /// missing or cannot be cast.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2], "b" => &["x", "y"]).unwrap();
+    /// let schema = Schema::new(vec![Field::new("a", DataType::Float64)]);
+    /// let df = df.cast(&schema).unwrap();
+    /// assert_eq!(df.dtypes(), &[DataType::Float64, DataType::Utf8]);
+    /// ```
+    pub fn cast(&self, schema: &Schema) -> Result<Self> {
+        self.cast_columns(
+            schema
+                .fields()
+                .iter()
+                .map(|field| (field.name().as_str(), field.data_type().clone())),
+        )
+    }
+
+    /// Cast the named columns to the given dtypes, other columns are left as is. The error lists
+    /// every column that is missing or cannot be cast.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2], "b" => &[3, 4]).unwrap();
+    /// let df = df.cast_columns(vec![("b", DataType::UInt8)]).unwrap();
+    /// assert_eq!(df.dtypes(), &[DataType::Int32, DataType::UInt8]);
+    /// ```
+    pub fn cast_columns<'a, I>(&self, dtypes: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (&'a str, DataType)>,
+    {
+        let mut columns = self.columns.clone();
+        let mut mismatches = vec![];
+        for (name, dtype) in dtypes {
+            match self.find_idx_by_name(name) {
+                Some(idx) => {
+                    if columns[idx].dtype() == &dtype {
+                        continue;
+                    }
+                    match columns[idx].cast_with_datatype(&dtype) {
+                        Ok(s) => columns[idx] = s,
+                        Err(e) => mismatches.push(format!(
+                            "column '{}' cannot be cast from {:?} to {:?}: {}",
+                            name,
+                            columns[idx].dtype(),
+                            dtype,
+                            e
+                        )),
+                    }
+                }
+                None => mismatches.push(format!("column '{}' is missing", name)),
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(DataFrame::new_no_checks(columns))
+        } else {
+            Err(PolarsError::SchemaMisMatch(mismatches.join("; ").into()))
+        }
+    }
+
     /// Get a reference to the DataFrame columns.
     #[inline]
     pub fn get_columns(&self) -> &Vec<Series> {
@@ -149,11 +390,17 @@ impl DataFrame {
         self.columns.iter().map(|s| s.name()).collect()
     }
 
-    /// Set the column names.
+    /// Set the column names. The names must be unique.
     pub fn set_column_names<S: AsRef<str>>(&mut self, names: &[S]) -> Result<()> {
         if names.len() != self.columns.len() {
             return Err(PolarsError::ShapeMisMatch("the provided slice with column names has not the same size as the DataFrame's width".into()));
         }
+        let mut unique = HashSet::with_capacity_and_hasher(names.len(), RandomState::default());
+        if let Some(name) = names.iter().find(|name| !unique.insert(name.as_ref())) {
+            return Err(PolarsError::Duplicate(
+                format!("column name '{}' occurs more than once", name.as_ref()).into(),
+            ));
+        }
         let columns = mem::take(&mut self.columns);
         self.columns = columns
             .into_iter()
@@ -261,8 +508,23 @@ impl DataFrame {
     /// }
     /// ```
     pub fn hstack_mut(&mut self, columns: &[Series]) -> Result<&mut Self> {
+        self.hstack_mut_with_policy(columns, DuplicatePolicy::Error)
+    }
+
+    /// Add multiple Series to a DataFrame and resolve duplicate column names according
+    /// to the given `DuplicatePolicy`.
+    /// The added Series are required to have the same length.
+    pub fn hstack_mut_with_policy(
+        &mut self,
+        columns: &[Series],
+        policy: DuplicatePolicy,
+    ) -> Result<&mut Self> {
         let mut names = self.hash_names();
-        let height = self.height();
+        // the columns of an empty DataFrame only need to have the same length
+        let height = match (self.width(), columns.first()) {
+            (0, Some(s)) => s.len(),
+            _ => self.height(),
+        };
         // first loop check validity. We don't do this in a single pass otherwise
         // this DataFrame is already modified when an error occurs.
         for col in columns {
@@ -272,7 +534,7 @@ impl DataFrame {
             }
 
             let name = col.name();
-            if names.contains(name) {
+            if policy == DuplicatePolicy::Error && names.contains(name) {
                 return Err(PolarsError::Duplicate(
                     format!(
                         "Cannot do hstack operation. Column with name: {} already exists",
@@ -283,38 +545,127 @@ impl DataFrame {
             }
             names.insert(name.to_string());
         }
-        Ok(self.hstack_mut_no_checks(columns))
+
+        match policy {
+            DuplicatePolicy::Error => Ok(self.hstack_mut_no_checks(columns)),
+            DuplicatePolicy::Suffix => {
+                let mut names = self.hash_names();
+                let columns = columns
+                    .iter()
+                    .map(|s| {
+                        let mut s = s.clone();
+                        if names.contains(s.name()) {
+                            let new_name = dedup_name(s.name(), &names);
+                            s.rename(&new_name);
+                        }
+                        names.insert(s.name().to_string());
+                        s
+                    })
+                    .collect::<Vec<_>>();
+                Ok(self.hstack_mut_no_checks(&columns))
+            }
+        }
     }
 
     /// Add multiple Series to a DataFrame
     /// The added Series are required to have the same length.
     pub fn hstack(&self, columns: &[Series]) -> Result<Self> {
+        self.hstack_with_policy(columns, DuplicatePolicy::Error)
+    }
+
+    /// Add multiple Series to a DataFrame and resolve duplicate column names according
+    /// to the given `DuplicatePolicy`.
+    /// The added Series are required to have the same length.
+    pub fn hstack_with_policy(&self, columns: &[Series], policy: DuplicatePolicy) -> Result<Self> {
         let mut new_cols = self.columns.clone();
         new_cols.extend_from_slice(columns);
-        DataFrame::new(new_cols)
+        DataFrame::new_with_policy(new_cols, policy)
+    }
+
+    /// Concatenate a DataFrame to this DataFrame and return as newly allocated DataFrame.
+    ///
+    /// The chunks of `other` are appended to the chunks of this DataFrame, so no data is copied
+    /// and the cost doesn't depend on the number of rows. The result has more chunks, which
+    /// slows down kernels that iterate over the data. If the result is used in many operations
+    /// use [vstack_and_rechunk](DataFrame::vstack_and_rechunk) or call
+    /// [agg_chunks](DataFrame::agg_chunks) after the last `vstack`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2]).unwrap();
+    /// let stacked = df.vstack(&df).unwrap();
+    /// assert_eq!(stacked.n_chunks().unwrap(), 2);
+    /// ```
+    pub fn vstack(&self, other: &DataFrame) -> Result<Self> {
+        let mut df = self.clone();
+        df.vstack_mut(other)?;
+        Ok(df)
     }
 
-    /// Concatenate a DataFrame to this DataFrame and return as newly allocated DataFrame
-    pub fn vstack(&self, columns: &DataFrame) -> Result<Self> {
+    /// Concatenate a DataFrame to this DataFrame and copy the result into contiguous memory.
+    ///
+    /// This copies all data of both DataFrames, but the result has a single chunk per column.
+    /// See [vstack](DataFrame::vstack) to append the chunks without copying.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2]).unwrap();
+    /// let stacked = df.vstack_and_rechunk(&df).unwrap();
+    /// assert_eq!(stacked.n_chunks().unwrap(), 1);
+    /// ```
+    pub fn vstack_and_rechunk(&self, other: &DataFrame) -> Result<Self> {
         let mut df = self.clone();
-        df.vstack_mut(columns)?;
+        df.vstack_mut(other)?;
+        df.as_single_chunk();
         Ok(df)
     }
 
-    /// Concatenate a DataFrame to this DataFrame
+    /// Concatenate a DataFrame to this DataFrame. The chunks of `df` are appended, see
+    /// [vstack](DataFrame::vstack).
+    ///
+    /// Both DataFrames must have the same column names and dtypes in the same order. Stacking
+    /// onto a DataFrame without columns results in a copy of `df`.
     pub fn vstack_mut(&mut self, df: &DataFrame) -> Result<&mut Self> {
+        if self.width() == 0 {
+            self.columns = df.columns.clone();
+            return Ok(self);
+        }
         if self.width() != df.width() {
             return Err(PolarsError::ShapeMisMatch(
                 format!("Could not vertically stack DataFrame. The DataFrames appended width {} differs from the parent DataFrames width {}", self.width(), df.width()).into()
             ));
         }
-
-        if self.dtypes() != df.dtypes() {
+        if let Some((left, right)) = self
+            .columns
+            .iter()
+            .zip(df.columns.iter())
+            .find(|(left, right)| left.name() != right.name())
+        {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot vstack: column '{}' is stacked onto column '{}'",
+                    right.name(),
+                    left.name()
+                )
+                .into(),
+            ));
+        }
+        if let Some((left, right)) = self
+            .columns
+            .iter()
+            .zip(df.columns.iter())
+            .find(|(left, right)| left.dtype() != right.dtype())
+        {
             return Err(PolarsError::DataTypeMisMatch(
                 format!(
-                    "cannot vstack: data types don't match of {:?} {:?}",
-                    self.head(Some(2)),
-                    df.head(Some(2))
+                    "cannot vstack: column '{}' has dtype {:?} and {:?}",
+                    left.name(),
+                    left.dtype(),
+                    right.dtype()
                 )
                 .into(),
             ));
@@ -341,9 +692,7 @@ impl DataFrame {
     /// ```
     pub fn drop_in_place(&mut self, name: &str) -> Result<Series> {
         let idx = self.name_to_idx(name)?;
-        let result = Ok(self.columns.remove(idx));
-        self.rechunk();
-        result
+        Ok(self.columns.remove(idx))
     }
 
     /// Return a new DataFrame where all null values are dropped
@@ -386,6 +735,16 @@ impl DataFrame {
     }
 
     fn insert_at_idx_no_name_check(&mut self, index: usize, series: Series) -> Result<&mut Self> {
+        if index > self.width() {
+            return Err(PolarsError::OutOfBounds(
+                format!(
+                    "cannot insert a column at index {} in a DataFrame of width {}",
+                    index,
+                    self.width()
+                )
+                .into(),
+            ));
+        }
         if series.len() == self.height() {
             self.columns.insert(index, series);
             self.rechunk();
@@ -402,7 +761,17 @@ impl DataFrame {
         }
     }
 
-    /// Insert a new column at a given index
+    /// Insert a new column at a given index. The index may be equal to the width of the
+    /// `DataFrame` to add the column at the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let mut df = df!("a" => &[1, 2], "c" => &[5, 6]).unwrap();
+    /// df.insert_at_idx(1, Series::new("b", &[3, 4])).unwrap();
+    /// assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    /// ```
     pub fn insert_at_idx<S: IntoSeries>(&mut self, index: usize, column: S) -> Result<&mut Self> {
         let series = column.into_series();
         self.has_column(series.name())?;
@@ -429,13 +798,158 @@ impl DataFrame {
         }
     }
 
-    /// Create a new `DataFrame` with the column added.
+    /// Create a new `DataFrame` with the column added, or replaced if the `DataFrame` already has
+    /// a column with the same name. The Series must have the height of the `DataFrame`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2], "b" => &[3, 4]).unwrap();
+    /// let doubled = df.column("a").unwrap() * 2;
+    /// let df = df.with_column(doubled).unwrap();
+    /// assert_eq!(df.get_column_names(), &["a", "b"]);
+    /// ```
     pub fn with_column<S: IntoSeries>(&self, column: S) -> Result<Self> {
         let mut df = self.clone();
-        df.add_column(column)?;
+        let series = column.into_series();
+        match self.find_idx_by_name(series.name()) {
+            Some(idx) => df.replace_at_idx(idx, series)?,
+            None => df.add_column(series)?,
+        };
+        Ok(df)
+    }
+
+    /// Create a new `DataFrame` with the columns added, or replaced where the `DataFrame` already
+    /// has a column with the same name. The lengths and names are checked once for all columns,
+    /// so this is cheaper than calling [with_column](DataFrame::with_column) repeatedly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2]).unwrap();
+    /// let a = df.column("a").unwrap();
+    /// let df = df
+    ///     .with_columns(vec![(a * 2).rename("a").clone(), (a + 1).rename("b").clone()])
+    ///     .unwrap();
+    /// assert_eq!(df.get_column_names(), &["a", "b"]);
+    /// ```
+    pub fn with_columns(&self, columns: Vec<Series>) -> Result<Self> {
+        let mut names = HashSet::with_capacity_and_hasher(columns.len(), RandomState::default());
+        if let Some(s) = columns.iter().find(|s| !names.insert(s.name())) {
+            return Err(PolarsError::Duplicate(
+                format!("column with name: '{}' is added more than once", s.name()).into(),
+            ));
+        }
+        let height = match self.columns.is_empty() {
+            true => columns.first().map_or(0, |s| s.len()),
+            false => self.height(),
+        };
+        if let Some(s) = columns.iter().find(|s| s.len() != height) {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "Could not add column '{}'. The Series length {} differs from the DataFrame height: {}",
+                    s.name(),
+                    s.len(),
+                    height
+                )
+                .into(),
+            ));
+        }
+
+        let positions = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| (s.name(), idx))
+            .collect::<HashMap<_, _, RandomState>>();
+        let mut df = self.clone();
+        for s in columns {
+            match positions.get(s.name()) {
+                Some(idx) => df.columns[*idx] = s,
+                None => df.columns.push(s),
+            }
+        }
+        df.rechunk();
+        Ok(df)
+    }
+
+    /// Create a new `DataFrame` with a UInt32 column `name` as first column that counts the rows,
+    /// starting at `offset` (default 0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &["x", "y"]).unwrap();
+    /// let df = df.with_row_index("index", Some(10)).unwrap();
+    /// assert_eq!(df.get_column_names(), &["index", "a"]);
+    /// assert_eq!(Vec::from(df.column("index").unwrap().u32().unwrap()), &[Some(10), Some(11)]);
+    /// ```
+    pub fn with_row_index(&self, name: &str, offset: Option<u32>) -> Result<Self> {
+        let offset = offset.unwrap_or(0);
+        let height = self.height();
+        let end = height as u64 + offset as u64;
+        if end > u32::MAX as u64 + 1 {
+            return Err(PolarsError::OutOfBounds(
+                format!(
+                    "a row index starting at {} does not fit in UInt32 for {} rows",
+                    offset, height
+                )
+                .into(),
+            ));
+        }
+        let index = UInt32Chunked::new_from_iter(name, (0..height).map(|i| offset + i as u32));
+        let mut df = self.clone();
+        df.insert_at_idx(0, index)?;
         Ok(df)
     }
 
+    /// Hash every row with a hasher seeded by `seed`. The hash of a row combines the hashes of
+    /// its values, see [Series::hash].
+    ///
+    /// The hashes are deterministic: rows with equal values in columns of equal dtypes get equal
+    /// hashes, also if they are in different `DataFrame`s with different chunks. This makes them
+    /// suitable for deduplication, consistent sampling and distributing rows over workers.
+    /// Categorical columns are hashed by their codes, so use the global string cache to compare
+    /// hashes of categorical columns of different `DataFrame`s.
+    ///
+    /// Returns an error for columns of dtype list or object.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2, 1],
+    ///     "b" => &["x", "y", "x"]
+    /// }
+    /// .unwrap();
+    /// let hashes = df.hash_rows(0).unwrap();
+    /// assert_eq!(hashes.get(0), hashes.get(2));
+    /// ```
+    pub fn hash_rows(&self, seed: u64) -> Result<UInt64Chunked> {
+        if let Some(s) = self
+            .columns
+            .iter()
+            .find(|s| matches!(s.dtype(), DataType::List(_) | DataType::Object))
+        {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot hash column '{}' of dtype {:?}", s.name(), s.dtype()).into(),
+            ));
+        }
+        if self.columns.is_empty() {
+            return Ok(UInt64Chunked::new_from_slice("hash", &[]));
+        }
+        let (mut hashes, _) = crate::vector_hasher::df_rows_to_hashes(
+            self,
+            Some(crate::vector_hasher::seeded_random_state(seed)),
+        );
+        hashes.rename("hash");
+        Ok(hashes)
+    }
+
     /// Get a row in the `DataFrame` Beware this is slow.
     ///
     /// # Example
@@ -485,7 +999,7 @@ impl DataFrame {
     pub fn column(&self, name: &str) -> Result<&Series> {
         let idx = self
             .find_idx_by_name(name)
-            .ok_or_else(|| PolarsError::NotFound(name.into()))?;
+            .ok_or_else(|| self.column_not_found(name))?;
         Ok(self.select_at_idx(idx).unwrap())
     }
 
@@ -514,12 +1028,19 @@ impl DataFrame {
         Ok(df)
     }
 
-    /// Select column(s) from this DataFrame and return them into a Vector.
+    /// Select column(s) from this DataFrame and return them into a Vector. The columns are
+    /// returned in the order of the selection, selecting a column twice is an error.
     pub fn select_series<'a, S, J>(&self, selection: S) -> Result<Vec<Series>>
     where
         S: Selection<'a, J>,
     {
         let cols = selection.to_selection_vec();
+        let mut names = HashSet::with_capacity_and_hasher(cols.len(), RandomState::default());
+        if let Some(name) = cols.iter().find(|name| !names.insert(**name)) {
+            return Err(PolarsError::Duplicate(
+                format!("column with name: '{}' is selected more than once", name).into(),
+            ));
+        }
         let selected = cols
             .iter()
             .map(|c| self.column(c).map(|s| s.clone()))
@@ -527,6 +1048,87 @@ impl DataFrame {
         Ok(selected)
     }
 
+    /// Create a new `DataFrame` with the `first` columns in the given order, followed by the
+    /// other columns in their current order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1],
+    ///     "b" => &[2],
+    ///     "c" => &[3]
+    /// }
+    /// .unwrap();
+    /// let df = df.reorder(&["c", "a"]).unwrap();
+    /// assert_eq!(df.get_column_names(), &["c", "a", "b"]);
+    /// ```
+    pub fn reorder(&self, first: &[&str]) -> Result<Self> {
+        let mut columns = self.select_series(first.to_vec())?;
+        columns.extend(
+            self.columns
+                .iter()
+                .filter(|s| !first.contains(&s.name()))
+                .cloned(),
+        );
+        Ok(DataFrame::new_no_checks(columns))
+    }
+
+    /// Select the columns of the given dtype, in the order of the DataFrame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1.0, 2.0],
+    ///     "b" => &["x", "y"],
+    ///     "c" => &[3.0, 4.0]
+    /// }
+    /// .unwrap();
+    /// let floats = df.select_by_dtype(&DataType::Float64);
+    /// assert_eq!(floats.get_column_names(), &["a", "c"]);
+    /// ```
+    pub fn select_by_dtype(&self, dtype: &DataType) -> DataFrame {
+        let selected = self
+            .columns
+            .iter()
+            .filter(|s| s.dtype() == dtype)
+            .cloned()
+            .collect();
+        DataFrame::new_no_checks(selected)
+    }
+
+    /// Select the columns of which the name matches the regular expression `pattern`. The columns
+    /// keep their order. To match complete names, anchor the pattern with `^` and `$`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "sensor_1" => &[1.0],
+    ///     "sensor_2" => &[2.0],
+    ///     "time" => &[0]
+    /// }
+    /// .unwrap();
+    /// let sensors = df.select_regex(r"^sensor_\d+$").unwrap();
+    /// assert_eq!(sensors.get_column_names(), &["sensor_1", "sensor_2"]);
+    /// ```
+    #[cfg(feature = "strings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
+    pub fn select_regex(&self, pattern: &str) -> Result<DataFrame> {
+        let re = regex::Regex::new(pattern)?;
+        let selected = self
+            .columns
+            .iter()
+            .filter(|s| re.is_match(s.name()))
+            .cloned()
+            .collect();
+        Ok(DataFrame::new_no_checks(selected))
+    }
+
     /// Select a mutable series by name.
     /// *Note: the length of the Series should remain the same otherwise the DataFrame is invalid.*
     /// For this reason the method is not public
@@ -539,8 +1141,29 @@ impl DataFrame {
         }
     }
 
-    /// Take DataFrame rows by a boolean mask.
+    /// Take DataFrame rows by a boolean mask. Rows for which the mask is null are dropped. A
+    /// mask of length 1 keeps all or no rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3], "b" => &["x", "y", "z"]).unwrap();
+    /// let mask = df.column("a").unwrap().gt(1);
+    /// let filtered = df.filter(&mask).unwrap();
+    /// assert_eq!(filtered.shape(), (2, 2));
+    /// ```
     pub fn filter(&self, mask: &BooleanChunked) -> Result<Self> {
+        if mask.len() != 1 && mask.len() != self.height() && self.width() > 0 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "cannot filter a DataFrame of height {} with a mask of length {}",
+                    self.height(),
+                    mask.len()
+                )
+                .into(),
+            ));
+        }
         let new_col = self
             .columns
             .par_iter()
@@ -777,28 +1400,132 @@ impl DataFrame {
     /// }
     /// ```
     pub fn rename(&mut self, column: &str, name: &str) -> Result<&mut Self> {
-        self.select_mut(column)
-            .ok_or_else(|| PolarsError::NotFound(name.to_string()))
-            .map(|s| s.rename(name))?;
-        Ok(self)
-    }
+        if column != name {
+            self.has_column(name)?;
+        }
+        let idx = self
+            .find_idx_by_name(column)
+            .ok_or_else(|| self.column_not_found(column))?;
+        self.columns[idx].rename(name);
+        Ok(self)
+    }
 
-    /// Sort DataFrame in place by a column.
-    pub fn sort_in_place(&mut self, by_column: &str, reverse: bool) -> Result<&mut Self> {
-        let s = self.column(by_column)?;
+    /// The indexes that sort this DataFrame by the columns `by`. Rows with equal values in the
+    /// first column are ordered by the second column, etc. `reverse` is given once for all
+    /// columns or once per column.
+    fn argsort_by(&self, by: &[&str], reverse: &[bool]) -> Result<Vec<usize>> {
+        if by.is_empty() {
+            return Err(PolarsError::NoData("no columns to sort by".into()));
+        }
+        if reverse.len() != 1 && reverse.len() != by.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "sorting by {} columns, but the sort order is given for {} columns",
+                    by.len(),
+                    reverse.len()
+                )
+                .into(),
+            ));
+        }
+        let reverse = |i: usize| reverse[std::cmp::min(i, reverse.len() - 1)];
+
+        // the argsort is stable, so sorting by the columns from last to first sorts
+        // lexicographically
+        let last = by.len() - 1;
+        let mut idx = self.column(by[last])?.argsort(reverse(last));
+        for i in (0..last).rev() {
+            let perm = self.column(by[i])?.take(&idx).argsort(reverse(i));
+            idx = perm.into_iter().map(|i| idx[i]).collect();
+        }
+        Ok(idx)
+    }
 
-        let take = s.argsort(reverse);
+    /// Sort DataFrame in place by one or multiple columns. See [sort](DataFrame::sort).
+    pub fn sort_in_place<'a, S, J>(
+        &mut self,
+        by_column: S,
+        reverse: impl IntoVec<bool>,
+    ) -> Result<&mut Self>
+    where
+        S: Selection<'a, J>,
+    {
+        let by_column = by_column.to_selection_vec();
+        let reverse = reverse.into_vec();
+        let take = self.argsort_by(&by_column, &reverse)?;
 
         self.columns = self.columns.par_iter().map(|s| s.take(&take)).collect();
+        self.set_column_sorted(by_column[0], reverse[0]);
         Ok(self)
     }
 
-    /// Return a sorted clone of this DataFrame.
-    pub fn sort(&self, by_column: &str, reverse: bool) -> Result<Self> {
+    /// Return a sorted clone of this DataFrame. Rows with equal values in the first column are
+    /// sorted by the second column, etc. `reverse` sorts in descending order and is given once
+    /// for all columns or once per column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 1], "b" => &[1, 2, 3]).unwrap();
+    /// let sorted = df.sort(&["a", "b"], &[false, true]).unwrap();
+    /// assert_eq!(
+    ///     Vec::from(sorted.column("b").unwrap().i32().unwrap()),
+    ///     &[Some(3), Some(1), Some(2)]
+    /// );
+    /// let sorted = df.sort("a", true).unwrap();
+    /// ```
+    pub fn sort<'a, S, J>(&self, by_column: S, reverse: impl IntoVec<bool>) -> Result<Self>
+    where
+        S: Selection<'a, J>,
+    {
+        let by_column = by_column.to_selection_vec();
+        let reverse = reverse.into_vec();
+        let take = self.argsort_by(&by_column, &reverse)?;
+        let mut df = self.take(&take);
+        df.set_column_sorted(by_column[0], reverse[0]);
+        Ok(df)
+    }
+
+    /// Return a clone of this DataFrame sorted by a column with the given options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// fn sort_nulls_last(df: &DataFrame) -> Result<DataFrame> {
+    ///     let options = SortOptions {
+    ///         descending: false,
+    ///         nulls_last: true,
+    ///         nans_last: true,
+    ///     };
+    ///     df.sort_with_options("a", options)
+    /// }
+    /// ```
+    pub fn sort_with_options(&self, by_column: &str, options: SortOptions) -> Result<Self> {
         let s = self.column(by_column)?;
 
-        let take = s.argsort(reverse);
-        Ok(self.take(&take))
+        let take = s.argsort_with_options(options);
+        let mut df = self.take(&take);
+        // the sorted flag assumes NaN is the maximum
+        if !options.moves_nans(s.dtype()) {
+            df.set_column_sorted(by_column, options.descending);
+        }
+        Ok(df)
+    }
+
+    /// Flag the column this DataFrame was just sorted by as sorted.
+    fn set_column_sorted(&mut self, column: &str, descending: bool) {
+        if let Some(idx) = self.find_idx_by_name(column) {
+            let s = &mut self.columns[idx];
+            // lists and objects can't be sorted, their argsort doesn't change the order
+            if !matches!(s.dtype(), DataType::List(_) | DataType::Object) {
+                s.set_sorted(if descending {
+                    IsSorted::Descending
+                } else {
+                    IsSorted::Ascending
+                });
+            }
+        }
     }
 
     /// Replace a column with a series.
@@ -809,9 +1536,9 @@ impl DataFrame {
     /// Replace or update a column.
     pub fn replace_or_add<S: IntoSeries>(&mut self, column: &str, new_col: S) -> Result<&mut Self> {
         let new_col = new_col.into_series();
-        match self.replace(column, new_col.clone()) {
-            Err(_) => self.add_column(new_col),
-            Ok(_) => Ok(self),
+        match self.find_idx_by_name(column) {
+            Some(_) => self.replace(column, new_col),
+            None => self.add_column(new_col),
         }
     }
 
@@ -897,7 +1624,7 @@ impl DataFrame {
     {
         let idx = self
             .find_idx_by_name(column)
-            .ok_or_else(|| PolarsError::NotFound(column.to_string()))?;
+            .ok_or_else(|| self.column_not_found(column))?;
         self.apply_at_idx(idx, f)
     }
 
@@ -1022,7 +1749,7 @@ impl DataFrame {
         S: IntoSeries,
     {
         let width = self.width();
-        let col = self.columns.get_mut(idx).ok_or_else(|| {
+        let col = self.columns.get(idx).ok_or_else(|| {
             PolarsError::OutOfBounds(
                 format!(
                     "Column index: {} outside of DataFrame with {} columns",
@@ -1031,17 +1758,19 @@ impl DataFrame {
                 .into(),
             )
         })?;
-        let name = col.name().to_string();
-
-        let _ = mem::replace(col, f(col).map(|s| s.into_series())?);
+        let new_col = f(col)?.into_series();
+        // the same length checks and renaming as the infallible version
+        self.apply_at_idx(idx, |_| new_col)
+    }
 
-        // make sure the name remains the same after applying the closure
-        unsafe {
-            let col = self.columns.get_unchecked_mut(idx);
-            col.rename(&name);
-        }
-        self.rechunk();
-        Ok(self)
+    /// Apply a closure that may fail to a column at index `idx`. This is the same as
+    /// [may_apply_at_idx](DataFrame::may_apply_at_idx).
+    pub fn try_apply_at_idx<F, S>(&mut self, idx: usize, f: F) -> Result<&mut Self>
+    where
+        F: FnOnce(&Series) -> Result<S>,
+        S: IntoSeries,
+    {
+        self.may_apply_at_idx(idx, f)
     }
 
     /// Apply a closure that may fail to a column. This is the recommended way to do in place
@@ -1092,11 +1821,45 @@ impl DataFrame {
     {
         let idx = self
             .find_idx_by_name(column)
-            .ok_or_else(|| PolarsError::NotFound(column.to_string()))?;
+            .ok_or_else(|| self.column_not_found(column))?;
         self.may_apply_at_idx(idx, f)
     }
 
-    /// Slice the DataFrame along the rows.
+    /// Apply a closure that may fail to a column and replace the column with the result. The
+    /// column keeps its name and the result must have the height of the `DataFrame` or a length
+    /// of 1, in which case the value is broadcast. An error of the closure is returned as is and
+    /// leaves the `DataFrame` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let mut df = df! {
+    ///     "values" => &[1, 2, 3]
+    /// }
+    /// .unwrap();
+    /// df.try_apply("values", |s| s.cast::<Float64Type>()).unwrap();
+    /// assert_eq!(df.column("values").unwrap().dtype(), &DataType::Float64);
+    /// ```
+    pub fn try_apply<F, S>(&mut self, column: &str, f: F) -> Result<&mut Self>
+    where
+        F: FnOnce(&Series) -> Result<S>,
+        S: IntoSeries,
+    {
+        self.may_apply(column, f)
+    }
+
+    /// Slice the DataFrame along the rows. This doesn't copy data, the columns are views on the
+    /// arrays of this DataFrame. Returns an error if the slice is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3, 4]).unwrap();
+    /// assert_eq!(df.slice(1, 2).unwrap().height(), 2);
+    /// assert!(df.slice(3, 2).is_err());
+    /// ```
     pub fn slice(&self, offset: usize, length: usize) -> Result<Self> {
         let col = self
             .columns
@@ -1106,7 +1869,17 @@ impl DataFrame {
         Ok(DataFrame::new_no_checks(col))
     }
 
-    /// Get the head of the DataFrame
+    /// Get the first `length` rows of the DataFrame, or the first 10 rows if `length` is `None`.
+    /// Like [slice](DataFrame::slice) this doesn't copy data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3, 4]).unwrap();
+    /// assert_eq!(df.head(Some(2)).height(), 2);
+    /// assert_eq!(df.head(Some(10)).height(), 4);
+    /// ```
     pub fn head(&self, length: Option<usize>) -> Self {
         let col = self
             .columns
@@ -1116,7 +1889,8 @@ impl DataFrame {
         DataFrame::new_no_checks(col)
     }
 
-    /// Get the tail of the DataFrame
+    /// Get the last `length` rows of the DataFrame, or the last 10 rows if `length` is `None`.
+    /// Like [slice](DataFrame::slice) this doesn't copy data.
     pub fn tail(&self, length: Option<usize>) -> Self {
         let col = self
             .columns
@@ -1126,8 +1900,29 @@ impl DataFrame {
         DataFrame::new_no_checks(col)
     }
 
+    /// Check that all columns can be converted to arrow. Object columns can't be converted,
+    /// so a DataFrame that holds them cannot be written by the IO writers.
+    pub fn check_arrow_compatible(&self) -> Result<()> {
+        #[cfg(feature = "object")]
+        if let Some(s) = self
+            .columns
+            .iter()
+            .find(|s| matches!(s.dtype(), DataType::Object))
+        {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "column {} of dtype object cannot be converted to arrow",
+                    s.name()
+                )
+                .into(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Transform the underlying chunks in the DataFrame to Arrow RecordBatches
     pub fn as_record_batches(&self) -> Result<Vec<RecordBatch>> {
+        self.check_arrow_compatible()?;
         let n_chunks = self.n_chunks()?;
         let width = self.width();
 
@@ -1183,6 +1978,28 @@ impl DataFrame {
         DataFrame::new_no_checks(col)
     }
 
+    /// Shift the values by a given period and fill the parts that will be empty due to this
+    /// operation with `fill_value`. The fill value is cast to the dtype of every column; columns
+    /// in which it doesn't fit, e.g. a Utf8 value in a numeric column, are filled with `Nones`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[1, 2, 3], "b" => &["x", "y", "z"]).unwrap();
+    /// let shifted = df.shift_and_fill(1, &AnyValue::Int32(0));
+    /// assert_eq!(Vec::from(shifted.column("a").unwrap().i32().unwrap()), &[Some(0), Some(1), Some(2)]);
+    /// assert_eq!(shifted.column("b").unwrap().null_count(), 1);
+    /// ```
+    pub fn shift_and_fill(&self, periods: i64, fill_value: &AnyValue) -> Self {
+        let col = self
+            .columns
+            .iter()
+            .map(|s| shift_and_fill_series(s, periods, fill_value))
+            .collect();
+        DataFrame::new_no_checks(col)
+    }
+
     /// Replace None values with one of the following strategies:
     /// * Forward fill (replace None with the previous value)
     /// * Backward fill (replace None with the next value)
@@ -1190,12 +2007,21 @@ impl DataFrame {
     /// * Min fill (replace None with the minimum of the whole array)
     /// * Max fill (replace None with the maximum of the whole array)
     ///
+    /// Columns without null values and columns of which the dtype doesn't support the strategy,
+    /// e.g. `Mean` on a Utf8 column, are returned unchanged.
+    ///
     /// See the method on [Series](../series/enum.Series.html#method.fill_none) for more info on the `fill_none` operation.
     pub fn fill_none(&self, strategy: FillNoneStrategy) -> Result<Self> {
         let col = self
             .columns
             .par_iter()
-            .map(|s| s.fill_none(strategy))
+            .map(|s| {
+                if s.null_count() == 0 || !fill_none_supported(s.dtype(), strategy) {
+                    Ok(s.clone())
+                } else {
+                    s.fill_none(strategy)
+                }
+            })
             .collect::<Result<Vec<_>>>()?;
         Ok(DataFrame::new_no_checks(col))
     }
@@ -1395,21 +2221,127 @@ impl DataFrame {
         Ok(df)
     }
 
+    /// Drop duplicate rows, comparing only the `subset` of columns. If `subset` is `None`, all
+    /// columns are used. `keep` determines which one of the duplicated rows remains. Except for
+    /// [UniqueKeepStrategy::Any] the rows stay in their original order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "id" => &[1, 2, 1, 3],
+    ///     "value" => &["a", "b", "c", "d"]
+    /// }
+    /// .unwrap();
+    /// let subset = ["id".to_string()];
+    /// let out = df.unique(Some(&subset), UniqueKeepStrategy::Last).unwrap();
+    /// assert_eq!(
+    ///     Vec::from(out.column("value").unwrap().utf8().unwrap()),
+    ///     &[Some("b"), Some("c"), Some("d")]
+    /// );
+    /// ```
+    pub fn unique(&self, subset: Option<&[String]>, keep: UniqueKeepStrategy) -> Result<Self> {
+        let names = match &subset {
+            Some(s) => s.iter().map(|s| &**s).collect(),
+            None => self.get_column_names(),
+        };
+        let gb = self.groupby(names)?;
+        let groups = gb.get_groups();
+
+        let mut idx = match keep {
+            UniqueKeepStrategy::First | UniqueKeepStrategy::Any => {
+                groups.iter().map(|(first, _)| *first).collect::<Vec<_>>()
+            }
+            UniqueKeepStrategy::Last => groups
+                .iter()
+                .map(|(first, g)| g.iter().copied().max().unwrap_or(*first))
+                .collect(),
+            UniqueKeepStrategy::None => groups
+                .iter()
+                .filter(|(_, g)| g.len() == 1)
+                .map(|(first, _)| *first)
+                .collect(),
+        };
+        if keep != UniqueKeepStrategy::Any {
+            idx.sort_unstable();
+        }
+        let cap = Some(idx.len());
+        Ok(unsafe { self.take_iter_unchecked(idx.into_iter(), cap) })
+    }
+
     /// Get a mask of all the unique rows in the DataFrame.
     pub fn is_unique(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
-        let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height(), true, false))
+        self.is_unique_subset(None)
     }
 
     /// Get a mask of all the duplicated rows in the DataFrame.
     pub fn is_duplicated(&self) -> Result<BooleanChunked> {
-        let mut gb = self.groupby(self.get_column_names())?;
+        self.is_duplicated_subset(None)
+    }
+
+    fn unique_mask(
+        &self,
+        subset: Option<&[String]>,
+        unique_val: bool,
+        duplicated_val: bool,
+    ) -> Result<BooleanChunked> {
+        let names = match &subset {
+            Some(s) => s.iter().map(|s| &**s).collect(),
+            None => self.get_column_names(),
+        };
+        let mut gb = self.groupby(names)?;
         let groups = std::mem::take(&mut gb.groups);
-        Ok(is_unique_helper(groups, self.height(), false, true))
+        Ok(is_unique_helper(
+            groups,
+            self.height(),
+            unique_val,
+            duplicated_val,
+        ))
+    }
+
+    /// Get a mask of the rows that are unique in the `subset` of columns.
+    /// If `subset` is `None`, all columns are used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "id" => &[1, 1, 2],
+    ///     "value" => &["a", "b", "c"]
+    /// }
+    /// .unwrap();
+    /// let mask = df.is_unique_subset(Some(&["id".to_string()])).unwrap();
+    /// assert_eq!(Vec::from(&mask), &[Some(false), Some(false), Some(true)]);
+    /// ```
+    pub fn is_unique_subset(&self, subset: Option<&[String]>) -> Result<BooleanChunked> {
+        self.unique_mask(subset, true, false)
+    }
+
+    /// Get a mask of the rows that are duplicated in the `subset` of columns.
+    /// If `subset` is `None`, all columns are used.
+    pub fn is_duplicated_subset(&self, subset: Option<&[String]>) -> Result<BooleanChunked> {
+        self.unique_mask(subset, false, true)
     }
 
     /// Create a new DataFrame that shows the null counts per column.
+    ///
+    /// The result has a single row and the same column names as `self`. The counts are UInt32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[Some(1), None, None],
+    ///     "b" => &[Some("x"), Some("y"), None]
+    /// }
+    /// .unwrap();
+    /// let counts = df.null_count();
+    /// assert_eq!(counts.shape(), (1, 2));
+    /// assert_eq!(counts.column("a").unwrap().u32().unwrap().get(0), Some(2));
+    /// ```
     pub fn null_count(&self) -> Self {
         let cols = self
             .columns
@@ -1418,6 +2350,20 @@ impl DataFrame {
             .collect();
         Self::new_no_checks(cols)
     }
+
+    /// Check if any column contains null values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df!("a" => &[Some(1), None]).unwrap();
+    /// assert!(df.has_nulls());
+    /// assert!(!df.slice(0, 1).unwrap().has_nulls());
+    /// ```
+    pub fn has_nulls(&self) -> bool {
+        self.columns.iter().any(|s| s.null_count() > 0)
+    }
 }
 
 pub struct RecordBatchIter<'a> {
@@ -1564,6 +2510,34 @@ mod test {
         return vec![batch0, batch1];
     }
 
+    #[test]
+    fn test_clone_copy_on_write() {
+        fn is_send_sync<T: Send + Sync>() {}
+        is_send_sync::<Series>();
+        is_send_sync::<DataFrame>();
+
+        let df = create_frame();
+        let mut cloned = df.clone();
+        // the clone shares the column data until it is mutated
+        assert!(Arc::ptr_eq(
+            &df.get_columns()[0].0,
+            &cloned.get_columns()[0].0
+        ));
+        cloned.rename("days", "day").unwrap();
+        assert!(!Arc::ptr_eq(
+            &df.get_columns()[0].0,
+            &cloned.get_columns()[0].0
+        ));
+        assert_eq!(df.get_column_names(), &["days", "temp"]);
+        assert!(Arc::ptr_eq(
+            &df.get_columns()[1].0,
+            &cloned.get_columns()[1].0
+        ));
+
+        let height = std::thread::spawn(move || cloned.height()).join().unwrap();
+        assert_eq!(height, df.height());
+    }
+
     #[test]
     fn test_recordbatch_iterator() {
         let mut df = df!(
@@ -1593,6 +2567,79 @@ mod test {
     fn test_select() {
         let df = create_frame();
         assert_eq!(df.column("days").unwrap().eq(1).sum(), Some(1));
+
+        let selected = df.select(&["temp", "days"]).unwrap();
+        assert_eq!(selected.get_column_names(), &["temp", "days"]);
+        assert!(selected
+            .column("temp")
+            .unwrap()
+            .series_equal(df.column("temp").unwrap()));
+        assert!(df.select(&["days", "days"]).is_err());
+        assert!(df.select(&["days", "foo"]).is_err());
+
+        let floats = df.select_by_dtype(&DataType::Float64);
+        assert_eq!(floats.get_column_names(), &["temp"]);
+        assert_eq!(floats.height(), df.height());
+        assert_eq!(df.select_by_dtype(&DataType::Utf8).width(), 0);
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut df = create_frame();
+        let dropped = df.drop("days").unwrap();
+        assert_eq!(dropped.get_column_names(), &["temp"]);
+        assert_eq!(df.width(), 2);
+        assert!(df.drop("foo").is_err());
+
+        let s = df.drop_in_place("days").unwrap();
+        assert_eq!(s.name(), "days");
+        assert_eq!(s.len(), 3);
+        assert_eq!(df.get_column_names(), &["temp"]);
+        assert!(df.drop_in_place("days").is_err());
+        assert_eq!(df.width(), 1);
+    }
+
+    #[test]
+    fn test_rename() {
+        let mut df = create_frame();
+        df.rename("days", "day").unwrap();
+        assert_eq!(df.get_column_names(), &["day", "temp"]);
+        df.rename("day", "day").unwrap();
+        assert!(matches!(
+            df.rename("day", "temp"),
+            Err(PolarsError::Duplicate(_))
+        ));
+        assert!(matches!(
+            df.rename("days", "foo"),
+            Err(PolarsError::NotFound(_))
+        ));
+
+        df.set_column_names(&["a", "b"]).unwrap();
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert!(df.set_column_names(&["a", "a"]).is_err());
+        assert!(df.set_column_names(&["a"]).is_err());
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+    }
+
+    #[test]
+    fn test_head_tail_slice() {
+        let mut df = create_frame();
+        df.vstack_mut(&create_frame()).unwrap();
+        let days = |df: &DataFrame| Vec::from(df.column("days").unwrap().i32().unwrap());
+
+        assert_eq!(days(&df.head(Some(2))), &[Some(0), Some(1)]);
+        assert_eq!(
+            days(&df.tail(Some(4))),
+            &[Some(2), Some(0), Some(1), Some(2)]
+        );
+        assert_eq!(df.head(None).height(), 6);
+        assert_eq!(df.tail(Some(0)).shape(), (0, 2));
+
+        // slices across the chunk boundary
+        let sliced = df.slice(2, 2).unwrap();
+        assert_eq!(days(&sliced), &[Some(2), Some(0)]);
+        assert_eq!(sliced.n_chunks().unwrap(), 2);
+        assert!(df.slice(5, 2).is_err());
     }
 
     #[test]
@@ -1600,7 +2647,22 @@ mod test {
         let df = create_frame();
         println!("{}", df.column("days").unwrap());
         println!("{:?}", df);
-        println!("{:?}", df.filter(&df.column("days").unwrap().eq(0)))
+        println!("{:?}", df.filter(&df.column("days").unwrap().eq(0)));
+
+        let mask = BooleanChunked::new_from_opt_slice("mask", &[Some(true), None, Some(true)]);
+        let filtered = df.filter(&mask).unwrap();
+        assert_eq!(filtered.shape(), (2, 2));
+        assert_eq!(
+            Vec::from(filtered.column("days").unwrap().i32().unwrap()),
+            &[Some(0), Some(2)]
+        );
+        let mask = BooleanChunked::new_from_slice("mask", &[false]);
+        assert_eq!(df.filter(&mask).unwrap().shape(), (0, 2));
+        let mask = BooleanChunked::new_from_slice("mask", &[true, false]);
+        assert!(matches!(
+            df.filter(&mask),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
     }
 
     #[test]
@@ -1610,6 +2672,43 @@ mod test {
         println!("{:?}", df);
     }
 
+    #[test]
+    fn test_sort_multiple() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(2), Some(1), None, Some(1), Some(2)],
+            "b" => &["x", "y", "z", "x", "y"],
+            "c" => &[0, 1, 2, 3, 4]
+        }?;
+        let sorted = df.sort(&["a", "b"], &[false, true])?;
+        assert_eq!(
+            Vec::from(sorted.column("c")?.i32()?),
+            &[Some(2), Some(1), Some(3), Some(4), Some(0)]
+        );
+        let sorted = df.sort(&["a", "b"], true)?;
+        assert_eq!(
+            Vec::from(sorted.column("c")?.i32()?),
+            &[Some(4), Some(0), Some(1), Some(3), Some(2)]
+        );
+        // equal keys keep their order
+        let sorted = df.sort("b", false)?;
+        assert_eq!(
+            Vec::from(sorted.column("c")?.i32()?),
+            &[Some(0), Some(3), Some(1), Some(4), Some(2)]
+        );
+
+        let mut in_place = df.clone();
+        in_place.sort_in_place(vec!["b", "a"], vec![false, false])?;
+        assert_eq!(
+            Vec::from(in_place.column("c")?.i32()?),
+            &[Some(3), Some(0), Some(1), Some(4), Some(2)]
+        );
+        assert_eq!(in_place.column("b")?.is_sorted(), IsSorted::Ascending);
+
+        assert!(df.sort(&["a", "b"], &[false, true, true]).is_err());
+        assert!(df.sort(&["a", "foo"], false).is_err());
+        Ok(())
+    }
+
     #[test]
     fn slice() {
         let df = create_frame();
@@ -1654,6 +2753,56 @@ mod test {
         assert!(df.add_column(Series::new("bar", &[1, 2, 3])).is_ok());
     }
 
+    #[test]
+    fn test_with_column() {
+        let df = create_frame();
+        let doubled = df.column("days").unwrap() * 2;
+        let out = df.with_column(doubled).unwrap();
+        assert_eq!(out.get_column_names(), &["days", "temp"]);
+        assert_eq!(
+            Vec::from(out.column("days").unwrap().i32().unwrap()),
+            &[Some(0), Some(2), Some(4)]
+        );
+        // the original is unchanged
+        assert_eq!(df.column("days").unwrap().i32().unwrap().get(1), Some(1));
+
+        let out = df.with_column(Series::new("foo", &[1, 2, 3])).unwrap();
+        assert_eq!(out.get_column_names(), &["days", "temp", "foo"]);
+        assert!(df.with_column(Series::new("days", &[1, 2])).is_err());
+        assert!(df.with_column(Series::new("foo", &[1, 2])).is_err());
+
+        let mut df = df;
+        df.replace("temp", Series::new("foo", &[1.0, 2.0, 3.0]))
+            .unwrap();
+        assert_eq!(df.get_column_names(), &["days", "temp"]);
+        assert!(df.replace("foo", Series::new("foo", &[1, 2, 3])).is_err());
+        assert!(df.replace("temp", Series::new("temp", &[1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_policy() {
+        let s0 = Series::new("foo", &[1, 2]);
+        let s1 = Series::new("foo", &[3, 4]);
+        let s2 = Series::new("foo_1", &[5, 6]);
+        assert!(DataFrame::new(vec![s0.clone(), s1.clone()]).is_err());
+
+        let df = DataFrame::new_with_policy(
+            vec![s0.clone(), s2.clone(), s1.clone()],
+            DuplicatePolicy::Suffix,
+        )
+        .unwrap();
+        assert_eq!(df.get_column_names(), &["foo", "foo_1", "foo_2"]);
+
+        let mut df = DataFrame::new(vec![s0]).unwrap();
+        assert!(df.hstack(&[s1.clone()]).is_err());
+        assert!(df.hstack_mut(&[s1.clone()]).is_err());
+        // nothing is modified on error
+        assert_eq!(df.width(), 1);
+        df.hstack_mut_with_policy(&[s1, s2], DuplicatePolicy::Suffix)
+            .unwrap();
+        assert_eq!(df.get_column_names(), &["foo", "foo_1", "foo_1_1"]);
+    }
+
     #[test]
     fn drop_duplicates() {
         let df = df! {
@@ -1678,6 +2827,278 @@ mod test {
         assert!(df.frame_equal(&valid));
     }
 
+    #[test]
+    fn test_is_unique_and_duplicated() -> Result<()> {
+        let df = df! {
+            "a" => [1, 1, 2, 2],
+            "b" => ["x", "y", "z", "z"]
+        }?;
+        assert_eq!(
+            Vec::from(&df.is_unique()?),
+            &[Some(true), Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&df.is_duplicated()?),
+            &[Some(false), Some(false), Some(true), Some(true)]
+        );
+        let subset = ["a".to_string()];
+        assert_eq!(df.is_duplicated_subset(Some(&subset))?.sum(), Some(4));
+        let subset = ["b".to_string()];
+        assert_eq!(
+            Vec::from(&df.is_unique_subset(Some(&subset))?),
+            &[Some(true), Some(true), Some(false), Some(false)]
+        );
+        assert!(df.is_unique_subset(Some(&["c".to_string()])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_apply() -> Result<()> {
+        let mut df = df! {
+            "a" => [1, 2, 3],
+            "b" => ["x", "y", "z"]
+        }?;
+        df.try_apply("a", |s| Ok(s * 2))?;
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(2), Some(4), Some(6)]
+        );
+        // the result must fit in the DataFrame and keeps the name of the column
+        assert!(df.try_apply("a", |s| Ok(s.head(Some(2)))).is_err());
+        df.try_apply_at_idx(1, |s| Ok(s.head(Some(1)).rename("c").clone()))?;
+        assert_eq!(df.get_column_names(), &["a", "b"]);
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            &[Some("x"), Some("x"), Some("x")]
+        );
+        assert!(df.try_apply("b", |s| s.i32().map(|ca| ca.clone())).is_err());
+        assert!(df.try_apply("c", |s| Ok(s.clone())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_select_regex() -> Result<()> {
+        let df = df! {
+            "sensor_1" => [1, 2],
+            "time" => [0, 1],
+            "sensor_10" => [3, 4],
+            "sensor_x" => [5, 6]
+        }?;
+        let out = df.select_regex(r"^sensor_\d+$")?;
+        assert_eq!(out.get_column_names(), &["sensor_1", "sensor_10"]);
+        assert_eq!(df.select_regex("^foo$")?.width(), 0);
+        assert!(df.select_regex("(").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_and_validate_schema() -> Result<()> {
+        use crate::datatypes::{DataType, Field, Schema};
+        let df = df! {
+            "a" => [1, 2],
+            "b" => ["x", "y"]
+        }?;
+        let schema = Schema::new(vec![
+            Field::new("b", DataType::Utf8),
+            Field::new("a", DataType::Float64),
+        ]);
+        assert!(matches!(
+            df.validate_schema(&schema),
+            Err(PolarsError::SchemaMisMatch(_))
+        ));
+        let out = df.cast(&schema)?;
+        assert_eq!(out.dtypes(), &[DataType::Float64, DataType::Utf8]);
+        out.validate_schema(&schema)?;
+
+        // every mismatch is reported
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("c", DataType::Int64),
+            Field::new("d", DataType::Int64),
+        ]);
+        match df.validate_schema(&schema) {
+            Err(PolarsError::SchemaMisMatch(msg)) => {
+                for name in &["'a'", "'b'", "'c'", "'d'"] {
+                    assert!(msg.contains(name));
+                }
+            }
+            _ => panic!("expected a schema mismatch"),
+        }
+        match df.cast(&schema) {
+            Err(PolarsError::SchemaMisMatch(msg)) => {
+                assert!(msg.contains("'c'") && msg.contains("'d'"));
+            }
+            _ => panic!("expected a schema mismatch"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_shift_and_fill() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 3],
+            "b" => [0.5, 1.5, 2.5],
+            "c" => ["x", "y", "z"]
+        }?;
+        let out = df.shift_and_fill(-1, &AnyValue::Int32(9));
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(3), Some(9)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.f64()?),
+            &[Some(1.5), Some(2.5), Some(9.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.utf8()?),
+            &[Some("y"), Some("z"), None]
+        );
+        let out = df.shift_and_fill(2, &AnyValue::Utf8("-"));
+        assert_eq!(
+            Vec::from(out.column("c")?.utf8()?),
+            &[Some("-"), Some("-"), Some("x")]
+        );
+        assert_eq!(out.column("a")?.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_rows() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), Some(2), Some(1)],
+            "b" => ["x", "y", "x"]
+        }?;
+        let hashes = df.hash_rows(0)?;
+        assert_eq!(hashes.name(), "hash");
+        assert_eq!(hashes.len(), 3);
+        assert_eq!(hashes.get(0), hashes.get(2));
+        assert_ne!(hashes.get(0), hashes.get(1));
+        assert_ne!(hashes.get(0), df.hash_rows(1)?.get(0));
+
+        // the hash of a row doesn't depend on the other rows or on the chunks
+        let mut other = df! {
+            "a" => [None, Some(1)],
+            "b" => ["y", "x"]
+        }?;
+        other.vstack_mut(&df)?;
+        let other_hashes = other.hash_rows(0)?;
+        assert_eq!(other_hashes.get(1), hashes.get(0));
+        assert_eq!(other_hashes.get(4), hashes.get(2));
+        assert_ne!(other_hashes.get(0), hashes.get(1));
+
+        let list = Series::new("a", &[Series::new("", &[1]), Series::new("", &[2])]);
+        let df = DataFrame::new(vec![list])?;
+        assert!(df.hash_rows(0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_at_idx_and_reorder() -> Result<()> {
+        let mut df = df! {
+            "a" => [1, 2],
+            "b" => [3, 4]
+        }?;
+        df.insert_at_idx(2, Series::new("c", &[5, 6]))?;
+        df.insert_at_idx(0, Series::new("d", &[7, 8]))?;
+        assert_eq!(df.get_column_names(), &["d", "a", "b", "c"]);
+        assert!(df.insert_at_idx(5, Series::new("e", &[0, 0])).is_err());
+        assert!(df.insert_at_idx(0, Series::new("e", &[0])).is_err());
+
+        let out = df.reorder(&["c", "a"])?;
+        assert_eq!(out.get_column_names(), &["c", "a", "d", "b"]);
+        assert!(df.reorder(&["c", "c"]).is_err());
+        assert!(df.reorder(&["e"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_columns() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2],
+            "b" => [3, 4]
+        }?;
+        let out = df.with_columns(vec![
+            Series::new("c", &[5, 6]),
+            Series::new("a", &[7, 8]),
+            Series::new("d", &[9, 10]),
+        ])?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c", "d"]);
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(7), Some(8)]);
+        assert!(df
+            .with_columns(vec![Series::new("c", &[1]), Series::new("d", &[1, 2])])
+            .is_err());
+        assert!(df
+            .with_columns(vec![Series::new("c", &[1, 2]), Series::new("c", &[1, 2])])
+            .is_err());
+
+        let out = DataFrame::new_no_checks(vec![]).with_columns(vec![Series::new("a", &[1])])?;
+        assert_eq!(out.shape(), (1, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_row_index() -> Result<()> {
+        let df = df! {
+            "a" => ["x", "y", "z"]
+        }?;
+        let out = df.with_row_index("index", None)?;
+        assert_eq!(out.get_column_names(), &["index", "a"]);
+        assert_eq!(
+            Vec::from(out.column("index")?.u32()?),
+            &[Some(0), Some(1), Some(2)]
+        );
+        let out = df.with_row_index("index", Some(u32::MAX - 2))?;
+        assert_eq!(out.column("index")?.u32()?.get(2), Some(u32::MAX));
+        assert!(df.with_row_index("index", Some(u32::MAX - 1)).is_err());
+        assert!(df.with_row_index("a", None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_keep() -> Result<()> {
+        let df = df! {
+            "a" => [2, 1, 2, 3, 1],
+            "b" => [1, 2, 3, 4, 5]
+        }?;
+        let subset = ["a".to_string()];
+        let kept = |keep| -> Result<Vec<Option<i32>>> {
+            let out = df.unique(Some(&subset), keep)?;
+            Ok(Vec::from(out.column("b")?.i32()?))
+        };
+        assert_eq!(
+            kept(UniqueKeepStrategy::First)?,
+            &[Some(1), Some(2), Some(4)]
+        );
+        assert_eq!(
+            kept(UniqueKeepStrategy::Last)?,
+            &[Some(3), Some(4), Some(5)]
+        );
+        assert_eq!(kept(UniqueKeepStrategy::None)?, &[Some(4)]);
+        assert_eq!(df.unique(None, UniqueKeepStrategy::Any)?.height(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_count() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => [Some("x"), Some("y"), Some("z")]
+        }?;
+        let counts = df.null_count();
+        assert_eq!(counts.get_column_names(), &["a", "b"]);
+        assert_eq!(Vec::from(counts.column("a")?.u32()?), &[Some(1)]);
+        assert_eq!(Vec::from(counts.column("b")?.u32()?), &[Some(0)]);
+        assert_eq!(
+            df.slice(1, 2)?.null_count().column("a")?.u32()?.get(0),
+            Some(1)
+        );
+        assert!(df.has_nulls());
+        assert!(!df.select("b")?.has_nulls());
+        assert!(!DataFrame::new_no_checks(vec![]).has_nulls());
+        Ok(())
+    }
+
     #[test]
     fn test_vstack() {
         // check that it does not accidentally rechunks
@@ -1691,4 +3112,102 @@ mod test {
         df.vstack_mut(&df.slice(0, 3).unwrap());
         assert_eq!(df.n_chunks().unwrap(), 2)
     }
+
+    #[test]
+    fn test_vstack_and_rechunk() -> Result<()> {
+        let df = df! {
+            "flt" => [1., 2., 3.],
+            "str" => ["a", "b", "c"]
+        }?;
+        let appended = df.vstack(&df)?;
+        let copied = df.vstack_and_rechunk(&df)?;
+        assert_eq!(appended.n_chunks()?, 2);
+        assert_eq!(copied.n_chunks()?, 1);
+        assert!(appended.frame_equal(&copied));
+        assert!(df.vstack_and_rechunk(&df.select("flt")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stack_validation() -> Result<()> {
+        let df = df! {
+            "flt" => [1., 2., 3.],
+            "str" => ["a", "b", "c"]
+        }?;
+        let renamed = df! {
+            "str" => ["a", "b", "c"],
+            "flt" => [1., 2., 3.]
+        }?;
+        assert!(matches!(
+            df.vstack(&renamed),
+            Err(PolarsError::ShapeMisMatch(_))
+        ));
+        let casted = df! {
+            "flt" => [1, 2, 3],
+            "str" => ["a", "b", "c"]
+        }?;
+        assert!(matches!(
+            df.vstack(&casted),
+            Err(PolarsError::DataTypeMisMatch(_))
+        ));
+
+        // incremental assembly from an empty DataFrame
+        let mut acc = DataFrame::new_no_checks(vec![]);
+        acc.vstack_mut(&df)?;
+        acc.vstack_mut(&df)?;
+        assert_eq!(acc.shape(), (6, 2));
+        assert_eq!(acc.n_chunks()?, 2);
+
+        let mut acc = DataFrame::new_no_checks(vec![]);
+        acc.hstack_mut(df.get_columns())?;
+        assert!(acc.frame_equal(&df));
+        assert!(acc.hstack_mut(&[Series::new("int", &[1, 2])]).is_err());
+        assert!(acc.hstack_mut(&[Series::new("flt", &[1, 2, 3])]).is_err());
+        assert!(acc.hstack(&[Series::new("int", &[1, 2])]).is_err());
+        assert_eq!(acc.hstack(&[Series::new("int", &[1, 2, 3])])?.width(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_none() -> Result<()> {
+        let df = df! {
+            "int" => [Some(1), None, Some(3)],
+            "str" => [Some("a"), None, Some("c")],
+            "full" => [1.0, 2.0, 3.0]
+        }?;
+        // Mean is not defined for the Utf8 column, which is left as is
+        let out = df.fill_none(FillNoneStrategy::Mean)?;
+        assert_eq!(
+            Vec::from(out.column("int")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(out.column("str")?.null_count(), 1);
+        assert!(out.column("full")?.series_equal(df.column("full")?));
+
+        let out = df.fill_none(FillNoneStrategy::Forward)?;
+        assert_eq!(
+            Vec::from(out.column("str")?.utf8()?),
+            &[Some("a"), Some("a"), Some("c")]
+        );
+        assert_eq!(
+            Vec::from(out.column("int")?.i32()?),
+            &[Some(1), Some(1), Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_df_macro() -> Result<()> {
+        let values = Series::new("values", &[1.5, 2.5]);
+        let df = df! {
+            "a" => &[Some(1), None],
+            "b" => [Some("x"), None],
+            "c" => values,
+        }?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(Vec::from(df["a"].i32()?), &[Some(1), None]);
+        assert_eq!(Vec::from(df["b"].utf8()?), &[Some("x"), None]);
+        assert_eq!(Vec::from(df["c"].f64()?), &[Some(1.5), Some(2.5)]);
+        Ok(())
+    }
 }
@@ -8,6 +8,21 @@ pub enum SampleRule {
     Hour(u32),
 }
 
+impl SampleRule {
+    /// The length of an interval in milliseconds.
+    fn duration_ms(&self) -> i64 {
+        use SampleRule::*;
+        match self {
+            Second(n) => 1000 * *n as i64,
+            Minute(n) => 60_000 * *n as i64,
+            Hour(n) => 3_600_000 * *n as i64,
+            Day(n) => MS_PER_DAY * *n as i64,
+        }
+    }
+}
+
+const MS_PER_DAY: i64 = 1000 * 3600 * 24;
+
 impl DataFrame {
     /// Downsample a temporal column by some frequency/ rule
     ///
@@ -111,6 +126,267 @@ impl DataFrame {
     }
 }
 
+/// The non-null timestamps of a Date32 or Date64 column in milliseconds, with their row index.
+/// The timestamps must be sorted in ascending order.
+fn sorted_timestamps(s: &Series) -> Result<Vec<(usize, i64)>> {
+    match s.dtype() {
+        DataType::Date32 | DataType::Date64 => {}
+        dt => {
+            return Err(PolarsError::DataTypeMisMatch(
+                format!("cannot group a column of dtype {:?} by time windows", dt).into(),
+            ))
+        }
+    }
+    let ms = s.cast::<Date64Type>()?;
+    let timestamps = ms
+        .date64()?
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, t)| t.map(|t| (idx, t)))
+        .collect::<Vec<_>>();
+    if timestamps.windows(2).any(|w| w[0].1 > w[1].1) {
+        return Err(PolarsError::InvalidOperation(
+            "the time column must be sorted in ascending order".into(),
+        ));
+    }
+    Ok(timestamps)
+}
+
+/// Group the sorted timestamps in windows `[start, start + period)` that start every `every`
+/// milliseconds at `offset`. Returns the groups and the start of their windows, windows without
+/// rows are skipped.
+fn dynamic_windows(
+    timestamps: &[(usize, i64)],
+    every: i64,
+    period: i64,
+    offset: i64,
+) -> (Vec<(usize, Vec<usize>)>, Vec<i64>) {
+    // the first window start after `t - period`, so the first window that contains `t`
+    let first_window = |t: i64| {
+        let x = t - period + 1 - offset;
+        let n = x.div_euclid(every) + (x.rem_euclid(every) != 0) as i64;
+        offset + n * every
+    };
+
+    let mut groups = vec![];
+    let mut starts = vec![];
+    let (mut lo, mut hi) = (0, 0);
+    let mut start = match timestamps.first() {
+        Some((_, t)) => first_window(*t),
+        None => return (groups, starts),
+    };
+    loop {
+        while lo < timestamps.len() && timestamps[lo].1 < start {
+            lo += 1;
+        }
+        while hi < timestamps.len() && timestamps[hi].1 < start + period {
+            hi += 1;
+        }
+        if lo == timestamps.len() {
+            break;
+        }
+        if lo == hi {
+            // jump over the empty windows
+            start = first_window(timestamps[lo].1);
+            continue;
+        }
+        let idx = timestamps[lo..hi]
+            .iter()
+            .map(|(idx, _)| *idx)
+            .collect::<Vec<_>>();
+        groups.push((idx[0], idx));
+        starts.push(start);
+        start += every;
+    }
+    (groups, starts)
+}
+
+impl DataFrame {
+    /// Group a sorted temporal column by time windows. A window `[start, start + period)`
+    /// starts every `every`, aligned to `offset` (default zero) from the epoch. With a `period`
+    /// longer than `every` the windows overlap and a row can be part of multiple groups,
+    /// windows without rows are skipped.
+    ///
+    /// The keys of the aggregated DataFrame are the window boundaries, in the Date64 columns
+    /// `_lower_boundary` and `_upper_boundary`. The time column itself is not aggregated and
+    /// rows with a null timestamp are not part of any window.
+    ///
+    /// # Example
+    ///
+    /// Open, high, low and close per 5 minutes:
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::frame::resample::SampleRule;
+    ///
+    /// fn ohlc(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.groupby_dynamic("time", SampleRule::Minute(5), SampleRule::Minute(5), None)?
+    ///         .agg(&[("price", &["first", "max", "min", "last"])])
+    /// }
+    /// ```
+    pub fn groupby_dynamic(
+        &self,
+        key: &str,
+        every: SampleRule,
+        period: SampleRule,
+        offset: Option<SampleRule>,
+    ) -> Result<GroupBy> {
+        let s = self.column(key)?;
+        let every = every.duration_ms();
+        let period = period.duration_ms();
+        if every <= 0 || period <= 0 {
+            return Err(PolarsError::InvalidOperation(
+                "time windows cannot have an interval or period of zero".into(),
+            ));
+        }
+        let offset = offset.map_or(0, |offset| offset.duration_ms());
+        let timestamps = sorted_timestamps(s)?;
+        let (groups, starts) = dynamic_windows(&timestamps, every, period, offset);
+
+        let ends = starts
+            .iter()
+            .map(|start| start + period)
+            .collect::<Vec<_>>();
+        let keys = vec![
+            Date64Chunked::new_from_slice("_lower_boundary", &starts).into_series(),
+            Date64Chunked::new_from_slice("_upper_boundary", &ends).into_series(),
+        ];
+        Ok(GroupBy::new_with_group_keys(
+            self,
+            vec![s.clone()],
+            groups,
+            keys,
+        ))
+    }
+
+    /// Group a sorted temporal column by trailing windows: every row with timestamp `t` gets a
+    /// group with the rows in the window `(t - period, t]`.
+    ///
+    /// The key of the aggregated DataFrame is the time column, one row per row with a non-null
+    /// timestamp. This is the upper boundary of the window.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::frame::resample::SampleRule;
+    ///
+    /// fn rolling_sum(df: &DataFrame) -> Result<DataFrame> {
+    ///     df.groupby_rolling("time", SampleRule::Hour(1))?
+    ///         .select("value")
+    ///         .sum()
+    /// }
+    /// ```
+    pub fn groupby_rolling(&self, key: &str, period: SampleRule) -> Result<GroupBy> {
+        let s = self.column(key)?;
+        let period = period.duration_ms();
+        if period <= 0 {
+            return Err(PolarsError::InvalidOperation(
+                "time windows cannot have a period of zero".into(),
+            ));
+        }
+        let timestamps = sorted_timestamps(s)?;
+
+        let (mut lo, mut hi) = (0, 0);
+        let groups = timestamps
+            .iter()
+            .map(|(_, t)| {
+                while timestamps[lo].1 <= t - period {
+                    lo += 1;
+                }
+                // rows with the same timestamp are in the same window
+                while hi < timestamps.len() && timestamps[hi].1 <= *t {
+                    hi += 1;
+                }
+                let idx = timestamps[lo..hi]
+                    .iter()
+                    .map(|(idx, _)| *idx)
+                    .collect::<Vec<_>>();
+                (idx[0], idx)
+            })
+            .collect::<Vec<_>>();
+        let keys = vec![s.take_iter(
+            &mut timestamps.iter().map(|(idx, _)| *idx),
+            Some(timestamps.len()),
+        )];
+        Ok(GroupBy::new_with_group_keys(
+            self,
+            vec![s.clone()],
+            groups,
+            keys,
+        ))
+    }
+}
+
+impl DataFrame {
+    /// Upsample a temporal column: insert a row with null values for every timestamp between the
+    /// first and last timestamp of `key` that is missing at the interval `every`.
+    ///
+    /// The output is sorted by `key`. Existing rows are kept, also if their timestamp doesn't fall
+    /// on the interval, rows with a null timestamp are dropped. Date32 columns can only be
+    /// upsampled by whole days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_core::frame::resample::SampleRule;
+    ///
+    /// let time = Date64Chunked::new_from_slice("time", &[0, 120_000]).into_series();
+    /// let df = DataFrame::new(vec![time, Series::new("value", &[1, 3])]).unwrap();
+    /// let out = df.upsample("time", SampleRule::Minute(1)).unwrap();
+    /// assert_eq!(
+    ///     Vec::from(out.column("value").unwrap().i32().unwrap()),
+    ///     &[Some(1), None, Some(3)]
+    /// );
+    /// ```
+    pub fn upsample(&self, key: &str, every: SampleRule) -> Result<DataFrame> {
+        let s = self.column(key)?;
+        let step = every.duration_ms();
+        if step <= 0 {
+            return Err(PolarsError::InvalidOperation(
+                "cannot upsample with an interval of zero".into(),
+            ));
+        }
+        let date32 = match s.dtype() {
+            DataType::Date64 => false,
+            DataType::Date32 if step % MS_PER_DAY == 0 => true,
+            DataType::Date32 => {
+                return Err(PolarsError::InvalidOperation(
+                    "a Date32 column can only be upsampled by whole days".into(),
+                ))
+            }
+            dt => {
+                return Err(PolarsError::DataTypeMisMatch(
+                    format!("cannot upsample a column of dtype {:?}", dt).into(),
+                ))
+            }
+        };
+        let ms = s.cast::<Date64Type>()?;
+        let ms = ms.date64()?;
+        let (min, max) = match (ms.min(), ms.max()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Ok(self.clone()),
+        };
+
+        let mut timestamps = (0..)
+            .map(|i| min + i * step)
+            .take_while(|t| *t <= max)
+            .collect::<Vec<_>>();
+        timestamps.extend(ms.into_iter().flatten());
+        timestamps.sort_unstable();
+        timestamps.dedup();
+        let mut grid = Date64Chunked::new_from_slice(key, &timestamps).into_series();
+        if date32 {
+            grid = grid.cast::<Date32Type>()?;
+        }
+
+        DataFrame::new_no_checks(vec![grid])
+            .left_join(self, key, key)?
+            .select(self.get_column_names())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -160,4 +436,134 @@ mod test {
             &[Some(0), Some(5), Some(10), Some(15)]
         );
     }
+
+    #[test]
+    fn test_upsample() -> Result<()> {
+        let minute = 60_000;
+        let time = Date64Chunked::new_from_opt_slice(
+            "time",
+            &[Some(3 * minute), Some(0), Some(2 * minute + 30_000), None],
+        )
+        .into_series();
+        let df = DataFrame::new(vec![Series::new("value", &[4, 1, 3, 5]), time])?;
+        let out = df.upsample("time", SampleRule::Minute(1))?;
+        assert_eq!(out.get_column_names(), &["value", "time"]);
+        assert_eq!(
+            Vec::from(out.column("time")?.date64()?),
+            &[
+                Some(0),
+                Some(minute),
+                Some(2 * minute),
+                Some(2 * minute + 30_000),
+                Some(3 * minute)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("value")?.i32()?),
+            &[Some(1), None, None, Some(3), Some(4)]
+        );
+
+        let days = Date32Chunked::new_from_slice("day", &[0, 3]).into_series();
+        let df = DataFrame::new(vec![days])?;
+        let out = df.upsample("day", SampleRule::Day(1))?;
+        assert_eq!(out.column("day")?.dtype(), &DataType::Date32);
+        assert_eq!(out.height(), 4);
+        assert!(df.upsample("day", SampleRule::Hour(1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_groupby_time_windows() -> Result<()> {
+        let minute = 60_000;
+        let time = Date64Chunked::new_from_opt_slice(
+            "time",
+            &[
+                Some(0),
+                Some(minute),
+                Some(2 * minute),
+                None,
+                Some(3 * minute),
+                Some(12 * minute),
+            ],
+        )
+        .into_series();
+        let df = DataFrame::new(vec![time, Series::new("price", &[1, 4, 2, 9, 3, 5])])?;
+
+        // tumbling windows
+        let out = df
+            .groupby_dynamic("time", SampleRule::Minute(2), SampleRule::Minute(2), None)?
+            .agg(&[("price", &["first", "max", "last"])])?;
+        assert_eq!(
+            out.get_column_names(),
+            &[
+                "_lower_boundary",
+                "_upper_boundary",
+                "price_first",
+                "price_max",
+                "price_last"
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("_lower_boundary")?.date64()?),
+            &[Some(0), Some(2 * minute), Some(12 * minute)]
+        );
+        assert_eq!(
+            Vec::from(out.column("_upper_boundary")?.date64()?),
+            &[Some(2 * minute), Some(4 * minute), Some(14 * minute)]
+        );
+        assert_eq!(
+            Vec::from(out.column("price_first")?.i32()?),
+            &[Some(1), Some(2), Some(5)]
+        );
+        assert_eq!(
+            Vec::from(out.column("price_max")?.i32()?),
+            &[Some(4), Some(3), Some(5)]
+        );
+
+        // overlapping windows, shifted by a minute
+        let out = df
+            .groupby_dynamic(
+                "time",
+                SampleRule::Minute(2),
+                SampleRule::Minute(4),
+                Some(SampleRule::Minute(1)),
+            )?
+            .select("price")
+            .count()?;
+        assert_eq!(
+            Vec::from(out.column("_lower_boundary")?.date64()?),
+            &[
+                Some(-3 * minute),
+                Some(-minute),
+                Some(minute),
+                Some(3 * minute),
+                Some(9 * minute),
+                Some(11 * minute)
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("price_count")?.u32()?),
+            &[Some(1), Some(3), Some(3), Some(1), Some(1), Some(1)]
+        );
+
+        let out = df
+            .groupby_rolling("time", SampleRule::Minute(2))?
+            .select("price")
+            .sum()?;
+        assert_eq!(out.get_column_names(), &["time", "price_sum"]);
+        assert_eq!(out.height(), 5);
+        assert_eq!(
+            Vec::from(out.column("price_sum")?.i32()?),
+            &[Some(1), Some(5), Some(6), Some(5), Some(5)]
+        );
+
+        let unsorted = df.sort("price", false)?;
+        assert!(unsorted
+            .groupby_rolling("time", SampleRule::Minute(2))
+            .is_err());
+        assert!(df
+            .groupby_dynamic("price", SampleRule::Minute(2), SampleRule::Minute(2), None)
+            .is_err());
+        Ok(())
+    }
 }
@@ -1,16 +1,225 @@
 use crate::prelude::*;
+use crate::utils::get_supertype;
 use itertools::Itertools;
+use std::borrow::Cow;
 
+/// The values of a single row of a `DataFrame`, one per column.
 #[derive(Debug, Clone, PartialEq)]
-pub struct Row<'a>(Vec<AnyValue<'a>>);
+pub struct Row<'a>(pub Vec<AnyValue<'a>>);
+
+/// The dtype of a column with this value. `None` for null values.
+fn any_value_dtype(av: &AnyValue) -> Result<Option<DataType>> {
+    use AnyValue::*;
+    let dtype = match av {
+        Null => return Ok(None),
+        Boolean(_) => DataType::Boolean,
+        Utf8(_) => DataType::Utf8,
+        UInt8(_) => DataType::UInt8,
+        UInt16(_) => DataType::UInt16,
+        UInt32(_) => DataType::UInt32,
+        UInt64(_) => DataType::UInt64,
+        Int8(_) => DataType::Int8,
+        Int16(_) => DataType::Int16,
+        Int32(_) => DataType::Int32,
+        Int64(_) => DataType::Int64,
+        Float32(_) => DataType::Float32,
+        Float64(_) => DataType::Float64,
+        Date32(_) => DataType::Date32,
+        Date64(_) => DataType::Date64,
+        Time64(_, tu) => DataType::Time64(tu.clone()),
+        Duration(_, tu) => DataType::Duration(tu.clone()),
+        av => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot create a DataFrame from rows with value {:?}", av).into(),
+            ))
+        }
+    };
+    Ok(Some(dtype))
+}
+
+/// Collect the values of a column into a Series of the given dtype.
+fn series_from_any_values(name: &str, values: &[&AnyValue], dtype: &DataType) -> Result<Series> {
+    let mismatch = |av: &AnyValue| {
+        PolarsError::DataTypeMisMatch(
+            format!(
+                "value {:?} in column {} does not fit dtype {:?}",
+                av, name, dtype
+            )
+            .into(),
+        )
+    };
+    macro_rules! primitive {
+        ($type:ty) => {{
+            values
+                .iter()
+                .map(|av| match av {
+                    AnyValue::Null => Ok(None),
+                    av => av
+                        .extract::<<$type as ArrowPrimitiveType>::Native>()
+                        .map(Some)
+                        .ok_or_else(|| mismatch(av)),
+                })
+                .collect::<Result<ChunkedArray<$type>>>()?
+                .into_series()
+        }};
+    }
+    macro_rules! utf8 {
+        () => {{
+            values
+                .iter()
+                .map(|av| match av {
+                    AnyValue::Null => None,
+                    AnyValue::Utf8(v) => Some(Cow::Borrowed(*v)),
+                    av => Some(Cow::Owned(format!("{}", av))),
+                })
+                .collect::<Utf8Chunked>()
+                .into_series()
+        }};
+    }
+    macro_rules! bool {
+        () => {{
+            values
+                .iter()
+                .map(|av| match av {
+                    AnyValue::Null => Ok(None),
+                    AnyValue::Boolean(v) => Ok(Some(*v)),
+                    av => Err(mismatch(av)),
+                })
+                .collect::<Result<BooleanChunked>>()?
+                .into_series()
+        }};
+    }
+    let mut s = match dtype {
+        DataType::Utf8
+        | DataType::Boolean
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Date32
+        | DataType::Date64
+        | DataType::Time64(TimeUnit::Nanosecond)
+        | DataType::Duration(TimeUnit::Nanosecond)
+        | DataType::Duration(TimeUnit::Millisecond) => {
+            match_arrow_data_type_apply_macro!(dtype, primitive, utf8, bool)
+        }
+        dt => {
+            return Err(PolarsError::InvalidOperation(
+                format!("cannot create a column of dtype {:?} from rows", dt).into(),
+            ))
+        }
+    };
+    s.rename(name);
+    Ok(s)
+}
 
 impl DataFrame {
+    /// Create a `DataFrame` from rows. The columns are named `column_0`, `column_1`, etc. and
+    /// get the supertype of the values in that column as dtype. A column without any non-null
+    /// values gets dtype Int32. Use [DataFrame::from_rows_and_schema] to set the names and
+    /// dtypes.
+    ///
+    /// All rows must have the same number of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let rows = vec![
+    ///     Row(vec![AnyValue::Utf8("a"), AnyValue::Int32(1)]),
+    ///     Row(vec![AnyValue::Utf8("b"), AnyValue::Float64(2.5)]),
+    /// ];
+    /// let df = DataFrame::from_rows(&rows).unwrap();
+    /// assert_eq!(df.dtypes(), &[DataType::Utf8, DataType::Float64]);
+    /// ```
+    pub fn from_rows(rows: &[Row]) -> Result<DataFrame> {
+        let width = rows.get(0).map_or(0, |row| row.0.len());
+        let mut fields = Vec::with_capacity(width);
+        for i in 0..width {
+            let mut dtype: Option<DataType> = None;
+            for row in rows {
+                if let Some(av) = row.0.get(i) {
+                    if let Some(dt) = any_value_dtype(av)? {
+                        dtype = Some(match dtype {
+                            Some(acc) => get_supertype(&acc, &dt)?,
+                            None => dt,
+                        });
+                    }
+                }
+            }
+            fields.push(Field::new(
+                &format!("column_{}", i),
+                dtype.unwrap_or(DataType::Int32),
+            ));
+        }
+        DataFrame::from_rows_and_schema(rows, &Schema::new(fields))
+    }
+
+    /// Create a `DataFrame` from rows with the column names and dtypes of `schema`. Numeric
+    /// values are cast to the dtype of their column, any value can be stored in a Utf8 column.
+    ///
+    /// Every row must have one value per field of the schema.
+    pub fn from_rows_and_schema(rows: &[Row], schema: &Schema) -> Result<DataFrame> {
+        let width = schema.fields().len();
+        if let Some(row) = rows.iter().find(|row| row.0.len() != width) {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "a row has {} values, but the schema has {} fields",
+                    row.0.len(),
+                    width
+                )
+                .into(),
+            ));
+        }
+        let columns = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let values = rows.iter().map(|row| &row.0[i]).collect::<Vec<_>>();
+                series_from_any_values(field.name(), &values, field.data_type())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
     /// Get a row from a DataFrame. Use of this is discouraged as it will likely be slow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds. Use [DataFrame::get] for a checked version.
     pub fn get_row(&self, idx: usize) -> Row {
         let values = self.columns.iter().map(|s| s.get(idx)).collect_vec();
         Row(values)
     }
 
+    /// Iterate over the rows of the DataFrame. Every row allocates a new `Row`, so this is a lot
+    /// slower than working on the columns and only meant for code that really needs the values
+    /// row by row, e.g. to write them to a row oriented format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "a" => &[1, 2],
+    ///     "b" => &["x", "y"]
+    /// }
+    /// .unwrap();
+    /// for row in df.iter_rows() {
+    ///     assert_eq!(row.0.len(), 2);
+    /// }
+    /// ```
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = Row> + '_ {
+        (0..self.height()).map(move |idx| self.get_row(idx))
+    }
+
     /// Amortize allocations by reusing a row.
     /// The caller is responsible for the making sure the row has at least capacity for the number
     /// of columns in the DataFrame
@@ -38,3 +247,55 @@ impl DataFrame {
             });
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_iter_rows() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(1), None],
+            "b" => &["x", "y"]
+        }?;
+        let rows = df.iter_rows().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], Row(vec![AnyValue::Int32(1), AnyValue::Utf8("x")]));
+        assert_eq!(rows[1], df.get_row(1));
+        assert_eq!(rows[1].0, df.get(1).unwrap());
+        assert_eq!(rows[1].0[0], AnyValue::Null);
+        assert!(df.get(2).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_rows() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(1), None, Some(3)],
+            "b" => &["x", "y", "z"]
+        }?;
+        let rows = df.iter_rows().collect::<Vec<_>>();
+        let out = DataFrame::from_rows_and_schema(&rows, &df.schema())?;
+        assert!(out.frame_equal_missing(&df));
+
+        let out = DataFrame::from_rows(&rows)?;
+        assert_eq!(out.get_column_names(), &["column_0", "column_1"]);
+        assert_eq!(out.dtypes(), df.dtypes());
+
+        // the values of a column are cast to their supertype
+        let rows = vec![
+            Row(vec![AnyValue::Int32(1), AnyValue::Null]),
+            Row(vec![AnyValue::Float64(0.5), AnyValue::Null]),
+        ];
+        let out = DataFrame::from_rows(&rows)?;
+        assert_eq!(
+            Vec::from(out.column("column_0")?.f64()?),
+            &[Some(1.0), Some(0.5)]
+        );
+        assert_eq!(out.column("column_1")?.null_count(), 2);
+
+        let rows = vec![Row(vec![AnyValue::Boolean(true)]), Row(vec![])];
+        assert!(DataFrame::from_rows(&rows).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::prelude::*;
+use crate::utils::get_supertype;
+
+impl DataFrame {
+    /// Transpose a `DataFrame`: row `i` becomes the column `column_i`.
+    ///
+    /// The columns are cast to their common supertype first, so every new column has the same
+    /// dtype. Categorical columns are transposed as Utf8. With `include_header` the original
+    /// column names are added as the first column, named `header_name` (default `"column"`).
+    ///
+    /// Every row ends up in its own column, so this is meant for small frames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let df = df! {
+    ///     "mean" => &[1.0, 2.0],
+    ///     "count" => &[10, 20]
+    /// }
+    /// .unwrap();
+    /// let transposed = df.transpose(true, Some("metric")).unwrap();
+    /// assert_eq!(transposed.get_column_names(), &["metric", "column_0", "column_1"]);
+    /// ```
+    pub fn transpose(&self, include_header: bool, header_name: Option<&str>) -> Result<DataFrame> {
+        let mut dtypes = self.columns.iter().map(|s| s.dtype());
+        let dtype = match dtypes.next() {
+            Some(first) => dtypes.try_fold(first.clone(), |acc, dt| get_supertype(&acc, dt))?,
+            None => return Ok(DataFrame::new_no_checks(vec![])),
+        };
+        let dtype = match dtype {
+            DataType::Categorical => DataType::Utf8,
+            DataType::List(_) | DataType::Object => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("cannot transpose columns of dtype {:?}", dtype).into(),
+                ))
+            }
+            dt => dt,
+        };
+        let cols = self
+            .columns
+            .iter()
+            .map(|s| s.cast_with_datatype(&dtype))
+            .collect::<Result<Vec<_>>>()?;
+        let height = self.height();
+
+        macro_rules! primitive {
+            ($type:ty) => {{
+                let cas = cols
+                    .iter()
+                    .map(|s| s.unpack::<$type>())
+                    .collect::<Result<Vec<_>>>()?;
+                (0..height)
+                    .map(|i| {
+                        cas.iter()
+                            .map(|ca| ca.get(i))
+                            .collect::<ChunkedArray<$type>>()
+                            .into_series()
+                    })
+                    .collect::<Vec<_>>()
+            }};
+        }
+        macro_rules! utf8 {
+            () => {{
+                let cas = cols.iter().map(|s| s.utf8()).collect::<Result<Vec<_>>>()?;
+                (0..height)
+                    .map(|i| {
+                        cas.iter()
+                            .map(|ca| ca.get(i))
+                            .collect::<Utf8Chunked>()
+                            .into_series()
+                    })
+                    .collect::<Vec<_>>()
+            }};
+        }
+        macro_rules! bool {
+            () => {{
+                let cas = cols.iter().map(|s| s.bool()).collect::<Result<Vec<_>>>()?;
+                (0..height)
+                    .map(|i| {
+                        cas.iter()
+                            .map(|ca| ca.get(i))
+                            .collect::<BooleanChunked>()
+                            .into_series()
+                    })
+                    .collect::<Vec<_>>()
+            }};
+        }
+        let transposed = match_arrow_data_type_apply_macro!(dtype, primitive, utf8, bool);
+
+        let mut columns = Vec::with_capacity(height + 1);
+        if include_header {
+            let names = self.get_column_names();
+            columns.push(
+                Utf8Chunked::new_from_slice(header_name.unwrap_or("column"), &names).into_series(),
+            );
+        }
+        columns.extend(transposed.into_iter().enumerate().map(|(i, mut s)| {
+            s.rename(&format!("column_{}", i));
+            s
+        }));
+        DataFrame::new(columns)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_transpose() -> Result<()> {
+        let df = df! {
+            "a" => &[Some(1), None],
+            "b" => &[Some(3.0), Some(4.0)]
+        }?;
+        let out = df.transpose(true, None)?;
+        assert_eq!(out.get_column_names(), &["column", "column_0", "column_1"]);
+        assert_eq!(
+            Vec::from(out.column("column")?.utf8()?),
+            &[Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("column_0")?.f64()?),
+            &[Some(1.0), Some(3.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("column_1")?.f64()?),
+            &[None, Some(4.0)]
+        );
+
+        let out = df.transpose(false, None)?;
+        assert_eq!(out.shape(), (2, 2));
+        // transposing twice restores the values
+        assert_eq!(
+            Vec::from(out.transpose(false, None)?.column("column_1")?.f64()?),
+            &[Some(3.0), Some(4.0)]
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 use crate::prelude::*;
+#[cfg(feature = "temporal")]
+use chrono::{Duration, NaiveDateTime};
 use num::{Float, NumCast};
 use std::ops::Div;
 
@@ -23,10 +25,300 @@ where
     Some(cov(a, b)? / (a.std()? * b.std()?))
 }
 
+/// Stack `DataFrame`s vertically. All `DataFrame`s must have the same column names and dtypes,
+/// in the same order. The columns of the result have a chunk per stacked `DataFrame`, unless
+/// `rechunk` is set.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::concat;
+///
+/// let df1 = df!("a" => &[1, 2]).unwrap();
+/// let df2 = df!("a" => &[3]).unwrap();
+/// let df = concat(&[df1, df2], true).unwrap();
+/// assert_eq!(df.shape(), (3, 1));
+/// assert_eq!(df.n_chunks().unwrap(), 1);
+/// ```
+pub fn concat(dfs: &[DataFrame], rechunk: bool) -> Result<DataFrame> {
+    let (first, rest) = dfs
+        .split_first()
+        .ok_or_else(|| PolarsError::NoData("cannot concat an empty list of DataFrames".into()))?;
+    // validate all schemas before copying any data
+    let schema = first.schema();
+    if let Some((i, df)) = rest
+        .iter()
+        .enumerate()
+        .find(|(_, df)| df.schema() != schema)
+    {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "cannot concat: the schema of DataFrame {} {:?} differs from the first {:?}",
+                i + 1,
+                df.schema(),
+                schema
+            )
+            .into(),
+        ));
+    }
+
+    let mut acc = first.clone();
+    for df in rest {
+        acc.vstack_mut(df)?;
+    }
+    if rechunk {
+        Ok(acc.agg_chunks())
+    } else {
+        Ok(acc)
+    }
+}
+
+/// Stack `DataFrame`s vertically that may have different columns. The result has the union of
+/// the columns, in order of first appearance. A `DataFrame` that misses a column gets null
+/// values in that column. Columns with the same name must have the same dtype.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::concat_df_diagonal;
+///
+/// let df1 = df!("a" => &[1, 2]).unwrap();
+/// let df2 = df!("b" => &["x"], "a" => &[3]).unwrap();
+/// let df = concat_df_diagonal(&[df1, df2]).unwrap();
+/// assert_eq!(df.get_column_names(), &["a", "b"]);
+/// assert_eq!(df.column("b").unwrap().null_count(), 2);
+/// ```
+pub fn concat_df_diagonal(dfs: &[DataFrame]) -> Result<DataFrame> {
+    let mut fields: Vec<(&str, &DataType)> = vec![];
+    for df in dfs {
+        for s in df.get_columns() {
+            if !fields.iter().any(|(name, _)| *name == s.name()) {
+                fields.push((s.name(), s.dtype()));
+            }
+        }
+    }
+
+    let dfs = dfs
+        .iter()
+        .map(|df| {
+            let columns = fields
+                .iter()
+                .map(|(name, dtype)| match df.column(name) {
+                    Ok(s) => s.clone(),
+                    Err(_) => Series::full_null(name, df.height(), dtype),
+                })
+                .collect();
+            DataFrame::new_no_checks(columns)
+        })
+        .collect::<Vec<_>>();
+    concat(&dfs, false)
+}
+
+/// Combine `DataFrame`s horizontally. The `DataFrame`s must have the same height and the column
+/// names must be unique over all `DataFrame`s.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::concat_df_horizontal;
+///
+/// let df1 = df!("a" => &[1, 2]).unwrap();
+/// let df2 = df!("b" => &["x", "y"]).unwrap();
+/// let df = concat_df_horizontal(&[df1, df2]).unwrap();
+/// assert_eq!(df.shape(), (2, 2));
+/// ```
+pub fn concat_df_horizontal(dfs: &[DataFrame]) -> Result<DataFrame> {
+    let (first, rest) = dfs
+        .split_first()
+        .ok_or_else(|| PolarsError::NoData("cannot concat an empty list of DataFrames".into()))?;
+    if let Some((i, df)) = rest
+        .iter()
+        .enumerate()
+        .find(|(_, df)| df.height() != first.height())
+    {
+        return Err(PolarsError::ShapeMisMatch(
+            format!(
+                "cannot concat horizontally: DataFrame {} has height {}, the first has height {}",
+                i + 1,
+                df.height(),
+                first.height()
+            )
+            .into(),
+        ));
+    }
+    let mut acc = first.clone();
+    for df in rest {
+        acc.hstack_mut(df.get_columns())?;
+    }
+    Ok(acc)
+}
+
+/// The bounds that are included in an interval, e.g. in a `date_range` or
+/// [Series::is_between].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClosedWindow {
+    /// `[start, stop]`
+    Both,
+    /// `[start, stop)`
+    Left,
+    /// `(start, stop]`
+    Right,
+    /// `(start, stop)`
+    None,
+}
+
+/// Create a Date64 Series with the datetimes from `start` to `stop`, spaced by `interval`.
+/// Which of the bounds are included is determined by `closed`. If `start` is after `stop` the
+/// Series is empty.
+///
+/// The `interval` is truncated to milliseconds and must be positive.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_core::functions::{date_range, ClosedWindow};
+/// use chrono::{Duration, NaiveDate};
+///
+/// let start = NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0);
+/// let stop = NaiveDate::from_ymd(2021, 1, 8).and_hms(0, 0, 0);
+/// let s = date_range("days", start, stop, Duration::days(1), ClosedWindow::Left).unwrap();
+/// assert_eq!(s.len(), 7);
+/// ```
+#[cfg(feature = "temporal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+pub fn date_range(
+    name: &str,
+    start: NaiveDateTime,
+    stop: NaiveDateTime,
+    interval: Duration,
+    closed: ClosedWindow,
+) -> Result<Series> {
+    let step = interval.num_milliseconds();
+    if step <= 0 {
+        return Err(PolarsError::ValueError(
+            format!(
+                "the interval of a date range must be positive, got {}",
+                interval
+            )
+            .into(),
+        ));
+    }
+    let start = naive_datetime_to_date64(&start);
+    let stop = naive_datetime_to_date64(&stop);
+
+    let mut values = AlignedVec::with_capacity_aligned(((stop - start).max(0) / step + 1) as usize);
+    let mut t = match closed {
+        ClosedWindow::Both | ClosedWindow::Left => start,
+        ClosedWindow::Right | ClosedWindow::None => start + step,
+    };
+    let include_stop = matches!(closed, ClosedWindow::Both | ClosedWindow::Right);
+    while t < stop || (include_stop && t == stop) {
+        values.push(t);
+        t += step;
+    }
+    Ok(Date64Chunked::new_from_aligned_vec(name, values).into_series())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "temporal")]
+    fn test_date_range() -> Result<()> {
+        let start = NaiveDateTime::from_timestamp(0, 0);
+        let stop = NaiveDateTime::from_timestamp(3, 0);
+        let interval = Duration::seconds(1);
+
+        let s = date_range("t", start, stop, interval, ClosedWindow::Both)?;
+        assert_eq!(s.dtype(), &DataType::Date64);
+        assert_eq!(s.name(), "t");
+        assert_eq!(s.date64()?.cont_slice()?, &[0, 1000, 2000, 3000]);
+        let s = date_range("t", start, stop, interval, ClosedWindow::Left)?;
+        assert_eq!(s.date64()?.cont_slice()?, &[0, 1000, 2000]);
+        let s = date_range("t", start, stop, interval, ClosedWindow::Right)?;
+        assert_eq!(s.date64()?.cont_slice()?, &[1000, 2000, 3000]);
+        let s = date_range("t", start, stop, interval, ClosedWindow::None)?;
+        assert_eq!(s.date64()?.cont_slice()?, &[1000, 2000]);
+
+        // the stop is not a multiple of the interval
+        let s = date_range("t", start, stop, Duration::seconds(2), ClosedWindow::Both)?;
+        assert_eq!(s.date64()?.cont_slice()?, &[0, 2000]);
+
+        assert_eq!(
+            date_range("t", stop, start, interval, ClosedWindow::Both)?.len(),
+            0
+        );
+        assert!(date_range("t", start, stop, Duration::zero(), ClosedWindow::Both).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat() -> Result<()> {
+        let df1 = df! {
+            "a" => [1, 2],
+            "b" => ["x", "y"]
+        }?;
+        let df2 = df! {
+            "a" => [3],
+            "b" => ["z"]
+        }?;
+        let df = concat(&[df1.clone(), df2.clone(), df1.clone()], false)?;
+        assert_eq!(df.shape(), (5, 2));
+        assert_eq!(df.n_chunks()?, 3);
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3), Some(1), Some(2)]
+        );
+        assert_eq!(concat(&[df1.clone(), df2], true)?.n_chunks()?, 1);
+
+        let other = df! {
+            "a" => [1.0],
+            "b" => ["x"]
+        }?;
+        assert!(concat(&[df1, other], true).is_err());
+        assert!(concat(&[], true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_diagonal_and_horizontal() -> Result<()> {
+        let df1 = df! {
+            "a" => [1, 2],
+            "b" => ["x", "y"]
+        }?;
+        let df2 = df! {
+            "c" => [0.5],
+            "a" => [3]
+        }?;
+        let df = concat_df_diagonal(&[df1.clone(), df2.clone()])?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(
+            Vec::from(df.column("a")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(df.column("b")?.utf8()?),
+            &[Some("x"), Some("y"), None]
+        );
+        assert_eq!(Vec::from(df.column("c")?.f64()?), &[None, None, Some(0.5)]);
+
+        let df3 = df! {
+            "c" => [1.5, 2.5]
+        }?;
+        let df = concat_df_horizontal(&[df1.clone(), df3])?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        // the heights differ
+        assert!(concat_df_horizontal(&[df1.clone(), df2]).is_err());
+        // duplicate names
+        assert!(concat_df_horizontal(&[df1.clone(), df1]).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_pearson_corr() {
         let a = Series::new("a", &[1.0f32, 2.0]);
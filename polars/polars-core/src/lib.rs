@@ -1,7 +1,10 @@
-#[cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+// the crate builds on stable Rust, only the documentation on docs.rs uses an unstable feature
+#![cfg_attr(not(docsrs), deny(unstable_features))]
 #[macro_use]
 pub mod utils;
 pub mod chunked_array;
+pub mod config;
 pub mod datatypes;
 #[cfg(feature = "docs")]
 pub mod doc;
@@ -10,6 +13,7 @@ mod fmt;
 pub mod frame;
 pub mod functions;
 pub mod prelude;
+pub mod progress;
 pub mod series;
 pub mod testing;
 pub(crate) mod vector_hasher;
@@ -23,7 +27,7 @@ use std::sync::{Mutex, MutexGuard};
 // this is re-exported in utils for polars child crates
 lazy_static! {
     pub static ref POOL: ThreadPool = ThreadPoolBuilder::new()
-        .num_threads(num_cpus::get())
+        .num_threads(config::max_threads())
         .build()
         .expect("could not spawn threads");
 }
@@ -50,7 +54,7 @@ impl Default for StringCache {
     }
 }
 
-thread_local! {pub(crate) static USE_STRING_CACHE: Cell<bool> = Cell::new(false)}
+thread_local! {pub(crate) static USE_STRING_CACHE: Cell<bool> = Cell::new(config::string_cache_default())}
 lazy_static! {
     static ref L_STRING_CACHE: StringCache = Default::default();
 }
@@ -64,6 +68,18 @@ pub fn toggle_string_cache(toggle: bool) {
     }
 }
 
+/// Run `func` with the global string cache enabled on this thread, so that the categoricals that
+/// are created in `func` share their codes and can be joined and appended without re-encoding.
+/// Afterwards the string cache is restored to its previous state, which clears the cache if it
+/// was disabled.
+pub fn with_string_cache<T, F: FnOnce() -> T>(func: F) -> T {
+    let previous = use_string_cache();
+    toggle_string_cache(true);
+    let out = func();
+    toggle_string_cache(previous);
+    out
+}
+
 pub(crate) fn use_string_cache() -> bool {
     USE_STRING_CACHE.with(|val| val.get())
 }
@@ -8,22 +8,28 @@ pub use crate::{
             PrimitiveChunkedBuilder, Utf8ChunkedBuilder,
         },
         comparison::{CompToSeries, NumComp},
-        iterator::{IntoNoNullIterator, PolarsIterator},
+        iterator::{IntoNoNullIterator, IntoOwnedIterator, PolarsIterator},
         ops::{
             chunkops::ChunkOps,
             take::{AsTakeIndex, IntoTakeRandom, NumTakeRandomChunked, NumTakeRandomCont},
             window::InitFold,
             *,
         },
-        ChunkedArray, Downcast, NoNull,
+        ChunkedArray, Downcast, IsSorted, NoNull,
     },
     datatypes,
     datatypes::*,
     error::{PolarsError, Result},
-    frame::{group_by::VecHash, hash_join::JoinType, DataFrame},
+    frame::{
+        asof_join::AsofStrategy,
+        group_by::{PivotAgg, VecHash},
+        hash_join::{JoinType, JoinValidation},
+        row::Row,
+        DataFrame, DuplicatePolicy, UniqueKeepStrategy,
+    },
     series::{
         arithmetic::{LhsNumOps, NumOpsDispatch},
-        IntoSeries, NamedFrom, Series, SeriesTrait,
+        IntoSeries, NamedFrom, Series, SeriesTrait, Unmapped,
     },
     testing::*,
 };
@@ -32,8 +38,15 @@ pub(crate) use polars_arrow::array::*;
 pub use polars_arrow::vec::AlignedVec;
 pub use std::sync::Arc;
 
+pub use crate::chunked_array::categorical::{CategoricalNameSpace, CategoricalOrdering};
+#[cfg(feature = "object")]
+pub use crate::chunked_array::object::{PolarsObject, PolarsObjectSafe};
+#[cfg(feature = "strings")]
+pub use crate::chunked_array::strings::StringNameSpace;
 #[cfg(feature = "temporal")]
 pub use crate::chunked_array::temporal::conversion::*;
+#[cfg(feature = "temporal")]
+pub use crate::chunked_array::temporal::DateTimeNameSpace;
 
 #[cfg(test)]
 pub(crate) fn create_df() -> DataFrame {
@@ -0,0 +1,197 @@
+//! Hooks to report the progress of long running operations.
+//!
+//! Register a [ProgressHandler](ProgressHandler) with [set_progress_handler](set_progress_handler)
+//! to receive updates of csv parsing, joins, groupby operations and lazy query execution.
+//!
+//! # Example
+//!
+//! ```rust
+//! use polars_core::progress::{set_progress_handler, ProgressHandler};
+//! use std::sync::Arc;
+//!
+//! struct PrintProgress {}
+//!
+//! impl ProgressHandler for PrintProgress {
+//!     fn on_progress(&self, operation: &str, rows: usize) {
+//!         println!("{}: processed {} rows", operation, rows)
+//!     }
+//! }
+//!
+//! set_progress_handler(Some(Arc::new(PrintProgress {})));
+//! ```
+use crate::prelude::*;
+use lazy_static::lazy_static;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+/// Receives progress updates of long running operations.
+///
+/// All methods have a default implementation that does nothing, so an implementor only needs to
+/// implement the hooks it is interested in. The methods may be called from multiple threads.
+pub trait ProgressHandler: Send + Sync {
+    /// Called when an operation starts. `total` is the number of rows that will be processed,
+    /// if that is known upfront.
+    fn on_start(&self, _operation: &str, _total: Option<usize>) {}
+
+    /// Called when `rows` (additional) rows are processed by an operation.
+    fn on_progress(&self, _operation: &str, _rows: usize) {}
+
+    /// Called when an operation is finished, also when it finished with an error.
+    fn on_finish(&self, _operation: &str) {}
+
+    /// Polled while an operation is running. If this returns `true`, the operation is aborted
+    /// with a `PolarsError::Cancelled` error.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+lazy_static! {
+    static ref PROGRESS_HANDLER: RwLock<Option<Arc<dyn ProgressHandler>>> = RwLock::new(None);
+}
+
+/// Set the global progress handler. Pass `None` to remove the current handler.
+pub fn set_progress_handler(handler: Option<Arc<dyn ProgressHandler>>) {
+    *PROGRESS_HANDLER.write().unwrap() = handler;
+}
+
+/// Get the global progress handler, if any.
+pub fn get_progress_handler() -> Option<Arc<dyn ProgressHandler>> {
+    PROGRESS_HANDLER.read().unwrap().clone()
+}
+
+/// A token that can be used to cancel an operation from another thread.
+///
+/// Clones of a token share their state, so cancelling one clone cancels them all.
+/// The operation aborts with `PolarsError::Cancelled` the next time it checks the token.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request the cancellation of all operations that hold this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns an error if cancellation was requested.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(PolarsError::Cancelled("operation was cancelled".into()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reports the progress of a single operation to the global [ProgressHandler](ProgressHandler).
+/// `on_finish` is called when this is dropped.
+///
+/// If no handler is set, all methods are no-ops.
+pub struct Progress {
+    operation: &'static str,
+    handler: Option<Arc<dyn ProgressHandler>>,
+    token: Option<CancellationToken>,
+}
+
+impl Progress {
+    /// Start reporting an operation.
+    pub fn start(operation: &'static str, total: Option<usize>) -> Self {
+        let handler = get_progress_handler();
+        if let Some(handler) = &handler {
+            handler.on_start(operation, total)
+        }
+        Progress {
+            operation,
+            handler,
+            token: None,
+        }
+    }
+
+    /// Also abort the operation if the given token is cancelled.
+    pub fn with_cancellation_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Report that `rows` (additional) rows are processed.
+    /// Returns an error if the operation should be cancelled.
+    pub fn update(&self, rows: usize) -> Result<()> {
+        if let Some(handler) = &self.handler {
+            handler.on_progress(self.operation, rows);
+        }
+        self.check_cancelled()
+    }
+
+    /// Returns an error if the operation should be cancelled.
+    pub fn check_cancelled(&self) -> Result<()> {
+        let handler_cancelled = matches!(&self.handler, Some(handler) if handler.is_cancelled());
+        let token_cancelled = matches!(&self.token, Some(token) if token.is_cancelled());
+        if handler_cancelled || token_cancelled {
+            Err(PolarsError::Cancelled(
+                format!("operation '{}' was cancelled", self.operation).into(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(handler) = &self.handler {
+            handler.on_finish(self.operation)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountRows {
+        rows: AtomicUsize,
+    }
+
+    impl ProgressHandler for CountRows {
+        fn on_progress(&self, operation: &str, rows: usize) {
+            if operation == "test_progress" {
+                self.rows.fetch_add(rows, Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_progress() {
+        let handler = Arc::new(CountRows {
+            rows: AtomicUsize::new(0),
+        });
+        set_progress_handler(Some(handler.clone()));
+        {
+            let progress = Progress::start("test_progress", Some(10));
+            progress.update(4).unwrap();
+            progress.update(6).unwrap();
+        }
+        set_progress_handler(None);
+        assert_eq!(handler.rows.load(Ordering::Relaxed), 10);
+    }
+
+    #[test]
+    fn test_cancellation_token() {
+        let token = CancellationToken::new();
+        let progress =
+            Progress::start("test_cancel", None).with_cancellation_token(Some(token.clone()));
+        assert!(progress.update(1).is_ok());
+        token.clone().cancel();
+        assert!(token.check().is_err());
+        assert!(matches!(progress.update(1), Err(PolarsError::Cancelled(_))));
+    }
+}
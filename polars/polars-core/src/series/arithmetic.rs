@@ -183,6 +183,19 @@ impl std::ops::Rem for &Series {
 
 // Series +-/* numbers instead of Series
 
+/// Cast an integer Series to the supertype of a float scalar, so that `&s * 0.5` doesn't truncate
+/// the scalar. With an integer scalar the dtype of the Series is kept.
+fn coerce_to_scalar<T: NumericNative>(s: &Series) -> Cow<Series> {
+    let is_float = |dtype: &DataType| matches!(dtype, DataType::Float32 | DataType::Float64);
+    let scalar_dtype = T::dtype();
+    if is_float(&scalar_dtype) && s.dtype().is_numeric() && !is_float(s.dtype()) {
+        let dtype = get_supertype(s.dtype(), &scalar_dtype).expect("numeric supertype");
+        Cow::Owned(s.cast_with_datatype(&dtype).expect("could not cast"))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
 pub(super) trait NumOpsDispatchSeriesSingleNumber {
     fn subtract_number<N: Num + NumCast>(&self, _rhs: N) -> Series {
         unimplemented!()
@@ -256,18 +269,19 @@ where
 
 impl<T> ops::Sub<T> for &Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Series;
 
     fn sub(self, rhs: T) -> Self::Output {
-        apply_method_all_arrow_series!(self, subtract_number, rhs)
+        let s = coerce_to_scalar::<T>(self);
+        apply_method_all_arrow_series!(s, subtract_number, rhs)
     }
 }
 
 impl<T> ops::Sub<T> for Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Self;
 
@@ -278,18 +292,19 @@ where
 
 impl<T> ops::Add<T> for &Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Series;
 
     fn add(self, rhs: T) -> Self::Output {
-        apply_method_all_arrow_series!(self, add_number, rhs)
+        let s = coerce_to_scalar::<T>(self);
+        apply_method_all_arrow_series!(s, add_number, rhs)
     }
 }
 
 impl<T> ops::Add<T> for Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Self;
 
@@ -300,18 +315,19 @@ where
 
 impl<T> ops::Div<T> for &Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Series;
 
     fn div(self, rhs: T) -> Self::Output {
-        apply_method_all_arrow_series!(self, divide_number, rhs)
+        let s = coerce_to_scalar::<T>(self);
+        apply_method_all_arrow_series!(s, divide_number, rhs)
     }
 }
 
 impl<T> ops::Div<T> for Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Self;
 
@@ -322,18 +338,19 @@ where
 
 impl<T> ops::Mul<T> for &Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Series;
 
     fn mul(self, rhs: T) -> Self::Output {
-        apply_method_all_arrow_series!(self, multiply_number, rhs)
+        let s = coerce_to_scalar::<T>(self);
+        apply_method_all_arrow_series!(s, multiply_number, rhs)
     }
 }
 
 impl<T> ops::Mul<T> for Series
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Self;
 
@@ -427,24 +444,56 @@ pub trait LhsNumOps {
 
 impl<T> LhsNumOps for T
 where
-    T: Num + NumCast,
+    T: NumericNative,
 {
     type Output = Series;
 
     fn add(self, rhs: &Series) -> Self::Output {
+        let rhs = coerce_to_scalar::<T>(rhs);
         apply_method_all_arrow_series!(rhs, lhs_add_number, self)
     }
     fn sub(self, rhs: &Series) -> Self::Output {
+        let rhs = coerce_to_scalar::<T>(rhs);
         apply_method_all_arrow_series!(rhs, lhs_subtract_number, self)
     }
     fn div(self, rhs: &Series) -> Self::Output {
+        let rhs = coerce_to_scalar::<T>(rhs);
         apply_method_all_arrow_series!(rhs, lhs_divide_number, self)
     }
     fn mul(self, rhs: &Series) -> Self::Output {
+        let rhs = coerce_to_scalar::<T>(rhs);
         apply_method_all_arrow_series!(rhs, lhs_multiply_number, self)
     }
 }
 
+// number +-/* Series, e.g. `2.0 * &s`
+macro_rules! impl_lhs_scalar_op {
+    ($trait:ident, $method:ident, $($native:ty),*) => {
+        $(
+            impl ops::$trait<&Series> for $native {
+                type Output = Series;
+
+                fn $method(self, rhs: &Series) -> Self::Output {
+                    LhsNumOps::$method(self, rhs)
+                }
+            }
+
+            impl ops::$trait<Series> for $native {
+                type Output = Series;
+
+                fn $method(self, rhs: Series) -> Self::Output {
+                    LhsNumOps::$method(self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_lhs_scalar_op!(Add, add, u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+impl_lhs_scalar_op!(Sub, sub, u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+impl_lhs_scalar_op!(Mul, mul, u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+impl_lhs_scalar_op!(Div, div, u8, u16, u32, u64, usize, i8, i16, i32, i64, f32, f64);
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -505,4 +554,36 @@ mod test {
             [Some(1), Some(2), Some(3)]
         );
     }
+
+    #[test]
+    fn test_arithmetic_scalar_broadcast() {
+        let s = Series::new("a", &[1, 2, 3]);
+        // a float scalar promotes an integer Series
+        let out = &s * 0.5;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            [Some(0.5), Some(1.0), Some(1.5)]
+        );
+        assert_eq!(out.name(), "a");
+        let out = 2.0 * &s;
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            [Some(2.0), Some(4.0), Some(6.0)]
+        );
+        let out = 3.0 / s.clone();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            [Some(3.0), Some(1.5), Some(1.0)]
+        );
+        assert_eq!((&s - 0.5f32).dtype(), &DataType::Float32);
+
+        // an integer scalar keeps the dtype
+        let out = 10 - &s;
+        assert_eq!(Vec::from(out.i32().unwrap()), [Some(9), Some(8), Some(7)]);
+        let s = Series::new("a", &[Some(1.5f32), None]);
+        let out = 2 + &s;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_eq!(Vec::from(out.f32().unwrap()), [Some(3.5), None]);
+    }
 }
@@ -2,6 +2,7 @@
 
 use super::Series;
 use crate::apply_method_numeric_series;
+use crate::functions::ClosedWindow;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 
@@ -169,3 +170,117 @@ impl ChunkCompare<&str> for Series {
         }
     }
 }
+
+impl Series {
+    /// Create a boolean mask by checking if the values are between `low` and `high`. Which of
+    /// the bounds are included is determined by `closed`. Null values stay null.
+    ///
+    /// The Series must be numeric or temporal. `low` and `high` have length 1 or the length of
+    /// the Series and are cast to the supertype if their dtypes differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// use polars_core::functions::ClosedWindow;
+    /// let s = Series::new("a", &[1, 2, 3, 4]);
+    /// let low = Series::new("low", &[2]);
+    /// let high = Series::new("high", &[4]);
+    /// let mask = s.is_between(&low, &high, ClosedWindow::Left).unwrap();
+    /// assert_eq!(Vec::from(&mask), &[Some(false), Some(true), Some(true), Some(false)]);
+    /// ```
+    pub fn is_between(
+        &self,
+        low: &Series,
+        high: &Series,
+        closed: ClosedWindow,
+    ) -> Result<BooleanChunked> {
+        let dtype = self.dtype();
+        if !dtype.is_numeric()
+            && !matches!(
+                dtype,
+                DataType::Date32 | DataType::Date64 | DataType::Time64(_) | DataType::Duration(_)
+            )
+        {
+            return Err(PolarsError::InvalidOperation(
+                format!("is_between is not supported for dtype {:?}", dtype).into(),
+            ));
+        }
+        for bound in &[low, high] {
+            if bound.len() != 1 && bound.len() != self.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "bound of length {} cannot be compared with a Series of length {}",
+                        bound.len(),
+                        self.len()
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        let (lhs, low) = coerce_lhs_rhs(self, low)?;
+        let lower = match closed {
+            ClosedWindow::Both | ClosedWindow::Left => lhs.gt_eq(low.as_ref()),
+            ClosedWindow::Right | ClosedWindow::None => lhs.gt(low.as_ref()),
+        };
+        let (lhs, high) = coerce_lhs_rhs(self, high)?;
+        let upper = match closed {
+            ClosedWindow::Both | ClosedWindow::Right => lhs.lt_eq(high.as_ref()),
+            ClosedWindow::Left | ClosedWindow::None => lhs.lt(high.as_ref()),
+        };
+        let mut mask = &lower & &upper;
+        mask.rename(self.name());
+        Ok(mask)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::functions::ClosedWindow;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_is_between() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(4)]);
+        let low = Series::new("low", &[2]);
+        let high = Series::new("high", &[4]);
+
+        let mask = s.is_between(&low, &high, ClosedWindow::Both)?;
+        assert_eq!(mask.name(), "a");
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(false), Some(true), None, Some(true), Some(true)]
+        );
+        let mask = s.is_between(&low, &high, ClosedWindow::None)?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(false), Some(false), None, Some(true), Some(false)]
+        );
+
+        // float bounds on an integer Series
+        let low = Series::new("low", &[1.5]);
+        let mask = s.is_between(&low, &high, ClosedWindow::Right)?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(false), Some(true), None, Some(true), Some(true)]
+        );
+
+        // temporal
+        let dates = s.cast::<Date32Type>()?;
+        let low = Series::new("low", &[2]).cast::<Date32Type>()?;
+        let high = Series::new("high", &[3]).cast::<Date32Type>()?;
+        let mask = dates.is_between(&low, &high, ClosedWindow::Left)?;
+        assert_eq!(
+            Vec::from(&mask),
+            &[Some(false), Some(true), None, Some(false), Some(false)]
+        );
+
+        assert!(s
+            .is_between(&Series::new("low", &[1, 2]), &high, ClosedWindow::Both)
+            .is_err());
+        let s = Series::new("a", &["a", "b"]);
+        assert!(s.is_between(&low, &high, ClosedWindow::Both).is_err());
+        Ok(())
+    }
+}
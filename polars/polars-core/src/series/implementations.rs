@@ -2,7 +2,7 @@ use super::private;
 use super::IntoSeries;
 use super::SeriesTrait;
 use crate::chunked_array::{
-    ops::aggregate::{ChunkAggSeries, VarAggSeries},
+    ops::aggregate::{ArgAgg, ChunkAggSeries, VarAggSeries},
     AsSinglePtr,
 };
 use crate::fmt::FmtList;
@@ -204,11 +204,19 @@ macro_rules! impl_dyn_series {
                 self.0.rename(name);
             }
 
+            fn is_sorted(&self) -> IsSorted {
+                self.0.is_sorted()
+            }
+
+            fn set_sorted(&mut self, sorted: IsSorted) {
+                self.0.set_sorted(sorted)
+            }
+
             fn array_data(&self) -> Vec<ArrayDataRef> {
                 self.0.array_data()
             }
 
-            fn chunk_lengths(&self) -> &Vec<usize> {
+            fn chunk_lengths(&self) -> &[usize] {
                 self.0.chunk_id()
             }
             fn name(&self) -> &str {
@@ -219,7 +227,7 @@ macro_rules! impl_dyn_series {
                 self.0.ref_field()
             }
 
-            fn chunks(&self) -> &Vec<ArrayRef> {
+            fn chunks(&self) -> &[ArrayRef] {
                 self.0.chunks()
             }
 
@@ -681,6 +689,14 @@ macro_rules! impl_dyn_series {
                 ChunkUnique::arg_unique(&self.0)
             }
 
+            fn arg_min(&self) -> Option<usize> {
+                ArgAgg::arg_min(&self.0)
+            }
+
+            fn arg_max(&self) -> Option<usize> {
+                ArgAgg::arg_max(&self.0)
+            }
+
             fn arg_true(&self) -> Result<UInt32Chunked> {
                 let ca: &BooleanChunked = self.bool()?;
                 Ok(ca.arg_true())
@@ -723,6 +739,16 @@ macro_rules! impl_dyn_series {
             }
 
             fn zip_with(&self, mask: &BooleanChunked, other: &Series) -> Result<Series> {
+                if self.0.dtype() != other.dtype() {
+                    return Err(PolarsError::DataTypeMisMatch(
+                        format!(
+                            "cannot zip_with Series of dtype {:?} and {:?}",
+                            self.0.dtype(),
+                            other.dtype()
+                        )
+                        .into(),
+                    ));
+                }
                 ChunkZip::zip_with(&self.0, mask, other.as_ref().as_ref())
                     .map(|ca| ca.into_series())
             }
@@ -840,6 +866,18 @@ macro_rules! impl_dyn_series {
                 }
             }
 
+            #[cfg(feature = "temporal")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+            fn weekday(&self) -> Result<Series> {
+                match self.0.dtype() {
+                    DataType::Date32 => self.date32().map(|ca| ca.weekday().into_series()),
+                    DataType::Date64 => self.date64().map(|ca| ca.weekday().into_series()),
+                    _ => Err(PolarsError::InvalidOperation(
+                        format!("operation not supported on dtype {:?}", self.dtype()).into(),
+                    )),
+                }
+            }
+
             #[cfg(feature = "temporal")]
             #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
             fn month(&self) -> Result<Series> {
@@ -869,17 +907,29 @@ macro_rules! impl_dyn_series {
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
+            fn sample_n(
+                &self,
+                n: usize,
+                with_replacement: bool,
+                shuffle: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_n(n, with_replacement)
+                    .sample_n(n, with_replacement, shuffle, seed)
                     .map(|ca| ca.into_series())
             }
 
             #[cfg(feature = "random")]
             #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-            fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
+            fn sample_frac(
+                &self,
+                frac: f64,
+                with_replacement: bool,
+                shuffle: bool,
+                seed: Option<u64>,
+            ) -> Result<Series> {
                 self.0
-                    .sample_frac(frac, with_replacement)
+                    .sample_frac(frac, with_replacement, shuffle, seed)
                     .map(|ca| ca.into_series())
             }
 
@@ -924,7 +974,7 @@ impl_dyn_series!(CategoricalChunked);
 #[cfg(feature = "object")]
 impl<T> IntoSeries for ObjectChunked<T>
 where
-    T: 'static + std::fmt::Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn into_series(self) -> Series {
         Series(Arc::new(Wrap(self)))
@@ -933,15 +983,23 @@ where
 
 #[cfg(feature = "object")]
 #[cfg_attr(docsrs, doc(cfg(feature = "object")))]
-impl<T> PrivateSeries for Wrap<ObjectChunked<T>> where
-    T: 'static + Debug + Clone + Send + Sync + Default
+impl<T> PrivateSeries for Wrap<ObjectChunked<T>>
+where
+    T: PolarsObject,
 {
+    fn agg_first(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0.agg_first(groups)
+    }
+
+    fn agg_last(&self, groups: &[(usize, Vec<usize>)]) -> Series {
+        self.0.agg_last(groups)
+    }
 }
 #[cfg(feature = "object")]
 #[cfg_attr(docsrs, doc(cfg(feature = "object")))]
 impl<T> SeriesTrait for Wrap<ObjectChunked<T>>
 where
-    T: 'static + Debug + Clone + Send + Sync + Default,
+    T: PolarsObject,
 {
     fn rename(&mut self, name: &str) {
         ObjectChunked::rename(&mut self.0, name)
@@ -951,7 +1009,7 @@ where
         ObjectChunked::array_data(&self.0)
     }
 
-    fn chunk_lengths(&self) -> &Vec<usize> {
+    fn chunk_lengths(&self) -> &[usize] {
         ObjectChunked::chunk_id(&self.0)
     }
 
@@ -967,7 +1025,7 @@ where
         ObjectChunked::dtype(&self.0)
     }
 
-    fn chunks(&self) -> &Vec<ArrayRef> {
+    fn chunks(&self) -> &[ArrayRef] {
         ObjectChunked::chunks(&self.0)
     }
 
@@ -1142,17 +1200,35 @@ where
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_n(&self.0, n, with_replacement).map(|ca| ca.into_series())
+    fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Series> {
+        ObjectChunked::sample_n(&self.0, n, with_replacement, shuffle, seed)
+            .map(|ca| ca.into_series())
     }
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series> {
-        ObjectChunked::sample_frac(&self.0, frac, with_replacement).map(|ca| ca.into_series())
+    fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Series> {
+        ObjectChunked::sample_frac(&self.0, frac, with_replacement, shuffle, seed)
+            .map(|ca| ca.into_series())
     }
 
     fn get_as_any(&self, index: usize) -> &dyn Any {
         ObjectChunked::get_as_any(&self.0, index)
     }
+
+    fn get_object(&self, index: usize) -> Option<&dyn PolarsObjectSafe> {
+        ObjectChunked::get_object(&self.0, index).map(|v| v as &dyn PolarsObjectSafe)
+    }
 }
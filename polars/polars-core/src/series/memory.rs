@@ -0,0 +1,111 @@
+use crate::prelude::*;
+use arrow::array::{Array, LargeListArray, LargeStringArray, ListArray, StringArray};
+use polars_arrow::bit_util::ceil;
+use std::mem::size_of;
+
+/// Byte width of the values of a fixed width arrow type.
+fn fixed_width(dtype: &ArrowDataType) -> Option<usize> {
+    use ArrowDataType::*;
+    match dtype {
+        Int8 | UInt8 => Some(1),
+        Int16 | UInt16 | Float16 => Some(2),
+        Int32 | UInt32 | Float32 | Date32 | Time32(_) => Some(4),
+        Int64 | UInt64 | Float64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => Some(8),
+        _ => None,
+    }
+}
+
+/// Bytes of the offsets and the values that are in the range of the offsets.
+fn offsets_size<O: Copy + Into<i64>>(offsets: &[O]) -> usize {
+    let first: i64 = offsets[0].into();
+    let last: i64 = offsets[offsets.len() - 1].into();
+    offsets.len() * size_of::<O>() + (last - first) as usize
+}
+
+/// Estimated bytes used by the part of the buffers that the array refers to.
+pub(crate) fn estimated_array_size(arr: &dyn Array) -> usize {
+    let len = arr.len();
+    let validity = match arr.data_ref().null_bitmap() {
+        Some(_) => ceil(len, 8),
+        None => 0,
+    };
+    let values = match arr.data_type() {
+        ArrowDataType::Boolean => ceil(len, 8),
+        ArrowDataType::Utf8 => {
+            let arr = arr.as_any().downcast_ref::<StringArray>().unwrap();
+            offsets_size(arr.value_offsets())
+        }
+        ArrowDataType::LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            offsets_size(arr.value_offsets())
+        }
+        ArrowDataType::List(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray>().unwrap();
+            let offsets = arr.value_offsets();
+            let (first, last) = (offsets[0] as usize, offsets[len] as usize);
+            offsets.len() * size_of::<i32>()
+                + estimated_array_size(&*arr.values().slice(first, last - first))
+        }
+        ArrowDataType::LargeList(_) => {
+            let arr = arr.as_any().downcast_ref::<LargeListArray>().unwrap();
+            let offsets = arr.value_offsets();
+            let (first, last) = (offsets[0] as usize, offsets[len] as usize);
+            offsets.len() * size_of::<i64>()
+                + estimated_array_size(&*arr.values().slice(first, last - first))
+        }
+        dtype => match fixed_width(dtype) {
+            Some(width) => width * len,
+            None => arr.get_buffer_memory_size(),
+        },
+    };
+    validity + values
+}
+
+impl Series {
+    /// Estimated number of bytes used by the values, the validity bitmaps and, for strings and
+    /// lists, the offsets of this Series.
+    ///
+    /// Only the part of the buffers that the Series refers to is counted, so a slice reports less
+    /// than the array it is a slice of, although they share memory. Values of dtype Object are not
+    /// counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("a", &[1i64, 2, 3]);
+    /// assert_eq!(s.estimated_size(), 24);
+    /// ```
+    pub fn estimated_size(&self) -> usize {
+        match self.dtype() {
+            #[cfg(feature = "object")]
+            DataType::Object => 0,
+            _ => self
+                .chunks()
+                .iter()
+                .map(|arr| estimated_array_size(&**arr))
+                .sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_estimated_size() {
+        let s = Series::new("a", &[Some(1i32), None, Some(3)]);
+        // values and a validity bitmap of 1 byte
+        assert_eq!(s.estimated_size(), 12 + 1);
+        assert_eq!(s.slice(0, 2).estimated_size(), 8 + 1);
+
+        let s = Series::new("a", &["a", "bb", "ccc"]);
+        // 4 offsets and 6 bytes
+        assert_eq!(s.estimated_size(), 4 * 8 + 6);
+        assert_eq!(s.slice(1, 1).estimated_size(), 2 * 8 + 2);
+
+        let s = Series::new("a", &[true, false]);
+        assert_eq!(s.estimated_size(), 1);
+    }
+}
@@ -2,10 +2,13 @@
 pub use crate::prelude::ChunkCompare;
 use crate::prelude::*;
 use arrow::{array::ArrayRef, buffer::Buffer};
+pub use replace::Unmapped;
 pub(crate) mod arithmetic;
 mod comparison;
 pub mod implementations;
 pub(crate) mod iterator;
+mod memory;
+mod replace;
 
 use crate::chunked_array::builder::get_list_builder;
 use crate::chunked_array::float::IsNan;
@@ -145,13 +148,21 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     /// Rename the Series.
     fn rename(&mut self, name: &str);
 
+    /// Whether the values are known to be sorted.
+    fn is_sorted(&self) -> IsSorted {
+        IsSorted::Not
+    }
+
+    /// Flag the values as sorted. This is not checked.
+    fn set_sorted(&mut self, _sorted: IsSorted) {}
+
     /// Get Arrow ArrayData
     fn array_data(&self) -> Vec<ArrayDataRef> {
         unimplemented!()
     }
 
     /// Get the lengths of the underlying chunks
-    fn chunk_lengths(&self) -> &Vec<usize> {
+    fn chunk_lengths(&self) -> &[usize] {
         unimplemented!()
     }
     /// Name of series.
@@ -170,7 +181,7 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
     }
 
     /// Underlying chunks.
-    fn chunks(&self) -> &Vec<ArrayRef> {
+    fn chunks(&self) -> &[ArrayRef] {
         unimplemented!()
     }
 
@@ -534,6 +545,18 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
+    /// Get the index of the minimal value. Null and `NaN` values are ignored.
+    /// Returns `None` if there are no valid values, or if the dtype has no ordering.
+    fn arg_min(&self) -> Option<usize> {
+        None
+    }
+
+    /// Get the index of the maximal value. Null and `NaN` values are ignored.
+    /// Returns `None` if there are no valid values, or if the dtype has no ordering.
+    fn arg_max(&self) -> Option<usize> {
+        None
+    }
+
     /// Get indexes that evaluate true
     fn arg_true(&self) -> Result<UInt32Chunked> {
         Err(PolarsError::InvalidOperation(
@@ -784,6 +807,15 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
         unimplemented!()
     }
 
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    /// Returns the day of the week starting from 0 for Monday.
+    ///
+    /// The return value ranges from 0 to 6.
+    fn weekday(&self) -> Result<Series> {
+        unimplemented!()
+    }
+
     #[cfg(feature = "temporal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
     /// Extract month from underlying NaiveDateTime representation.
@@ -796,7 +828,7 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     #[cfg(feature = "temporal")]
     #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
-    /// Extract month from underlying NaiveDateTime representation.
+    /// Extract year from underlying NaiveDateTime representation.
     /// Returns the year number in the calendar date.
     fn year(&self) -> Result<Series> {
         unimplemented!()
@@ -822,19 +854,41 @@ pub trait SeriesTrait: Send + Sync + private::PrivateSeries {
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    /// Sample n datapoints from this Series.
-    fn sample_n(&self, n: usize, with_replacement: bool) -> Result<Series>;
+    /// Sample n datapoints from this Series. With `shuffle` the sample is in random order,
+    /// otherwise the values keep their original order. A `seed` makes the sample reproducible.
+    fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Series>;
 
     #[cfg(feature = "random")]
     #[cfg_attr(docsrs, doc(cfg(feature = "random")))]
-    /// Sample a fraction between 0.0-1.0 of this ChunkedArray.
-    fn sample_frac(&self, frac: f64, with_replacement: bool) -> Result<Series>;
+    /// Sample a fraction of this Series. The fraction can only be larger than 1.0 with
+    /// replacement.
+    fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> Result<Series>;
 
     /// Get the value at this index as a downcastable Any trait ref.
     fn get_as_any(&self, _index: usize) -> &dyn Any {
         unimplemented!()
     }
 
+    #[cfg(feature = "object")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "object")))]
+    /// Get the value at this index of an object Series. Returns `None` if the value is null or
+    /// if this is not an object Series.
+    fn get_object(&self, _index: usize) -> Option<&dyn PolarsObjectSafe> {
+        None
+    }
+
     /// Raise a numeric series to the power of exponent.
     fn pow(&self, _exponent: f64) -> Result<Series> {
         Err(PolarsError::InvalidOperation(
@@ -962,12 +1016,42 @@ impl Series {
         Arc::get_mut(&mut self.0).expect("implementation error")
     }
 
+    /// Create a Series of `size` null values of the given `dtype`.
+    pub fn full_null(name: &str, size: usize, dtype: &DataType) -> Series {
+        macro_rules! primitive {
+            ($type:ty) => {{
+                ChunkedArray::<$type>::full_null(name, size).into_series()
+            }};
+        }
+        macro_rules! utf8 {
+            () => {{
+                Utf8Chunked::full_null(name, size).into_series()
+            }};
+        }
+        macro_rules! bool {
+            () => {{
+                BooleanChunked::full_null(name, size).into_series()
+            }};
+        }
+        match dtype {
+            DataType::List(_) => ListChunked::full_null(name, size).into_series(),
+            dt => match_arrow_data_type_apply_macro!(dt, primitive, utf8, bool),
+        }
+    }
+
     /// Rename series.
     pub fn rename(&mut self, name: &str) -> &mut Series {
         self.get_inner_mut().rename(name);
         self
     }
 
+    /// Flag the values as sorted, see [IsSorted]. This is not checked, setting a wrong flag leads
+    /// to wrong results of the operations that use it.
+    pub fn set_sorted(&mut self, sorted: IsSorted) -> &mut Series {
+        self.get_inner_mut().set_sorted(sorted);
+        self
+    }
+
     /// Append arrow array of same datatype.
     pub fn append_array(&mut self, other: ArrayRef) -> Result<&mut Self> {
         self.get_inner_mut().append_array(other)?;
@@ -975,11 +1059,122 @@ impl Series {
     }
 
     /// Append a Series of the same type in place.
+    ///
+    /// This only appends the chunks of `other` and does not copy any data. Use
+    /// [rechunk](SeriesTrait::rechunk) or [rechunk_with_sizes](Series::rechunk_with_sizes)
+    /// to compact the chunks after many appends.
     pub fn append(&mut self, other: &Series) -> Result<&mut Self> {
         self.get_inner_mut().append(other)?;
         Ok(self)
     }
 
+    /// Aggregate all chunks to contiguous memory, optionally split in chunks of the given sizes.
+    /// The sizes must sum to the length of the Series. Zero sized chunks are skipped.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let mut s = Series::new("a", &[1, 2]);
+    /// s.append(&Series::new("b", &[3])).unwrap();
+    /// let s = s.rechunk_with_sizes(Some(&[1, 2])).unwrap();
+    /// assert_eq!(s.chunk_lengths(), &[1, 2]);
+    /// ```
+    pub fn rechunk_with_sizes(&self, chunk_sizes: Option<&[usize]>) -> Result<Series> {
+        let rechunked = self.rechunk();
+        let chunk_sizes = match chunk_sizes {
+            None => return Ok(rechunked),
+            Some(chunk_sizes) => chunk_sizes,
+        };
+        if chunk_sizes.iter().sum::<usize>() != self.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "chunk sizes sum to {}, but the Series has length {}",
+                    chunk_sizes.iter().sum::<usize>(),
+                    self.len()
+                )
+                .into(),
+            ));
+        }
+
+        let mut out: Option<Series> = None;
+        let mut offset = 0;
+        for &size in chunk_sizes.iter().filter(|&&size| size > 0) {
+            // slices are zero copy views on the rechunked data.
+            let chunk = rechunked.slice(offset, size)?;
+            offset += size;
+            match &mut out {
+                Some(out) => {
+                    out.append(&chunk)?;
+                }
+                None => out = Some(chunk),
+            }
+        }
+        Ok(out.unwrap_or(rechunked))
+    }
+
+    /// Returns true if any of the values is `true`. Null values are ignored.
+    /// Only implemented for Boolean Series.
+    pub fn any(&self) -> Result<bool> {
+        self.bool().map(|ca| ca.any())
+    }
+
+    /// Returns true if all values are `true`. Null values are ignored.
+    /// Only implemented for Boolean Series.
+    pub fn all(&self) -> Result<bool> {
+        self.bool().map(|ca| ca.all())
+    }
+
+    /// Retrieve the indexes needed to sort this Series with the given options.
+    ///
+    /// The sort kernels are stable, so equal values always maintain their order.
+    pub fn argsort_with_options(&self, options: SortOptions) -> Vec<usize> {
+        let idx = self.argsort(options.descending);
+        // the kernels place the nulls last if descending and first otherwise
+        let moves_nulls = self.null_count() > 0 && options.nulls_last != options.descending;
+        let moves_nans = options.moves_nans(self.dtype());
+        if !moves_nulls && !moves_nans {
+            return idx;
+        }
+        let is_null = self.is_null();
+        let (nulls, valid): (Vec<usize>, Vec<usize>) =
+            idx.into_iter().partition(|&i| is_null.get(i) == Some(true));
+        let valid = match self.is_nan() {
+            Ok(is_nan) if moves_nans => {
+                let (nans, values): (Vec<usize>, Vec<usize>) = valid
+                    .into_iter()
+                    .partition(|&i| is_nan.get(i) == Some(true));
+                if options.nans_last {
+                    values.into_iter().chain(nans).collect()
+                } else {
+                    nans.into_iter().chain(values).collect()
+                }
+            }
+            _ => valid,
+        };
+        if options.nulls_last {
+            valid.into_iter().chain(nulls).collect()
+        } else {
+            nulls.into_iter().chain(valid).collect()
+        }
+    }
+
+    /// Return a sorted clone of this Series with the given options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("a", &[Some(2), None, Some(1)]);
+    /// let options = SortOptions {
+    ///     nulls_last: true,
+    ///     ..Default::default()
+    /// };
+    /// let sorted = s.sort_with_options(options);
+    /// assert_eq!(Vec::from(sorted.i32().unwrap()), &[Some(1), Some(2), None]);
+    /// ```
+    pub fn sort_with_options(&self, options: SortOptions) -> Series {
+        self.take(&self.argsort_with_options(options))
+    }
+
     /// Sort in place.
     pub fn sort_in_place(&mut self, reverse: bool) -> &mut Self {
         self.get_inner_mut().sort_in_place(reverse);
@@ -1049,6 +1244,80 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Get the product of the values as a new Series of length 1. Null values are ignored.
+    ///
+    /// With [ProductOverflow::ToFloat] the product is computed as `Float64`. With
+    /// [ProductOverflow::Checked] the product keeps the dtype of the Series and an error is
+    /// returned if it overflows.
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let returns = Series::new("returns", [1.1, 0.9, 1.2].as_ref());
+    /// let growth = returns.product(ProductOverflow::Checked).unwrap();
+    /// assert!((growth.f64().unwrap().get(0).unwrap() - 1.188).abs() < 1e-9);
+    ///
+    /// let s = Series::new("a", [100u8, 3].as_ref());
+    /// assert!(s.product(ProductOverflow::Checked).is_err());
+    /// let s = s.product(ProductOverflow::ToFloat).unwrap();
+    /// assert_eq!(s.f64().unwrap().get(0), Some(300.0));
+    /// ```
+    pub fn product(&self, overflow: ProductOverflow) -> Result<Series> {
+        if !self.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("product is not supported for dtype {:?}", self.dtype()).into(),
+            ));
+        }
+        apply_method_numeric_series!(self, product_as_series, overflow)
+    }
+
+    fn numeric_as_f64(&self, agg: &str) -> Result<Series> {
+        if !self.dtype().is_numeric() {
+            return Err(PolarsError::InvalidOperation(
+                format!("{} is not supported for dtype {:?}", agg, self.dtype()).into(),
+            ));
+        }
+        self.cast::<Float64Type>()
+    }
+
+    /// Compute the sample skewness of the values. Null values are ignored.
+    ///
+    /// If `bias` is false the result is corrected for statistical bias.
+    /// See [ChunkedArray::skew](crate::chunked_array::ChunkedArray::skew).
+    pub fn skew(&self, bias: bool) -> Result<Option<f64>> {
+        let s = self.numeric_as_f64("skew")?;
+        Ok(s.f64().unwrap().skew(bias))
+    }
+
+    /// Compute the kurtosis of the values. Null values are ignored.
+    ///
+    /// With `fisher` the excess kurtosis is returned (0.0 for a normal distribution), otherwise
+    /// Pearson's definition is used. If `bias` is false the result is corrected for statistical
+    /// bias. See [ChunkedArray::kurtosis](crate::chunked_array::ChunkedArray::kurtosis).
+    pub fn kurtosis(&self, fisher: bool, bias: bool) -> Result<Option<f64>> {
+        let s = self.numeric_as_f64("kurtosis")?;
+        Ok(s.f64().unwrap().kurtosis(fisher, bias))
+    }
+
+    /// Compute the mean of the values weighted by `weights`: `sum(value * weight) / sum(weight)`.
+    /// Pairs where the value or the weight is null are ignored. Returns `None` if there are no
+    /// such pairs or if the weights sum to zero.
+    pub fn weighted_mean(&self, weights: &Series) -> Result<Option<f64>> {
+        if self.len() != weights.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                "the values and weights of a weighted mean must have the same length".into(),
+            ));
+        }
+        let values = self.numeric_as_f64("weighted mean")?;
+        let weights = weights.numeric_as_f64("weighted mean")?;
+        Ok(crate::frame::group_by::weighted_mean(
+            values
+                .f64()
+                .unwrap()
+                .into_iter()
+                .zip(weights.f64().unwrap()),
+        ))
+    }
+
     /// Returns the mean value in the array
     /// Returns an option because the array is nullable.
     pub fn mean<T>(&self) -> Option<T>
@@ -1061,6 +1330,97 @@ impl Series {
             .and_then(|s| s.f64().unwrap().get(0).and_then(T::from))
     }
 
+    /// Get the categorical namespace of a Series of dtype Categorical.
+    pub fn cat(&self) -> Result<crate::chunked_array::categorical::CategoricalNameSpace> {
+        self.categorical()
+            .map(crate::chunked_array::categorical::CategoricalNameSpace)
+    }
+
+    /// Get the string namespace of a Series of dtype Utf8.
+    #[cfg(feature = "strings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "strings")))]
+    pub fn str(&self) -> Result<crate::chunked_array::strings::StringNameSpace> {
+        self.utf8()
+            .map(crate::chunked_array::strings::StringNameSpace)
+    }
+
+    /// Get the temporal namespace of a Series of dtype Date32 or Date64.
+    #[cfg(feature = "temporal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
+    pub fn dt(&self) -> Result<crate::chunked_array::temporal::DateTimeNameSpace> {
+        match self.dtype() {
+            DataType::Date32 | DataType::Date64 => {
+                Ok(crate::chunked_array::temporal::DateTimeNameSpace(self))
+            }
+            dt => Err(PolarsError::InvalidOperation(
+                format!("dt namespace is not supported for dtype {:?}", dt).into(),
+            )),
+        }
+    }
+
+    /// Hash all values, including null values, with a hasher seeded by `seed`.
+    /// See [ChunkedArray::hash](crate::chunked_array::ChunkedArray::hash).
+    ///
+    /// Returns an error for Series of dtype list or object, these can't be hashed.
+    pub fn hash(&self, seed: u64) -> Result<UInt64Chunked> {
+        match self.dtype() {
+            DataType::List(_) => Err(PolarsError::InvalidOperation(
+                format!("cannot hash series '{}' of dtype list", self.name()).into(),
+            )),
+            #[cfg(feature = "object")]
+            DataType::Object => Err(PolarsError::InvalidOperation(
+                format!("cannot hash series '{}' of dtype object", self.name()).into(),
+            )),
+            _ => {
+                let mut ca = self
+                    .0
+                    .vec_hash(crate::vector_hasher::seeded_random_state(seed));
+                ca.rename(self.name());
+                Ok(ca)
+            }
+        }
+    }
+
+    /// Compute the dot product of two numeric Series of equal length.
+    /// Pairs where either value is null are skipped.
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let a = Series::new("a", &[1, 2, 3]);
+    /// let b = Series::new("b", &[Some(1.0), None, Some(2.0)]);
+    /// assert_eq!(a.dot(&b).unwrap(), 7.0);
+    /// ```
+    pub fn dot(&self, other: &Series) -> Result<f64> {
+        if self.len() != other.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "dot product of series with different lengths: {} and {}",
+                    self.len(),
+                    other.len()
+                )
+                .into(),
+            ));
+        }
+        for s in &[self, other] {
+            if !s.dtype().is_numeric() {
+                return Err(PolarsError::InvalidOperation(
+                    format!("dot not supported for series with dtype {:?}", s.dtype()).into(),
+                ));
+            }
+        }
+        let lhs = self.cast::<Float64Type>()?;
+        let rhs = other.cast::<Float64Type>()?;
+        let dot = lhs
+            .f64()?
+            .into_iter()
+            .zip(rhs.f64()?.into_iter())
+            .filter_map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => Some(l * r),
+                _ => None,
+            })
+            .sum();
+        Ok(dot)
+    }
+
     /// Explode a list or utf8 Series. This expands every item to a new row..
     pub fn explode(&self) -> Result<Series> {
         match self.dtype() {
@@ -1135,6 +1495,56 @@ impl Series {
             )),
         }
     }
+
+    /// Find the index where `value` should be inserted in this (ascending) sorted Series to
+    /// maintain the order. `value` should be a Series of length 1 and is cast to the dtype of
+    /// this Series.
+    pub fn search_sorted(&self, value: &Series, side: SearchSortedSide) -> Result<usize> {
+        if value.len() != 1 {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "search_sorted expects a value of length 1, got length {}",
+                    value.len()
+                )
+                .into(),
+            ));
+        }
+        let value = value.cast_with_datatype(self.dtype())?;
+
+        macro_rules! search {
+            ($unpack:ident) => {{
+                match value.$unpack()?.get(0) {
+                    Some(v) => self.$unpack()?.search_sorted(v, side),
+                    None => Err(PolarsError::HasNullValues(
+                        "cannot search for a null value".into(),
+                    )),
+                }
+            }};
+        }
+
+        match self.dtype() {
+            DataType::Boolean => search!(bool),
+            DataType::Utf8 => search!(utf8),
+            DataType::UInt8 => search!(u8),
+            DataType::UInt16 => search!(u16),
+            DataType::UInt32 => search!(u32),
+            DataType::UInt64 => search!(u64),
+            DataType::Int8 => search!(i8),
+            DataType::Int16 => search!(i16),
+            DataType::Int32 => search!(i32),
+            DataType::Int64 => search!(i64),
+            DataType::Float32 => search!(f32),
+            DataType::Float64 => search!(f64),
+            DataType::Date32 => search!(date32),
+            DataType::Date64 => search!(date64),
+            DataType::Time64(TimeUnit::Nanosecond) => search!(time64_nanosecond),
+            DataType::Duration(TimeUnit::Nanosecond) => search!(duration_nanosecond),
+            DataType::Duration(TimeUnit::Millisecond) => search!(duration_millisecond),
+            dt => Err(PolarsError::InvalidOperation(
+                format!("search_sorted not supported for series with dtype {:?}", dt).into(),
+            )),
+        }
+    }
 }
 
 impl Deref for Series {
@@ -1202,6 +1612,13 @@ impl_named_from!([Option<i64>], Int64Type, new_from_opt_slice);
 impl_named_from!([Option<f32>], Float32Type, new_from_opt_slice);
 impl_named_from!([Option<f64>], Float64Type, new_from_opt_slice);
 
+impl NamedFrom<Series, Series> for Series {
+    fn new(name: &str, mut s: Series) -> Self {
+        s.rename(name);
+        s
+    }
+}
+
 impl<T: AsRef<[Series]>> NamedFrom<T, ListType> for Series {
     fn new(name: &str, s: T) -> Self {
         let series_slice = s.as_ref();
@@ -1334,6 +1751,94 @@ mod test {
     use crate::series::*;
     use arrow::array::*;
 
+    #[test]
+    fn test_series_hash() -> Result<()> {
+        let s = Series::new("a", &[Some(1), None, Some(1)]);
+        let hashes = s.hash(0)?;
+        assert_eq!(hashes.name(), "a");
+        assert_eq!(hashes.get(0), hashes.get(2));
+
+        let list = Series::new("list", &[Series::new("", &[1, 2])]);
+        assert!(matches!(
+            list.hash(0),
+            Err(PolarsError::InvalidOperation(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_rechunk() {
+        let mut s = Series::new("a", &[1, 2, 3]);
+        s.append(&Series::new("b", &[4, 5])).unwrap();
+        s.append(&Series::new("c", &[6])).unwrap();
+        assert_eq!(s.n_chunks(), 3);
+
+        assert_eq!(s.rechunk_with_sizes(None).unwrap().n_chunks(), 1);
+        let out = s.rechunk_with_sizes(Some(&[2, 0, 4])).unwrap();
+        assert_eq!(out.chunk_lengths(), &[2, 4]);
+        assert_eq!(out.name(), "a");
+        let values: Vec<_> = out.i32().unwrap().into_no_null_iter().collect();
+        assert_eq!(values, &[1, 2, 3, 4, 5, 6]);
+        assert!(s.rechunk_with_sizes(Some(&[2])).is_err());
+    }
+
+    #[test]
+    fn test_sort_with_options() {
+        let s = Series::new("a", &[Some(2), None, Some(1), Some(2), None]);
+        let sorted = |descending, nulls_last| {
+            let options = SortOptions {
+                descending,
+                nulls_last,
+                nans_last: !descending,
+            };
+            s.argsort_with_options(options)
+        };
+        assert_eq!(sorted(false, false), &[1, 4, 2, 0, 3]);
+        assert_eq!(sorted(false, true), &[2, 0, 3, 1, 4]);
+        assert_eq!(sorted(true, true), &[0, 3, 2, 1, 4]);
+        assert_eq!(sorted(true, false), &[1, 4, 0, 3, 2]);
+        assert_eq!(
+            s.argsort_with_options(SortOptions::from_reverse(true)),
+            s.argsort(true)
+        );
+    }
+
+    #[test]
+    fn test_sort_nans() {
+        let s = Series::new("a", &[Some(1.0), Some(f64::NAN), None, Some(0.5)]);
+        let sorted = |descending, nulls_last, nans_last| {
+            let options = SortOptions {
+                descending,
+                nulls_last,
+                nans_last,
+            };
+            s.argsort_with_options(options)
+        };
+        // NaN is the maximum by default
+        assert_eq!(sorted(false, false, true), &[2, 3, 0, 1]);
+        assert_eq!(sorted(true, true, false), &[1, 0, 3, 2]);
+        assert_eq!(sorted(false, false, false), &[2, 1, 3, 0]);
+        assert_eq!(sorted(false, true, false), &[1, 3, 0, 2]);
+        assert_eq!(sorted(true, true, true), &[0, 3, 1, 2]);
+        assert_eq!(sorted(true, false, true), &[2, 0, 3, 1]);
+        // the NaN option does not affect other dtypes
+        let s = Series::new("a", &[2, 1]);
+        let options = SortOptions {
+            nans_last: false,
+            ..Default::default()
+        };
+        assert_eq!(s.argsort_with_options(options), &[1, 0]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = Series::new("a", &[1i32, 2, 3]);
+        let b = Series::new("b", &[Some(2.0), None, Some(0.5)]);
+        assert_eq!(a.dot(&b).unwrap(), 3.5);
+        assert!(a.dot(&Series::new("c", &[1.0])).is_err());
+        assert!(a.dot(&Series::new("c", &["a", "b", "c"])).is_err());
+    }
+
     #[test]
     fn cast() {
         let ar = UInt32Chunked::new_from_slice("a", &[1, 2]);
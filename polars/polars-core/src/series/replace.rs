@@ -0,0 +1,129 @@
+//! Remap the values of a Series with a lookup table.
+use crate::prelude::*;
+use crate::utils::get_supertype;
+
+/// What [Series::replace] does with values that are not in the mapping.
+#[derive(Clone, Debug)]
+pub enum Unmapped {
+    /// Keep the original value.
+    Keep,
+    /// Replace the value with null.
+    Null,
+    /// Replace the value with the value of this Series of length 1.
+    Default(Series),
+}
+
+impl Series {
+    /// Replace the values that occur in `old` with the value at the same index in `new`.
+    /// Values that don't occur in `old` are handled according to `unmapped`.
+    ///
+    /// `old` is cast to the dtype of the Series and must not contain duplicates. The dtype of the
+    /// result is that of `new`, or the supertype of `new` and the values that are kept or used as
+    /// default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use polars_core::prelude::*;
+    /// let s = Series::new("a", &["x", "y", "z"]);
+    /// let old = Series::new("old", &["x", "z"]);
+    /// let new = Series::new("new", &["a", "c"]);
+    /// let out = s.replace(&old, &new, Unmapped::Keep).unwrap();
+    /// assert_eq!(Vec::from(out.utf8().unwrap()), &[Some("a"), Some("y"), Some("c")]);
+    /// ```
+    pub fn replace(&self, old: &Series, new: &Series, unmapped: Unmapped) -> Result<Series> {
+        if old.len() != new.len() {
+            return Err(PolarsError::ShapeMisMatch(
+                format!(
+                    "the mapping has {} old values and {} new values",
+                    old.len(),
+                    new.len()
+                )
+                .into(),
+            ));
+        }
+        let old = old.cast_with_datatype(self.dtype())?;
+        if old.n_unique()? != old.len() {
+            return Err(PolarsError::ValueError(
+                "the old values of the mapping contain duplicates".into(),
+            ));
+        }
+
+        // index in the mapping of every value, the old values are unique so every value has at
+        // most one match
+        let mut idx = vec![None; self.len()];
+        for (idx_left, idx_right) in self.hash_join_left(&old) {
+            idx[idx_left] = idx_right;
+        }
+        let mut mapped = new.take_opt_iter(&mut idx.iter().copied(), Some(self.len()));
+        mapped.rename(self.name());
+
+        let other = match unmapped {
+            Unmapped::Null => return Ok(mapped),
+            Unmapped::Keep => self.clone(),
+            Unmapped::Default(value) => {
+                if value.len() != 1 {
+                    return Err(PolarsError::ShapeMisMatch(
+                        "the default value must be a Series of length 1".into(),
+                    ));
+                }
+                value.expand_at_index(0, self.len())
+            }
+        };
+        let dtype = get_supertype(mapped.dtype(), other.dtype())?;
+        let mapped = mapped.cast_with_datatype(&dtype)?;
+        let other = other.cast_with_datatype(&dtype)?;
+        let mask: BooleanChunked = idx.iter().map(|idx| idx.is_some()).collect();
+        let mut out = mapped.zip_with(&mask, &other)?;
+        out.rename(self.name());
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_replace() -> Result<()> {
+        let s = Series::new("a", &[Some(1), Some(2), None, Some(3), Some(1)]);
+        let old = Series::new("old", &[1, 3]);
+        let new = Series::new("new", &[10, 30]);
+
+        let out = s.replace(&old, &new, Unmapped::Keep)?;
+        assert_eq!(out.name(), "a");
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(10), Some(2), None, Some(30), Some(10)]
+        );
+
+        let out = s.replace(&old, &new, Unmapped::Null)?;
+        assert_eq!(
+            Vec::from(out.i32()?),
+            &[Some(10), None, None, Some(30), Some(10)]
+        );
+
+        let out = s.replace(&old, &new, Unmapped::Default(Series::new("", &[0.5])))?;
+        assert_eq!(out.dtype(), &DataType::Float64);
+        assert_eq!(
+            Vec::from(out.f64()?),
+            &[Some(10.0), Some(0.5), Some(0.5), Some(30.0), Some(10.0)]
+        );
+
+        // map integers to strings
+        let new = Series::new("new", &["one", "three"]);
+        let out = s.replace(&old, &new, Unmapped::Null)?;
+        assert_eq!(
+            Vec::from(out.utf8()?),
+            &[Some("one"), None, None, Some("three"), Some("one")]
+        );
+
+        assert!(s
+            .replace(&Series::new("old", &[1, 1]), &new, Unmapped::Null)
+            .is_err());
+        assert!(s
+            .replace(&Series::new("old", &[1]), &new, Unmapped::Null)
+            .is_err());
+        Ok(())
+    }
+}
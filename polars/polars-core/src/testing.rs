@@ -33,6 +33,50 @@ impl Series {
             Some(sum) => sum as usize == self.len(),
         }
     }
+
+    /// Check if all values in series are equal where `None == None` evaluates to `true` and
+    /// floating point values are equal within a tolerance.
+    ///
+    /// Two floats `a` and `b` are equal if `|a - b| <= abs_tol + rel_tol * |b|`. `NaN` values are
+    /// equal to each other. Unless both series are numeric and at least one of them has a float
+    /// dtype, this is the same as [series_equal_missing](Series::series_equal_missing).
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let a = Series::new("a", &[Some(0.1 + 0.2), None]);
+    /// let b = Series::new("b", &[Some(0.3), None]);
+    /// assert!(!a.series_equal_missing(&b));
+    /// assert!(a.series_equal_tolerance(&b, 1e-12, 0.0));
+    /// ```
+    pub fn series_equal_tolerance(&self, other: &Series, abs_tol: f64, rel_tol: f64) -> bool {
+        let is_float = |s: &Series| matches!(s.dtype(), DataType::Float32 | DataType::Float64);
+        let compare_floats = self.dtype().is_numeric()
+            && other.dtype().is_numeric()
+            && (is_float(self) || is_float(other));
+        if !compare_floats {
+            return self.series_equal_missing(other);
+        }
+        if self.len() != other.len() || self.null_count() != other.null_count() {
+            return false;
+        }
+        let (left, right) = match (self.cast::<Float64Type>(), other.cast::<Float64Type>()) {
+            (Ok(left), Ok(right)) => (left, right),
+            _ => return false,
+        };
+        left.f64()
+            .unwrap()
+            .into_iter()
+            .zip(right.f64().unwrap().into_iter())
+            .all(|opt_vals| match opt_vals {
+                (None, None) => true,
+                (Some(l), Some(r)) => {
+                    l == r
+                        || (l.is_nan() && r.is_nan())
+                        || (l - r).abs() <= abs_tol + rel_tol * r.abs()
+                }
+                _ => false,
+            })
+    }
 }
 
 impl DataFrame {
@@ -61,6 +105,158 @@ impl DataFrame {
         }
         true
     }
+
+    /// Check if `DataFrames` have the same column names and if all their columns are equal
+    /// according to [series_equal_tolerance](Series::series_equal_tolerance).
+    pub fn frame_equal_tolerance(&self, other: &DataFrame, abs_tol: f64, rel_tol: f64) -> bool {
+        if self.shape() != other.shape() || self.get_column_names() != other.get_column_names() {
+            return false;
+        }
+        self.get_columns()
+            .iter()
+            .zip(other.get_columns())
+            .all(|(left, right)| left.series_equal_tolerance(right, abs_tol, rel_tol))
+    }
+}
+
+/// Assert that two [Series] are equal, where `None == None` evaluates to `true`. Floats can be
+/// compared within a tolerance, see [Series::series_equal_tolerance]. Both series are printed if
+/// the assertion fails.
+///
+/// ```
+/// # use polars_core::prelude::*;
+/// # use polars_core::assert_series_eq;
+/// let a = Series::new("a", &[Some(1.0), None]);
+/// assert_series_eq!(a, Series::new("a", &[Some(1.0), None]));
+/// assert_series_eq!(a, Series::new("a", &[Some(1.001), None]), abs_tol = 0.01);
+/// assert_series_eq!(a, Series::new("a", &[Some(1.001), None]), abs_tol = 0.0, rel_tol = 0.01);
+/// ```
+#[macro_export]
+macro_rules! assert_series_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_series_eq!($left, $right, abs_tol = 0.0, rel_tol = 0.0)
+    };
+    ($left:expr, $right:expr, abs_tol = $abs_tol:expr) => {
+        $crate::assert_series_eq!($left, $right, abs_tol = $abs_tol, rel_tol = 0.0)
+    };
+    ($left:expr, $right:expr, abs_tol = $abs_tol:expr, rel_tol = $rel_tol:expr) => {{
+        let (left, right) = (&$left, &$right);
+        if !left.series_equal_tolerance(right, $abs_tol, $rel_tol) {
+            panic!(
+                "assertion failed: series are not equal\nleft: {:?}\nright: {:?}",
+                left, right
+            )
+        }
+    }};
+}
+
+/// Assert that two [DataFrame]s are equal, see [DataFrame::frame_equal_tolerance] and
+/// [assert_series_eq]. Both frames are printed if the assertion fails.
+///
+/// ```
+/// # use polars_core::prelude::*;
+/// # use polars_core::assert_frame_eq;
+/// let df = df!("a" => &[Some(1.0), None]).unwrap();
+/// assert_frame_eq!(df, df.clone());
+/// assert_frame_eq!(df, df!("a" => &[Some(1.0 + 1e-12), None]).unwrap(), abs_tol = 1e-9);
+/// ```
+#[macro_export]
+macro_rules! assert_frame_eq {
+    ($left:expr, $right:expr) => {
+        $crate::assert_frame_eq!($left, $right, abs_tol = 0.0, rel_tol = 0.0)
+    };
+    ($left:expr, $right:expr, abs_tol = $abs_tol:expr) => {
+        $crate::assert_frame_eq!($left, $right, abs_tol = $abs_tol, rel_tol = 0.0)
+    };
+    ($left:expr, $right:expr, abs_tol = $abs_tol:expr, rel_tol = $rel_tol:expr) => {{
+        let (left, right) = (&$left, &$right);
+        if !left.frame_equal_tolerance(right, $abs_tol, $rel_tol) {
+            panic!(
+                "assertion failed: frames are not equal\nleft: {:?}\nright: {:?}",
+                left, right
+            )
+        }
+    }};
+}
+
+/// Generators of datasets of configurable size, used by the benchmarks.
+///
+/// The data is pseudo random but deterministic: the same `seed` always generates the same data,
+/// so runs of a benchmark can be compared.
+pub mod generate {
+    use crate::prelude::*;
+
+    /// splitmix64, small and fast enough for generating benchmark data.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        /// Value in `[0, n)`.
+        fn below(&mut self, n: u64) -> u64 {
+            self.next_u64() % n
+        }
+
+        /// Value in `[0, 1)`.
+        fn unit(&mut self) -> f64 {
+            (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    /// `size` integers in `[0, n_unique)`.
+    pub fn ints(name: &str, size: usize, n_unique: u64, seed: u64) -> Series {
+        let mut rng = Rng(seed);
+        let v: AlignedVec<i64> = (0..size).map(|_| rng.below(n_unique) as i64).collect();
+        Int64Chunked::new_from_aligned_vec(name, v).into_series()
+    }
+
+    /// `size` floats in `[0, 1)`.
+    pub fn floats(name: &str, size: usize, seed: u64) -> Series {
+        let mut rng = Rng(seed);
+        let v: AlignedVec<f64> = (0..size).map(|_| rng.unit()).collect();
+        Float64Chunked::new_from_aligned_vec(name, v).into_series()
+    }
+
+    /// `size` strings with `n_unique` distinct values of the form `"id{}"`.
+    pub fn strings(name: &str, size: usize, n_unique: u64, seed: u64) -> Series {
+        let mut rng = Rng(seed);
+        let ca: Utf8Chunked = (0..size)
+            .map(|_| format!("id{}", rng.below(n_unique)))
+            .collect();
+        let mut s = ca.into_series();
+        s.rename(name);
+        s
+    }
+
+    /// Replace a fraction `null_fraction` of the values of `s` with null.
+    pub fn with_nulls(s: &Series, null_fraction: f64, seed: u64) -> Result<Series> {
+        let mut rng = Rng(seed);
+        let mask: BooleanChunked = (0..s.len()).map(|_| rng.unit() >= null_fraction).collect();
+        let nulls = Series::full_null(s.name(), s.len(), s.dtype());
+        s.zip_with(&mask, &nulls)
+    }
+
+    /// A frame of `size` rows in the spirit of the h2oai db-benchmark, with the columns:
+    ///
+    /// * `id_str`: Utf8 group keys with `n_groups` distinct values
+    /// * `id_int`: Int64 group keys with `n_groups` distinct values
+    /// * `v_int`: Int64 values in `[0, 100)`
+    /// * `v_float`: Float64 values in `[0, 1)` of which 5% are null
+    pub fn frame(size: usize, n_groups: u64, seed: u64) -> Result<DataFrame> {
+        let v_float = floats("v_float", size, seed.wrapping_add(3));
+        DataFrame::new(vec![
+            strings("id_str", size, n_groups, seed),
+            ints("id_int", size, n_groups, seed.wrapping_add(1)),
+            ints("v_int", size, 100, seed.wrapping_add(2)),
+            with_nulls(&v_float, 0.05, seed.wrapping_add(4))?,
+        ])
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +273,52 @@ mod test {
         assert!(s.series_equal_missing(&s));
     }
 
+    #[test]
+    fn test_series_equal_tolerance() {
+        let a = Series::new("a", &[Some(1.0), None, Some(f64::NAN), Some(100.0)]);
+        let b = Series::new("b", &[Some(1.0 + 1e-10), None, Some(f64::NAN), Some(101.0)]);
+        assert!(!a.series_equal_tolerance(&b, 1e-8, 0.0));
+        assert!(a.series_equal_tolerance(&b, 1e-8, 0.01));
+        // nulls must be at the same positions
+        let c = Series::new("c", &[None, Some(1.0), Some(f64::NAN), Some(100.0)]);
+        assert!(!a.series_equal_tolerance(&c, 1.0, 1.0));
+
+        // integers are compared with floats
+        let a = Series::new("a", &[1i32, 2]);
+        let b = Series::new("b", &[1.0, 2.0 + 1e-12]);
+        assert!(a.series_equal_tolerance(&b, 1e-9, 0.0));
+
+        let a = Series::new("a", &[Some("x"), None]);
+        assert!(a.series_equal_tolerance(&a, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_assert_macros() {
+        let a = Series::new("a", &[Some(0.1 + 0.2), None]);
+        let b = Series::new("a", &[Some(0.3), None]);
+        crate::assert_series_eq!(a, b, abs_tol = 1e-12);
+        let df1 = DataFrame::new(vec![a]).unwrap();
+        let df2 = DataFrame::new(vec![b]).unwrap();
+        crate::assert_frame_eq!(df1, df2, abs_tol = 0.0, rel_tol = 1e-12);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::assert_frame_eq!(df1, df2)
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_generate() -> Result<()> {
+        let df = generate::frame(1000, 10, 0)?;
+        assert_eq!(df.shape(), (1000, 4));
+        assert!(df.column("id_str")?.n_unique()? <= 10);
+        assert!(df.column("id_int")?.max::<i64>().unwrap() < 10);
+        assert!(df.column("v_float")?.null_count() > 0);
+        // deterministic
+        assert!(df.frame_equal_missing(&generate::frame(1000, 10, 0)?));
+        assert!(!df.frame_equal_missing(&generate::frame(1000, 10, 1)?));
+        Ok(())
+    }
+
     #[test]
     fn test_df_equal() {
         let a = Series::new("a", [1, 2, 3].as_ref());
@@ -7,6 +7,8 @@ pub use num_cpus;
 pub use polars_arrow::utils::TrustMyLength;
 pub use rayon;
 use rayon::prelude::*;
+#[cfg(feature = "strings")]
+pub use regex;
 use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 
@@ -222,6 +224,30 @@ where
     }
 }
 
+/// Convert an argument that can be given once or once per item, e.g. the sort order of the
+/// columns in [DataFrame::sort], to a Vec.
+pub trait IntoVec<T> {
+    fn into_vec(self) -> Vec<T>;
+}
+
+impl IntoVec<bool> for bool {
+    fn into_vec(self) -> Vec<bool> {
+        vec![self]
+    }
+}
+
+impl IntoVec<bool> for Vec<bool> {
+    fn into_vec(self) -> Vec<bool> {
+        self
+    }
+}
+
+impl<T: AsRef<[bool]> + ?Sized> IntoVec<bool> for &T {
+    fn into_vec(self) -> Vec<bool> {
+        self.as_ref().to_vec()
+    }
+}
+
 #[macro_export]
 macro_rules! match_arrow_data_type_apply_macro {
     ($obj:expr, $macro:ident, $macro_utf8:ident, $macro_bool:ident $(, $opt_args:expr)*) => {{
@@ -342,9 +368,26 @@ macro_rules! static_zip {
     };
 }
 
+/// Create a [DataFrame](crate::frame::DataFrame) from column names and values. The values are
+/// anything a [Series](crate::series::Series) can be created from with `Series::new`, e.g.
+/// slices of values, slices of `Option`s for null values or a `Series` that is renamed.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// let df = df! {
+///     "a" => &[Some(1), None, Some(3)],
+///     "b" => &["x", "y", "z"],
+///     "c" => Series::new("to_be_renamed", &[1.0, 2.0, 3.0]),
+/// }
+/// .unwrap();
+/// assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+/// assert_eq!(df["a"].null_count(), 1);
+/// ```
 #[macro_export]
 macro_rules! df {
-    ($($col_name:expr => $slice:expr), +) => {
+    ($($col_name:expr => $slice:expr), + $(,)?) => {
         {
             let mut columns = vec![];
             $(
@@ -595,37 +638,63 @@ pub(crate) trait CustomIterTools: Iterator {
 
 impl<T: ?Sized> CustomIterTools for T where T: Iterator {}
 
+/// The chunk lengths obtained by splitting at the chunk boundaries of all the given chunk
+/// lengths. Empty chunks are dropped.
+fn merge_chunk_ids(chunk_ids: &[&[usize]]) -> Vec<usize> {
+    let mut ends = Vec::with_capacity(chunk_ids.iter().map(|ids| ids.len()).sum());
+    for ids in chunk_ids {
+        ends.extend(ids.iter().scan(0, |end, len| {
+            *end += len;
+            Some(*end)
+        }));
+    }
+    ends.sort_unstable();
+    ends.dedup();
+
+    let mut start = 0;
+    ends.into_iter()
+        .filter(|end| *end > 0)
+        .map(|end| {
+            let len = end - start;
+            start = end;
+            len
+        })
+        .collect()
+}
+
+fn split_to_chunk_id<'a, T>(ca: &'a ChunkedArray<T>, chunk_id: &[usize]) -> Cow<'a, ChunkedArray<T>>
+where
+    T: PolarsDataType,
+{
+    if ca.chunk_id() == chunk_id {
+        Cow::Borrowed(ca)
+    } else {
+        Cow::Owned(ca.split_chunks(chunk_id))
+    }
+}
+
+/// Align the chunks of two arrays of equal length. Chunks are split at the boundaries of the
+/// chunks of the other array, which doesn't copy any data.
 pub(crate) fn align_chunks_binary<'a, T, B>(
     left: &'a ChunkedArray<T>,
     right: &'a ChunkedArray<B>,
 ) -> (Cow<'a, ChunkedArray<T>>, Cow<'a, ChunkedArray<B>>)
 where
-    ChunkedArray<B>: ChunkOps,
-    ChunkedArray<T>: ChunkOps,
     B: PolarsDataType,
     T: PolarsDataType,
 {
-    match (left.chunks.len(), right.chunks.len()) {
-        (1, 1) => (Cow::Borrowed(left), Cow::Borrowed(right)),
-        (_, 1) => (
-            Cow::Borrowed(left),
-            Cow::Owned(right.match_chunks(left.chunk_id())),
-        ),
-        (1, _) => (
-            Cow::Owned(left.match_chunks(right.chunk_id())),
-            Cow::Borrowed(right),
-        ),
-        (_, _) => {
-            // could optimize to choose to rechunk a primitive and not a string or list type
-            let left = left.rechunk();
-            (
-                Cow::Owned(left.match_chunks(right.chunk_id())),
-                Cow::Borrowed(right),
-            )
-        }
+    debug_assert_eq!(left.len(), right.len());
+    if left.chunk_id() == right.chunk_id() {
+        return (Cow::Borrowed(left), Cow::Borrowed(right));
     }
+    let chunk_id = merge_chunk_ids(&[left.chunk_id(), right.chunk_id()]);
+    (
+        split_to_chunk_id(left, &chunk_id),
+        split_to_chunk_id(right, &chunk_id),
+    )
 }
 
+/// Align the chunks of three arrays of equal length. See [align_chunks_binary].
 #[allow(clippy::type_complexity)]
 pub(crate) fn align_chunks_ternary<'a, A, B, C>(
     a: &'a ChunkedArray<A>,
@@ -637,63 +706,18 @@ pub(crate) fn align_chunks_ternary<'a, A, B, C>(
     Cow<'a, ChunkedArray<C>>,
 )
 where
-    ChunkedArray<A>: ChunkOps,
-    ChunkedArray<B>: ChunkOps,
-    ChunkedArray<C>: ChunkOps,
     A: PolarsDataType,
     B: PolarsDataType,
     C: PolarsDataType,
 {
-    match (a.chunks.len(), b.chunks.len(), c.chunks.len()) {
-        (1, 1, 1) => (Cow::Borrowed(a), Cow::Borrowed(b), Cow::Borrowed(c)),
-        (_, 1, 1) => (
-            Cow::Borrowed(a),
-            Cow::Owned(b.match_chunks(a.chunk_id())),
-            Cow::Owned(c.match_chunks(a.chunk_id())),
-        ),
-        (1, 1, _) => (
-            Cow::Owned(a.match_chunks(c.chunk_id())),
-            Cow::Owned(b.match_chunks(c.chunk_id())),
-            Cow::Borrowed(c),
-        ),
-        (1, _, 1) => (
-            Cow::Owned(a.match_chunks(b.chunk_id())),
-            Cow::Borrowed(b),
-            Cow::Owned(c.match_chunks(b.chunk_id())),
-        ),
-        (1, _, _) => {
-            let b = b.rechunk();
-            (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
-            )
-        }
-        (_, 1, _) => {
-            let a = a.rechunk();
-            (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
-            )
-        }
-        (_, _, 1) => {
-            let b = b.rechunk();
-            (
-                Cow::Borrowed(a),
-                Cow::Owned(b.match_chunks(a.chunk_id())),
-                Cow::Owned(c.match_chunks(a.chunk_id())),
-            )
-        }
-        _ => {
-            // could optimize to choose to rechunk a primitive and not a string or list type
-            let a = a.rechunk();
-            let b = b.rechunk();
-            (
-                Cow::Owned(a.match_chunks(c.chunk_id())),
-                Cow::Owned(b.match_chunks(c.chunk_id())),
-                Cow::Borrowed(c),
-            )
-        }
+    debug_assert!(a.len() == b.len() && b.len() == c.len());
+    if a.chunk_id() == b.chunk_id() && b.chunk_id() == c.chunk_id() {
+        return (Cow::Borrowed(a), Cow::Borrowed(b), Cow::Borrowed(c));
     }
+    let chunk_id = merge_chunk_ids(&[a.chunk_id(), b.chunk_id(), c.chunk_id()]);
+    (
+        split_to_chunk_id(a, &chunk_id),
+        split_to_chunk_id(b, &chunk_id),
+        split_to_chunk_id(c, &chunk_id),
+    )
 }
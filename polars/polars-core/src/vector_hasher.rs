@@ -38,6 +38,17 @@ impl Default for IdHasher {
 
 pub(crate) type IdBuildHasher = BuildHasherDefault<IdHasher>;
 
+/// A `RandomState` with keys derived from `seed` only, such that hashes computed with it are
+/// deterministic: the same seed gives the same hashes for every run of a program.
+pub(crate) fn seeded_random_state(seed: u64) -> RandomState {
+    RandomState::with_seeds(
+        seed,
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+    )
+}
+
 pub(crate) struct IdxHash {
     // idx in row of Series, DataFrame
     pub(crate) idx: usize,
@@ -61,7 +72,29 @@ impl IdxHash {
 /// Check if a hash should be processed in that thread.
 #[inline]
 pub(crate) fn this_thread(h: u64, thread_no: u64, n_threads: u64) -> bool {
-    (h + thread_no) % n_threads == 0
+    h % n_threads == thread_no
+}
+
+/// Estimate the number of unique keys from the hashes of the first keys of every partition.
+/// Used to size hash tables, so that they don't need to grow while they are filled.
+fn estimate_n_unique<T>(hashes_and_keys: &[Vec<(u64, T)>]) -> usize {
+    const SAMPLE_SIZE: usize = 1024;
+    let size = hashes_and_keys.iter().map(|v| v.len()).sum::<usize>();
+    let mut sample = hashes_and_keys
+        .iter()
+        .flat_map(|v| v.iter().take(SAMPLE_SIZE).map(|(h, _)| *h))
+        .collect::<Vec<_>>();
+    if sample.is_empty() {
+        return 0;
+    }
+    let n_sampled = sample.len();
+    sample.sort_unstable();
+    sample.dedup();
+    // all keys in the sample are unique, so there may be as many unique keys as there are keys
+    if sample.len() == n_sampled {
+        return size;
+    }
+    ((sample.len() as f64 / n_sampled as f64) * size as f64) as usize
 }
 
 fn finish_table_from_key_hashes<T>(
@@ -122,7 +155,7 @@ where
 {
     let n_threads = iters.len();
     let (hashes_and_keys, random_state) = create_hash_and_keys_threaded_vectorized(iters, None);
-    let size = hashes_and_keys.iter().fold(0, |acc, v| acc + v.len());
+    let n_unique = estimate_n_unique(&hashes_and_keys);
 
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
@@ -133,7 +166,7 @@ where
             let hashes_and_keys = &hashes_and_keys;
             let thread_no = thread_no as u64;
             let mut hash_tbl: HashMap<T, Vec<usize>, RandomState> =
-                HashMap::with_capacity_and_hasher(size / (5 * n_threads), random_state);
+                HashMap::with_capacity_and_hasher(n_unique / n_threads, random_state);
 
             let n_threads = n_threads as u64;
             let mut offset = 0;
@@ -53,7 +53,7 @@
 use crate::csv_core::csv::{build_csv_reader, SequentialReader};
 use crate::{SerReader, SerWriter};
 pub use arrow::csv::WriterBuilder;
-use polars_core::prelude::*;
+use polars_core::{config, prelude::*, progress::CancellationToken};
 use std::fs::File;
 use std::io::{Read, Seek, Write};
 use std::sync::Arc;
@@ -80,6 +80,7 @@ where
     }
 
     fn finish(self, df: &mut DataFrame) -> Result<()> {
+        df.check_arrow_compatible()?;
         let mut csv_writer = self.writer_builder.build(self.buffer);
 
         let iter = df.iter_record_batches(self.buffer_size);
@@ -182,6 +183,7 @@ where
     schema_overwrite: Option<&'a Schema>,
     sample_size: usize,
     stable_parser: bool,
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -243,7 +245,8 @@ where
         self
     }
 
-    /// Set the CSV reader to infer the schema of the file
+    /// Set the CSV reader to infer the schema of the file. Defaults to
+    /// [infer_schema_length](polars_core::config::infer_schema_length) rows.
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         // used by error ignore logic
         self.max_records = max_records;
@@ -299,8 +302,16 @@ where
         self
     }
 
+    /// Abort parsing with a `PolarsError::Cancelled` error when this token is cancelled.
+    /// The token is checked between the parsed batches.
+    pub fn with_cancellation_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancellation_token = token;
+        self
+    }
+
     pub fn build_inner_reader(self) -> Result<SequentialReader<R>> {
-        build_csv_reader(
+        let cancellation_token = self.cancellation_token;
+        let mut reader = build_csv_reader(
             self.reader,
             self.stop_after_n_rows,
             self.skip_rows,
@@ -318,7 +329,9 @@ where
             self.schema_overwrite,
             self.sample_size,
             self.stable_parser,
-        )
+        )?;
+        reader.set_cancellation_token(cancellation_token);
+        Ok(reader)
     }
 }
 
@@ -340,7 +353,7 @@ where
             reader,
             rechunk: true,
             stop_after_n_rows: None,
-            max_records: Some(128),
+            max_records: Some(config::infer_schema_length()),
             skip_rows: 0,
             projection: None,
             batch_size: 32,
@@ -355,6 +368,7 @@ where
             schema_overwrite: None,
             sample_size: 1024,
             stable_parser: false,
+            cancellation_token: None,
         }
     }
 
@@ -5,9 +5,13 @@ use crate::csv_core::chunked_parser::{
 use crate::csv_core::utils::*;
 use crate::csv_core::{buffer::*, parser::*};
 use crate::PhysicalIoExpr;
-use crate::ScanAggregation;
+use crate::{count_rows_to_df, ScanAggregation};
 use csv::ByteRecordsIntoIter;
-use polars_core::{prelude::*, POOL};
+use polars_core::{
+    prelude::*,
+    progress::{CancellationToken, Progress},
+    POOL,
+};
 use rayon::prelude::*;
 use std::fmt;
 use std::io::{Read, Seek};
@@ -38,6 +42,7 @@ pub struct SequentialReader<R: Read> {
     delimiter: u8,
     sample_size: usize,
     stable_parser: bool,
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl<R> fmt::Debug for SequentialReader<R>
@@ -110,9 +115,14 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             delimiter,
             sample_size,
             stable_parser,
+            cancellation_token: None,
         }
     }
 
+    pub(crate) fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation_token = token;
+    }
+
     fn find_starting_point<'a>(&self, mut bytes: &'a [u8]) -> Result<&'a [u8]> {
         // Skip all leading white space and the occasional utf8-bom
         bytes = skip_line_ending(skip_whitespace(skip_bom(bytes)).0).0;
@@ -132,6 +142,17 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         Ok(bytes)
     }
 
+    /// Count the rows that would be parsed without parsing them. Like the parser, this skips
+    /// empty lines.
+    fn count_rows(&self, bytes: &[u8]) -> Result<usize> {
+        let bytes = self.find_starting_point(bytes)?;
+        let n_rows = bytes
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .count();
+        Ok(self.n_rows.map_or(n_rows, |n| std::cmp::min(n, n_rows)))
+    }
+
     fn parse_csv_chunked(
         &mut self,
         predicate: Option<&Arc<dyn PhysicalIoExpr>>,
@@ -139,6 +160,7 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         capacity: usize,
         n_threads: usize,
         bytes: &[u8],
+        progress: &Progress,
     ) -> Result<Vec<DataFrame>> {
         let projection = self
             .projection
@@ -205,6 +227,7 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                                 ignore_parser_errors,
                                 encoding,
                             )?;
+                            progress.update(correctly_parsed)?;
 
                             if total_bytes_offset >= stop_at_nbytes {
                                 break;
@@ -253,7 +276,12 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         Ok(parsed_dfs)
     }
 
-    fn parse_csv_fast(&mut self, n_threads: usize, bytes: &[u8]) -> Result<DataFrame> {
+    fn parse_csv_fast(
+        &mut self,
+        n_threads: usize,
+        bytes: &[u8],
+        progress: &Progress,
+    ) -> Result<DataFrame> {
         // Make the variable mutable so that we can reassign the sliced file to this variable.
         let mut bytes = self.find_starting_point(bytes)?;
 
@@ -322,6 +350,7 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                         &mut buffers,
                         ignore_parser_errors,
                     )?;
+                    progress.update(buffers.first().map(|buf| buf.len()).unwrap_or(0))?;
                     Ok(buffers)
                 })
                 .collect::<Result<Vec<_>>>()
@@ -368,7 +397,22 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         aggregate: Option<&[ScanAggregation]>,
     ) -> Result<DataFrame> {
+        // counting the rows doesn't need the values of the columns
+        if let (None, Some(aggregate), Some(path)) = (&predicate, aggregate, &self.path) {
+            if aggregate
+                .iter()
+                .all(|scan_agg| matches!(scan_agg, ScanAggregation::Count { .. }))
+            {
+                let file = std::fs::File::open(path)?;
+                let mmap = unsafe { memmap::Mmap::map(&file)? };
+                let n_rows = self.count_rows(&mmap[..])?;
+                return Ok(count_rows_to_df(aggregate, n_rows).unwrap());
+            }
+        }
+
         let n_threads = self.n_threads.unwrap_or_else(num_cpus::get);
+        let progress = Progress::start("csv", self.n_rows)
+            .with_cancellation_token(self.cancellation_token.clone());
 
         let mut df = if predicate.is_some() || self.stable_parser || aggregate.is_some() {
             let mut capacity = self.batch_size * CAPACITY_MULTIPLIER;
@@ -382,8 +426,14 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
             let mmap = unsafe { memmap::Mmap::map(&file).unwrap() };
             let bytes = mmap[..].as_ref();
 
-            let parsed_dfs =
-                self.parse_csv_chunked(predicate.as_ref(), aggregate, capacity, n_threads, bytes)?;
+            let parsed_dfs = self.parse_csv_chunked(
+                predicate.as_ref(),
+                aggregate,
+                capacity,
+                n_threads,
+                bytes,
+                &progress,
+            )?;
             polars_core::utils::accumulate_dataframes_vertical(parsed_dfs)?
         } else {
             match (&self.path, self.record_iter.is_some()) {
@@ -391,7 +441,7 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                     let file = std::fs::File::open(p).unwrap();
                     let mmap = unsafe { memmap::Mmap::map(&file).unwrap() };
                     let bytes = mmap[..].as_ref();
-                    self.parse_csv_fast(n_threads, bytes)?
+                    self.parse_csv_fast(n_threads, bytes, &progress)?
                 }
                 (None, true) => {
                     let mut r = std::mem::take(&mut self.record_iter).unwrap().into_reader();
@@ -400,7 +450,7 @@ impl<R: Read + Sync + Send> SequentialReader<R> {
                     if bytes[bytes.len() - 1] != b'\n' || bytes[bytes.len() - 1] != b'\r' {
                         bytes.push(b'\n')
                     }
-                    self.parse_csv_fast(n_threads, &bytes)?
+                    self.parse_csv_fast(n_threads, &bytes, &progress)?
                 }
                 _ => return Err(PolarsError::Other("file or reader must be set".into())),
             }
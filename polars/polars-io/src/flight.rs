@@ -0,0 +1,441 @@
+//! # Arrow Flight integration.
+//!
+//! [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) is an RPC framework to
+//! transfer Arrow data between services. This module contains a client that fetches a Flight
+//! stream into a `DataFrame` and a service that serves `DataFrame`s as Flight streams.
+//!
+//! Both are `async` and need to run in a [tokio](https://tokio.rs) runtime.
+//!
+//! ## Fetch a DataFrame
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::flight::FlightReader;
+//!
+//! async fn fetch() -> Result<DataFrame> {
+//!     FlightReader::new("http://localhost:50051", b"my_table".to_vec())
+//!         .finish()
+//!         .await
+//! }
+//! ```
+//!
+//! ## Serve LazyFrame results
+//!
+//! The service calls a closure with the ticket of every `DoGet` request. To serve the result of
+//! a lazy query, collect it in that closure:
+//!
+//! ```ignore
+//! use polars_io::flight::FlightFrameService;
+//! use polars_lazy::prelude::*;
+//!
+//! async fn serve(lf: LazyFrame) -> Result<(), Box<dyn std::error::Error>> {
+//!     let service = FlightFrameService::new(move |_ticket: &[u8]| lf.clone().collect());
+//!     tonic::transport::Server::builder()
+//!         .add_service(service.into_server())
+//!         .serve("0.0.0.0:50051".parse()?)
+//!         .await?;
+//!     Ok(())
+//! }
+//! ```
+use arrow::array::{ArrayRef, PrimitiveBuilder, StringBuilder, StringDictionaryBuilder};
+use arrow::buffer::Buffer;
+use arrow::compute::cast;
+use arrow::datatypes::SchemaRef;
+use arrow::ipc;
+use arrow::ipc::reader::read_dictionary;
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::utils::{
+    flight_data_from_arrow_batch, flight_data_from_arrow_schema, flight_data_to_arrow_batch,
+};
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use futures::Stream;
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use std::convert::TryFrom;
+use std::pin::Pin;
+use tonic::{Request, Response, Status, Streaming};
+
+fn flight_err<E: std::fmt::Display>(e: E) -> PolarsError {
+    PolarsError::Other(format!("arrow flight: {}", e).into())
+}
+
+/// Categorical columns are sent as dictionary arrays with `u32` keys and string values.
+fn dictionary_type() -> ArrowDataType {
+    ArrowDataType::Dictionary(
+        Box::new(ArrowDataType::UInt32),
+        Box::new(ArrowDataType::Utf8),
+    )
+}
+
+/// The schema of the Flight stream of a `DataFrame`. Every categorical column gets its own
+/// dictionary id.
+fn flight_schema(df: &DataFrame) -> ArrowSchema {
+    let fields = df
+        .get_columns()
+        .iter()
+        .enumerate()
+        .map(|(i, s)| match s.dtype() {
+            DataType::Categorical => {
+                ArrowField::new_dict(s.name(), dictionary_type(), true, i as i64, false)
+            }
+            dt => ArrowField::new(s.name(), dt.to_arrow(), true),
+        })
+        .collect();
+    ArrowSchema::new(fields)
+}
+
+/// The array of a single chunked column in a Flight record batch.
+fn flight_array(s: &Series) -> Result<ArrayRef> {
+    match s.dtype() {
+        DataType::Categorical => {
+            let s = s.cast::<Utf8Type>()?;
+            let ca = s.utf8()?;
+            let mut builder = StringDictionaryBuilder::new(
+                PrimitiveBuilder::<UInt32Type>::new(ca.len()),
+                StringBuilder::new(ca.len()),
+            );
+            for opt_v in ca {
+                match opt_v {
+                    Some(v) => {
+                        builder.append(v)?;
+                    }
+                    None => builder.append_null()?,
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        _ => Ok(s.chunks()[0].clone()),
+    }
+}
+
+/// Encode a `DataFrame` as the messages of a Flight stream: the schema followed by the
+/// dictionaries and the record batches.
+pub(crate) fn frame_to_flight_data(df: &DataFrame, batch_size: usize) -> Result<Vec<FlightData>> {
+    let options = IpcWriteOptions::default();
+    let schema = Arc::new(flight_schema(df));
+    let df = df.agg_chunks();
+
+    let mut flight_data = vec![flight_data_from_arrow_schema(&schema, &options)];
+    let mut offset = 0;
+    while offset < df.height() {
+        let length = std::cmp::min(batch_size, df.height() - offset);
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| flight_array(&s.slice(offset, length)?))
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        let (dictionaries, batch) = flight_data_from_arrow_batch(&batch, &options);
+        flight_data.extend(dictionaries);
+        flight_data.push(batch);
+        offset += length;
+    }
+    Ok(flight_data)
+}
+
+/// Convert a decoded record batch to a `DataFrame`, dictionary arrays become categorical
+/// columns.
+fn batch_to_frame(batch: RecordBatch) -> Result<DataFrame> {
+    let columns = batch
+        .schema()
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, arr)| match field.data_type() {
+            ArrowDataType::Dictionary(_, values) => {
+                let arr = cast(arr, values)?;
+                Series::try_from((field.name().as_str(), arr))?.cast::<CategoricalType>()
+            }
+            _ => Series::try_from((field.name().as_str(), arr.clone())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
+
+/// Decode the dictionary and record batch messages of a Flight stream with the given schema.
+pub(crate) fn flight_data_to_frame<I>(
+    schema: SchemaRef,
+    data: I,
+    rechunk: bool,
+) -> Result<DataFrame>
+where
+    I: IntoIterator<Item = FlightData>,
+{
+    let mut dictionaries_by_field = vec![None; schema.fields().len()];
+    let mut dfs = Vec::new();
+    for data in data {
+        let message = ipc::root_as_message(&data.data_header[..]).map_err(flight_err)?;
+        match message.header_type() {
+            ipc::MessageHeader::DictionaryBatch => {
+                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                    flight_err("dictionary message does not contain a dictionary batch")
+                })?;
+                read_dictionary(
+                    &Buffer::from(&data.data_body),
+                    batch,
+                    &schema,
+                    &mut dictionaries_by_field,
+                )?;
+            }
+            _ => {
+                let batch =
+                    flight_data_to_arrow_batch(&data, schema.clone(), &dictionaries_by_field)?;
+                dfs.push(batch_to_frame(batch)?);
+            }
+        }
+    }
+    if dfs.is_empty() {
+        // start from an empty batch of the dictionary values, as there is no dictionary to decode
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| match f.data_type() {
+                ArrowDataType::Dictionary(_, values) => {
+                    ArrowField::new(f.name(), (**values).clone(), true)
+                }
+                _ => f.clone(),
+            })
+            .collect();
+        let empty = RecordBatch::new_empty(Arc::new(ArrowSchema::new(fields)));
+        let columns = DataFrame::try_from(empty)?
+            .get_columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(s, f)| match f.data_type() {
+                ArrowDataType::Dictionary(_, _) => s.cast::<CategoricalType>(),
+                _ => Ok(s.clone()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        return DataFrame::new(columns);
+    }
+    let df = accumulate_dataframes_vertical(dfs)?;
+    match rechunk {
+        true => Ok(df.agg_chunks()),
+        false => Ok(df),
+    }
+}
+
+/// Fetch a `DataFrame` from an Arrow Flight service.
+pub struct FlightReader {
+    endpoint: String,
+    ticket: Vec<u8>,
+    rechunk: bool,
+}
+
+impl FlightReader {
+    /// Create a reader that requests the stream identified by `ticket` from the Flight service
+    /// at `endpoint`, e.g. `"http://localhost:50051"`.
+    pub fn new<S: Into<String>>(endpoint: S, ticket: Vec<u8>) -> Self {
+        FlightReader {
+            endpoint: endpoint.into(),
+            ticket,
+            rechunk: true,
+        }
+    }
+
+    /// Rechunk to a single chunk after reading the stream.
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Connect to the service and read the stream into a `DataFrame`.
+    pub async fn finish(self) -> Result<DataFrame> {
+        let mut client = FlightServiceClient::connect(self.endpoint)
+            .await
+            .map_err(flight_err)?;
+        let mut stream = client
+            .do_get(Ticket {
+                ticket: self.ticket,
+            })
+            .await
+            .map_err(flight_err)?
+            .into_inner();
+
+        let schema_data =
+            stream.message().await.map_err(flight_err)?.ok_or_else(|| {
+                PolarsError::NoData("flight stream does not contain a schema".into())
+            })?;
+        let schema = Arc::new(ArrowSchema::try_from(&schema_data)?);
+
+        let mut data = Vec::new();
+        while let Some(flight_data) = stream.message().await.map_err(flight_err)? {
+            data.push(flight_data);
+        }
+        flight_data_to_frame(schema, data, self.rechunk)
+    }
+}
+
+type FlightStream<T> = Pin<Box<dyn Stream<Item = std::result::Result<T, Status>> + Send + Sync>>;
+
+/// An Arrow Flight service that serves `DataFrame`s.
+///
+/// Only `DoGet` is supported. The ticket of a `DoGet` request is passed to a closure that
+/// produces the `DataFrame` to send. The closure runs on a blocking thread, so it may collect a
+/// `LazyFrame` without stalling the runtime.
+pub struct FlightFrameService<F> {
+    get_frame: Arc<F>,
+}
+
+impl<F> FlightFrameService<F>
+where
+    F: Fn(&[u8]) -> Result<DataFrame> + Send + Sync + 'static,
+{
+    pub fn new(get_frame: F) -> Self {
+        FlightFrameService {
+            get_frame: Arc::new(get_frame),
+        }
+    }
+
+    /// Wrap the service in a server that can be added to a `tonic::transport::Server`.
+    pub fn into_server(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+}
+
+fn unimplemented_status<T>(method: &str) -> std::result::Result<T, Status> {
+    Err(Status::unimplemented(format!(
+        "{} is not supported by this service",
+        method
+    )))
+}
+
+#[tonic::async_trait]
+impl<F> FlightService for FlightFrameService<F>
+where
+    F: Fn(&[u8]) -> Result<DataFrame> + Send + Sync + 'static,
+{
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+    type ListFlightsStream = FlightStream<FlightInfo>;
+    type DoGetStream = FlightStream<FlightData>;
+    type DoPutStream = FlightStream<PutResult>;
+    type DoActionStream = FlightStream<arrow_flight::Result>;
+    type ListActionsStream = FlightStream<ActionType>;
+    type DoExchangeStream = FlightStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> std::result::Result<Response<Self::HandshakeStream>, Status> {
+        unimplemented_status("handshake")
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> std::result::Result<Response<Self::ListFlightsStream>, Status> {
+        unimplemented_status("list_flights")
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<FlightInfo>, Status> {
+        unimplemented_status("get_flight_info")
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<SchemaResult>, Status> {
+        unimplemented_status("get_schema")
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> std::result::Result<Response<Self::DoGetStream>, Status> {
+        let ticket = request.into_inner().ticket;
+        let get_frame = self.get_frame.clone();
+        let flight_data = tokio::task::spawn_blocking(move || {
+            let df = get_frame(&ticket).map_err(|e| Status::internal(e.to_string()))?;
+            df.check_arrow_compatible()
+                .map_err(|e| Status::invalid_argument(e.to_string()))?;
+            frame_to_flight_data(&df, config::chunk_size())
+                .map_err(|e| Status::internal(e.to_string()))
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))??;
+        let stream = futures::stream::iter(flight_data.into_iter().map(Ok));
+        Ok(Response::new(Box::pin(stream) as Self::DoGetStream))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoPutStream>, Status> {
+        unimplemented_status("do_put")
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> std::result::Result<Response<Self::DoActionStream>, Status> {
+        unimplemented_status("do_action")
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> std::result::Result<Response<Self::ListActionsStream>, Status> {
+        unimplemented_status("list_actions")
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoExchangeStream>, Status> {
+        unimplemented_status("do_exchange")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::create_df;
+
+    #[test]
+    fn test_flight_data_roundtrip() {
+        let df = create_df();
+        let mut flight_data = frame_to_flight_data(&df, 2).unwrap();
+        // schema + 3 batches
+        assert_eq!(flight_data.len(), 4);
+
+        let schema_data = flight_data.remove(0);
+        let schema = Arc::new(ArrowSchema::try_from(&schema_data).unwrap());
+        let out = flight_data_to_frame(schema, flight_data, true).unwrap();
+        assert!(out.frame_equal(&df));
+    }
+
+    #[test]
+    fn test_flight_data_categorical() -> Result<()> {
+        let cat = Series::new("cat", &[Some("a"), Some("b"), None, Some("a"), Some("c")])
+            .cast::<CategoricalType>()?;
+        let df = DataFrame::new(vec![cat, Series::new("n", &[1, 2, 3, 4, 5])])?;
+        let mut flight_data = frame_to_flight_data(&df, 2)?;
+        // schema + 3 dictionaries + 3 batches
+        assert_eq!(flight_data.len(), 7);
+
+        let schema_data = flight_data.remove(0);
+        let schema = Arc::new(ArrowSchema::try_from(&schema_data)?);
+        let out = flight_data_to_frame(schema.clone(), flight_data, true)?;
+        assert_eq!(out.column("cat")?.dtype(), &DataType::Categorical);
+        assert!(out
+            .column("cat")?
+            .cast::<Utf8Type>()?
+            .series_equal_missing(&df.column("cat")?.cast::<Utf8Type>()?));
+        assert!(out.column("n")?.series_equal(df.column("n")?));
+
+        let empty = flight_data_to_frame(schema, vec![], true)?;
+        assert_eq!(empty.height(), 0);
+        assert_eq!(empty.column("cat")?.dtype(), &DataType::Categorical);
+        Ok(())
+    }
+}
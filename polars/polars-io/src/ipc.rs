@@ -98,6 +98,7 @@ where
     }
 
     fn finish(self, df: &mut DataFrame) -> Result<()> {
+        df.check_arrow_compatible()?;
         let mut ipc_writer = ArrowIPCFileWriter::try_new(self.writer, &df.schema().to_arrow())?;
 
         let iter = df.iter_record_batches(df.height());
@@ -1,6 +1,11 @@
-#[cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+// the crate builds on stable Rust, only the documentation on docs.rs uses an unstable feature
+#![cfg_attr(not(docsrs), deny(unstable_features))]
 pub mod csv;
 pub mod csv_core;
+#[cfg(feature = "flight")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flight")))]
+pub mod flight;
 #[cfg(feature = "ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipc")))]
 pub mod ipc;
@@ -8,9 +13,12 @@ pub mod ipc;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub mod json;
 #[cfg(feature = "parquet")]
-#[cfg_attr(docsrs, doc(cfg(feature = "feature")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "parquet")))]
 pub mod parquet;
 pub mod prelude;
+#[cfg(feature = "streaming")]
+#[cfg_attr(docsrs, doc(cfg(feature = "streaming")))]
+pub mod streaming;
 
 use arrow::{
     csv::Reader as ArrowCsvReader, error::Result as ArrowResult, json::Reader as ArrowJsonReader,
@@ -155,6 +163,26 @@ pub enum ScanAggregation {
         column: String,
         alias: Option<String>,
     },
+    Count {
+        column: String,
+        alias: Option<String>,
+    },
+}
+
+/// Finish the aggregations of a scan from its number of rows if they all count rows. Formats
+/// that know the number of rows without parsing the values use this to skip reading them.
+pub(crate) fn count_rows_to_df(aggregate: &[ScanAggregation], n_rows: usize) -> Option<DataFrame> {
+    let cols = aggregate
+        .iter()
+        .map(|scan_agg| match scan_agg {
+            ScanAggregation::Count { column, alias } => {
+                let name = alias.as_ref().unwrap_or(column);
+                Some(UInt32Chunked::new_from_slice(name, &[n_rows as u32]).into_series())
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(DataFrame::new_no_checks(cols))
 }
 
 impl ScanAggregation {
@@ -167,6 +195,11 @@ impl ScanAggregation {
             Max { column, .. } => df.column(column)?.max_as_series(),
             First { column, .. } => df.column(column)?.head(Some(1)),
             Last { column, .. } => df.column(column)?.tail(Some(1)),
+            // the counts of the batches are summed when the scan is finished
+            Count { column, .. } => {
+                UInt32Chunked::new_from_slice(column, &[df.column(column)?.len() as u32])
+                    .into_series()
+            }
         };
         Ok(s)
     }
@@ -210,6 +243,13 @@ impl ScanAggregation {
                 }
                 Ok(s)
             }
+            Count { column, alias } => {
+                let mut s = df.column(column)?.sum_as_series().cast::<UInt32Type>()?;
+                if let Some(alias) = alias {
+                    s.rename(alias);
+                }
+                Ok(s)
+            }
         }
     }
 }
@@ -14,20 +14,30 @@
 //! }
 //! ```
 //!
+//! Row groups are decoded in parallel unless this is turned off with
+//! [ParquetReader::with_parallel] or the number of rows is limited with
+//! [ParquetReader::with_stop_after_n_rows].
+//!
 use super::{finish_reader, ArrowReader, ArrowResult, RecordBatch};
 use crate::prelude::*;
-use crate::{PhysicalIoExpr, ScanAggregation};
+use crate::{count_rows_to_df, PhysicalIoExpr, ScanAggregation};
 use arrow::record_batch::RecordBatchReader;
-use parquet_lib::file::reader::{FileReader, SerializedFileReader};
+use parquet_lib::file::reader::{ChunkReader, FileReader, RowGroupReader, SerializedFileReader};
 pub use parquet_lib::file::serialized_reader::SliceableCursor;
 use parquet_lib::{
     arrow::{
         arrow_reader::ParquetRecordBatchReader, arrow_writer::ArrowWriter as ParquetArrowWriter,
         ArrowReader as ParquetArrowReader, ParquetFileArrowReader,
     },
-    file::writer::TryClone,
+    errors::Result as ParquetResult,
+    file::{metadata::ParquetMetaData, writer::TryClone},
+    record::reader::RowIter,
+    schema::types::Type as ParquetType,
 };
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+use rayon::prelude::*;
 use std::io::{Read, Seek, Write};
 use std::sync::Arc;
 
@@ -41,16 +51,94 @@ fn set_batch_size(max_rows: usize, stop_after_n_rows: Option<usize>) -> usize {
     batch_size
 }
 
+/// A [FileReader] that only exposes a single row group of a file. The arrow reader reads all
+/// row groups of a file, this lets it decode one row group independently of the others.
+struct SingleRowGroupReader<R: ChunkReader> {
+    reader: Arc<SerializedFileReader<R>>,
+    row_group: usize,
+    metadata: ParquetMetaData,
+}
+
+impl<R: 'static + ChunkReader> SingleRowGroupReader<R> {
+    fn new(reader: Arc<SerializedFileReader<R>>, row_group: usize) -> Self {
+        let file_metadata = reader.metadata().file_metadata().clone();
+        let row_group_metadata = reader.metadata().row_group(row_group).clone();
+        let metadata = ParquetMetaData::new(file_metadata, vec![row_group_metadata]);
+        SingleRowGroupReader {
+            reader,
+            row_group,
+            metadata,
+        }
+    }
+}
+
+impl<R: 'static + ChunkReader> FileReader for SingleRowGroupReader<R> {
+    fn metadata(&self) -> &ParquetMetaData {
+        &self.metadata
+    }
+
+    fn num_row_groups(&self) -> usize {
+        1
+    }
+
+    fn get_row_group(&self, i: usize) -> ParquetResult<Box<dyn RowGroupReader + '_>> {
+        debug_assert_eq!(i, 0);
+        self.reader.get_row_group(self.row_group)
+    }
+
+    fn get_row_iter(&self, projection: Option<ParquetType>) -> ParquetResult<RowIter> {
+        RowIter::from_file(projection, self)
+    }
+}
+
+/// Decode the row groups of a file in parallel and concatenate them in the order of the file.
+/// Every thread decodes all (projected) columns of its row group.
+fn read_row_groups_parallel<R>(
+    file_reader: Arc<SerializedFileReader<R>>,
+    projection: Option<&[usize]>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    rechunk: bool,
+) -> Result<DataFrame>
+where
+    R: 'static + ChunkReader + Send + Sync,
+{
+    let n_row_groups = file_reader.num_row_groups();
+    let dfs = POOL.install(|| {
+        (0..n_row_groups)
+            .into_par_iter()
+            .map(|row_group| {
+                let n_rows = file_reader.metadata().row_group(row_group).num_rows() as usize;
+                let batch_size = std::cmp::max(n_rows, 1);
+                let row_group_reader = SingleRowGroupReader::new(file_reader.clone(), row_group);
+                let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(row_group_reader));
+                let record_reader = match projection {
+                    Some(projection) => arrow_reader
+                        .get_record_reader_by_columns(projection.iter().copied(), batch_size),
+                    None => arrow_reader.get_record_reader(batch_size),
+                }?;
+                finish_reader(record_reader, false, None, predicate.clone(), None)
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let df = accumulate_dataframes_vertical(dfs)?;
+    match rechunk {
+        true => Ok(df.agg_chunks()),
+        false => Ok(df),
+    }
+}
+
 /// Read Apache parquet format into a DataFrame.
 pub struct ParquetReader<R> {
     reader: R,
     rechunk: bool,
     stop_after_n_rows: Option<usize>,
+    parallel: bool,
 }
 
 impl<R> ParquetReader<R>
 where
-    R: 'static + Read + Seek + parquet_lib::file::reader::ChunkReader,
+    R: 'static + Read + Seek + ChunkReader + Send + Sync,
 {
     #[cfg(feature = "lazy")]
     // todo! hoist to lazy crate
@@ -65,6 +153,24 @@ where
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
         let rows_in_file = file_reader.metadata().file_metadata().num_rows() as usize;
 
+        // the number of rows is in the metadata, so counting rows doesn't read the columns
+        if predicate.is_none() {
+            let n_rows = self
+                .stop_after_n_rows
+                .map_or(rows_in_file, |n| std::cmp::min(n, rows_in_file));
+            if let Some(df) = aggregate.and_then(|aggregate| count_rows_to_df(aggregate, n_rows)) {
+                return Ok(df);
+            }
+        }
+
+        // aggregations are finished on the concatenated batches, so they are read sequentially
+        if self.parallel
+            && self.stop_after_n_rows.is_none()
+            && aggregate.is_none()
+            && file_reader.num_row_groups() > 1
+        {
+            return read_row_groups_parallel(file_reader, projection, predicate, rechunk);
+        }
         if let Some(stop_after_n_rows) = self.stop_after_n_rows {
             if stop_after_n_rows > rows_in_file {
                 self.stop_after_n_rows = Some(rows_in_file)
@@ -100,6 +206,13 @@ where
         self
     }
 
+    /// Decode the row groups of the file in parallel. This is enabled by default and has no
+    /// effect if `stop_after_n_rows` is set.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
     pub fn schema(self) -> Result<Schema> {
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
@@ -122,13 +235,14 @@ impl<R> ParquetReader<R> {}
 
 impl<R> SerReader<R> for ParquetReader<R>
 where
-    R: 'static + Read + Seek + parquet_lib::file::reader::ChunkReader,
+    R: 'static + Read + Seek + ChunkReader + Send + Sync,
 {
     fn new(reader: R) -> Self {
         ParquetReader {
             reader,
             rechunk: false,
             stop_after_n_rows: None,
+            parallel: true,
         }
     }
 
@@ -140,6 +254,9 @@ where
     fn finish(self) -> Result<DataFrame> {
         let rechunk = self.rechunk;
         let file_reader = Arc::new(SerializedFileReader::new(self.reader)?);
+        if self.parallel && self.stop_after_n_rows.is_none() && file_reader.num_row_groups() > 1 {
+            return read_row_groups_parallel(file_reader, None, None, rechunk);
+        }
         let n_rows = file_reader.metadata().file_metadata().num_rows() as usize;
         let batch_size = set_batch_size(n_rows, self.stop_after_n_rows);
         let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
@@ -167,6 +284,7 @@ where
 
     /// Write the given DataFrame in the the writer `W`.
     pub fn finish(self, df: &mut DataFrame) -> Result<()> {
+        df.check_arrow_compatible()?;
         let mut parquet_writer =
             ParquetArrowWriter::try_new(self.writer, Arc::new(df.schema().to_arrow()), None)?;
 
@@ -182,6 +300,7 @@ where
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::prelude::*;
     use std::fs::File;
 
@@ -200,4 +319,42 @@ mod test {
             Err(_) => {}
         }
     }
+
+    #[test]
+    fn test_parquet_parallel_row_groups() -> Result<()> {
+        let df = create_df();
+        let path = std::env::temp_dir().join("polars_test_parquet_row_groups.parquet");
+
+        // every written batch is a row group
+        let file = File::create(&path)?;
+        let mut writer = ParquetArrowWriter::try_new(file, Arc::new(df.schema().to_arrow()), None)?;
+        for batch in df.clone().iter_record_batches(2) {
+            writer.write(&batch)?;
+        }
+        writer.close()?;
+
+        let parallel = ParquetReader::new(File::open(&path)?).finish()?;
+        assert_eq!(parallel.n_chunks()?, 3);
+        assert!(parallel.frame_equal(&df));
+
+        let sequential = ParquetReader::new(File::open(&path)?)
+            .with_parallel(false)
+            .set_rechunk(true)
+            .finish()?;
+        assert!(sequential.frame_equal(&df));
+
+        #[cfg(feature = "lazy")]
+        {
+            let projected = ParquetReader::new(File::open(&path)?).finish_with_scan_ops(
+                None,
+                None,
+                Some(&[1]),
+            )?;
+            assert_eq!(projected.get_column_names(), ["temp"]);
+            assert_eq!(projected.height(), 5);
+        }
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
 }
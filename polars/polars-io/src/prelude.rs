@@ -1,5 +1,7 @@
 pub use crate::{csv::*, SerReader, SerWriter};
 
+#[cfg(feature = "flight")]
+pub use crate::flight::*;
 #[cfg(feature = "ipc")]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
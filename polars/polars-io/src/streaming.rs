@@ -0,0 +1,417 @@
+//! # Out-of-core groupby.
+//!
+//! A [StreamingGroupBy] aggregates a stream of `DataFrame` batches that don't fit in memory
+//! together. Every batch is aggregated when it is pushed and only these partial aggregates are
+//! kept, partitioned by the hash of their keys. When the partial aggregates exceed the memory
+//! budget they are combined, and spilled to disk as IPC files if that didn't free enough memory.
+//! [finish](StreamingGroupBy::finish) combines the partial aggregates one partition at a time, so
+//! only a single partition has to fit in memory.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//! use polars_io::streaming::{StreamingAgg, StreamingGroupBy};
+//!
+//! fn sales_per_shop(paths: &[&str]) -> Result<DataFrame> {
+//!     let mut gb = StreamingGroupBy::new(
+//!         &["shop"],
+//!         &[("sales", StreamingAgg::Sum), ("sales", StreamingAgg::Mean)],
+//!     )
+//!     .with_memory_budget(256 * 1024 * 1024);
+//!     for path in paths {
+//!         let df = CsvReader::from_path(path)?.finish()?;
+//!         gb.push(&df)?;
+//!     }
+//!     gb.finish()
+//! }
+//! ```
+use crate::prelude::*;
+use polars_core::frame::group_by::{fmt_groupby_column, GroupByMethod};
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use std::fs::{self, File};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The seed of the hashes that assign the keys to partitions.
+const PARTITION_SEED: u64 = 0;
+
+/// Used to give every groupby its own spill directory.
+static SPILL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// An aggregation that can be computed from the partial aggregates of batches.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StreamingAgg {
+    Sum,
+    Min,
+    Max,
+    Mean,
+    /// The number of rows per group.
+    Count,
+    /// The first value in the order the batches are pushed.
+    First,
+    /// The last value in the order the batches are pushed.
+    Last,
+}
+
+impl StreamingAgg {
+    fn method(&self) -> GroupByMethod {
+        use StreamingAgg::*;
+        match self {
+            Sum => GroupByMethod::Sum,
+            Min => GroupByMethod::Min,
+            Max => GroupByMethod::Max,
+            Mean => GroupByMethod::Mean,
+            Count => GroupByMethod::Count,
+            First => GroupByMethod::First,
+            Last => GroupByMethod::Last,
+        }
+    }
+
+    /// The aggregation of the partial aggregates, which is also the aggregation of the batches.
+    fn partial_agg(&self) -> &'static str {
+        use StreamingAgg::*;
+        match self {
+            Sum | Mean | Count => "sum",
+            Min => "min",
+            Max => "max",
+            First => "first",
+            Last => "last",
+        }
+    }
+}
+
+/// Group and aggregate `DataFrame` batches that don't have to fit in memory together. See the
+/// [module](crate::streaming) documentation.
+pub struct StreamingGroupBy {
+    keys: Vec<String>,
+    aggs: Vec<(String, StreamingAgg)>,
+    n_partitions: usize,
+    memory_budget: usize,
+    spill_dir: PathBuf,
+    // partial aggregates per partition that are not spilled
+    partitions: Vec<Vec<DataFrame>>,
+    // spilled files per partition, in the order they are written
+    spilled: Vec<Vec<PathBuf>>,
+    in_memory: usize,
+    n_spills: usize,
+}
+
+impl StreamingGroupBy {
+    /// Group by the columns `keys` and compute the aggregations `aggs` of the value columns.
+    pub fn new(keys: &[&str], aggs: &[(&str, StreamingAgg)]) -> Self {
+        let spill_dir = std::env::temp_dir().join(format!(
+            "polars-groupby-{}-{}",
+            std::process::id(),
+            SPILL_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        StreamingGroupBy {
+            keys: keys.iter().map(|s| s.to_string()).collect(),
+            aggs: aggs.iter().map(|(s, agg)| (s.to_string(), *agg)).collect(),
+            n_partitions: 16,
+            memory_budget: 1024 * 1024 * 1024,
+            spill_dir,
+            partitions: vec![],
+            spilled: vec![],
+            in_memory: 0,
+            n_spills: 0,
+        }
+    }
+
+    /// The number of bytes the partial aggregates may use before they are spilled to disk.
+    /// Defaults to 1 GiB.
+    pub fn with_memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = bytes;
+        self
+    }
+
+    /// The number of partitions the keys are hashed into. Every partition has to fit in memory
+    /// when the groupby is finished. Defaults to 16.
+    pub fn with_partitions(mut self, n_partitions: usize) -> Self {
+        self.n_partitions = std::cmp::max(n_partitions, 1);
+        self
+    }
+
+    /// The directory the partial aggregates are spilled to. Defaults to a new directory in the
+    /// temporary directory of the OS.
+    pub fn with_spill_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.spill_dir = dir.into();
+        self
+    }
+
+    /// The number of times the partial aggregates were spilled to disk.
+    pub fn n_spills(&self) -> usize {
+        self.n_spills
+    }
+
+    /// The names of the partial aggregate columns with their aggregation. A mean has two partial
+    /// columns, the sum and the number of valid values.
+    fn partial_columns(&self) -> Vec<(String, &'static str)> {
+        let mut cols = Vec::with_capacity(self.aggs.len());
+        for (i, (_, agg)) in self.aggs.iter().enumerate() {
+            cols.push((format!("__agg_{}", i), agg.partial_agg()));
+            if *agg == StreamingAgg::Mean {
+                cols.push((format!("__agg_{}_n", i), "sum"));
+            }
+        }
+        cols
+    }
+
+    /// Aggregate the partial aggregate columns by the keys.
+    fn aggregate(&self, df: &DataFrame) -> Result<DataFrame> {
+        let partial_columns = self.partial_columns();
+        let aggs = partial_columns
+            .iter()
+            .map(|(name, agg)| (name.as_str(), [*agg]))
+            .collect::<Vec<_>>();
+        let keys = self.keys.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let mut out = df.groupby_stable(keys)?.agg(&aggs)?;
+        for (name, agg) in &partial_columns {
+            out.rename(&format!("{}_{}", name, agg), name)?;
+        }
+        Ok(out)
+    }
+
+    /// Aggregate a batch and add the partial aggregates to the partitions.
+    pub fn push(&mut self, df: &DataFrame) -> Result<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let mut cols = Vec::with_capacity(self.keys.len() + self.aggs.len());
+        // the codes of categoricals differ between batches
+        let to_utf8 = |s: &Series| match s.dtype() {
+            DataType::Categorical => s.cast::<Utf8Type>(),
+            _ => Ok(s.clone()),
+        };
+        for key in &self.keys {
+            cols.push(to_utf8(df.column(key)?)?);
+        }
+        for (i, (name, agg)) in self.aggs.iter().enumerate() {
+            let s = df.column(name)?;
+            let name = format!("__agg_{}", i);
+            let mut partial = match agg {
+                StreamingAgg::Count => UInt32Chunked::full(&name, 1, df.height()).into_series(),
+                StreamingAgg::Mean => {
+                    let mut n = s.is_not_null().into_series().cast::<UInt32Type>()?;
+                    n.rename(&format!("{}_n", name));
+                    cols.push(n);
+                    s.cast::<Float64Type>()?
+                }
+                _ => to_utf8(s)?,
+            };
+            partial.rename(&name);
+            cols.push(partial);
+        }
+        let partial = self.aggregate(&DataFrame::new(cols)?)?;
+
+        if self.partitions.is_empty() {
+            self.partitions = vec![vec![]; self.n_partitions];
+            self.spilled = vec![vec![]; self.n_partitions];
+        }
+        let hashes = partial.select(&self.keys)?.hash_rows(PARTITION_SEED)?;
+        let mut partition_idx = vec![vec![]; self.n_partitions];
+        for (i, h) in hashes.into_no_null_iter().enumerate() {
+            partition_idx[(h % self.n_partitions as u64) as usize].push(i);
+        }
+        for (partition, idx) in partition_idx.into_iter().enumerate() {
+            if idx.is_empty() {
+                continue;
+            }
+            let part = partial.take_iter(idx.iter().copied(), Some(idx.len()));
+            self.in_memory += part.estimated_size();
+            self.partitions[partition].push(part);
+        }
+
+        if self.in_memory > self.memory_budget {
+            self.compact()?;
+            // spill if combining the partial aggregates didn't free enough memory, otherwise
+            // every next batch would be compacted again
+            if self.in_memory > self.memory_budget / 2 {
+                self.spill()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Combine the partial aggregates in memory per partition.
+    fn compact(&mut self) -> Result<()> {
+        self.in_memory = 0;
+        for i in 0..self.partitions.len() {
+            let frames = std::mem::take(&mut self.partitions[i]);
+            if frames.is_empty() {
+                continue;
+            }
+            let df = self.aggregate(&accumulate_dataframes_vertical(frames)?)?;
+            self.in_memory += df.estimated_size();
+            self.partitions[i].push(df);
+        }
+        Ok(())
+    }
+
+    /// Write the partial aggregates in memory to disk, one file per partition.
+    fn spill(&mut self) -> Result<()> {
+        fs::create_dir_all(&self.spill_dir)?;
+        for (i, frames) in self.partitions.iter_mut().enumerate() {
+            if frames.is_empty() {
+                continue;
+            }
+            let mut df = accumulate_dataframes_vertical(std::mem::take(frames))?;
+            let path = self
+                .spill_dir
+                .join(format!("partition-{}-{}.ipc", i, self.n_spills));
+            let mut file = File::create(&path)?;
+            IpcWriter::new(&mut file).finish(&mut df)?;
+            self.spilled[i].push(path);
+        }
+        self.n_spills += 1;
+        self.in_memory = 0;
+        Ok(())
+    }
+
+    /// Combine the partial aggregates of all batches. The output has the key columns followed by
+    /// the aggregations, named `{column}_{aggregation}` like the aggregations of a `GroupBy`.
+    /// Categorical keys are returned as Utf8. The order of the groups is arbitrary.
+    pub fn finish(mut self) -> Result<DataFrame> {
+        let mut out = Vec::with_capacity(self.partitions.len());
+        for i in 0..self.partitions.len() {
+            let mut frames = Vec::with_capacity(self.spilled[i].len() + 1);
+            for path in &self.spilled[i] {
+                frames.push(IpcReader::new(File::open(path)?).finish()?);
+            }
+            frames.extend(std::mem::take(&mut self.partitions[i]));
+            if frames.is_empty() {
+                continue;
+            }
+            let df = self.aggregate(&accumulate_dataframes_vertical(frames)?)?;
+            out.push(self.finish_partition(df)?);
+        }
+        if out.is_empty() {
+            return Err(PolarsError::NoData(
+                "no rows were pushed to the streaming groupby".into(),
+            ));
+        }
+        accumulate_dataframes_vertical(out)
+    }
+
+    /// Compute the final aggregations from the combined partial aggregates.
+    fn finish_partition(&self, df: DataFrame) -> Result<DataFrame> {
+        let mut cols = Vec::with_capacity(self.keys.len() + self.aggs.len());
+        for key in &self.keys {
+            cols.push(df.column(key)?.clone());
+        }
+        for (i, (name, agg)) in self.aggs.iter().enumerate() {
+            let partial = df.column(&format!("__agg_{}", i))?;
+            let mut s = match agg {
+                StreamingAgg::Mean => {
+                    let sum = partial.cast::<Float64Type>()?;
+                    let n = df.column(&format!("__agg_{}_n", i))?.cast::<UInt32Type>()?;
+                    // a group without valid values has a null mean, not 0 / 0
+                    let mean: Float64Chunked = sum
+                        .f64()?
+                        .into_iter()
+                        .zip(n.u32()?.into_iter())
+                        .map(|(sum, n)| match n {
+                            Some(n) if n > 0 => Some(sum.unwrap_or(0.0) / n as f64),
+                            _ => None,
+                        })
+                        .collect();
+                    mean.into_series()
+                }
+                _ => partial.clone(),
+            };
+            s.rename(&fmt_groupby_column(name, agg.method()));
+            cols.push(s);
+        }
+        DataFrame::new(cols)
+    }
+}
+
+impl Drop for StreamingGroupBy {
+    fn drop(&mut self) {
+        for path in self.spilled.iter().flatten() {
+            let _ = fs::remove_file(path);
+        }
+        // only removes the directory if it is empty
+        let _ = fs::remove_dir(&self.spill_dir);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_streaming_groupby() -> Result<()> {
+        let batch = |offset: i32| {
+            let keys = (0..100)
+                .map(|i| format!("key_{}", (offset + i) % 7))
+                .collect::<Vec<_>>();
+            let values = (0..100)
+                .map(|i| match i % 11 {
+                    0 => None,
+                    _ => Some(offset + i),
+                })
+                .collect::<Vec<_>>();
+            DataFrame::new(vec![
+                Series::new("key", &keys),
+                Series::new("value", &values),
+            ])
+        };
+        let aggs = [
+            ("value", StreamingAgg::Sum),
+            ("value", StreamingAgg::Mean),
+            ("value", StreamingAgg::Min),
+            ("value", StreamingAgg::Count),
+            ("value", StreamingAgg::First),
+            ("value", StreamingAgg::Last),
+        ];
+        // a budget this small spills every batch
+        let mut gb = StreamingGroupBy::new(&["key"], &aggs)
+            .with_memory_budget(1)
+            .with_partitions(3);
+        let mut all = batch(0)?;
+        gb.push(&all)?;
+        for offset in 1..5 {
+            let df = batch(offset * 100)?;
+            gb.push(&df)?;
+            all.vstack_mut(&df)?;
+        }
+        assert_eq!(gb.n_spills(), 5);
+        let spill_dir = gb.spill_dir.clone();
+        let out = gb.finish()?.sort("key", false)?;
+        assert!(!spill_dir.exists());
+
+        let expected = all
+            .groupby("key")?
+            .agg(&[("value", &["sum", "mean", "min", "count", "first", "last"])])?
+            .sort("key", false)?;
+        assert_eq!(out.get_column_names(), expected.get_column_names());
+        assert!(out.frame_equal_missing(&expected));
+
+        let mut gb = StreamingGroupBy::new(&["key"], &aggs);
+        gb.push(&batch(0)?)?;
+        assert_eq!(gb.n_spills(), 0);
+        assert_eq!(gb.finish()?.height(), 7);
+        assert!(StreamingGroupBy::new(&["key"], &aggs).finish().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_mean_of_nulls() -> Result<()> {
+        let df = DataFrame::new(vec![
+            Series::new("key", &["a", "a", "b"]),
+            Series::new("value", &[None, None, Some(1.0)]),
+        ])?;
+        let mut gb = StreamingGroupBy::new(&["key"], &[("value", StreamingAgg::Mean)]);
+        gb.push(&df)?;
+        gb.push(&df)?;
+        let out = gb.finish()?.sort("key", false)?;
+        assert_eq!(
+            Vec::from(out.column("value_mean")?.f64()?),
+            &[None, Some(1.0)]
+        );
+        Ok(())
+    }
+}
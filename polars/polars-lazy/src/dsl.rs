@@ -3,6 +3,7 @@ use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{output_name, rename_field};
 use polars_core::{
+    chunked_array::builder::get_list_builder,
     frame::group_by::{fmt_groupby_column, GroupByMethod},
     prelude::*,
     utils::get_supertype,
@@ -22,6 +23,12 @@ pub use crate::frame::IntoLazy;
 
 pub trait SeriesUdf: Send + Sync {
     fn call_udf(&self, s: Series) -> Result<Series>;
+
+    /// Call the function in an aggregation, where `s` is a List with the values of every group.
+    /// By default the function gets the whole List.
+    fn call_udf_groups(&self, s: Series) -> Result<Series> {
+        self.call_udf(s)
+    }
 }
 
 impl<F> SeriesUdf for F
@@ -372,7 +379,11 @@ impl Expr {
     }
 
     /// Get Field result of the expression. The schema is the input data.
-    pub(crate) fn to_field(&self, schema: &Schema, ctxt: Context) -> Result<Field> {
+    ///
+    /// This determines the schema of a logical plan, so the data type must be the data type
+    /// the physical expression produces. In the [Aggregation](Context::Aggregation) context the
+    /// names of aggregations get the suffix of the groupby method, e.g. `foo_mean`.
+    pub fn to_field(&self, schema: &Schema, ctxt: Context) -> Result<Field> {
         use Expr::*;
         match self {
             Window { function, .. } => function.to_field(schema, ctxt),
@@ -397,32 +408,29 @@ impl Expr {
                 let right_type = right.get_type(schema, ctxt)?;
 
                 let expr_type = match op {
-                    Operator::Not
-                    | Operator::Lt
+                    Operator::Lt
                     | Operator::Gt
                     | Operator::Eq
                     | Operator::NotEq
-                    | Operator::And
                     | Operator::LtEq
-                    | Operator::GtEq
+                    | Operator::GtEq => {
+                        // the operands are compared in their supertype
+                        if left_type != DataType::Null && right_type != DataType::Null {
+                            get_supertype(&left_type, &right_type)?;
+                        }
+                        DataType::Boolean
+                    }
+                    Operator::Not
+                    | Operator::And
                     | Operator::Or
                     | Operator::NotLike
                     | Operator::Like => DataType::Boolean,
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
-                use Operator::*;
-                let out_field;
-                let out_name = match op {
-                    Plus | Minus | Multiply | Divide | Modulus => {
-                        out_field = left.to_field(schema, ctxt)?;
-                        out_field.name().as_str()
-                    }
-                    Eq | Lt | GtEq | LtEq => "",
-                    _ => "binary_expr",
-                };
-
-                Ok(Field::new(out_name, expr_type))
+                // the result is named after the left operand
+                let out_field = left.to_field(schema, ctxt)?;
+                Ok(Field::new(out_field.name(), expr_type))
             }
             Not(_) => Ok(Field::new("not", DataType::Boolean)),
             IsNull(_) => Ok(Field::new("is_null", DataType::Boolean)),
@@ -441,7 +449,9 @@ impl Expr {
                         field_by_context(expr.to_field(schema, ctxt)?, ctxt, GroupByMethod::Median)
                     }
                     Mean(expr) => {
-                        field_by_context(expr.to_field(schema, ctxt)?, ctxt, GroupByMethod::Mean)
+                        let field = expr.to_field(schema, ctxt)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Mean)
                     }
                     First(expr) => {
                         field_by_context(expr.to_field(schema, ctxt)?, ctxt, GroupByMethod::First)
@@ -468,13 +478,11 @@ impl Expr {
                         field_by_context(expr.to_field(schema, ctxt)?, ctxt, GroupByMethod::Sum)
                     }
                     Std(expr) => {
-                        let field = expr.to_field(schema, ctxt)?;
-                        let field = Field::new(field.name(), DataType::Float64);
+                        let field = float_field(expr.to_field(schema, ctxt)?);
                         field_by_context(field, ctxt, GroupByMethod::Std)
                     }
                     Var(expr) => {
-                        let field = expr.to_field(schema, ctxt)?;
-                        let field = Field::new(field.name(), DataType::Float64);
+                        let field = float_field(expr.to_field(schema, ctxt)?);
                         field_by_context(field, ctxt, GroupByMethod::Var)
                     }
                     Count(expr) => {
@@ -506,7 +514,14 @@ impl Expr {
                 let field = expr.to_field(schema, ctxt)?;
                 Ok(Field::new(field.name(), data_type.clone()))
             }
-            Ternary { truthy, .. } => truthy.to_field(schema, ctxt),
+            Ternary { truthy, falsy, .. } => {
+                // a null literal takes the type of the other branch
+                let field = truthy.to_field(schema, ctxt)?;
+                match field.data_type() {
+                    DataType::Null => falsy.to_field(schema, ctxt),
+                    _ => Ok(field),
+                }
+            }
             Udf {
                 output_type, input, ..
             } => match output_type {
@@ -639,7 +654,7 @@ pub fn except(name: &str) -> Expr {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Operator {
     Eq,
     NotEq,
@@ -739,6 +754,24 @@ impl Expr {
         binary_expr(self, Operator::LtEq, other)
     }
 
+    /// Check if `low` <= `Expr` <= `high`. Which of the bounds are included is determined by
+    /// `closed`.
+    ///
+    /// This is written as two comparisons combined with `and`, so it can be pushed down like any
+    /// other predicate.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_between(self, low: Expr, high: Expr, closed: ClosedWindow) -> Expr {
+        let lower = match closed {
+            ClosedWindow::Both | ClosedWindow::Left => self.clone().gt_eq(low),
+            ClosedWindow::Right | ClosedWindow::None => self.clone().gt(low),
+        };
+        let upper = match closed {
+            ClosedWindow::Both | ClosedWindow::Right => self.lt_eq(high),
+            ClosedWindow::Left | ClosedWindow::None => self.lt(high),
+        };
+        lower.and(upper)
+    }
+
     /// Negate `Expr`
     #[allow(clippy::should_implement_trait)]
     pub fn not(self) -> Expr {
@@ -881,6 +914,36 @@ impl Expr {
         }
     }
 
+    /// Apply a function/closure on every group in a groupby context, e.g. to compute a custom
+    /// aggregation. Outside of an aggregation the function is applied on the whole column, like
+    /// [map](Expr::map).
+    ///
+    /// With a List `output_type` the result is a List with the values the function returns for
+    /// every group. Otherwise the function must return a single value for every group and the
+    /// result has a value per group of `output_type`, or the input dtype if None is given.
+    pub fn apply<F>(self, function: F, output_type: Option<DataType>) -> Self
+    where
+        F: Fn(Series) -> Result<Series> + Send + Sync + 'static,
+    {
+        let udf = GroupsUdf {
+            function,
+            output_type: output_type.clone(),
+        };
+        self.map(udf, output_type)
+    }
+
+    /// Flag the values as sorted, so that operations like joins and groupbys can use faster
+    /// algorithms. This is not checked, a wrong flag leads to wrong results.
+    pub fn set_sorted(self, sorted: IsSorted) -> Self {
+        self.map(
+            move |mut s: Series| {
+                s.set_sorted(sorted);
+                Ok(s)
+            },
+            None,
+        )
+    }
+
     /// Get mask of finite values if dtype is Float
     #[allow(clippy::wrong_self_convention)]
     pub fn is_finite(self) -> Self {
@@ -1064,7 +1127,7 @@ impl Expr {
     #[cfg(feature = "temporal")]
     pub fn year(self) -> Expr {
         let function = move |s: Series| s.year();
-        self.map(function, Some(DataType::UInt32))
+        self.map(function, Some(DataType::Int32))
     }
 
     /// Get the month of a Date32/Date64
@@ -1073,7 +1136,7 @@ impl Expr {
         let function = move |s: Series| s.month();
         self.map(function, Some(DataType::UInt32))
     }
-    /// Get the month of a Date32/Date64
+    /// Get the day of a Date32/Date64
     #[cfg(feature = "temporal")]
     pub fn day(self) -> Expr {
         let function = move |s: Series| s.day();
@@ -1085,6 +1148,12 @@ impl Expr {
         let function = move |s: Series| s.ordinal_day();
         self.map(function, Some(DataType::UInt32))
     }
+    /// Get the weekday of a Date32/Date64, starting from 0 for Monday
+    #[cfg(feature = "temporal")]
+    pub fn weekday(self) -> Expr {
+        let function = move |s: Series| s.weekday();
+        self.map(function, Some(DataType::UInt32))
+    }
     /// Get the hour of a Date64/Time64
     #[cfg(feature = "temporal")]
     pub fn hour(self) -> Expr {
@@ -1113,6 +1182,9 @@ impl Expr {
 }
 
 /// Create a Column Expression based on a column name.
+///
+/// `col("*")` selects all columns. With the `strings` feature a name that starts with `^` and
+/// ends with `$` is a regular expression and selects all columns whose name matches it.
 pub fn col(name: &str) -> Expr {
     match name {
         "*" => Expr::Wildcard,
@@ -1163,6 +1235,92 @@ pub fn quantile(name: &str, quantile: f64) -> Expr {
     col(name).quantile(quantile)
 }
 
+/// Find the mean of the column `values` weighted by the column `weights`. In a groupby context
+/// the weighted mean is computed per group. The output is named `{values}_wmean`.
+/// See [Series::weighted_mean](polars_core::series::Series::weighted_mean).
+pub fn wmean(values: &str, weights: &str) -> Expr {
+    let name = format!("{}_wmean", values);
+    let function = |values: Series, weights: Series| {
+        let mean = values.weighted_mean(&weights)?;
+        Ok(Float64Chunked::new_from_opt_slice("", &[mean]).into_series())
+    };
+    map_binary(
+        col(values),
+        col(weights),
+        function,
+        Some(Field::new(&name, DataType::Float64)),
+    )
+    .alias(&name)
+}
+
+/// The function of [apply](Expr::apply), which is called per group in an aggregation.
+struct GroupsUdf<F> {
+    function: F,
+    output_type: Option<DataType>,
+}
+
+impl<F> SeriesUdf for GroupsUdf<F>
+where
+    F: Fn(Series) -> Result<Series> + Send + Sync,
+{
+    fn call_udf(&self, s: Series) -> Result<Series> {
+        (self.function)(s)
+    }
+
+    fn call_udf_groups(&self, s: Series) -> Result<Series> {
+        apply_on_lists(&s, &self.function, self.output_type.as_ref())
+    }
+}
+
+/// Apply `function` on every list of a List Series. With a List `output_type` the result has a
+/// list per input list, otherwise `function` must reduce every list to a single value. Null
+/// lists stay null.
+fn apply_on_lists<F>(s: &Series, function: &F, output_type: Option<&DataType>) -> Result<Series>
+where
+    F: Fn(Series) -> Result<Series>,
+{
+    let out = s
+        .list()?
+        .into_iter()
+        .map(|opt_s| opt_s.map(function).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    let first_dtype = out.iter().flatten().next().map(|s| s.dtype().clone());
+
+    if let Some(DataType::List(inner)) = output_type {
+        let dtype = first_dtype.unwrap_or_else(|| inner.into());
+        let mut builder = get_list_builder(&dtype, out.len() * 5, out.len(), s.name());
+        for opt_s in &out {
+            builder.append_opt_series(opt_s.as_ref());
+        }
+        return Ok(builder.finish().into_series());
+    }
+
+    let dtype = match (first_dtype, output_type, s.dtype()) {
+        (Some(dtype), _, _) => dtype,
+        (None, Some(dtype), _) => dtype.clone(),
+        (None, None, DataType::List(inner)) => inner.into(),
+        (None, None, dtype) => dtype.clone(),
+    };
+    let mut flat = Series::full_null(s.name(), 0, &dtype);
+    for opt_s in &out {
+        match opt_s {
+            Some(group) if group.len() == 1 => flat.append(group)?,
+            Some(group) => {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "apply returned {} values for a group, give a List output type to \
+                        return other than a single value per group",
+                        group.len()
+                    )
+                    .into(),
+                ))
+            }
+            None => flat.append(&Series::full_null(s.name(), 1, &dtype))?,
+        };
+    }
+    Ok(flat)
+}
+
 /// Apply a closure on the two columns that are evaluated from `Expr` a and `Expr` b.
 pub fn map_binary<F: 'static>(a: Expr, b: Expr, f: F, output_field: Option<Field>) -> Expr
 where
@@ -1297,11 +1455,29 @@ impl Literal for NaiveDate {
     }
 }
 
+/// A null value of any type, e.g. `lit(Null {})`. In a when-then-otherwise expression it takes
+/// the type of the other branch.
+pub struct Null {}
+
+impl Literal for Null {
+    fn lit(self) -> Expr {
+        Expr::Literal(LiteralValue::Null)
+    }
+}
+
 /// Create a Literal Expression from `L`
 pub fn lit<L: Literal>(t: L) -> Expr {
     t.lit()
 }
 
+/// Create a named placeholder for a literal value. The value is bound when the query is executed
+/// with [collect_with_params](crate::frame::LazyFrame::collect_with_params) or a
+/// [PreparedQuery](crate::frame::PreparedQuery). This way an optimized query can be reused for
+/// different values.
+pub fn lit_param(name: &str) -> Expr {
+    Expr::Literal(LiteralValue::Param(name.to_string()))
+}
+
 /// [Not](Expr::Not) expression.
 pub fn not(expr: Expr) -> Expr {
     Expr::Not(Box::new(expr))
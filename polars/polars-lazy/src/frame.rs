@@ -3,18 +3,177 @@ use crate::logical_plan::optimizer::aggregate_pushdown::AggregatePushdown;
 use crate::logical_plan::optimizer::aggregate_scan_projections::{
     agg_projection, AggScanProjection,
 };
+use crate::logical_plan::optimizer::common_subexpr::CommonSubExprElim;
+use crate::logical_plan::optimizer::join_order::JoinOrder;
 use crate::logical_plan::optimizer::predicate::combine_predicates;
 use crate::logical_plan::optimizer::simplify_expr::SimplifyExprRule;
+use crate::logical_plan::optimizer::slice_pushdown::SlicePushDown;
+use crate::logical_plan::optimizer::type_coercion::check_plan;
 use crate::prelude::simplify_expr::SimplifyBooleanRule;
+use crate::utils::{has_expr, is_elementwise};
 use crate::{logical_plan::FETCH_ROWS, prelude::*};
 use ahash::RandomState;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
+use polars_core::progress::CancellationToken;
 use polars_core::toggle_string_cache;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+fn execute_plan(
+    logical_plan: LogicalPlan,
+    use_string_cache: bool,
+    planner: DefaultPlanner,
+) -> Result<DataFrame> {
+    toggle_string_cache(use_string_cache);
+    let mut physical_plan = planner.create_physical_plan(logical_plan)?;
+    let cache = Arc::new(Mutex::new(HashMap::with_capacity_and_hasher(
+        64,
+        RandomState::default(),
+    )));
+    let out = physical_plan.execute(&cache);
+    if use_string_cache {
+        toggle_string_cache(!use_string_cache);
+    }
+    out
+}
+
+/// An optimized query that can be executed many times with different values bound to its
+/// [lit_param](crate::dsl::lit_param) placeholders. Created by [prepare](LazyFrame::prepare).
+#[derive(Clone)]
+pub struct PreparedQuery {
+    logical_plan: LogicalPlan,
+    use_string_cache: bool,
+}
+
+impl PreparedQuery {
+    /// Execute the query with the given parameter values.
+    pub fn collect(&self, params: &[(&str, LiteralValue)]) -> Result<DataFrame> {
+        let params = params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _, RandomState>>();
+        let planner = DefaultPlanner::default().with_params(Arc::new(params));
+        execute_plan(self.logical_plan.clone(), self.use_string_cache, planner)
+    }
+
+    /// Get the optimized logical plan.
+    pub fn logical_plan(&self) -> &LogicalPlan {
+        &self.logical_plan
+    }
+}
+
+/// Number of nodes at the top of the plan that can be executed on slices of their input.
+fn batched_depth(lp: &LogicalPlan) -> usize {
+    use LogicalPlan::*;
+    let column = Expr::Column(Arc::new(String::new()));
+    let (input, exprs, projection) = match lp {
+        Selection { input, predicate } => (input, std::slice::from_ref(predicate), false),
+        HStack { input, exprs, .. } => (input, exprs.as_slice(), false),
+        Projection { input, expr, .. } | LocalProjection { input, expr, .. } => {
+            (input, expr.as_slice(), true)
+        }
+        _ => return 0,
+    };
+    // a projection of only literals produces a single row, not one per input row
+    let batched = exprs.iter().all(|e| {
+        is_elementwise(e) && (!projection || has_expr(e, &column) || has_expr(e, &Expr::Wildcard))
+    });
+    match batched {
+        true => 1 + batched_depth(input),
+        false => 0,
+    }
+}
+
+/// Copy the first `depth` nodes of the plan on top of a new source.
+fn replace_source(lp: &LogicalPlan, depth: usize, source: LogicalPlan) -> LogicalPlan {
+    use LogicalPlan::*;
+    if depth == 0 {
+        return source;
+    }
+    let input = |input: &LogicalPlan| Box::new(replace_source(input, depth - 1, source));
+    match lp {
+        Selection {
+            input: i,
+            predicate,
+        } => Selection {
+            input: input(i),
+            predicate: predicate.clone(),
+        },
+        HStack {
+            input: i,
+            exprs,
+            schema,
+        } => HStack {
+            input: input(i),
+            exprs: exprs.clone(),
+            schema: schema.clone(),
+        },
+        Projection {
+            input: i,
+            expr,
+            schema,
+        } => Projection {
+            input: input(i),
+            expr: expr.clone(),
+            schema: schema.clone(),
+        },
+        LocalProjection {
+            input: i,
+            expr,
+            schema,
+        } => LocalProjection {
+            input: input(i),
+            expr: expr.clone(),
+            schema: schema.clone(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// An iterator over the result of a query in batches of DataFrames. Created by
+/// [collect_iter](LazyFrame::collect_iter).
+pub struct BatchedCollect {
+    logical_plan: LogicalPlan,
+    depth: usize,
+    source: DataFrame,
+    batch_size: usize,
+    offset: usize,
+    use_string_cache: bool,
+}
+
+impl Iterator for BatchedCollect {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.source.height() {
+            return None;
+        }
+        let len = std::cmp::min(self.batch_size, self.source.height() - self.offset);
+        let batch = match self.source.slice(self.offset, len) {
+            Ok(batch) => batch,
+            Err(e) => return Some(Err(e)),
+        };
+        self.offset += len;
+        if self.depth == 0 {
+            return Some(Ok(batch));
+        }
+        let source = LogicalPlan::DataFrameScan {
+            schema: Arc::new(batch.schema()),
+            df: Arc::new(batch),
+            projection: None,
+            selection: None,
+        };
+        let lp = replace_source(&self.logical_plan, self.depth, source);
+        Some(execute_plan(
+            lp,
+            self.use_string_cache,
+            DefaultPlanner::default(),
+        ))
+    }
+}
+
 #[derive(Clone)]
 pub struct LazyCsvReader<'a> {
     path: String,
@@ -112,10 +271,17 @@ impl<'a> LazyCsvReader<'a> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct JoinOptions {
     pub allow_parallel: bool,
     pub force_parallel: bool,
+    /// Added to the names of the right columns that clash with a left column. Defaults to
+    /// `"_right"`.
+    pub suffix: Option<String>,
+    /// Check that the join has this cardinality before joining.
+    pub validation: Option<JoinValidation>,
+    /// The right input is small, see [LazyFrame::hint_broadcast].
+    pub broadcast: bool,
 }
 
 impl Default for JoinOptions {
@@ -123,10 +289,31 @@ impl Default for JoinOptions {
         JoinOptions {
             allow_parallel: true,
             force_parallel: false,
+            suffix: None,
+            validation: None,
+            broadcast: false,
         }
     }
 }
 
+impl JoinOptions {
+    /// Set the suffix for the names of the right columns that clash with a left column.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Let the join fail if it doesn't have the cardinality of `validation`.
+    pub fn validate(mut self, validation: JoinValidation) -> Self {
+        self.validation = Some(validation);
+        self
+    }
+
+    pub(crate) fn get_suffix(&self) -> &str {
+        self.suffix.as_deref().unwrap_or("_right")
+    }
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -174,6 +361,10 @@ pub struct OptState {
     pub simplify_expr: bool,
     pub agg_scan_projection: bool,
     pub aggregate_pushdown: bool,
+    pub slice_pushdown: bool,
+    pub common_subexpr_elim: bool,
+    pub join_order: bool,
+    pub broadcast: bool,
     pub global_string_cache: bool,
 }
 
@@ -186,6 +377,10 @@ impl Default for OptState {
             simplify_expr: true,
             agg_scan_projection: false,
             aggregate_pushdown: false,
+            slice_pushdown: true,
+            common_subexpr_elim: true,
+            join_order: true,
+            broadcast: false,
             global_string_cache: true,
         }
     }
@@ -205,6 +400,22 @@ impl LazyFrame {
         lf
     }
 
+    /// Create a LazyFrame from a user defined [AnonymousScan] source.
+    pub fn anonymous_scan(function: Arc<dyn AnonymousScan>) -> Result<Self> {
+        Ok(LogicalPlanBuilder::anonymous_scan(function)?.build().into())
+    }
+
+    /// Describe the logical plan as an indented tree, annotated with the schema and estimated
+    /// number of rows of every node. See [LogicalPlan::explain].
+    pub fn explain(&self, optimized: bool) -> Result<String> {
+        let logical_plan = if optimized {
+            self.clone().optimize()?
+        } else {
+            self.logical_plan.clone()
+        };
+        Ok(logical_plan.explain())
+    }
+
     /// Get a dot language representation of the LogicalPlan.
     pub fn to_dot(&self, optimized: bool) -> Result<String> {
         let mut s = String::with_capacity(512);
@@ -264,6 +475,46 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle pushing slices into the file scans, so only the sliced rows are read.
+    pub fn with_slice_pushdown(mut self, toggle: bool) -> Self {
+        self.opt_state.slice_pushdown = toggle;
+        self
+    }
+
+    /// Toggle computing subexpressions that occur more than once in a projection or aggregation
+    /// only once.
+    pub fn with_common_subexpr_elim(mut self, toggle: bool) -> Self {
+        self.opt_state.common_subexpr_elim = toggle;
+        self
+    }
+
+    /// Toggle reordering chained inner joins, so the smallest input is joined first.
+    pub fn with_join_order(mut self, toggle: bool) -> Self {
+        self.opt_state.join_order = toggle;
+        self
+    }
+
+    /// Hint that this frame is small compared to the frames it is joined with. When it is the
+    /// right input of a chain of inner joins, the optimizer joins it before the other inputs,
+    /// whatever their estimated size. The hint is kept by the operations on this frame until it
+    /// is joined.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_lookup(orders: LazyFrame, customers: LazyFrame, products: LazyFrame) -> LazyFrame {
+    ///     orders
+    ///         .inner_join(customers, col("customer"), col("customer"), None)
+    ///         .inner_join(products.hint_broadcast(), col("product"), col("product"), None)
+    /// }
+    /// ```
+    pub fn hint_broadcast(mut self) -> Self {
+        self.opt_state.broadcast = true;
+        self
+    }
+
     /// Toggle global string cache.
     pub fn with_string_cache(mut self, toggle: bool) -> Self {
         self.opt_state.global_string_cache = toggle;
@@ -296,10 +547,33 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn sort(self, by_column: &str, reverse: bool) -> Self {
+        self.sort_with_options(by_column, SortOptions::from_reverse(reverse))
+    }
+
+    /// Sort the DataFrame by a column with explicit null placement and tie handling.
+    /// See [DataFrame::sort_with_options].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       let options = SortOptions {
+    ///           descending: true,
+    ///           nulls_last: false,
+    ///           nans_last: false,
+    ///       };
+    ///       df.lazy()
+    ///         .sort_with_options("sepal.width", options)
+    /// }
+    /// ```
+    pub fn sort_with_options(self, by_column: &str, options: SortOptions) -> Self {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .sort(by_column.into(), reverse)
+            .sort(by_column.into(), options)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -356,7 +630,9 @@ impl LazyFrame {
     }
 
     /// Caches the result into a new LazyFrame. This should be used to prevent computations
-    /// running multiple times
+    /// running multiple times, e.g. when the frame is joined with itself. Every branch of the
+    /// query that reads the cached frame gets the same DataFrame, so filters and projections of
+    /// a branch are applied after the cache.
     pub fn cache(self) -> Self {
         let opt_state = self.get_opt_state();
         let lp = self.get_plan_builder().cache().build();
@@ -384,8 +660,13 @@ impl LazyFrame {
         let simplify_expr = self.opt_state.simplify_expr;
         let agg_scan_projection = self.opt_state.agg_scan_projection;
         let aggregate_pushdown = self.opt_state.aggregate_pushdown;
+        let slice_pushdown = self.opt_state.slice_pushdown;
+        let common_subexpr_elim = self.opt_state.common_subexpr_elim;
+        let join_order = self.opt_state.join_order;
 
         let mut logical_plan = self.get_plan_builder().build();
+        // report missing columns and invalid types before they make the executor panic
+        check_plan(&logical_plan)?;
 
         // gradually fill the rules passed to the optimizer
         let mut rules: Vec<Box<dyn OptimizationRule>> = Vec::with_capacity(8);
@@ -450,6 +731,18 @@ impl LazyFrame {
             rules.push(Box::new(AggregatePushdown::new()))
         }
 
+        if slice_pushdown {
+            rules.push(Box::new(SlicePushDown {}))
+        }
+
+        if common_subexpr_elim {
+            rules.push(Box::new(CommonSubExprElim::new()))
+        }
+
+        if join_order {
+            rules.push(Box::new(JoinOrder {}))
+        }
+
         let opt = StackOptimizer {};
         lp_top = opt.optimize_loop(&mut rules, &mut expr_arena, &mut lp_arena, lp_top);
         let lp = node_to_lp(lp_top, &mut expr_arena, &mut lp_arena);
@@ -477,19 +770,127 @@ impl LazyFrame {
     pub fn collect(self) -> Result<DataFrame> {
         let use_string_cache = self.opt_state.global_string_cache;
         let logical_plan = self.optimize()?;
+        execute_plan(logical_plan, use_string_cache, DefaultPlanner::default())
+    }
 
-        toggle_string_cache(use_string_cache);
-        let planner = DefaultPlanner::default();
-        let mut physical_plan = planner.create_physical_plan(logical_plan)?;
-        let cache = Arc::new(Mutex::new(HashMap::with_capacity_and_hasher(
-            64,
-            RandomState::default(),
-        )));
-        let out = physical_plan.execute(&cache);
-        if use_string_cache {
-            toggle_string_cache(!use_string_cache);
+    /// Execute the query and iterate over the result in DataFrames of at most `batch_size`
+    /// rows.
+    ///
+    /// Filters, projections and `with_column(s)` operations at the end of the query are executed
+    /// per batch if their expressions are evaluated row by row, so their result is never
+    /// materialized as a whole. The input of those operations and all other operations, like
+    /// sorts, joins and aggregations, are executed before the first batch is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> Result<usize> {
+    ///     let mut rows = 0;
+    ///     for df in lf.filter(col("foo").gt(lit(10))).collect_iter(1024)? {
+    ///         rows += df?.height();
+    ///     }
+    ///     Ok(rows)
+    /// }
+    /// ```
+    pub fn collect_iter(self, batch_size: usize) -> Result<BatchedCollect> {
+        if batch_size == 0 {
+            return Err(PolarsError::ValueError(
+                "batch_size should be larger than 0".into(),
+            ));
         }
-        out
+        let use_string_cache = self.opt_state.global_string_cache;
+        let logical_plan = self.optimize()?;
+        let depth = batched_depth(&logical_plan);
+
+        let mut source_plan = &logical_plan;
+        for _ in 0..depth {
+            source_plan = match source_plan {
+                LogicalPlan::Selection { input, .. }
+                | LogicalPlan::HStack { input, .. }
+                | LogicalPlan::Projection { input, .. }
+                | LogicalPlan::LocalProjection { input, .. } => input,
+                _ => unreachable!(),
+            };
+        }
+        let source = execute_plan(
+            source_plan.clone(),
+            use_string_cache,
+            DefaultPlanner::default(),
+        )?;
+
+        Ok(BatchedCollect {
+            logical_plan,
+            depth,
+            source,
+            batch_size,
+            offset: 0,
+            use_string_cache,
+        })
+    }
+
+    /// Execute the query, but abort with a `PolarsError::Cancelled` error as soon as the `token`
+    /// is cancelled. The token is checked between the steps of the query and between the
+    /// batches of csv scans.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_core::progress::CancellationToken;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(lf: LazyFrame) -> Result<DataFrame> {
+    ///     let token = CancellationToken::new();
+    ///     let handle = token.clone();
+    ///     // `handle.cancel()` may be called from another thread.
+    ///     std::thread::spawn(move || handle.cancel());
+    ///     lf.collect_with_cancellation(token)
+    /// }
+    /// ```
+    pub fn collect_with_cancellation(self, token: CancellationToken) -> Result<DataFrame> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let logical_plan = self.optimize()?;
+        let planner = DefaultPlanner::default().with_cancellation_token(Some(token));
+        execute_plan(logical_plan, use_string_cache, planner)
+    }
+
+    /// Optimize the query once, such that it can be executed many times with different values
+    /// bound to the [lit_param](crate::dsl::lit_param) placeholders.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> Result<Vec<DataFrame>> {
+    ///     let query = df
+    ///         .lazy()
+    ///         .filter(col("temp").gt(lit_param("cutoff")))
+    ///         .prepare()?;
+    ///
+    ///     (0..10)
+    ///         .map(|cutoff| query.collect(&[("cutoff", LiteralValue::Int32(cutoff))]))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn prepare(self) -> Result<PreparedQuery> {
+        let use_string_cache = self.opt_state.global_string_cache;
+        let logical_plan = self.optimize()?;
+        Ok(PreparedQuery {
+            logical_plan,
+            use_string_cache,
+        })
+    }
+
+    /// Execute the query with values bound to the [lit_param](crate::dsl::lit_param) placeholders.
+    /// If the same query is executed multiple times, use [prepare](LazyFrame::prepare)
+    /// to only optimize it once.
+    pub fn collect_with_params(self, params: &[(&str, LiteralValue)]) -> Result<DataFrame> {
+        self.prepare()?.collect(params)
     }
 
     /// Filter by some predicate expression.
@@ -580,6 +981,7 @@ impl LazyFrame {
             logical_plan: self.logical_plan,
             opt_state,
             keys: by,
+            maintain_order: false,
         }
     }
 
@@ -669,6 +1071,10 @@ impl LazyFrame {
 
     /// Generic join function that can join on multiple columns.
     ///
+    /// The keys are expressions, so they may be computed from the columns, e.g.
+    /// `col("id").cast(DataType::Utf8)`, without adding them as columns first. A key column of
+    /// the right input with the output name of a right key is not part of the result.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -688,18 +1094,14 @@ impl LazyFrame {
         options: Option<JoinOptions>,
         how: JoinType,
     ) -> LazyFrame {
-        let opt_state = self.get_opt_state();
-        let opts = options.unwrap_or_default();
+        let mut opt_state = self.get_opt_state();
+        let mut opts = options.unwrap_or_default();
+        opts.broadcast |= other.opt_state.broadcast;
+        // the result of the join is not assumed to be small
+        opt_state.broadcast = false;
         let lp = self
             .get_plan_builder()
-            .join(
-                other.logical_plan,
-                how,
-                left_on,
-                right_on,
-                opts.allow_parallel,
-                opts.force_parallel,
-            )
+            .join(other.logical_plan, how, left_on, right_on, opts)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -747,6 +1149,28 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Hint that a column is sorted, e.g. because the source is known to be sorted. The hint
+    /// is kept by filters and lets joins and groupbys on the column use merge and run based
+    /// algorithms instead of hashing. This is not checked, a wrong hint leads to wrong results.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn join_sorted(left: LazyFrame, right: LazyFrame) -> LazyFrame {
+    ///     left.set_sorted("ts", IsSorted::Ascending).inner_join(
+    ///         right.set_sorted("ts", IsSorted::Ascending),
+    ///         col("ts"),
+    ///         col("ts"),
+    ///         None,
+    ///     )
+    /// }
+    /// ```
+    pub fn set_sorted(self, column: &str, sorted: IsSorted) -> LazyFrame {
+        self.with_column(col(column).set_sorted(sorted))
+    }
+
     /// Aggregate all the columns as their maximum values.
     pub fn max(self) -> LazyFrame {
         self.select_local(vec![col("*").max()])
@@ -886,9 +1310,17 @@ pub struct LazyGroupBy {
     pub(crate) logical_plan: LogicalPlan,
     opt_state: OptState,
     keys: Vec<Expr>,
+    maintain_order: bool,
 }
 
 impl LazyGroupBy {
+    /// Output the groups in the order their keys first appear in the input, instead of an
+    /// arbitrary order. See [GroupBy::maintain_order](polars_core::frame::group_by::GroupBy::maintain_order).
+    pub fn maintain_order(mut self, maintain_order: bool) -> Self {
+        self.maintain_order = maintain_order;
+        self
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col](crate::dsl::col) and choose an aggregation.
@@ -913,17 +1345,40 @@ impl LazyGroupBy {
     /// ```
     pub fn agg(self, aggs: Vec<Expr>) -> LazyFrame {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), aggs, None)
+            .groupby(Arc::new(self.keys), aggs, None, self.maintain_order)
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
 
+    /// Apply a function over the groups as a new `DataFrame`. The results of all groups are
+    /// concatenated into a single `DataFrame`.
+    ///
+    /// The function is opaque to the optimizer: the input of this node is not pruned by
+    /// projection pushdown and predicates are not pushed through it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .groupby(vec![col("date")])
+    ///        .apply(|df| Ok(df.head(Some(1))))
+    /// }
+    /// ```
     pub fn apply<F>(self, f: F) -> LazyFrame
     where
         F: 'static + Fn(DataFrame) -> Result<DataFrame> + Send + Sync,
     {
         let lp = LogicalPlanBuilder::from(self.logical_plan)
-            .groupby(Arc::new(self.keys), vec![], Some(Arc::new(f)))
+            .groupby(
+                Arc::new(self.keys),
+                vec![],
+                Some(Arc::new(f)),
+                self.maintain_order,
+            )
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -941,6 +1396,450 @@ mod test {
         LazyCsvReader::new(path.to_string()).finish()
     }
 
+    #[test]
+    fn test_lazy_set_sorted() -> Result<()> {
+        let left = df! {
+            "a" => &[1, 2, 2, 3, 5],
+            "b" => &[1, 2, 3, 4, 5]
+        }?;
+        let right = df! {
+            "a" => &[2, 3, 3, 4],
+            "c" => &[1, 2, 3, 4]
+        }?;
+
+        let out = left
+            .clone()
+            .lazy()
+            .set_sorted("a", IsSorted::Ascending)
+            .filter(col("b").gt(lit(1)))
+            .collect()?;
+        assert_eq!(out.column("a")?.is_sorted(), IsSorted::Ascending);
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+
+        let expected = left
+            .clone()
+            .lazy()
+            .inner_join(right.clone().lazy(), col("a"), col("a"), None)
+            .sort("c", false)
+            .collect()?;
+        let joined = left
+            .lazy()
+            .set_sorted("a", IsSorted::Ascending)
+            .inner_join(
+                right.lazy().set_sorted("a", IsSorted::Ascending),
+                col("a"),
+                col("a"),
+                None,
+            )
+            .sort("c", false)
+            .collect()?;
+        assert!(joined.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_predicate_pushdown_renamed() -> Result<()> {
+        let left = df! {
+            "days" => &[1, 2, 3, 4],
+            "x" => &[1, 2, 3, 4]
+        }?;
+        let right = df! {
+            "days" => &[1, 2, 3, 4],
+            "x" => &[10, 20, 30, 40]
+        }?;
+
+        let check = |how: JoinType, predicate: Expr, expected_days: &[i32]| -> Result<()> {
+            let query = left
+                .clone()
+                .lazy()
+                .join(
+                    right.clone().lazy(),
+                    vec![col("days")],
+                    vec![col("days")],
+                    None,
+                    how,
+                )
+                .filter(predicate)
+                .sort("days", false);
+            let out = query.clone().collect()?;
+            let expected = query.with_predicate_pushdown(false).collect()?;
+            assert!(out.frame_equal_missing(&expected));
+            let days = out
+                .column("days")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(days, expected_days);
+            Ok(())
+        };
+
+        // "x" is the left column, the right "x" is renamed to "x_right"
+        check(JoinType::Inner, col("x").lt(lit(3)), &[1, 2])?;
+        check(JoinType::Inner, col("x_right").gt(lit(25)), &[3, 4])?;
+        check(JoinType::Left, col("x_right").gt(lit(25)), &[3, 4])?;
+        check(JoinType::Left, col("x").lt(lit(3)), &[1, 2])?;
+        // a predicate on the join key filters both inputs
+        check(JoinType::Outer, col("days").gt(lit(2)), &[3, 4])?;
+        check(
+            JoinType::Inner,
+            col("x")
+                .cast(DataType::Float64)
+                .lt(col("x_right").cast(DataType::Float64)),
+            &[1, 2, 3, 4],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_suffix() -> Result<()> {
+        let left = df! {
+            "days" => &[1, 2, 3],
+            "x" => &[1, 2, 3]
+        }?;
+        let right = df! {
+            "days" => &[1, 2, 3],
+            "x" => &[10, 20, 30]
+        }?;
+        let query = left
+            .lazy()
+            .join(
+                right.lazy(),
+                vec![col("days")],
+                vec![col("days")],
+                Some(JoinOptions::default().suffix("_other")),
+                JoinType::Inner,
+            )
+            .filter(col("x_other").gt(lit(15)))
+            .sort("days", false);
+        // the projection and predicate on the renamed column are pushed down
+        let out = query.clone().select(&[col("x_other")]).collect()?;
+        assert_eq!(
+            Vec::from(out.column("x_other")?.i32()?),
+            &[Some(20), Some(30)]
+        );
+        let out = query.collect()?;
+        assert_eq!(out.get_column_names(), &["days", "x", "x_other"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_computed_keys() -> Result<()> {
+        let left = df! {
+            "a" => &[1, 2, 3],
+            "x" => &[10, 20, 30]
+        }?;
+        let right = df! {
+            "b" => &[6, 2, 4],
+            "v" => &["c", "a", "b"]
+        }?;
+        let query = left
+            .lazy()
+            .join(
+                right.lazy(),
+                vec![col("a") * lit(2)],
+                vec![col("b")],
+                None,
+                JoinType::Inner,
+            )
+            .select(&[col("a"), col("v")])
+            .sort("a", false);
+        let out = query.clone().collect()?;
+        let expected = query.with_projection_pushdown(false).collect()?;
+        assert!(out.frame_equal(&expected));
+        assert_eq!(out.get_column_names(), &["a", "v"]);
+        assert_eq!(
+            Vec::from(out.column("v")?.utf8()?),
+            &[Some("a"), Some("b"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_validation() -> Result<()> {
+        let left = df! {
+            "days" => &[1, 1, 2],
+            "x" => &[1, 2, 3]
+        }?;
+        let right = df! {
+            "days" => &[1, 2],
+            "y" => &[10, 20]
+        }?;
+        let join = |validation| {
+            left.clone()
+                .lazy()
+                .join(
+                    right.clone().lazy(),
+                    vec![col("days")],
+                    vec![col("days")],
+                    Some(JoinOptions::default().validate(validation)),
+                    JoinType::Left,
+                )
+                .collect()
+        };
+        assert_eq!(join(JoinValidation::ManyToOne)?.height(), 3);
+        assert!(join(JoinValidation::OneToOne).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_is_between() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3, 4, 5]
+        }?;
+        let out = df
+            .lazy()
+            .filter(col("a").is_between(lit(2), lit(4), ClosedWindow::Left))
+            .collect()?;
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(2), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_frame_send() {
+        fn is_send_sync<T: Send + Sync>() {}
+        is_send_sync::<LazyFrame>();
+        is_send_sync::<LogicalPlan>();
+        is_send_sync::<Arena<ALogicalPlan>>();
+        is_send_sync::<Arena<AExpr>>();
+
+        let lf = get_df().lazy().filter(col("sepal.width").gt(lit(3.0)));
+        let expected = lf.clone().collect().unwrap();
+        let out = std::thread::spawn(move || lf.collect().unwrap())
+            .join()
+            .unwrap();
+        assert!(out.frame_equal(&expected));
+    }
+
+    #[test]
+    fn test_lazy_collect_iter() -> Result<()> {
+        let df = get_df();
+        let lf = df
+            .clone()
+            .lazy()
+            .filter(col("sepal.width").gt(lit(3.1)))
+            .with_column((col("sepal.length") * lit(2.0)).alias("double"));
+        let expected = lf.clone().collect()?;
+
+        let batches = lf.clone().collect_iter(2)?.collect::<Result<Vec<_>>>()?;
+        assert!(batches.len() > 1);
+        assert!(batches.iter().all(|df| df.height() <= 2));
+        let out = polars_core::utils::accumulate_dataframes_vertical(batches)?;
+        assert!(out.frame_equal(&expected));
+
+        // a sort can't be executed per batch, its output is sliced
+        let lf = df.lazy().sort("sepal.width", false);
+        let expected = lf.clone().collect()?;
+        let batches = lf.collect_iter(3)?.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 3);
+        assert!(polars_core::utils::accumulate_dataframes_vertical(batches)?.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_apply() {
+        let df = get_df()
+            .lazy()
+            .groupby(vec![col("variety")])
+            .apply(|df| {
+                let sum = df.column("sepal.length")?.sum_as_series();
+                DataFrame::new(vec![sum])
+            })
+            .collect()
+            .unwrap();
+        assert_eq!(df.shape(), (1, 1));
+        assert!((df.column("sepal.length").unwrap().sum::<f64>().unwrap() - 34.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lazy_expr_apply_on_groups() -> Result<()> {
+        let df = df! {
+            "groups" => &["a", "b", "a", "a"],
+            "values" => &[1, 5, 2, 3]
+        }?;
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .maintain_order(true)
+            .agg(vec![
+                col("values")
+                    .apply(|s| Ok(s.sum_as_series()), None)
+                    .alias("sum"),
+                col("values")
+                    .apply(
+                        |s| Ok(s.head(Some(2))),
+                        Some(DataType::List(ArrowDataType::Int32)),
+                    )
+                    .alias("head"),
+                // a single value per group is still a list per group with a List output type
+                col("values")
+                    .apply(
+                        |s| Ok(s.head(Some(1))),
+                        Some(DataType::List(ArrowDataType::Int32)),
+                    )
+                    .alias("first"),
+            ])
+            .collect()?;
+        assert_eq!(Vec::from(out.column("sum")?.i32()?), &[Some(6), Some(5)]);
+        let list_lengths = |name: &str| -> Result<Vec<usize>> {
+            Ok(out
+                .column(name)?
+                .list()?
+                .into_iter()
+                .map(|s| s.unwrap().len())
+                .collect())
+        };
+        // group "a" has 3 rows and group "b" a single row
+        assert_eq!(list_lengths("head")?, &[2, 1]);
+        assert_eq!(list_lengths("first")?, &[1, 1]);
+
+        // without a List output type the function must reduce every group to a single value
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("groups")])
+            .agg(vec![col("values").apply(|s| Ok(s.head(Some(2))), None)])
+            .collect();
+        assert!(out.is_err());
+
+        // outside of an aggregation it is applied on the whole column
+        let out = df
+            .lazy()
+            .select(&[col("values").apply(|s| Ok(&s * 2), None)])
+            .collect()?;
+        assert_eq!(out.column("values")?.sum::<i32>(), Some(22));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_wmean() -> Result<()> {
+        let df = df! {
+            "product" => ["a", "b", "a", "b"],
+            "price" => [10.0, 5.0, 20.0, 7.0],
+            "revenue" => [1, 2, 3, 2]
+        }?;
+        let out = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("product")])
+            .maintain_order(true)
+            .agg(vec![wmean("price", "revenue")])
+            .collect()?;
+        assert!(out.frame_equal(&df.groupby_stable("product")?.wmean("price", "revenue")?));
+        assert_eq!(
+            Vec::from(out.column("price_wmean")?.f64()?),
+            &[Some(17.5), Some(6.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_groupby_maintain_order() -> Result<()> {
+        let df = df! {
+            "shop" => ["c", "a", "c", "b", "a"],
+            "sales" => [1, 2, 3, 4, 5]
+        }?;
+        // a single aggregation would otherwise use the partitioned groupby
+        let out = df
+            .lazy()
+            .groupby(vec![col("shop")])
+            .maintain_order(true)
+            .agg(vec![col("sales").sum()])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("shop")?.utf8()?),
+            &[Some("c"), Some("a"), Some("b")]
+        );
+        assert_eq!(
+            Vec::from(out.column("sales_sum")?.i32()?),
+            &[Some(4), Some(7), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_sort_with_options() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "b" => [1, 2, 3]
+        }?;
+        let options = SortOptions {
+            descending: true,
+            nulls_last: true,
+            nans_last: false,
+        };
+        let out = df
+            .clone()
+            .lazy()
+            .sort_with_options("a", options)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(3), Some(1), Some(2)]
+        );
+        assert!(out.frame_equal_missing(&df.sort_with_options("a", options)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_anonymous_scan() {
+        use std::sync::Mutex;
+
+        struct Source {
+            seen: Mutex<Option<AnonymousScanOptions>>,
+        }
+
+        impl AnonymousScan for Source {
+            fn schema(&self) -> Result<Schema> {
+                Ok(Schema::new(vec![
+                    Field::new("a", DataType::Int32),
+                    Field::new("b", DataType::Float64),
+                ]))
+            }
+
+            fn scan(&self, options: AnonymousScanOptions) -> Result<Vec<DataFrame>> {
+                *self.seen.lock().unwrap() = Some(options);
+                Ok(vec![
+                    df![
+                        "a" => &[1, 2],
+                        "b" => &[1.0, 2.0]
+                    ]?,
+                    df![
+                        "a" => &[3],
+                        "b" => &[3.0]
+                    ]?,
+                ])
+            }
+
+            fn allows_projection_pushdown(&self) -> bool {
+                true
+            }
+        }
+
+        let source = Arc::new(Source {
+            seen: Mutex::new(None),
+        });
+        let out = LazyFrame::anonymous_scan(source.clone())
+            .unwrap()
+            .filter(col("b").gt(lit(1.5)))
+            .select(vec![col("a")])
+            .collect()
+            .unwrap();
+        assert_eq!(out.shape(), (2, 1));
+        assert_eq!(
+            Vec::from(out.column("a").unwrap().i32().unwrap()),
+            &[Some(2), Some(3)]
+        );
+
+        let options = source.seen.lock().unwrap().take().unwrap();
+        // the predicate column needs to be scanned as well
+        let mut columns = options.with_columns.unwrap();
+        columns.sort();
+        assert_eq!(columns, &["a", "b"]);
+        // predicate pushdown is not allowed by the source
+        assert!(options.predicate.is_none());
+    }
+
     #[test]
     fn test_lazy_ternary() {
         let df = get_df()
@@ -956,6 +1855,32 @@ mod test {
         assert_eq!(Some(43), df.column("new").unwrap().sum::<i32>());
     }
 
+    #[test]
+    fn test_lazy_ternary_null_branch() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &["x", "y", "z"]
+        }?;
+        let out = df
+            .lazy()
+            .select(&[
+                when(col("a").gt(lit(1)))
+                    .then(lit(Null {}))
+                    .otherwise(col("a")),
+                when(col("a").lt(lit(3)))
+                    .then(col("b"))
+                    .otherwise(lit(Null {})),
+            ])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["a", "b"]);
+        assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), None, None]);
+        assert_eq!(
+            Vec::from(out.column("b")?.utf8()?),
+            &[Some("x"), Some("y"), None]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_with_column() {
         let df = get_df()
@@ -998,6 +1923,42 @@ mod test {
         assert!(check.all_true())
     }
 
+    #[test]
+    fn test_lazy_params() {
+        let query = get_df()
+            .lazy()
+            .filter(col("sepal.width").gt(lit_param("cutoff")))
+            .prepare()
+            .unwrap();
+
+        for &cutoff in &[3.0, 3.5] {
+            let out = query
+                .collect(&[("cutoff", LiteralValue::Float64(cutoff))])
+                .unwrap();
+            let check = out.column("sepal.width").unwrap().f64().unwrap().gt(cutoff);
+            assert!(check.all_true());
+        }
+        // the parameter is cast to the type of the column it is compared with
+        let out = query
+            .collect(&[("cutoff", LiteralValue::Int32(3))])
+            .unwrap();
+        let check = out.column("sepal.width").unwrap().f64().unwrap().gt(3.0);
+        assert!(check.all_true());
+        // unbound parameter
+        assert!(query.collect(&[]).is_err());
+    }
+
+    #[test]
+    fn test_lazy_cancellation() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let out = get_df()
+            .lazy()
+            .filter(col("sepal.width").gt(lit(3.0)))
+            .collect_with_cancellation(token);
+        assert!(matches!(out, Err(PolarsError::Cancelled(_))));
+    }
+
     #[test]
     fn test_lazy_alias() {
         let df = get_df();
@@ -1192,6 +2153,38 @@ mod test {
         assert_eq!(new.column("foo").unwrap().sum::<i32>(), Some(1));
     }
 
+    #[test]
+    fn test_lazy_binary_ops_output_names() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &[3, 2, 1]
+        }?;
+        let lf = df.lazy().select(&[
+            col("a").gt(col("b")),
+            col("b").neq(lit(2)),
+            (col("a") * lit(2) + col("b"))
+                .cast(DataType::Float64)
+                .alias("c"),
+        ]);
+        let schema = lf.logical_plan.schema().clone();
+        let out = lf.collect()?;
+        // the results are named after the left operand, like in the schema
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        for (field, s) in schema.fields().iter().zip(out.get_columns()) {
+            assert_eq!(field.name(), s.name());
+            assert_eq!(field.data_type(), s.dtype());
+        }
+        assert_eq!(
+            Vec::from(out.column("a")?.bool()?),
+            &[Some(false), Some(false), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(out.column("c")?.f64()?),
+            &[Some(5.0), Some(6.0), Some(7.0)]
+        );
+        Ok(())
+    }
+
     fn load_df() -> DataFrame {
         df!("a" => &[1, 2, 3, 4, 5],
                      "b" => &["a", "a", "b", "c", "c"],
@@ -1364,6 +2357,92 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_lazy_constant_folding() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3]
+        }?;
+        let plan = df
+            .clone()
+            .lazy()
+            .select(vec![(lit(2) + lit(3)).alias("five")])
+            .optimize()?;
+        assert!(
+            matches!(plan, LogicalPlan::Projection{ expr, ..} if matches!(&expr[0], Expr::Alias(e, _) if **e == Expr::Literal(LiteralValue::Int32(5))))
+        );
+
+        let out = df
+            .lazy()
+            .select(vec![
+                col("a").gt(lit(1)).or(lit(false)).alias("or_false"),
+                col("a").gt(lit(1)).or(lit(true)).alias("or_true"),
+                (col("a") + lit(1)).lt_eq(lit(1) + lit(2)).alias("lt_eq"),
+                (col("a") / lit(0.5)).alias("div"),
+            ])
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("or_false")?.bool()?),
+            &[Some(false), Some(true), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(out.column("or_true")?.bool()?),
+            &[Some(true), Some(true), Some(true)]
+        );
+        assert_eq!(
+            Vec::from(out.column("lt_eq")?.bool()?),
+            &[Some(true), Some(true), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(out.column("div")?.f64()?),
+            &[Some(2.0), Some(4.0), Some(6.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_common_subexpr_elim() -> Result<()> {
+        let df = df! {
+            "g" => &["x", "x", "y"],
+            "a" => &[1, 2, 3],
+            "b" => &[2, 2, 4]
+        }?;
+        let product = || col("a") * col("b");
+        let lf = df.clone().lazy().select(vec![
+            (product() + lit(1)).alias("x"),
+            ((product() + lit(1)) * lit(2)).alias("y"),
+            product(),
+        ]);
+        let plan = lf.clone().optimize()?;
+        assert!(
+            matches!(plan, LogicalPlan::Projection{ input, ..} if matches!(*input, LogicalPlan::HStack{..}))
+        );
+        let out = lf.collect()?;
+        assert_eq!(out.get_column_names(), &["x", "y", "a"]);
+        assert_eq!(
+            Vec::from(out.column("x")?.i32()?),
+            &[Some(3), Some(5), Some(13)]
+        );
+        assert_eq!(
+            Vec::from(out.column("y")?.i32()?),
+            &[Some(6), Some(10), Some(26)]
+        );
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(4), Some(12)]
+        );
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("g")])
+            .agg(vec![product().sum(), product().max()])
+            .sort("g", false)
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["g", "a_sum", "a_max"]);
+        assert_eq!(Vec::from(out.column("a_sum")?.i32()?), &[Some(6), Some(12)]);
+        assert_eq!(Vec::from(out.column("a_max")?.i32()?), &[Some(4), Some(12)]);
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_wildcard() {
         let df = load_df();
@@ -1431,6 +2510,106 @@ mod test {
         assert!(df.frame_equal_missing(&lf().collect().unwrap().mean()));
     }
 
+    #[test]
+    fn test_lazy_cached_scans_with_other_projections() -> Result<()> {
+        let mut lf = scan_foods_csv();
+        // read every scan with its own projection
+        lf.opt_state.agg_scan_projection = false;
+        let left = lf.clone().select(&[col("category"), col("calories")]);
+        let right = lf.select(&[col("category"), col("fats_g")]);
+        let out = left
+            .inner_join(right, col("category"), col("category"), None)
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["category", "calories", "fats_g"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_slice_pushdown() -> Result<()> {
+        let lf = scan_foods_csv().select(&[col("category"), col("calories")]);
+        fn scan_rows(lp: &LogicalPlan) -> Option<usize> {
+            match lp {
+                LogicalPlan::Slice { input, .. } | LogicalPlan::Projection { input, .. } => {
+                    scan_rows(input)
+                }
+                LogicalPlan::CsvScan {
+                    stop_after_n_rows, ..
+                } => *stop_after_n_rows,
+                _ => None,
+            }
+        }
+        assert_eq!(scan_rows(&lf.clone().slice(2, 3).optimize()?), Some(5));
+        // the number of rows to scan saturates instead of overflowing
+        assert_eq!(
+            scan_rows(&lf.clone().slice(2, usize::MAX).optimize()?),
+            Some(usize::MAX)
+        );
+
+        let out = lf.clone().slice(2, 3).collect()?;
+        assert!(out.frame_equal(&lf.clone().collect()?.slice(2, 3)?));
+
+        // the filter needs all rows, so the scan isn't limited
+        let out = lf
+            .clone()
+            .filter(col("calories").gt(lit(100)))
+            .limit(2)
+            .collect()?;
+        let expected = lf
+            .with_slice_pushdown(false)
+            .filter(col("calories").gt(lit(100)))
+            .limit(2)
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_drop_duplicates_pushdown() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 1, 2],
+            "b" => &["x", "y", "x"]
+        }?;
+        let check = |lf: LazyFrame, expected: &[i32]| -> Result<()> {
+            let out = lf
+                .clone()
+                .with_projection_pushdown(false)
+                .with_predicate_pushdown(false)
+                .collect()?;
+            assert!(lf.collect()?.frame_equal(&out));
+            let a = out
+                .column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(a, expected);
+            Ok(())
+        };
+
+        // all columns decide which rows are duplicates, not only the selected ones
+        check(
+            df.clone()
+                .lazy()
+                .drop_duplicates(true, None)
+                .select(&[col("a")]),
+            &[1, 1, 2],
+        )?;
+        // only the first row with a = 1 is kept, which does not have b = "y"
+        check(
+            df.clone()
+                .lazy()
+                .drop_duplicates(true, Some(vec!["a".into()]))
+                .filter(col("b").eq(lit("y"))),
+            &[],
+        )?;
+        check(
+            df.lazy()
+                .drop_duplicates(true, Some(vec!["a".into()]))
+                .filter(col("a").gt(lit(1))),
+            &[2],
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_df_aggregations() {
         let df = load_df();
@@ -1468,6 +2647,400 @@ mod test {
             .unwrap();
     }
 
+    #[test]
+    fn test_lazy_predicate_pushdown_computed_and_slice() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3, 4, 5],
+            "b" => &[5, 4, 3, 2, 1]
+        }?;
+        let check = |lf: LazyFrame, expected: &[i32]| -> Result<()> {
+            let out = lf.clone().collect()?;
+            assert!(out.frame_equal(&lf.with_predicate_pushdown(false).collect()?));
+            let a = out
+                .column("a")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(a, expected);
+            Ok(())
+        };
+
+        // a predicate on a computed column is not pushed below the projection
+        check(
+            df.clone()
+                .lazy()
+                .select(&[col("a") * lit(10), col("b")])
+                .filter(col("a").gt(lit(30))),
+            &[40, 50],
+        )?;
+        check(
+            df.clone()
+                .lazy()
+                .select(&[(col("b") + lit(1)).alias("a")])
+                .filter(col("a").lt(lit(4))),
+            &[3, 2],
+        )?;
+        // an aliased column is renamed
+        check(
+            df.clone()
+                .lazy()
+                .select(&[col("b").alias("a")])
+                .filter(col("a").lt(lit(3))),
+            &[2, 1],
+        )?;
+        // a filter after a slice only filters the sliced rows
+        check(df.lazy().slice(0, 2).filter(col("a").gt(lit(1))), &[2])?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_with_columns_on_input() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3]
+        }?;
+        let lf = df.lazy().with_columns(vec![
+            (col("a") * lit(2)).alias("a"),
+            (col("a") + lit(1)).alias("b"),
+            lit(1.5).alias("c"),
+            lit(2.5).alias("c"),
+        ]);
+        let schema = lf.logical_plan.schema().clone();
+        let out = lf.collect()?;
+        assert_eq!(out.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(schema.fields().len(), 3);
+        // every expression sees the input columns, not the ones replaced by the others
+        assert_eq!(
+            Vec::from(out.column("a")?.i32()?),
+            &[Some(2), Some(4), Some(6)]
+        );
+        assert_eq!(
+            Vec::from(out.column("b")?.i32()?),
+            &[Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(out.column("c")?.f64()?.get(0), Some(2.5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_plan_type_check() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &[1.5, 2.5, 3.5]
+        }?;
+        let lf = df.clone().lazy().select(vec![col("c")]);
+        assert!(lf.describe_optimized_plan().is_err());
+        let lf = df.clone().lazy().filter(col("c").gt(lit(1)));
+        assert!(matches!(lf.collect(), Err(PolarsError::NotFound(_))));
+
+        // a list can not be compared with an integer
+        let lf = df
+            .clone()
+            .lazy()
+            .groupby(vec![col("a")])
+            .agg(vec![col("b").list().alias("l")])
+            .filter(col("l").gt(lit(1)));
+        assert!(lf.collect().is_err());
+
+        // numeric columns are coerced to their supertype
+        let out = df.lazy().filter(col("a").lt(col("b"))).collect()?;
+        assert_eq!(out.height(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_order() -> Result<()> {
+        let orders = df! {
+            "customer" => &[1, 2, 3, 1],
+            "product" => &[10, 20, 10, 30],
+            "quantity" => &[1, 2, 3, 4]
+        }?;
+        let customers = df! {
+            "customer" => &[1, 2, 3],
+            "customer_name" => &["a", "b", "c"]
+        }?;
+        let products = df! {
+            "product" => &[10, 20],
+            "product_name" => &["x", "y"]
+        }?;
+        let query = |products: LazyFrame| {
+            orders
+                .clone()
+                .lazy()
+                .inner_join(
+                    customers.clone().lazy(),
+                    col("customer"),
+                    col("customer"),
+                    None,
+                )
+                .inner_join(products, col("product"), col("product"), None)
+        };
+        let joins_customers_last = |plan: &LogicalPlan| {
+            matches!(plan, LogicalPlan::Projection { input, .. }
+                if matches!(&**input, LogicalPlan::Join { input_right, .. }
+                    if input_right.schema().field_with_name("customer_name").is_ok()))
+        };
+
+        // the products are smaller, so they are joined first
+        let lf = query(products.clone().lazy());
+        assert!(joins_customers_last(&lf.clone().optimize()?));
+        assert!(!joins_customers_last(
+            &lf.clone().with_join_order(false).optimize()?
+        ));
+        let out = lf.sort("quantity", false).collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &[
+                "customer",
+                "product",
+                "quantity",
+                "customer_name",
+                "product_name"
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("quantity")?.i32()?),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(out.column("product_name")?.utf8()?),
+            &[Some("x"), Some("y"), Some("x")]
+        );
+
+        // a hinted input is joined first, whatever its size
+        let many_products = df! {
+            "product" => &[10, 20, 30, 40],
+            "product_name" => &["x", "y", "z", "w"]
+        }?;
+        assert!(!joins_customers_last(
+            &query(many_products.clone().lazy()).optimize()?
+        ));
+        assert!(joins_customers_last(
+            &query(many_products.lazy().hint_broadcast()).optimize()?
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_shared_cache_runs_once() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &[4, 5, 6]
+        }?;
+        // both inputs of the join read the same cache, so they don't run in parallel and the
+        // second input finds the cache filled by the first
+        let cached = df
+            .lazy()
+            .map(
+                |df: DataFrame| {
+                    EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+                    Ok(df)
+                },
+                None,
+                None,
+            )
+            .cache();
+        let out = cached
+            .clone()
+            .inner_join(cached, col("a"), col("a"), None)
+            .collect()?;
+        assert_eq!(out.height(), 3);
+        assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_cache_reused_by_branches() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        static EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+        let left = df! {
+            "k" => &[1, 2, 3],
+            "v" => &[10, 20, 30]
+        }?;
+        let right = df! {
+            "k" => &[1, 2, 3],
+            "v" => &[100, 200, 300]
+        }?;
+        // caches of frames with the same columns don't share their output
+        let out = left
+            .clone()
+            .lazy()
+            .cache()
+            .inner_join(right.lazy().cache(), col("k"), col("k"), None)
+            .sort("k", false)
+            .collect()?;
+        assert_eq!(
+            Vec::from(out.column("v_right")?.i32()?),
+            &[Some(100), Some(200), Some(300)]
+        );
+
+        // the filters of both branches are applied after the cache, which is filled once
+        let cached = left
+            .lazy()
+            .map(
+                |df: DataFrame| {
+                    EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+                    Ok(df)
+                },
+                None,
+                None,
+            )
+            .cache();
+        let out = cached
+            .clone()
+            .filter(col("v").gt(lit(10)))
+            .inner_join(
+                cached.filter(col("v").lt(lit(30))),
+                col("k"),
+                col("k"),
+                None,
+            )
+            .collect()?;
+        assert_eq!(Vec::from(out.column("k")?.i32()?), &[Some(2)]);
+        assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_explain() -> Result<()> {
+        let df = df! {
+            "a" => &[1, 2, 3],
+            "b" => &[1.0, 2.0, 3.0]
+        }?;
+        let explained = df
+            .clone()
+            .lazy()
+            .filter(col("a").gt(lit(1)))
+            .select(&[col("a")])
+            .explain(false)?;
+        let lines = explained.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("SELECT"));
+        assert_eq!(lines[1], "  schema: {a: Int32}; rows: 3");
+        assert!(lines[2].starts_with("  FILTER"));
+        assert!(lines[4].starts_with("    TABLE"));
+        assert_eq!(lines[5], "      schema: {a: Int32, b: Float64}; rows: 3");
+
+        // the inputs of a join get their own nodes in the graph, even if they are equal
+        let dot = df
+            .clone()
+            .lazy()
+            .inner_join(df.lazy(), col("a"), col("a"), None)
+            .to_dot(false)?;
+        assert!(dot.contains("[1]\""));
+        assert!(dot.contains("[2]\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_join_keys() -> Result<()> {
+        let left = df! {
+            "a" => &[1, 2, 3],
+            "b" => &[1, 1, 2],
+            "value" => &[10, 20, 30]
+        }?;
+        let right = df! {
+            "x" => &[1, 2, 3],
+            "y" => &[2, 3, 3],
+            "other" => &[100, 200, 300]
+        }?;
+
+        // a computed right key has no output name, every right column stays in the result
+        let lf = left.clone().lazy().inner_join(
+            right.clone().lazy(),
+            col("a") + col("b"),
+            col("x") + col("y"),
+            None,
+        );
+        let schema = lf.logical_plan().schema().clone();
+        let out = lf.collect()?;
+        assert_eq!(
+            out.get_column_names(),
+            &["a", "b", "value", "x", "y", "other"]
+        );
+        assert_eq!(
+            schema
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>(),
+            out.get_column_names()
+        );
+        let out = out.sort("other", false)?;
+        assert_eq!(
+            Vec::from(out.column("other")?.i32()?),
+            &[Some(100), Some(200)]
+        );
+        // the user column x and not the evaluated key
+        assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(1), Some(2)]);
+
+        // the number of keys must match
+        let lf = left.lazy().join(
+            right.lazy(),
+            vec![col("a"), col("b")],
+            vec![col("x")],
+            None,
+            JoinType::Inner,
+        );
+        assert!(matches!(lf.collect(), Err(PolarsError::ShapeMisMatch(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_aggregate_pushdown_count() -> Result<()> {
+        let aggs = || {
+            vec![
+                col("calories").count().alias("n"),
+                col("calories").first(),
+                col("sugars_g").last(),
+            ]
+        };
+        let pushed = scan_foods_csv()
+            .select(aggs())
+            .with_aggregate_pushdown(true);
+        assert!(matches!(
+            pushed.clone().optimize()?,
+            LogicalPlan::CsvScan { aggregate, .. } if aggregate.len() == 3
+        ));
+        let out = pushed.collect()?;
+        let expected = scan_foods_csv().select(aggs()).collect()?;
+        assert!(out.frame_equal(&expected));
+        assert_eq!(Vec::from(out.column("n")?.u32()?), &[Some(27)]);
+        assert_eq!(out.column("sugars_g")?.get(0), AnyValue::Int64(11));
+
+        // the scan aggregates the column itself, so expressions on the column are not pushed down
+        let out = scan_foods_csv()
+            .select(vec![(col("calories") * lit(2)).max()])
+            .with_aggregate_pushdown(true)
+            .collect()?;
+        let expected = scan_foods_csv()
+            .select(vec![(col("calories") * lit(2)).max()])
+            .collect()?;
+        assert!(out.frame_equal(&expected));
+
+        // only counting rows doesn't parse the values
+        let counts = || vec![col("calories").count().alias("n"), col("fats_g").count()];
+        let out = scan_foods_csv()
+            .select(counts())
+            .with_aggregate_pushdown(true)
+            .collect()?;
+        let expected = scan_foods_csv().select(counts()).collect()?;
+        assert!(out.frame_equal(&expected));
+        assert_eq!(out.get_column_names(), &["n", "fats_g"]);
+        assert_eq!(Vec::from(out.column("fats_g")?.u32()?), &[Some(27)]);
+        let path = "../../examples/aggregate_multiple_files_in_chunks/datasets/foods1.csv";
+        let out = LazyCsvReader::new(path.to_string())
+            .with_stop_after_n_rows(Some(10))
+            .finish()
+            .select(counts())
+            .with_aggregate_pushdown(true)
+            .collect()?;
+        assert_eq!(Vec::from(out.column("n")?.u32()?), &[Some(10)]);
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_update_column() {
         let df = load_df();
@@ -1529,6 +3102,44 @@ mod test {
         dbg!(out);
     }
 
+    #[test]
+    fn test_lazy_window_names_and_computed_inputs() -> Result<()> {
+        let df = df! {
+            "groups" => &[1, 1, 2, 2],
+            "values" => &[1.0, 3.0, 5.0, 9.0]
+        }?;
+        let out = df
+            .lazy()
+            .select(&[
+                col("groups"),
+                col("values").mean().over(col("groups")),
+                (col("values") * lit(2.0))
+                    .sum()
+                    .over(col("groups"))
+                    .alias("double_sum"),
+                (col("values") - col("values").mean().over(col("groups"))).alias("centered"),
+            ])
+            .collect()?;
+        // the window result is named after the column it is applied on
+        assert_eq!(
+            out.get_column_names(),
+            &["groups", "values", "double_sum", "centered"]
+        );
+        assert_eq!(
+            Vec::from(out.column("values")?.f64()?),
+            &[Some(2.0), Some(2.0), Some(7.0), Some(7.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("double_sum")?.f64()?),
+            &[Some(8.0), Some(8.0), Some(28.0), Some(28.0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("centered")?.f64()?),
+            &[Some(-1.0), Some(1.0), Some(-2.0), Some(2.0)]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_double_projection() {
         let df = df! {
@@ -1634,4 +3245,28 @@ mod test {
 
         assert_eq!(out.get_column_names(), &["ham", "bar"]);
     }
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_select_regex_column() -> Result<()> {
+        let df = df! {
+            "sensor_1" => &[1, 2],
+            "time" => &[0, 1],
+            "sensor_2" => &[3, 4]
+        }?;
+        let out = df
+            .clone()
+            .lazy()
+            .select(vec![col(r"^sensor_\d+$").sum()])
+            .collect()?;
+        assert_eq!(out.get_column_names(), &["sensor_1", "sensor_2"]);
+        assert_eq!(out.column("sensor_2")?.i32()?.get(0), Some(7));
+
+        let out = df
+            .lazy()
+            .groupby(vec![col("time")])
+            .agg(vec![col("^sensor.*$").first()])
+            .collect()?;
+        assert_eq!(out.width(), 3);
+        Ok(())
+    }
 }
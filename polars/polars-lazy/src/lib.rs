@@ -192,7 +192,9 @@
 //!      )
 //! }
 //! ```
-#[cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+// the crate builds on stable Rust, only the documentation on docs.rs uses an unstable feature
+#![cfg_attr(not(docsrs), deny(unstable_features))]
 pub mod dsl;
 pub mod frame;
 pub mod functions;
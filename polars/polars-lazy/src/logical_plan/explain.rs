@@ -0,0 +1,201 @@
+use crate::prelude::*;
+use polars_core::prelude::*;
+use std::fmt::Write;
+
+impl LogicalPlan {
+    /// The number of rows of the source of the plan, if it is known. This is an upper bound, the
+    /// rows removed by filters, joins and aggregations are not taken into account.
+    pub(crate) fn estimated_rows(&self) -> Option<usize> {
+        use LogicalPlan::*;
+        match self {
+            DataFrameScan { df, .. } => Some(df.height()),
+            CsvScan {
+                stop_after_n_rows, ..
+            } => *stop_after_n_rows,
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                stop_after_n_rows, ..
+            } => *stop_after_n_rows,
+            Slice { input, len, .. } => Some(input.estimated_rows().map_or(*len, |n| n.min(*len))),
+            Selection { input, .. }
+            | Projection { input, .. }
+            | LocalProjection { input, .. }
+            | HStack { input, .. }
+            | Sort { input, .. }
+            | Cache { input, .. }
+            | Distinct { input, .. }
+            | Aggregate { input, .. } => input.estimated_rows(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn inputs(&self) -> Vec<&LogicalPlan> {
+        use LogicalPlan::*;
+        match self {
+            Selection { input, .. }
+            | Cache { input, .. }
+            | LocalProjection { input, .. }
+            | Projection { input, .. }
+            | Aggregate { input, .. }
+            | HStack { input, .. }
+            | Distinct { input, .. }
+            | Sort { input, .. }
+            | Explode { input, .. }
+            | Slice { input, .. }
+            | Melt { input, .. }
+            | Udf { input, .. } => vec![&**input],
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => vec![&**input_left, &**input_right],
+            _ => vec![],
+        }
+    }
+
+    /// The number of nodes in the plan.
+    pub(crate) fn n_nodes(&self) -> usize {
+        1 + self
+            .inputs()
+            .iter()
+            .map(|input| input.n_nodes())
+            .sum::<usize>()
+    }
+
+    /// A one line description of the node, without its inputs.
+    fn explain_node(&self) -> String {
+        use LogicalPlan::*;
+        let fmt_projection = |total: usize, projected: Option<usize>| match projected {
+            Some(n) => format!("{}/{}", n, total),
+            None => format!("*/{}", total),
+        };
+        match self {
+            Cache { id, .. } => format!("CACHE {}", id),
+            Selection { predicate, .. } => format!("FILTER {:?}", predicate),
+            CsvScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => format!(
+                "CSV SCAN {}; PROJECT {} COLUMNS; SELECTION: {:?}",
+                path,
+                fmt_projection(
+                    schema.fields().len(),
+                    with_columns.as_ref().map(|c| c.len())
+                ),
+                predicate
+            ),
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                path,
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => format!(
+                "PARQUET SCAN {}; PROJECT {} COLUMNS; SELECTION: {:?}",
+                path,
+                fmt_projection(
+                    schema.fields().len(),
+                    with_columns.as_ref().map(|c| c.len())
+                ),
+                predicate
+            ),
+            LogicalPlan::AnonymousScan {
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => format!(
+                "ANONYMOUS SCAN; PROJECT {} COLUMNS; SELECTION: {:?}",
+                fmt_projection(
+                    schema.fields().len(),
+                    with_columns.as_ref().map(|c| c.len())
+                ),
+                predicate
+            ),
+            DataFrameScan {
+                schema,
+                projection,
+                selection,
+                ..
+            } => format!(
+                "TABLE; PROJECT {} COLUMNS; SELECTION: {:?}",
+                fmt_projection(schema.fields().len(), projection.as_ref().map(|p| p.len())),
+                selection
+            ),
+            Projection { expr, .. } => format!("SELECT {:?}", expr),
+            LocalProjection { expr, .. } => format!("LOCAL SELECT {:?}", expr),
+            Aggregate { keys, aggs, .. } => format!("AGGREGATE {:?} BY {:?}", aggs, keys),
+            Join {
+                how,
+                left_on,
+                right_on,
+                ..
+            } => format!(
+                "{:?} JOIN ON left: {:?} right: {:?}",
+                how, left_on, right_on
+            ),
+            HStack { exprs, .. } => format!("WITH COLUMNS {:?}", exprs),
+            Distinct { subset, .. } => match &**subset {
+                Some(subset) => format!("DISTINCT BY {:?}", subset),
+                None => "DISTINCT".to_string(),
+            },
+            Sort {
+                by_column, options, ..
+            } => format!(
+                "SORT BY {}{}",
+                by_column,
+                if options.descending {
+                    " DESCENDING"
+                } else {
+                    ""
+                }
+            ),
+            Explode { columns, .. } => format!("EXPLODE {:?}", columns),
+            Slice { offset, len, .. } => format!("SLICE offset: {}, len: {}", offset, len),
+            Melt {
+                id_vars,
+                value_vars,
+                ..
+            } => format!("MELT id_vars: {:?} value_vars: {:?}", id_vars, value_vars),
+            Udf { .. } => "UDF".to_string(),
+        }
+    }
+
+    fn explain_level(&self, acc_str: &mut String, level: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(level);
+        writeln!(acc_str, "{}{}", indent, self.explain_node())?;
+        let schema = self
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let rows = match self.estimated_rows() {
+            Some(rows) => rows.to_string(),
+            None => "?".to_string(),
+        };
+        writeln!(
+            acc_str,
+            "{}  schema: {{{}}}; rows: {}",
+            indent, schema, rows
+        )?;
+        for input in self.inputs() {
+            input.explain_level(acc_str, level + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Describe the plan as an indented tree, one node per line with its inputs below it. Every
+    /// node is annotated with its output schema and the estimated number of rows, which is the
+    /// number of rows of its source if that is known and `?` otherwise.
+    pub fn explain(&self) -> String {
+        let mut s = String::with_capacity(512);
+        self.explain_level(&mut s, 0).expect("io error");
+        s
+    }
+}
@@ -1,3 +1,4 @@
+mod explain;
 pub(crate) mod optimizer;
 use crate::logical_plan::optimizer::predicate::combine_predicates;
 use crate::logical_plan::LogicalPlan::CsvScan;
@@ -8,12 +9,14 @@ use crate::utils::{
 use crate::{prelude::*, utils};
 use ahash::RandomState;
 use itertools::Itertools;
+use polars_core::config;
 use polars_core::frame::hash_join::JoinType;
 use polars_core::prelude::*;
 use polars_io::csv_core::utils::infer_file_schema;
 use polars_io::prelude::*;
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{cell::Cell, fmt, sync::Arc};
 
 #[cfg_attr(docsrs, doc(cfg(feature = "temporal")))]
@@ -48,6 +51,46 @@ impl Debug for dyn DataFrameUdf {
     }
 }
 
+/// Options passed to an [AnonymousScan].
+#[derive(Clone, Debug, Default)]
+pub struct AnonymousScanOptions {
+    /// Columns that are needed downstream. Only set if the source
+    /// [allows projection pushdown](AnonymousScan::allows_projection_pushdown).
+    pub with_columns: Option<Vec<String>>,
+    /// Filter that will be applied on the scanned data. Only set if the source
+    /// [allows predicate pushdown](AnonymousScan::allows_predicate_pushdown).
+    pub predicate: Option<Expr>,
+}
+
+/// A user defined data source that can be used as a scan in a lazy query.
+///
+/// The pushed down projection and predicate are hints; the source may use them to read less data.
+/// The query engine selects the projected columns and applies the predicate on the scanned data
+/// regardless.
+pub trait AnonymousScan: Send + Sync {
+    /// The schema of the data produced by [scan](AnonymousScan::scan).
+    fn schema(&self) -> Result<Schema>;
+
+    /// Produce the data as a sequence of batches, all with the same schema.
+    fn scan(&self, options: AnonymousScanOptions) -> Result<Vec<DataFrame>>;
+
+    /// Accept the projected columns in [scan](AnonymousScan::scan).
+    fn allows_projection_pushdown(&self) -> bool {
+        false
+    }
+
+    /// Accept the filter predicate in [scan](AnonymousScan::scan).
+    fn allows_predicate_pushdown(&self) -> bool {
+        false
+    }
+}
+
+impl Debug for dyn AnonymousScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "anonymous_scan")
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum LiteralValue {
     Null,
@@ -82,6 +125,9 @@ pub enum LiteralValue {
     },
     #[cfg(feature = "temporal")]
     DateTime(NaiveDateTime),
+    /// A named placeholder that is bound to a value when the query is executed.
+    /// See [lit_param](crate::dsl::lit_param).
+    Param(String),
 }
 
 impl LiteralValue {
@@ -103,6 +149,8 @@ impl LiteralValue {
             LiteralValue::Range { data_type, .. } => data_type.clone(),
             #[cfg(feature = "temporal")]
             LiteralValue::DateTime(_) => DataType::Date64,
+            // the type of a parameter is only known once it is bound
+            LiteralValue::Param(_) => DataType::Null,
             _ => panic!("Cannot treat {:?} as scalar value", self),
         }
     }
@@ -116,8 +164,11 @@ pub enum LogicalPlan {
         input: Box<LogicalPlan>,
         predicate: Expr,
     },
+    /// Execute the input once and reuse its output for every branch of the plan that reads it.
     Cache {
         input: Box<LogicalPlan>,
+        /// Identifies the cached plan, copies of the plan share the id.
+        id: usize,
     },
     CsvScan {
         path: String,
@@ -145,6 +196,12 @@ pub enum LogicalPlan {
         stop_after_n_rows: Option<usize>,
         cache: bool,
     },
+    AnonymousScan {
+        function: Arc<dyn AnonymousScan>,
+        schema: SchemaRef,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Expr>,
+    },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     DataFrameScan {
         df: Arc<DataFrame>,
@@ -171,6 +228,7 @@ pub enum LogicalPlan {
         aggs: Vec<Expr>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        maintain_order: bool,
     },
     Join {
         input_left: Box<LogicalPlan>,
@@ -179,8 +237,7 @@ pub enum LogicalPlan {
         how: JoinType,
         left_on: Vec<Expr>,
         right_on: Vec<Expr>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     },
     HStack {
         input: Box<LogicalPlan>,
@@ -195,7 +252,7 @@ pub enum LogicalPlan {
     Sort {
         input: Box<LogicalPlan>,
         by_column: String,
-        reverse: bool,
+        options: SortOptions,
     },
     Explode {
         input: Box<LogicalPlan>,
@@ -245,7 +302,7 @@ impl fmt::Debug for LogicalPlan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use LogicalPlan::*;
         match self {
-            Cache { input } => write!(f, "CACHE {:?}", input),
+            Cache { input, id } => write!(f, "CACHE [{}] {:?}", id, input),
             #[cfg(feature = "parquet")]
             ParquetScan {
                 path,
@@ -289,6 +346,23 @@ impl fmt::Debug for LogicalPlan {
                     path, n_columns, total_columns, predicate
                 )
             }
+            LogicalPlan::AnonymousScan {
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+                write!(
+                    f,
+                    "ANONYMOUS SCAN; PROJECT {}/{} COLUMNS; SELECTION: {:?}",
+                    n_columns, total_columns, predicate
+                )
+            }
             DataFrameScan {
                 schema,
                 projection,
@@ -392,7 +466,7 @@ impl LogicalPlan {
     pub(crate) fn dot(&self, acc_str: &mut String, id: usize, prev_node: &str) -> std::fmt::Result {
         use LogicalPlan::*;
         match self {
-            Cache { input } => {
+            Cache { input, .. } => {
                 let current_node = format!("CACHE [{}]", id);
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input.dot(acc_str, id + 1, &current_node)
@@ -428,6 +502,30 @@ impl LogicalPlan {
                     self.write_dot(acc_str, prev_node, &current_node, id)
                 }
             }
+            LogicalPlan::AnonymousScan {
+                schema,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let total_columns = schema.fields().len();
+                let mut n_columns = "*".to_string();
+                if let Some(columns) = with_columns {
+                    n_columns = format!("{}", columns.len());
+                }
+                let pred = fmt_predicate(predicate.as_ref());
+
+                let current_node = format!(
+                    "ANONYMOUS SCAN;\nπ {}/{};\nσ {}\n[{}]",
+                    n_columns, total_columns, pred, id
+                );
+                if id == 0 {
+                    self.write_dot(acc_str, prev_node, &current_node, id)?;
+                    write!(acc_str, "\"{}\"", current_node)
+                } else {
+                    self.write_dot(acc_str, prev_node, &current_node, id)
+                }
+            }
             DataFrameScan {
                 schema,
                 projection,
@@ -572,7 +670,9 @@ impl LogicalPlan {
                     format!("JOIN left {:?}; right: {:?} [{}]", left_on, right_on, id);
                 self.write_dot(acc_str, prev_node, &current_node, id)?;
                 input_left.dot(acc_str, id + 1, &current_node)?;
-                input_right.dot(acc_str, id + 1, &current_node)
+                // the ids of the right input follow those of the left input, so equal nodes in
+                // both inputs don't collapse into one node in the graph
+                input_right.dot(acc_str, id + 1 + input_left.n_nodes(), &current_node)
             }
             Udf { input, .. } => {
                 let current_node = format!("UDF [{}]", id);
@@ -717,14 +817,36 @@ fn replace_wildcard_with_column(expr: Expr, column_name: Arc<String>) -> Expr {
             expr: Box::new(replace_wildcard_with_column(*expr, column_name)),
             data_type,
         },
+        // a regex column is expanded the same way as a wildcard
+        #[cfg(feature = "strings")]
+        Expr::Column(name) if is_regex_column(&name) => Expr::Column(column_name),
         Expr::Column(_) => expr,
         Expr::Literal(_) => expr,
         Expr::Except(_) => expr,
     }
 }
 
+/// A column name that starts with `^` and ends with `$` is a regular expression that selects
+/// all matching columns.
+#[cfg(feature = "strings")]
+fn is_regex_column(name: &str) -> bool {
+    name.len() > 1 && name.starts_with('^') && name.ends_with('$')
+}
+
+/// The pattern of an expression with a single root column of which the name is a regular
+/// expression, e.g. `col("^sensor_.*$")`.
+#[cfg(feature = "strings")]
+fn root_column_regex(expr: &Expr) -> Option<String> {
+    let roots = expr_to_root_column_exprs(expr);
+    match roots.as_slice() {
+        [Expr::Column(name)] if is_regex_column(name) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
 /// In case of single col(*) -> do nothing, no selection is the same as select all
 /// In other cases replace the wildcard with an expression with all columns
+/// Expressions on a regex column, `col("^...$")`, are repeated for every matching column.
 fn rewrite_projections(exprs: Vec<Expr>, schema: &Schema) -> Vec<Expr> {
     let mut result = Vec::with_capacity(exprs.len() + schema.fields().len());
     let mut exclude = vec![];
@@ -740,6 +862,20 @@ fn rewrite_projections(exprs: Vec<Expr>, schema: &Schema) -> Vec<Expr> {
             }
         }
 
+        #[cfg(feature = "strings")]
+        if let Some(pattern) = root_column_regex(&expr) {
+            // an invalid pattern is left as column name and fails as unknown column later on
+            if let Ok(re) = polars_core::utils::regex::Regex::new(&pattern) {
+                for field in schema.fields() {
+                    if re.is_match(field.name()) {
+                        let name = Arc::new(field.name().clone());
+                        result.push(replace_wildcard_with_column(expr.clone(), name));
+                    }
+                }
+                continue;
+            }
+        }
+
         let mut has_wildcard = false;
         let roots = expr_to_root_column_exprs(&expr);
         for e in roots {
@@ -797,7 +933,7 @@ impl LogicalPlan {
     pub(crate) fn schema(&self) -> &Schema {
         use LogicalPlan::*;
         match self {
-            Cache { input } => input.schema(),
+            Cache { input, .. } => input.schema(),
             Sort { input, .. } => input.schema(),
             Explode { input, .. } => input.schema(),
             #[cfg(feature = "parquet")]
@@ -805,6 +941,7 @@ impl LogicalPlan {
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => input.schema(),
             CsvScan { schema, .. } => schema,
+            LogicalPlan::AnonymousScan { schema, .. } => schema,
             Projection { schema, .. } => schema,
             LocalProjection { schema, .. } => schema,
             Aggregate { schema, .. } => schema,
@@ -859,6 +996,17 @@ impl LogicalPlanBuilder {
         .into()
     }
 
+    pub fn anonymous_scan(function: Arc<dyn AnonymousScan>) -> Result<Self> {
+        let schema = Arc::new(function.schema()?);
+        Ok(LogicalPlan::AnonymousScan {
+            function,
+            schema,
+            with_columns: None,
+            predicate: None,
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn scan_csv(
         path: String,
@@ -877,7 +1025,7 @@ impl LogicalPlanBuilder {
             let (schema, _) = infer_file_schema(
                 &mut file,
                 delimiter,
-                Some(100),
+                Some(config::infer_schema_length()),
                 has_header,
                 schema_overwrite,
             )
@@ -901,8 +1049,10 @@ impl LogicalPlanBuilder {
     }
 
     pub fn cache(self) -> Self {
+        static CACHE_ID: AtomicUsize = AtomicUsize::new(0);
         LogicalPlan::Cache {
             input: Box::new(self.0),
+            id: CACHE_ID.fetch_add(1, Ordering::Relaxed),
         }
         .into()
     }
@@ -960,12 +1110,13 @@ impl LogicalPlanBuilder {
         let mut new_fields = schema.fields().clone();
 
         for e in &exprs {
-            let field = e.to_field(schema, Context::Other).unwrap();
-            match schema.index_of(field.name()) {
-                Ok(idx) => {
+            let field = utils::expr_to_field_or_null(e, schema, Context::Other);
+            // a later expression with the same name replaces an earlier one
+            match new_fields.iter().position(|f| f.name() == field.name()) {
+                Some(idx) => {
                     new_fields[idx] = field;
                 }
-                Err(_) => new_fields.push(field),
+                None => new_fields.push(field),
             }
         }
 
@@ -1001,6 +1152,7 @@ impl LogicalPlanBuilder {
         keys: Arc<Vec<Expr>>,
         aggs: Vec<Expr>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        maintain_order: bool,
     ) -> Self {
         debug_assert!(!keys.is_empty());
         let current_schema = self.0.schema();
@@ -1016,6 +1168,7 @@ impl LogicalPlanBuilder {
             aggs,
             schema: Arc::new(schema),
             apply,
+            maintain_order,
         }
         .into()
     }
@@ -1035,11 +1188,11 @@ impl LogicalPlanBuilder {
         .into()
     }
 
-    pub fn sort(self, by_column: String, reverse: bool) -> Self {
+    pub fn sort(self, by_column: String, options: SortOptions) -> Self {
         LogicalPlan::Sort {
             input: Box::new(self.0),
             by_column,
-            reverse,
+            options,
         }
         .into()
     }
@@ -1116,8 +1269,7 @@ impl LogicalPlanBuilder {
         how: JoinType,
         left_on: Vec<Expr>,
         right_on: Vec<Expr>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     ) -> Self {
         let schema_left = self.0.schema();
         let schema_right = other.schema();
@@ -1132,9 +1284,10 @@ impl LogicalPlanBuilder {
             fields.push(f.clone());
         }
 
+        // a key without an output name doesn't remove a column of the right table
         let right_names: HashSet<_, RandomState> = right_on
             .iter()
-            .map(|e| utils::output_name(e).expect("could not find name"))
+            .filter_map(|e| utils::output_name(e).ok())
             .collect();
 
         for f in schema_right.fields() {
@@ -1142,7 +1295,7 @@ impl LogicalPlanBuilder {
 
             if !right_names.contains(name) {
                 if names.contains(name) {
-                    let new_name = format!("{}_right", name);
+                    let new_name = format!("{}{}", name, options.get_suffix());
                     let field = Field::new(&new_name, f.data_type().clone());
                     fields.push(field)
                 } else {
@@ -1160,8 +1313,7 @@ impl LogicalPlanBuilder {
             schema,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         }
         .into()
     }
@@ -1200,6 +1352,58 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_expr_to_field_matches_execution() -> Result<()> {
+        let df = df! {
+            "int" => &[1i32, 2, 2],
+            "float32" => &[1.0f32, 2.0, 4.0],
+            "float64" => &[1.0f64, 2.0, 4.0],
+            "str" => &["a", "b", "a"]
+        }?;
+        let check = |lf: LazyFrame| -> Result<()> {
+            let plan_schema = lf.logical_plan.schema().clone();
+            let out = lf.collect()?;
+            for (field, s) in plan_schema.fields().iter().zip(out.get_columns()) {
+                assert_eq!(field.data_type(), s.dtype(), "column {}", field.name());
+            }
+            Ok(())
+        };
+
+        check(
+            df.clone().lazy().select(&[
+                (col("int") + lit(1)).alias("add_lit"),
+                (col("int") * col("float64")).alias("supertype"),
+                col("int").gt(lit(1)).alias("gt"),
+                col("int").cast(DataType::Float32).alias("cast"),
+                col("int").is_null().alias("is_null"),
+                col("int").is_unique().alias("is_unique"),
+                col("str").shift(1).alias("shift"),
+                when(col("int").gt(lit(1)))
+                    .then(col("float64"))
+                    .otherwise(col("float64") * lit(2.0))
+                    .alias("ternary"),
+            ]),
+        )?;
+
+        check(df.clone().lazy().select(&[
+            col("int").mean().alias("mean"),
+            col("int").sum().alias("sum"),
+            col("int").count().alias("count"),
+            col("int").n_unique().alias("n_unique"),
+            col("float32").std().alias("std_f32"),
+            col("float64").var().alias("var_f64"),
+            col("int").max().alias("max"),
+        ]))?;
+
+        check(df.lazy().groupby(vec![col("str")]).agg(vec![
+            col("int").mean(),
+            col("int").min(),
+            col("int").first(),
+            col("int").count(),
+            col("float64").sum(),
+        ]))
+    }
+
     #[test]
     fn test_lazy_arithmetic() {
         let df = get_df();
@@ -1,6 +1,5 @@
 use crate::logical_plan::Context;
 use crate::prelude::*;
-use crate::utils::{aexpr_to_root_nodes, has_aexpr};
 use polars_core::prelude::*;
 
 pub(crate) struct AggregatePushdown {
@@ -8,6 +7,25 @@ pub(crate) struct AggregatePushdown {
     processed_state: bool,
 }
 
+/// Check if the expression is an aggregation that a scan can compute while it reads the file.
+fn is_scan_aggregation(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    let node = match expr_arena.get(node) {
+        AExpr::Alias(input, _) => *input,
+        _ => node,
+    };
+    match expr_arena.get(node) {
+        AExpr::Agg(AAggExpr::Min(input))
+        | AExpr::Agg(AAggExpr::Max(input))
+        | AExpr::Agg(AAggExpr::First(input))
+        | AExpr::Agg(AAggExpr::Last(input))
+        | AExpr::Agg(AAggExpr::Sum(input))
+        | AExpr::Agg(AAggExpr::Count(input)) => {
+            matches!(expr_arena.get(*input), AExpr::Column(_))
+        }
+        _ => false,
+    }
+}
+
 impl AggregatePushdown {
     pub(crate) fn new() -> Self {
         AggregatePushdown {
@@ -30,27 +48,12 @@ impl AggregatePushdown {
         lp_arena: &mut Arena<ALogicalPlan>,
         expr_arena: &mut Arena<AExpr>,
     ) -> Option<ALogicalPlan> {
-        let dummy_node = usize::max_value();
-        let dummy_min = AExpr::Agg(AAggExpr::Min(Node(dummy_node)));
-        let dummy_max = AExpr::Agg(AAggExpr::Max(Node(dummy_node)));
-        let dummy_first = AExpr::Agg(AAggExpr::First(Node(dummy_node)));
-        let dummy_last = AExpr::Agg(AAggExpr::First(Node(dummy_node)));
-        let dummy_sum = AExpr::Agg(AAggExpr::Sum(Node(dummy_node)));
-
-        // only do aggregation pushdown if all projections are aggreegations
-        #[allow(clippy::blocks_in_if_conditions)]
+        // only do aggregation pushdown if all projections are aggregations of a column, the
+        // scan aggregates the column itself, so an expression on the column would be ignored
         if !self.processed_state
-            && expr.iter().all(|node| {
-                (has_aexpr(*node, expr_arena, &dummy_min, true)
-                    || has_aexpr(*node, expr_arena, &dummy_max, true)
-                    || has_aexpr(*node, expr_arena, &dummy_first, true)
-                    || has_aexpr(*node, expr_arena, &dummy_sum, true)
-                    || has_aexpr(*node, expr_arena, &dummy_last, true))
-                    && {
-                        let roots = aexpr_to_root_nodes(*node, expr_arena);
-                        roots.len() == 1
-                    }
-            })
+            && expr
+                .iter()
+                .all(|node| is_scan_aggregation(*node, expr_arena))
         {
             // add to state
             self.state.extend_from_slice(&expr);
@@ -95,7 +98,11 @@ impl OptimizationRule for AggregatePushdown {
                 schema,
             } => self.pushdown_projection(node, expr, input, schema, lp_arena, expr_arena),
             // todo! hstack should pushown not dependent columns
-            Join { .. } | Aggregate { .. } | HStack { .. } | DataFrameScan { .. } => {
+            Join { .. }
+            | Aggregate { .. }
+            | HStack { .. }
+            | DataFrameScan { .. }
+            | ALogicalPlan::AnonymousScan { .. } => {
                 if self.state.is_empty() {
                     lp_arena.assign(node, lp);
                     None
@@ -30,7 +30,7 @@ pub(crate) fn agg_projection(
         Selection { input, .. } => {
             agg_projection(input, columns);
         }
-        Cache { input } => {
+        Cache { input, .. } => {
             agg_projection(input, columns);
         }
         CsvScan {
@@ -45,6 +45,7 @@ pub(crate) fn agg_projection(
             process_with_columns(&path, &with_columns, columns);
         }
         DataFrameScan { .. } => (),
+        LogicalPlan::AnonymousScan { .. } => (),
         Projection { input, .. } => {
             agg_projection(input, columns);
         }
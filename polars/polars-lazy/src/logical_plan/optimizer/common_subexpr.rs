@@ -0,0 +1,312 @@
+use crate::logical_plan::Context;
+use crate::prelude::*;
+use polars_core::prelude::*;
+use polars_core::utils::{Arena, Node};
+
+/// Evaluate subexpressions that occur more than once in a projection or aggregation only once.
+///
+/// The first subexpression that is found twice is computed in a `HStack` below the node as a
+/// temporary column and every occurrence is replaced by that column. The projection or
+/// aggregation only outputs its own expressions, so the temporary column doesn't show up in the
+/// result. Only elementwise subexpressions are shared, those give the same values whether they
+/// are computed once on the whole input or per group.
+pub(crate) struct CommonSubExprElim {
+    n_columns: usize,
+}
+
+impl CommonSubExprElim {
+    pub(crate) fn new() -> Self {
+        Self { n_columns: 0 }
+    }
+}
+
+/// Check if two elementwise expressions are the same.
+fn is_same(a: Node, b: Node, expr_arena: &Arena<AExpr>) -> bool {
+    use AExpr::*;
+    match (expr_arena.get(a), expr_arena.get(b)) {
+        (Column(a), Column(b)) => a == b,
+        (Literal(a), Literal(b)) => a == b,
+        (Not(a), Not(b)) | (IsNull(a), IsNull(b)) | (IsNotNull(a), IsNotNull(b)) => {
+            is_same(*a, *b, expr_arena)
+        }
+        (
+            Cast {
+                expr: a,
+                data_type: dt_a,
+            },
+            Cast {
+                expr: b,
+                data_type: dt_b,
+            },
+        ) => dt_a == dt_b && is_same(*a, *b, expr_arena),
+        (
+            BinaryExpr {
+                left: left_a,
+                op: op_a,
+                right: right_a,
+            },
+            BinaryExpr {
+                left: left_b,
+                op: op_b,
+                right: right_b,
+            },
+        ) => {
+            op_a == op_b
+                && is_same(*left_a, *left_b, expr_arena)
+                && is_same(*right_a, *right_b, expr_arena)
+        }
+        (
+            Ternary {
+                predicate: predicate_a,
+                truthy: truthy_a,
+                falsy: falsy_a,
+            },
+            Ternary {
+                predicate: predicate_b,
+                truthy: truthy_b,
+                falsy: falsy_b,
+            },
+        ) => {
+            is_same(*predicate_a, *predicate_b, expr_arena)
+                && is_same(*truthy_a, *truthy_b, expr_arena)
+                && is_same(*falsy_a, *falsy_b, expr_arena)
+        }
+        _ => false,
+    }
+}
+
+/// Check if the expression is elementwise and reads a column. Literal expressions are left to
+/// the constant folding of [SimplifyExprRule](super::simplify_expr::SimplifyExprRule).
+fn is_shareable(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    fn elementwise(node: Node, expr_arena: &Arena<AExpr>, has_column: &mut bool) -> bool {
+        use AExpr::*;
+        match expr_arena.get(node) {
+            Column(_) => {
+                *has_column = true;
+                true
+            }
+            Literal(LiteralValue::Param(_)) => false,
+            Literal(_) => true,
+            Not(e) | IsNull(e) | IsNotNull(e) | Cast { expr: e, .. } => {
+                elementwise(*e, expr_arena, has_column)
+            }
+            BinaryExpr { left, right, .. } => {
+                elementwise(*left, expr_arena, has_column)
+                    && elementwise(*right, expr_arena, has_column)
+            }
+            Ternary {
+                predicate,
+                truthy,
+                falsy,
+            } => {
+                elementwise(*predicate, expr_arena, has_column)
+                    && elementwise(*truthy, expr_arena, has_column)
+                    && elementwise(*falsy, expr_arena, has_column)
+            }
+            _ => false,
+        }
+    }
+    // a single column is not worth a temporary column
+    if matches!(expr_arena.get(node), AExpr::Column(_)) {
+        return false;
+    }
+    let mut has_column = false;
+    elementwise(node, expr_arena, &mut has_column) && has_column
+}
+
+/// Collect the nodes of the expression tree, parents before their children.
+fn collect_nodes(node: Node, expr_arena: &Arena<AExpr>, nodes: &mut Vec<Node>) {
+    use AExpr::*;
+    nodes.push(node);
+    let inputs = match expr_arena.get(node) {
+        Column(_) | Literal(_) | Wildcard => vec![],
+        Unique(e)
+        | Duplicated(e)
+        | Reverse(e)
+        | Explode(e)
+        | Alias(e, _)
+        | Not(e)
+        | IsNotNull(e)
+        | IsNull(e)
+        | Cast { expr: e, .. }
+        | Sort { expr: e, .. }
+        | Udf { input: e, .. }
+        | Shift { input: e, .. }
+        | Slice { input: e, .. } => vec![*e],
+        Agg(agg) => match agg {
+            AAggExpr::Min(e)
+            | AAggExpr::Max(e)
+            | AAggExpr::Median(e)
+            | AAggExpr::NUnique(e)
+            | AAggExpr::First(e)
+            | AAggExpr::Last(e)
+            | AAggExpr::Mean(e)
+            | AAggExpr::List(e)
+            | AAggExpr::Quantile { expr: e, .. }
+            | AAggExpr::Sum(e)
+            | AAggExpr::Count(e)
+            | AAggExpr::Std(e)
+            | AAggExpr::Var(e)
+            | AAggExpr::AggGroups(e) => vec![*e],
+        },
+        BinaryExpr { left, right, .. } => vec![*left, *right],
+        Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => vec![*predicate, *truthy, *falsy],
+        Window {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            let mut inputs = vec![*function, *partition_by];
+            inputs.extend(order_by);
+            inputs
+        }
+        BinaryFunction {
+            input_a, input_b, ..
+        } => vec![*input_a, *input_b],
+    };
+    for input in inputs {
+        collect_nodes(input, expr_arena, nodes);
+    }
+}
+
+impl CommonSubExprElim {
+    /// Move the first shared subexpression of `exprs` to a temporary column. Returns the new
+    /// expressions and the `HStack` that computes the column.
+    fn eliminate(
+        &mut self,
+        exprs: &[Node],
+        input: Node,
+        ctxt: Context,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<(Vec<Node>, Node)> {
+        let per_expr = exprs
+            .iter()
+            .map(|e| {
+                let mut nodes = vec![];
+                collect_nodes(*e, expr_arena, &mut nodes);
+                nodes
+            })
+            .collect::<Vec<_>>();
+        let nodes = per_expr.iter().flatten().copied().collect::<Vec<_>>();
+
+        // the nodes are in pre-order, so the largest shared subexpression is found first
+        let (shared, occurrences) = nodes.iter().find_map(|&node| {
+            if !is_shareable(node, expr_arena) {
+                return None;
+            }
+            let occurrences = nodes
+                .iter()
+                .copied()
+                .filter(|&other| is_same(node, other, expr_arena))
+                .collect::<Vec<_>>();
+            match occurrences.len() > 1 {
+                true => Some((node, occurrences)),
+                false => None,
+            }
+        })?;
+
+        let input_schema = lp_arena.get(input).schema(lp_arena);
+        let field = expr_arena
+            .get(shared)
+            .to_field(input_schema, Context::Other, expr_arena)
+            .ok()?;
+        // the output names of the expressions must not change when a column is substituted
+        let names = exprs
+            .iter()
+            .map(|e| {
+                expr_arena
+                    .get(*e)
+                    .to_field(input_schema, ctxt, expr_arena)
+                    .map(|f| f.name().clone())
+                    .ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let name = Arc::new(format!("__POLARS_CSE_{}", self.n_columns));
+        self.n_columns += 1;
+        let mut fields = input_schema.fields().clone();
+        fields.push(Field::new(&name, field.data_type().clone()));
+
+        // copy the shared expression, as the occurrences are replaced in place
+        let computed = expr_arena.add(expr_arena.get(shared).clone());
+        let computed = expr_arena.add(AExpr::Alias(computed, name.clone()));
+        for &node in &occurrences {
+            expr_arena.assign(node, AExpr::Column(name.clone()));
+        }
+
+        let new_exprs = exprs
+            .iter()
+            .zip(names)
+            .zip(&per_expr)
+            .map(|((&e, name), nodes)| {
+                let replaced = nodes.iter().any(|n| occurrences.contains(n));
+                match expr_arena.get(e) {
+                    AExpr::Alias(_, _) => e,
+                    _ if replaced => expr_arena.add(AExpr::Alias(e, Arc::new(name))),
+                    _ => e,
+                }
+            })
+            .collect();
+
+        let hstack = lp_arena.add(ALogicalPlan::HStack {
+            input,
+            exprs: vec![computed],
+            schema: Arc::new(Schema::new(fields)),
+        });
+        Some((new_exprs, hstack))
+    }
+}
+
+impl OptimizationRule for CommonSubExprElim {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        match lp_arena.get(node) {
+            ALogicalPlan::Projection {
+                expr,
+                input,
+                schema,
+            } => {
+                let (expr, input, schema) = (expr.clone(), *input, schema.clone());
+                let (expr, input) =
+                    self.eliminate(&expr, input, Context::Other, lp_arena, expr_arena)?;
+                Some(ALogicalPlan::Projection {
+                    expr,
+                    input,
+                    schema,
+                })
+            }
+            ALogicalPlan::Aggregate {
+                input,
+                keys,
+                aggs,
+                schema,
+                apply: None,
+                maintain_order,
+            } => {
+                let (keys, schema, maintain_order) =
+                    (keys.clone(), schema.clone(), *maintain_order);
+                let (aggs, input) = (aggs.clone(), *input);
+                let (aggs, input) =
+                    self.eliminate(&aggs, input, Context::Aggregation, lp_arena, expr_arena)?;
+                Some(ALogicalPlan::Aggregate {
+                    input,
+                    keys,
+                    aggs,
+                    schema,
+                    apply: None,
+                    maintain_order,
+                })
+            }
+            _ => None,
+        }
+    }
+}
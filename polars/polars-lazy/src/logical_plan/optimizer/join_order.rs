@@ -0,0 +1,198 @@
+use crate::prelude::*;
+use ahash::RandomState;
+use polars_core::frame::hash_join::JoinType;
+use polars_core::prelude::*;
+use polars_core::utils::{Arena, Node};
+use std::collections::HashSet;
+
+/// Reorder chained inner joins so the smaller input is joined first.
+///
+/// `(a JOIN b) JOIN c` becomes `(a JOIN c) JOIN b` if `c` is estimated to be smaller than `b`,
+/// or if `c` is hinted with [hint_broadcast](crate::frame::LazyFrame::hint_broadcast) and `b`
+/// isn't. A projection on top restores the column order of the original plan.
+///
+/// The size of an input is the number of rows of its source times the number of columns. Only
+/// in memory DataFrames and scans with `stop_after_n_rows` have a known number of rows, other
+/// inputs are never moved in front of them. The joins are only reordered if they join on plain
+/// columns, the keys of the outer join are columns of `a` and no column names clash, so the
+/// suffix is never added.
+pub(crate) struct JoinOrder {}
+
+/// The number of rows of the source of `node`, if it is known. This is an upper bound, filters
+/// are not taken into account.
+fn estimated_rows(node: Node, lp_arena: &Arena<ALogicalPlan>) -> Option<usize> {
+    use ALogicalPlan::*;
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => Some(df.height()),
+        CsvScan {
+            stop_after_n_rows, ..
+        } => *stop_after_n_rows,
+        #[cfg(feature = "parquet")]
+        ParquetScan {
+            stop_after_n_rows, ..
+        } => *stop_after_n_rows,
+        Slice { input, len, .. } => {
+            Some(estimated_rows(*input, lp_arena).map_or(*len, |rows| rows.min(*len)))
+        }
+        Selection { input, .. }
+        | Projection { input, .. }
+        | LocalProjection { input, .. }
+        | HStack { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. }
+        | Aggregate { input, .. } => estimated_rows(*input, lp_arena),
+        _ => None,
+    }
+}
+
+/// Order the inputs of a join: hinted inputs first, then the inputs of a known size by size.
+fn join_cost(node: Node, broadcast: bool, lp_arena: &Arena<ALogicalPlan>) -> (bool, usize) {
+    let width = lp_arena.get(node).schema(lp_arena).fields().len();
+    let size = estimated_rows(node, lp_arena).map_or(usize::MAX, |rows| rows.saturating_mul(width));
+    (!broadcast, size)
+}
+
+fn column_names(nodes: &[Node], expr_arena: &Arena<AExpr>) -> Option<Vec<Arc<String>>> {
+    nodes
+        .iter()
+        .map(|node| match expr_arena.get(*node) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The columns of the right input of a join that are part of the output.
+fn right_columns<'a>(right: &'a Schema, right_on: &[Arc<String>]) -> Vec<&'a Field> {
+    right
+        .fields()
+        .iter()
+        .filter(|f| !right_on.iter().any(|key| key.as_str() == f.name()))
+        .collect()
+}
+
+/// The schema of a join without clashing column names.
+fn join_schema(left: &Schema, right: &Schema, right_on: &[Arc<String>]) -> Schema {
+    let mut fields = left.fields().clone();
+    fields.extend(right_columns(right, right_on).into_iter().cloned());
+    Schema::new(fields)
+}
+
+impl OptimizationRule for JoinOrder {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        use ALogicalPlan::*;
+        let (inner, c, outer_left_on, outer_right_on, outer_options, schema) =
+            match lp_arena.get(node) {
+                Join {
+                    input_left,
+                    input_right,
+                    schema,
+                    how: JoinType::Inner,
+                    left_on,
+                    right_on,
+                    options,
+                } if options.validation.is_none() => (
+                    *input_left,
+                    *input_right,
+                    left_on.clone(),
+                    right_on.clone(),
+                    options.clone(),
+                    schema.clone(),
+                ),
+                _ => return None,
+            };
+        let (a, b, inner_left_on, inner_right_on, inner_options) = match lp_arena.get(inner) {
+            Join {
+                input_left,
+                input_right,
+                how: JoinType::Inner,
+                left_on,
+                right_on,
+                options,
+                ..
+            } if options.validation.is_none() => (
+                *input_left,
+                *input_right,
+                left_on.clone(),
+                right_on.clone(),
+                options.clone(),
+            ),
+            _ => return None,
+        };
+
+        if join_cost(c, outer_options.broadcast, lp_arena)
+            >= join_cost(b, inner_options.broadcast, lp_arena)
+        {
+            return None;
+        }
+
+        let outer_left_keys = column_names(&outer_left_on, expr_arena)?;
+        let outer_right_keys = column_names(&outer_right_on, expr_arena)?;
+        let inner_right_keys = column_names(&inner_right_on, expr_arena)?;
+        column_names(&inner_left_on, expr_arena)?;
+
+        let schema_a = lp_arena.get(a).schema(lp_arena);
+        let schema_b = lp_arena.get(b).schema(lp_arena);
+        let schema_c = lp_arena.get(c).schema(lp_arena);
+        if outer_left_keys
+            .iter()
+            .any(|key| schema_a.field_with_name(key).is_err())
+        {
+            return None;
+        }
+        // without clashing names both orders output the same columns
+        let mut names: HashSet<&str, RandomState> = HashSet::default();
+        let n_columns = schema_a.fields().len()
+            + right_columns(schema_b, &inner_right_keys).len()
+            + right_columns(schema_c, &outer_right_keys).len();
+        names.extend(
+            schema_a
+                .fields()
+                .iter()
+                .chain(right_columns(schema_b, &inner_right_keys))
+                .chain(right_columns(schema_c, &outer_right_keys))
+                .map(|f| f.name().as_str()),
+        );
+        if names.len() != n_columns {
+            return None;
+        }
+
+        let schema_ac = join_schema(schema_a, schema_c, &outer_right_keys);
+        let schema_acb = join_schema(&schema_ac, schema_b, &inner_right_keys);
+
+        let joined_ac = lp_arena.add(Join {
+            input_left: a,
+            input_right: c,
+            schema: Arc::new(schema_ac),
+            how: JoinType::Inner,
+            left_on: outer_left_on,
+            right_on: outer_right_on,
+            options: outer_options,
+        });
+        let joined_acb = lp_arena.add(Join {
+            input_left: joined_ac,
+            input_right: b,
+            schema: Arc::new(schema_acb),
+            how: JoinType::Inner,
+            left_on: inner_left_on,
+            right_on: inner_right_on,
+            options: inner_options,
+        });
+        let expr = schema
+            .fields()
+            .iter()
+            .map(|f| expr_arena.add(AExpr::Column(Arc::new(f.name().clone()))))
+            .collect();
+        Some(Projection {
+            expr,
+            input: joined_acb,
+            schema,
+        })
+    }
+}
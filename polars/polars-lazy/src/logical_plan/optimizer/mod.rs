@@ -1,6 +1,6 @@
 use crate::logical_plan::{prepare_projection, Context};
 use crate::prelude::*;
-use crate::utils::{expr_to_root_column_exprs, rename_field};
+use crate::utils::{aexpr_to_root_nodes, expr_to_root_column_exprs, output_name, rename_field};
 use ahash::RandomState;
 use polars_core::frame::group_by::{fmt_groupby_column, GroupByMethod};
 use polars_core::frame::hash_join::JoinType;
@@ -10,9 +10,12 @@ use std::collections::HashMap;
 
 pub(crate) mod aggregate_pushdown;
 pub(crate) mod aggregate_scan_projections;
+pub(crate) mod common_subexpr;
+pub(crate) mod join_order;
 pub(crate) mod predicate;
 pub(crate) mod projection;
 pub(crate) mod simplify_expr;
+pub(crate) mod slice_pushdown;
 pub(crate) mod type_coercion;
 
 // check if a selection/projection can be done on the downwards schema
@@ -27,6 +30,83 @@ fn check_down_node(expr: &Expr, down_schema: &Schema) -> bool {
     }
 }
 
+/// The input of a join a column of the join output originates from, with the name of the column
+/// in that input.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JoinOrigin {
+    Left(Arc<String>),
+    Right(Arc<String>),
+}
+
+/// Resolve a column of the output of a join to the input it originates from. This follows the
+/// schema of [LogicalPlanBuilder::join]: the columns of the left input keep their names, the right
+/// join keys are dropped and right columns that clash with a left column get the `suffix`.
+///
+/// Returns `None` if the column is not in the output of the join.
+pub(crate) fn join_column_origin(
+    name: &str,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    right_on: &[Expr],
+    suffix: &str,
+) -> Option<JoinOrigin> {
+    if schema_left.field_with_name(name).is_ok() {
+        return Some(JoinOrigin::Left(Arc::new(name.to_string())));
+    }
+    let is_right_key = |name: &str| {
+        right_on
+            .iter()
+            .any(|e| matches!(output_name(e), Ok(key) if key.as_str() == name))
+    };
+    if schema_right.field_with_name(name).is_ok() && !is_right_key(name) {
+        return Some(JoinOrigin::Right(Arc::new(name.to_string())));
+    }
+    match name.strip_suffix(suffix) {
+        Some(stripped)
+            if schema_left.field_with_name(stripped).is_ok()
+                && schema_right.field_with_name(stripped).is_ok()
+                && !is_right_key(stripped) =>
+        {
+            Some(JoinOrigin::Right(Arc::new(stripped.to_string())))
+        }
+        _ => None,
+    }
+}
+
+/// The name of the right join key that is joined with the left join key column `name`.
+pub(crate) fn join_key_counterpart(
+    name: &str,
+    left_on: &[Expr],
+    right_on: &[Expr],
+) -> Option<Arc<String>> {
+    left_on
+        .iter()
+        .zip(right_on)
+        .find_map(|(left, right)| match (left, right) {
+            (Expr::Column(left), Expr::Column(right)) if left.as_str() == name => {
+                Some(right.clone())
+            }
+            _ => None,
+        })
+}
+
+/// Rename the columns of `expr` to the names returned by `rename`. Unlike
+/// [rename_expr_root_name](crate::utils::rename_expr_root_name) this renames any number of
+/// columns and keeps the rest of the expression intact.
+pub(crate) fn rename_columns<F>(expr: Expr, rename: F) -> Expr
+where
+    F: Fn(&Arc<String>) -> Arc<String>,
+{
+    let mut expr_arena = Arena::new();
+    let root = to_aexpr(expr, &mut expr_arena);
+    for node in aexpr_to_root_nodes(root, &expr_arena) {
+        if let AExpr::Column(name) = expr_arena.get_mut(node) {
+            *name = rename(name);
+        }
+    }
+    node_to_exp(root, &mut expr_arena)
+}
+
 pub trait Optimize {
     fn optimize(&self, logical_plan: LogicalPlan) -> Result<LogicalPlan>;
 }
@@ -97,7 +177,7 @@ impl StackOptimizer {
                     ALogicalPlan::Explode { input, .. } => {
                         plans.push(*input);
                     }
-                    ALogicalPlan::Cache { input } => {
+                    ALogicalPlan::Cache { input, .. } => {
                         plans.push(*input);
                     }
                     ALogicalPlan::Aggregate {
@@ -132,6 +212,11 @@ impl StackOptimizer {
                             exprs.push((predicate, current_node))
                         }
                     }
+                    ALogicalPlan::AnonymousScan { predicate, .. } => {
+                        if let Some(predicate) = *predicate {
+                            exprs.push((predicate, current_node))
+                        }
+                    }
                     #[cfg(feature = "parquet")]
                     ALogicalPlan::ParquetScan { predicate, .. } => {
                         if let Some(predicate) = *predicate {
@@ -368,6 +453,15 @@ impl Default for AExpr {
     }
 }
 
+/// The output field of a variance or standard deviation: Float32 stays Float32, all other
+/// types become Float64.
+pub(crate) fn float_field(field: Field) -> Field {
+    match field.data_type() {
+        DataType::Float32 => field,
+        _ => Field::new(field.name(), DataType::Float64),
+    }
+}
+
 pub(crate) fn field_by_context(
     mut field: Field,
     ctxt: Context,
@@ -432,32 +526,28 @@ impl AExpr {
                 let right_type = arena.get(*right).get_type(schema, ctxt, arena)?;
 
                 let expr_type = match op {
-                    Operator::Not
-                    | Operator::Lt
+                    Operator::Lt
                     | Operator::Gt
                     | Operator::Eq
                     | Operator::NotEq
-                    | Operator::And
                     | Operator::LtEq
-                    | Operator::GtEq
+                    | Operator::GtEq => {
+                        // the operands are compared in their supertype
+                        if left_type != DataType::Null && right_type != DataType::Null {
+                            get_supertype(&left_type, &right_type)?;
+                        }
+                        DataType::Boolean
+                    }
+                    Operator::Not
+                    | Operator::And
                     | Operator::Or
                     | Operator::NotLike
                     | Operator::Like => DataType::Boolean,
                     _ => get_supertype(&left_type, &right_type)?,
                 };
 
-                use Operator::*;
-                let out_field;
-                let out_name = match op {
-                    Plus | Minus | Multiply | Divide | Modulus => {
-                        out_field = arena.get(*left).to_field(schema, ctxt, arena)?;
-                        out_field.name().as_str()
-                    }
-                    Eq | Lt | GtEq | LtEq => "",
-                    _ => "binary_expr",
-                };
-
-                Ok(Field::new(out_name, expr_type))
+                let out_field = arena.get(*left).to_field(schema, ctxt, arena)?;
+                Ok(Field::new(out_field.name(), expr_type))
             }
             Not(_) => Ok(Field::new("not", DataType::Boolean)),
             IsNull(_) => Ok(Field::new("is_null", DataType::Boolean)),
@@ -481,11 +571,11 @@ impl AExpr {
                         ctxt,
                         GroupByMethod::Median,
                     ),
-                    Mean(expr) => field_by_context(
-                        arena.get(*expr).to_field(schema, ctxt, arena)?,
-                        ctxt,
-                        GroupByMethod::Mean,
-                    ),
+                    Mean(expr) => {
+                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
+                        let field = Field::new(field.name(), DataType::Float64);
+                        field_by_context(field, ctxt, GroupByMethod::Mean)
+                    }
                     First(expr) => field_by_context(
                         arena.get(*expr).to_field(schema, ctxt, arena)?,
                         ctxt,
@@ -502,13 +592,11 @@ impl AExpr {
                         GroupByMethod::List,
                     ),
                     Std(expr) => {
-                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
-                        let field = Field::new(field.name(), DataType::Float64);
+                        let field = float_field(arena.get(*expr).to_field(schema, ctxt, arena)?);
                         field_by_context(field, ctxt, GroupByMethod::Std)
                     }
                     Var(expr) => {
-                        let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
-                        let field = Field::new(field.name(), DataType::Float64);
+                        let field = float_field(arena.get(*expr).to_field(schema, ctxt, arena)?);
                         field_by_context(field, ctxt, GroupByMethod::Var)
                     }
                     NUnique(expr) => {
@@ -557,7 +645,13 @@ impl AExpr {
                 let field = arena.get(*expr).to_field(schema, ctxt, arena)?;
                 Ok(Field::new(field.name(), data_type.clone()))
             }
-            Ternary { truthy, .. } => arena.get(*truthy).to_field(schema, ctxt, arena),
+            Ternary { truthy, falsy, .. } => {
+                let field = arena.get(*truthy).to_field(schema, ctxt, arena)?;
+                match field.data_type() {
+                    DataType::Null => arena.get(*falsy).to_field(schema, ctxt, arena),
+                    _ => Ok(field),
+                }
+            }
             Udf {
                 output_type, input, ..
             } => match output_type {
@@ -626,6 +720,12 @@ pub enum ALogicalPlan {
         stop_after_n_rows: Option<usize>,
         cache: bool,
     },
+    AnonymousScan {
+        function: Arc<dyn AnonymousScan>,
+        schema: SchemaRef,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Node>,
+    },
     DataFrameScan {
         df: Arc<DataFrame>,
         schema: SchemaRef,
@@ -645,7 +745,7 @@ pub enum ALogicalPlan {
     Sort {
         input: Node,
         by_column: String,
-        reverse: bool,
+        options: SortOptions,
     },
     Explode {
         input: Node,
@@ -653,6 +753,7 @@ pub enum ALogicalPlan {
     },
     Cache {
         input: Node,
+        id: usize,
     },
     Aggregate {
         input: Node,
@@ -660,6 +761,7 @@ pub enum ALogicalPlan {
         aggs: Vec<Node>,
         schema: SchemaRef,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        maintain_order: bool,
     },
     Join {
         input_left: Node,
@@ -668,8 +770,7 @@ pub enum ALogicalPlan {
         how: JoinType,
         left_on: Vec<Node>,
         right_on: Vec<Node>,
-        allow_par: bool,
-        force_par: bool,
+        options: JoinOptions,
     },
     HStack {
         input: Node,
@@ -707,7 +808,7 @@ impl ALogicalPlan {
     pub(crate) fn schema<'a>(&'a self, arena: &'a Arena<ALogicalPlan>) -> &'a Schema {
         use ALogicalPlan::*;
         match self {
-            Cache { input } => arena.get(*input).schema(arena),
+            Cache { input, .. } => arena.get(*input).schema(arena),
             Sort { input, .. } => arena.get(*input).schema(arena),
             Explode { input, .. } => arena.get(*input).schema(arena),
             #[cfg(feature = "parquet")]
@@ -715,6 +816,7 @@ impl ALogicalPlan {
             DataFrameScan { schema, .. } => schema,
             Selection { input, .. } => arena.get(*input).schema(arena),
             CsvScan { schema, .. } => schema,
+            ALogicalPlan::AnonymousScan { schema, .. } => schema,
             Projection { schema, .. } => schema,
             LocalProjection { schema, .. } => schema,
             Aggregate { schema, .. } => schema,
@@ -927,6 +1029,17 @@ pub(crate) fn to_alp(
             stop_after_n_rows,
             cache,
         },
+        LogicalPlan::AnonymousScan {
+            function,
+            schema,
+            with_columns,
+            predicate,
+        } => ALogicalPlan::AnonymousScan {
+            function,
+            schema,
+            with_columns,
+            predicate: predicate.map(|expr| to_aexpr(expr, expr_arena)),
+        },
         LogicalPlan::DataFrameScan {
             df,
             schema,
@@ -968,22 +1081,22 @@ pub(crate) fn to_alp(
         LogicalPlan::Sort {
             input,
             by_column,
-            reverse,
+            options,
         } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Sort {
                 input,
                 by_column,
-                reverse,
+                options,
             }
         }
         LogicalPlan::Explode { input, columns } => {
             let input = to_alp(*input, expr_arena, lp_arena);
             ALogicalPlan::Explode { input, columns }
         }
-        LogicalPlan::Cache { input } => {
+        LogicalPlan::Cache { input, id } => {
             let input = to_alp(*input, expr_arena, lp_arena);
-            ALogicalPlan::Cache { input }
+            ALogicalPlan::Cache { input, id }
         }
         LogicalPlan::Aggregate {
             input,
@@ -991,6 +1104,7 @@ pub(crate) fn to_alp(
             aggs,
             schema,
             apply,
+            maintain_order,
         } => {
             let i = to_alp(*input, expr_arena, lp_arena);
             let aggs_new = aggs.into_iter().map(|x| to_aexpr(x, expr_arena)).collect();
@@ -1005,6 +1119,7 @@ pub(crate) fn to_alp(
                 aggs: aggs_new,
                 schema,
                 apply,
+                maintain_order,
             }
         }
         LogicalPlan::Join {
@@ -1014,8 +1129,7 @@ pub(crate) fn to_alp(
             how,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         } => {
             let i_l = to_alp(*input_left, expr_arena, lp_arena);
             let i_r = to_alp(*input_right, expr_arena, lp_arena);
@@ -1036,8 +1150,7 @@ pub(crate) fn to_alp(
                 left_on: l_on,
                 how,
                 right_on: r_on,
-                allow_par,
-                force_par,
+                options,
             }
         }
         LogicalPlan::HStack {
@@ -1344,6 +1457,17 @@ pub(crate) fn node_to_lp(
             stop_after_n_rows,
             cache,
         },
+        ALogicalPlan::AnonymousScan {
+            function,
+            schema,
+            with_columns,
+            predicate,
+        } => LogicalPlan::AnonymousScan {
+            function,
+            schema,
+            with_columns,
+            predicate: predicate.map(|n| node_to_exp(n, expr_arena)),
+        },
         ALogicalPlan::DataFrameScan {
             df,
             schema,
@@ -1388,22 +1512,22 @@ pub(crate) fn node_to_lp(
         ALogicalPlan::Sort {
             input,
             by_column,
-            reverse,
+            options,
         } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Sort {
                 input,
                 by_column,
-                reverse,
+                options,
             }
         }
         ALogicalPlan::Explode { input, columns } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
             LogicalPlan::Explode { input, columns }
         }
-        ALogicalPlan::Cache { input } => {
+        ALogicalPlan::Cache { input, id } => {
             let input = Box::new(node_to_lp(input, expr_arena, lp_arena));
-            LogicalPlan::Cache { input }
+            LogicalPlan::Cache { input, id }
         }
         ALogicalPlan::Aggregate {
             input,
@@ -1411,6 +1535,7 @@ pub(crate) fn node_to_lp(
             aggs,
             schema,
             apply,
+            maintain_order,
         } => {
             let i = node_to_lp(input, expr_arena, lp_arena);
             let a = aggs.iter().map(|x| node_to_exp(*x, expr_arena)).collect();
@@ -1422,6 +1547,7 @@ pub(crate) fn node_to_lp(
                 aggs: a,
                 schema,
                 apply,
+                maintain_order,
             }
         }
         ALogicalPlan::Join {
@@ -1431,8 +1557,7 @@ pub(crate) fn node_to_lp(
             how,
             left_on,
             right_on,
-            allow_par,
-            force_par,
+            options,
         } => {
             let i_l = node_to_lp(input_left, expr_arena, lp_arena);
             let i_r = node_to_lp(input_right, expr_arena, lp_arena);
@@ -1453,8 +1578,7 @@ pub(crate) fn node_to_lp(
                 how,
                 left_on: l_on,
                 right_on: r_on,
-                allow_par,
-                force_par,
+                options,
             }
         }
         ALogicalPlan::HStack {
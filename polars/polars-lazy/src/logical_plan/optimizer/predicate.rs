@@ -1,4 +1,4 @@
-use crate::logical_plan::optimizer::{check_down_node, HASHMAP_SIZE};
+use crate::logical_plan::optimizer::{check_down_node, JoinOrigin, HASHMAP_SIZE};
 use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{
@@ -38,7 +38,6 @@ pub struct PredicatePushDown {
     // used in has_expr check. This reduces box allocations
     unique_dummy: Expr,
     duplicated_dummy: Expr,
-    binary_dummy: Expr,
     is_null_dummy: Expr,
     is_not_null_dummy: Expr,
     explode_dummy: Expr,
@@ -49,9 +48,8 @@ impl Default for PredicatePushDown {
         PredicatePushDown {
             unique_dummy: lit("_").is_unique(),
             duplicated_dummy: lit("_").is_duplicated(),
-            binary_dummy: lit("_").eq(lit("_")),
             is_null_dummy: lit("_").is_null(),
-            is_not_null_dummy: lit("_").is_null(),
+            is_not_null_dummy: lit("_").is_not_null(),
             explode_dummy: Expr::Explode(Box::new(Expr::Wildcard)),
         }
     }
@@ -125,10 +123,12 @@ impl PredicatePushDown {
         use LogicalPlan::*;
 
         match logical_plan {
-            // TODO! maybe the predicate should not pass the slice
             Slice { input, offset, len } => {
-                let input = Box::new(self.push_down(*input, acc_predicates)?);
-                Ok(Slice { input, offset, len })
+                // filtering before the slice changes which rows are sliced, so the predicates
+                // are applied after it
+                let input = Box::new(self.push_down(*input, optimizer::init_hashmap())?);
+                let lp = Slice { input, offset, len };
+                Ok(self.finish_at_leaf(lp, acc_predicates))
             }
             Selection { predicate, input } => {
                 let name = roots_to_key(&expr_to_root_column_names(&predicate));
@@ -139,19 +139,41 @@ impl PredicatePushDown {
                 let mut local_predicates = Vec::with_capacity(acc_predicates.len());
                 // maybe update predicate name if a projection is an alias
                 for e in &expr {
-                    // check if there is an alias
-                    if let Expr::Alias(e, name) = e {
+                    match e {
+                        Expr::Column(_) | Expr::Wildcard => {}
                         // if this alias refers to one of the predicates in the upper nodes
                         // we rename the column of the predicate before we push it downwards.
-                        if let Some(predicate) = acc_predicates.remove(name) {
-                            let new_name = expr_to_root_column_name(e).unwrap();
-                            let new_predicate =
-                                rename_expr_root_name(&predicate, new_name.clone()).unwrap();
-                            insert_and_combine_predicate(
-                                &mut acc_predicates,
-                                new_name,
-                                new_predicate,
-                            );
+                        Expr::Alias(inner, name) if matches!(&**inner, Expr::Column(_)) => {
+                            if let Some(predicate) = acc_predicates.remove(name) {
+                                let new_name = expr_to_root_column_name(inner).unwrap();
+                                let new_predicate =
+                                    rename_expr_root_name(&predicate, new_name.clone()).unwrap();
+                                insert_and_combine_predicate(
+                                    &mut acc_predicates,
+                                    new_name,
+                                    new_predicate,
+                                );
+                            }
+                        }
+                        // a computed column has other values than the column it is computed
+                        // from, so predicates on it are applied after the projection
+                        _ => {
+                            if let Ok(field) = e.to_field(input.schema(), Context::Other) {
+                                let output_name = field.name();
+                                let remove_keys = acc_predicates
+                                    .iter()
+                                    .filter(|(_, predicate)| {
+                                        expr_to_root_column_names(predicate)
+                                            .iter()
+                                            .any(|name| &**name == output_name)
+                                    })
+                                    .map(|(key, _)| key.clone())
+                                    .collect::<Vec<_>>();
+                                for key in remove_keys {
+                                    let pred = acc_predicates.remove(&*key).unwrap();
+                                    local_predicates.push(pred)
+                                }
+                            }
                         }
                     }
 
@@ -269,6 +291,22 @@ impl PredicatePushDown {
                 };
                 Ok(lp)
             }
+            LogicalPlan::AnonymousScan {
+                function,
+                schema,
+                with_columns,
+                predicate,
+            } => {
+                let predicate = predicate_at_scan(acc_predicates, predicate);
+
+                let lp = LogicalPlan::AnonymousScan {
+                    function,
+                    schema,
+                    with_columns,
+                    predicate,
+                };
+                Ok(lp)
+            }
             CsvScan {
                 path,
                 schema,
@@ -302,13 +340,13 @@ impl PredicatePushDown {
             Sort {
                 input,
                 by_column,
-                reverse,
+                options,
             } => {
                 let input = Box::new(self.push_down(*input, acc_predicates)?);
                 Ok(Sort {
                     input,
                     by_column,
-                    reverse,
+                    options,
                 })
             }
             Explode { input, columns } => {
@@ -340,23 +378,36 @@ impl PredicatePushDown {
                 }
                 Ok(builder.build())
             }
-            Cache { input } => {
-                let input = Box::new(self.push_down(*input, acc_predicates)?);
-                Ok(Cache { input })
+            Cache { input, id } => {
+                // every branch that reads the cache gets the same rows, so the predicates of a
+                // branch are applied after the cache
+                let input = Box::new(self.push_down(*input, optimizer::init_hashmap())?);
+                let lp = Cache { input, id };
+                Ok(self.finish_at_leaf(lp, acc_predicates))
             }
             Distinct {
                 input,
                 subset,
                 maintain_order,
             } => {
-                // currently the distinct operation only keeps the first occurrences.
-                // this may have influence on the pushed down predicates. If the pushed down predicates
-                // contain a binary expression (thus depending on values in multiple columns) the final result may differ if it is pushed down.
+                // the distinct operation only keeps the first occurrence of the rows with equal
+                // values in the subset. A predicate on other columns may filter this first
+                // occurrence and keep a later one if it is pushed down, so only predicates on the
+                // subset columns are pushed down. Those keep or remove all equal rows at once.
                 let mut local_pred = Vec::with_capacity(acc_predicates.len());
 
                 let mut new_acc_predicates = optimizer::init_hashmap();
                 for (name, predicate) in acc_predicates {
-                    if has_expr(&predicate, &self.binary_dummy) {
+                    let in_subset = match &subset {
+                        Some(subset) => expr_to_root_column_names(&predicate)
+                            .iter()
+                            .all(|name| subset.iter().any(|s| s == &**name)),
+                        None => true,
+                    };
+                    if !in_subset
+                        || has_expr(&predicate, &self.unique_dummy)
+                        || has_expr(&predicate, &self.duplicated_dummy)
+                    {
                         local_pred.push(predicate)
                     } else {
                         new_acc_predicates.insert(name, predicate);
@@ -382,6 +433,7 @@ impl PredicatePushDown {
                 aggs,
                 schema,
                 apply,
+                maintain_order,
             } => {
                 // dont push down predicates. An aggregation needs all rows
                 let lp = Aggregate {
@@ -390,6 +442,7 @@ impl PredicatePushDown {
                     aggs,
                     schema,
                     apply,
+                    maintain_order,
                 };
                 Ok(self.finish_at_leaf(lp, acc_predicates))
             }
@@ -399,8 +452,7 @@ impl PredicatePushDown {
                 left_on,
                 right_on,
                 how,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => {
                 let schema_left = input_left.schema();
@@ -412,55 +464,100 @@ impl PredicatePushDown {
 
                 for (_, predicate) in acc_predicates {
                     // unique and duplicated can be caused by joins
-                    if has_expr(&predicate, &self.unique_dummy) {
-                        local_predicates.push(predicate.clone());
-                        continue;
-                    }
-                    if has_expr(&predicate, &self.duplicated_dummy) {
-                        local_predicates.push(predicate.clone());
-                        continue;
-                    }
-                    let mut filter_left = false;
-                    let mut filter_right = false;
-
-                    // no else if. predicate can be in both tables.
-                    if check_down_node(&predicate, schema_left) {
-                        let name = Arc::new(
-                            predicate
-                                .to_field(schema_left, Context::Other)
-                                .unwrap()
-                                .name()
-                                .clone(),
-                        );
-                        insert_and_combine_predicate(&mut pushdown_left, name, predicate.clone());
-                        filter_left = true;
-                    }
-                    if check_down_node(&predicate, schema_right) {
-                        let name = Arc::new(
-                            predicate
-                                .to_field(schema_right, Context::Other)
-                                .unwrap()
-                                .name()
-                                .clone(),
-                        );
-                        insert_and_combine_predicate(&mut pushdown_right, name, predicate.clone());
-                        filter_right = true;
-                    }
-                    if !(filter_left & filter_right) {
-                        local_predicates.push(predicate.clone());
+                    if has_expr(&predicate, &self.unique_dummy)
+                        || has_expr(&predicate, &self.duplicated_dummy)
+                    {
+                        local_predicates.push(predicate);
                         continue;
                     }
                     // An outer join or left join may create null values.
                     // we also do it local
-                    if (how == JoinType::Outer) | (how == JoinType::Left) {
-                        if has_expr(&predicate, &self.is_not_null_dummy) {
-                            local_predicates.push(predicate.clone());
-                            continue;
-                        }
-                        if has_expr(&predicate, &self.is_null_dummy) {
+                    if matches!(how, JoinType::Outer | JoinType::Left)
+                        && (has_expr(&predicate, &self.is_null_dummy)
+                            || has_expr(&predicate, &self.is_not_null_dummy))
+                    {
+                        local_predicates.push(predicate);
+                        continue;
+                    }
+
+                    // resolve the columns of the predicate to the input they originate from.
+                    // Columns may be renamed by the join, so we cannot check the names against the
+                    // input schemas.
+                    let mut roots = expr_to_root_column_names(&predicate);
+                    roots.sort();
+                    roots.dedup();
+                    let origins = roots
+                        .iter()
+                        .map(|name| {
+                            optimizer::join_column_origin(
+                                name,
+                                schema_left,
+                                schema_right,
+                                &right_on,
+                                options.get_suffix(),
+                            )
+                        })
+                        .collect::<Option<Vec<_>>>();
+                    let origins = match origins {
+                        Some(origins) if !origins.is_empty() => origins,
+                        _ => {
                             local_predicates.push(predicate);
                             continue;
                         }
+                    };
+
+                    if origins.iter().all(|o| matches!(o, JoinOrigin::Left(_))) {
+                        // a predicate on a join key filters both inputs
+                        let right_key = match roots.as_slice() {
+                            [name] => optimizer::join_key_counterpart(name, &left_on, &right_on),
+                            _ => None,
+                        };
+                        match right_key {
+                            Some(key) => {
+                                let right_predicate =
+                                    optimizer::rename_columns(predicate.clone(), |_| key.clone());
+                                insert_and_combine_predicate(
+                                    &mut pushdown_right,
+                                    key,
+                                    right_predicate,
+                                )
+                            }
+                            // the right only rows of an outer join have null values in the left
+                            // columns, so the predicate is also needed after the join
+                            None if how == JoinType::Outer => {
+                                local_predicates.push(predicate);
+                                continue;
+                            }
+                            None => {}
+                        }
+                        insert_and_combine_predicate(
+                            &mut pushdown_left,
+                            roots_to_key(&roots),
+                            predicate,
+                        );
+                    } else if how == JoinType::Inner
+                        && origins.iter().all(|o| matches!(o, JoinOrigin::Right(_)))
+                    {
+                        // a left or outer join keeps the left rows whose right rows are filtered,
+                        // so only an inner join allows pushing down to the right input.
+                        let right_names = origins
+                            .into_iter()
+                            .map(|o| match o {
+                                JoinOrigin::Right(name) => name,
+                                JoinOrigin::Left(_) => unreachable!(),
+                            })
+                            .collect::<Vec<_>>();
+                        let right_predicate = optimizer::rename_columns(predicate, |name| {
+                            let idx = roots.iter().position(|root| root == name).unwrap();
+                            right_names[idx].clone()
+                        });
+                        insert_and_combine_predicate(
+                            &mut pushdown_right,
+                            roots_to_key(&right_names),
+                            right_predicate,
+                        );
+                    } else {
+                        local_predicates.push(predicate);
                     }
                 }
 
@@ -468,7 +565,7 @@ impl PredicatePushDown {
                 let lp_right = self.push_down(*input_right, pushdown_right)?;
 
                 let builder = LogicalPlanBuilder::from(lp_left)
-                    .join(lp_right, how, left_on, right_on, allow_par, force_par);
+                    .join(lp_right, how, left_on, right_on, options);
                 Ok(self.finish_node(local_predicates, builder))
             }
             HStack { input, exprs, .. } => {
@@ -1,4 +1,4 @@
-use crate::logical_plan::optimizer::check_down_node;
+use crate::logical_plan::optimizer::{check_down_node, join_column_origin, JoinOrigin};
 use crate::logical_plan::Context;
 use crate::prelude::*;
 use crate::utils::{
@@ -236,6 +236,21 @@ impl ProjectionPushDown {
                 };
                 Ok(lp)
             }
+            LogicalPlan::AnonymousScan {
+                function,
+                schema,
+                predicate,
+                ..
+            } => {
+                let with_columns = get_scan_columns(&mut acc_projections);
+                let lp = LogicalPlan::AnonymousScan {
+                    function,
+                    schema,
+                    with_columns,
+                    predicate,
+                };
+                Ok(lp)
+            }
             CsvScan {
                 path,
                 schema,
@@ -268,7 +283,7 @@ impl ProjectionPushDown {
             Sort {
                 input,
                 by_column,
-                reverse,
+                options,
             } => {
                 if !acc_projections.is_empty() {
                     add_to_accumulated(&col(&by_column), &mut acc_projections, &mut names).unwrap();
@@ -279,7 +294,7 @@ impl ProjectionPushDown {
                 Ok(Sort {
                     input,
                     by_column,
-                    reverse,
+                    options,
                 })
             }
             Explode { input, columns } => {
@@ -292,31 +307,44 @@ impl ProjectionPushDown {
                     Box::new(self.push_down(*input, acc_projections, names, projections_seen)?);
                 Ok(Explode { input, columns })
             }
-            Cache { input } => {
+            Cache { input, id } => {
+                // every branch that reads the cache gets the same columns, so the projections of
+                // a branch are done after the cache
                 let input =
-                    Box::new(self.push_down(*input, acc_projections, names, projections_seen)?);
-                Ok(Cache { input })
+                    Box::new(self.push_down(*input, init_vec(), init_set(), projections_seen)?);
+                let lp = Cache { input, id };
+                Ok(self.finish_node(acc_projections, LogicalPlanBuilder::from(lp)))
             }
             Distinct {
                 input,
                 maintain_order,
                 subset,
             } => {
-                if let Some(subset) = subset.as_ref() {
-                    if !acc_projections.is_empty() {
-                        for name in subset {
-                            add_to_accumulated(&col(name), &mut acc_projections, &mut names)
-                                .unwrap();
+                // without a subset the rows are compared on all columns, so all columns are
+                // needed and the projection is done after the distinct operation.
+                let mut local_projections = vec![];
+                match subset.as_ref() {
+                    Some(subset) => {
+                        if !acc_projections.is_empty() {
+                            for name in subset {
+                                add_to_accumulated(&col(name), &mut acc_projections, &mut names)
+                                    .unwrap();
+                            }
                         }
                     }
+                    None => {
+                        local_projections = std::mem::take(&mut acc_projections);
+                        names.clear();
+                    }
                 };
 
                 let input = self.push_down(*input, acc_projections, names, projections_seen)?;
-                Ok(Distinct {
+                let lp = Distinct {
                     input: Box::new(input),
                     maintain_order,
                     subset,
-                })
+                };
+                Ok(self.finish_node(local_projections, LogicalPlanBuilder::from(lp)))
             }
             Selection { predicate, input } => {
                 if !acc_projections.is_empty() {
@@ -364,6 +392,7 @@ impl ProjectionPushDown {
                 aggs,
                 apply,
                 schema,
+                maintain_order,
             } => {
                 // the custom function may have all columns
                 if let Some(f) = apply {
@@ -373,6 +402,7 @@ impl ProjectionPushDown {
                         aggs,
                         schema,
                         apply: Some(f),
+                        maintain_order,
                     };
 
                     let mut builder = LogicalPlanBuilder::from(lp);
@@ -397,7 +427,8 @@ impl ProjectionPushDown {
                     }
 
                     let lp = self.push_down(*input, acc_projections, names, projections_seen)?;
-                    let builder = LogicalPlanBuilder::from(lp).groupby(keys, aggs, apply);
+                    let builder =
+                        LogicalPlanBuilder::from(lp).groupby(keys, aggs, apply, maintain_order);
                     Ok(builder.build())
                 }
             }
@@ -407,8 +438,7 @@ impl ProjectionPushDown {
                 left_on,
                 right_on,
                 how,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => {
                 let mut pushdown_left = init_vec();
@@ -422,9 +452,10 @@ impl ProjectionPushDown {
                     let schema_left = input_left.schema();
                     let schema_right = input_right.schema();
 
-                    // We need the join columns so we push the projection downwards
-                    pushdown_left.extend_from_slice(&left_on);
-                    pushdown_right.extend_from_slice(&right_on);
+                    // We need the columns of the join keys so we push them downwards. The keys
+                    // may be computed, so we push down the columns they are computed from.
+                    pushdown_left.extend(left_on.iter().flat_map(expr_to_root_column_exprs));
+                    pushdown_right.extend(right_on.iter().flat_map(expr_to_root_column_exprs));
 
                     for mut proj in acc_projections {
                         let mut add_local = true;
@@ -443,7 +474,7 @@ impl ProjectionPushDown {
                         }
 
                         // Path for renamed columns due to the join. The column name of the left table
-                        // stays as is, the column of the right will have the "_right" suffix.
+                        // stays as is, the column of the right will have the suffix, "_right" by default.
                         // Thus joining two tables with both a foo column leads to ["foo", "foo_right"]
                         if !self.join_push_down(
                             schema_left,
@@ -457,21 +488,22 @@ impl ProjectionPushDown {
                             // Column name of the projection without any alias.
                             let root_column_name = expr_to_root_column_name(&proj).unwrap();
 
-                            // If _right suffix exists we need to push a projection down without this
-                            // suffix.
-                            if root_column_name.ends_with("_right") {
-                                // downwards name is the name without the _right i.e. "foo".
-                                let (downwards_name, _) = root_column_name
-                                    .split_at(root_column_name.len() - "_right".len());
-
+                            // If the column is a right column renamed with the suffix we
+                            // need to push a projection down without this suffix.
+                            if let Some(JoinOrigin::Right(downwards_name)) = join_column_origin(
+                                &root_column_name,
+                                schema_left,
+                                schema_right,
+                                &right_on,
+                                options.get_suffix(),
+                            ) {
                                 // project downwards and locally immediately alias to prevent wrong projections
-                                if names_right.insert(Arc::new(downwards_name.to_string())) {
-                                    let projection = col(downwards_name);
-                                    pushdown_right.push(projection);
+                                if names_right.insert(downwards_name.clone()) {
+                                    pushdown_right.push(Expr::Column(downwards_name.clone()));
                                 }
                                 // locally we project and alias
                                 let projection =
-                                    col(downwards_name).alias(&format!("{}_right", downwards_name));
+                                    Expr::Column(downwards_name).alias(&root_column_name);
                                 local_projection.push(projection);
                             }
                         } else if add_local {
@@ -493,7 +525,7 @@ impl ProjectionPushDown {
                 let lp_right =
                     self.push_down(*input_right, pushdown_right, names_right, projections_seen)?;
                 let builder = LogicalPlanBuilder::from(lp_left)
-                    .join(lp_right, how, left_on, right_on, allow_par, force_par);
+                    .join(lp_right, how, left_on, right_on, options);
                 Ok(self.finish_node(local_projection, builder))
             }
             HStack { input, exprs, .. } => {
@@ -1,5 +1,6 @@
 use crate::logical_plan::*;
 use crate::prelude::*;
+use polars_core::prelude::*;
 use polars_core::utils::Arena;
 
 macro_rules! eval_binary_same_type {
@@ -162,32 +163,19 @@ impl OptimizationRule for SimplifyBooleanRule {
                 Some(expr_arena.get(*right).clone())
             }
             // x or false => x
-            AExpr::BinaryExpr {
-                op: Operator::Or,
-                right,
-                ..
-            } if matches!(
-                expr_arena.get(*right),
-                AExpr::Literal(LiteralValue::Boolean(false))
-            ) =>
-            {
-                Some(expr_arena.get(*right).clone())
-            }
-
-            // false OR x => x
             AExpr::BinaryExpr {
                 left,
                 op: Operator::Or,
                 right,
             } if matches!(
-                expr_arena.get(*left),
+                expr_arena.get(*right),
                 AExpr::Literal(LiteralValue::Boolean(false))
             ) =>
             {
-                Some(expr_arena.get(*right).clone())
+                Some(expr_arena.get(*left).clone())
             }
 
-            // true OR x => true
+            // x OR true => true
             AExpr::BinaryExpr {
                 op: Operator::Or,
                 right,
@@ -197,10 +185,10 @@ impl OptimizationRule for SimplifyBooleanRule {
                 AExpr::Literal(LiteralValue::Boolean(true))
             ) =>
             {
-                Some(AExpr::Literal(LiteralValue::Boolean(false)))
+                Some(AExpr::Literal(LiteralValue::Boolean(true)))
             }
 
-            // x OR true => true
+            // true OR x => true
             AExpr::BinaryExpr {
                 op: Operator::Or,
                 left,
@@ -210,7 +198,7 @@ impl OptimizationRule for SimplifyBooleanRule {
                 AExpr::Literal(LiteralValue::Boolean(true))
             ) =>
             {
-                Some(AExpr::Literal(LiteralValue::Boolean(false)))
+                Some(AExpr::Literal(LiteralValue::Boolean(true)))
             }
 
             AExpr::Not(x) => {
@@ -255,6 +243,71 @@ fn eval_or(left: &AExpr, right: &AExpr) -> Option<AExpr> {
     None
 }
 
+fn is_integer_zero(expr: &AExpr) -> bool {
+    match expr {
+        AExpr::Literal(lit) => matches!(
+            lit,
+            LiteralValue::Int8(0)
+                | LiteralValue::Int16(0)
+                | LiteralValue::Int32(0)
+                | LiteralValue::Int64(0)
+                | LiteralValue::UInt8(0)
+                | LiteralValue::UInt16(0)
+                | LiteralValue::UInt32(0)
+                | LiteralValue::UInt64(0)
+        ),
+        _ => false,
+    }
+}
+
+/// Cast a numeric literal to `$data_type`. Values that don't fit the new type are not folded, so
+/// the executor decides what they become.
+macro_rules! cast_numeric {
+    ($value:expr, $data_type:expr) => {{
+        let value = $value;
+        macro_rules! fits {
+            ($variant:ident, $type:ty) => {{
+                let out = value as $type;
+                match out as f64 == value as f64 {
+                    true => Some(LiteralValue::$variant(out)),
+                    false => None,
+                }
+            }};
+        }
+        match $data_type {
+            DataType::Int8 => fits!(Int8, i8),
+            DataType::Int16 => fits!(Int16, i16),
+            DataType::Int32 => fits!(Int32, i32),
+            DataType::Int64 => fits!(Int64, i64),
+            DataType::UInt8 => fits!(UInt8, u8),
+            DataType::UInt16 => fits!(UInt16, u16),
+            DataType::UInt32 => fits!(UInt32, u32),
+            DataType::UInt64 => fits!(UInt64, u64),
+            DataType::Float32 => fits!(Float32, f32),
+            DataType::Float64 => fits!(Float64, f64),
+            _ => None,
+        }
+    }};
+}
+
+#[allow(clippy::float_cmp)]
+fn cast_literal(lit: &LiteralValue, data_type: &DataType) -> Option<LiteralValue> {
+    use LiteralValue::*;
+    match lit {
+        Int8(v) => cast_numeric!(*v, data_type),
+        Int16(v) => cast_numeric!(*v, data_type),
+        Int32(v) => cast_numeric!(*v, data_type),
+        Int64(v) => cast_numeric!(*v, data_type),
+        UInt8(v) => cast_numeric!(*v, data_type),
+        UInt16(v) => cast_numeric!(*v, data_type),
+        UInt32(v) => cast_numeric!(*v, data_type),
+        UInt64(v) => cast_numeric!(*v, data_type),
+        Float32(v) => cast_numeric!(*v, data_type),
+        Float64(v) => cast_numeric!(*v, data_type),
+        _ => None,
+    }
+}
+
 pub struct SimplifyExprRule {}
 
 impl OptimizationRule for SimplifyExprRule {
@@ -276,6 +329,14 @@ impl OptimizationRule for SimplifyExprRule {
                 Some(AExpr::Literal(LiteralValue::Null))
             }
 
+            // an integer division by zero is left to the executor
+            AExpr::BinaryExpr { op, right, .. }
+                if matches!(op, Operator::Divide | Operator::Modulus)
+                    && is_integer_zero(expr_arena.get(*right)) =>
+            {
+                None
+            }
+
             // lit(left) + lit(right) => lit(left = right)
             AExpr::BinaryExpr { left, op, right } => {
                 let left = expr_arena.get(*left);
@@ -292,12 +353,31 @@ impl OptimizationRule for SimplifyExprRule {
                     Operator::Eq => eval_binary_bool_type!(left, ==, right),
                     Operator::NotEq => eval_binary_bool_type!(left, !=, right),
                     Operator::GtEq => eval_binary_bool_type!(left, >=, right),
-                    Operator::LtEq => eval_binary_bool_type!(left, >=, right),
+                    Operator::LtEq => eval_binary_bool_type!(left, <=, right),
                     Operator::And => eval_and(left, right),
                     Operator::Or => eval_or(left, right),
                     _ => None,
                 }
             }
+            // cast(lit(x)) => lit(x as data_type)
+            AExpr::Cast { expr, data_type } => match expr_arena.get(*expr) {
+                AExpr::Literal(lit) => cast_literal(lit, data_type).map(AExpr::Literal),
+                _ => None,
+            },
+            AExpr::IsNull(expr) => match expr_arena.get(*expr) {
+                AExpr::Literal(LiteralValue::Param(_)) => None,
+                AExpr::Literal(lit) => Some(AExpr::Literal(LiteralValue::Boolean(
+                    lit == &LiteralValue::Null,
+                ))),
+                _ => None,
+            },
+            AExpr::IsNotNull(expr) => match expr_arena.get(*expr) {
+                AExpr::Literal(LiteralValue::Param(_)) => None,
+                AExpr::Literal(lit) => Some(AExpr::Literal(LiteralValue::Boolean(
+                    lit != &LiteralValue::Null,
+                ))),
+                _ => None,
+            },
             _ => None,
         }
     }
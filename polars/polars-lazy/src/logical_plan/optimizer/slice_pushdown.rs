@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use polars_core::utils::{Arena, Node};
+
+/// Push a slice into the file scan below it, so the scan stops after `offset + len` rows instead
+/// of reading the whole file. The slice itself stays in the plan to apply the offset.
+///
+/// The slice only passes projections of plain columns, those don't change the number of rows.
+/// Scans with a predicate or aggregation are not limited, as they need every row of the file.
+pub(crate) struct SlicePushDown {}
+
+fn limit_rows(stop_after_n_rows: &mut Option<usize>, n_rows: usize) {
+    let n_rows = stop_after_n_rows.map_or(n_rows, |stop| stop.min(n_rows));
+    *stop_after_n_rows = Some(n_rows);
+}
+
+impl OptimizationRule for SlicePushDown {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<ALogicalPlan>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<ALogicalPlan> {
+        use ALogicalPlan::*;
+        let (mut current, n_rows) = match lp_arena.get(node) {
+            Slice { input, offset, len } => (*input, offset.saturating_add(*len)),
+            _ => return None,
+        };
+        loop {
+            match lp_arena.get(current) {
+                Projection { expr, input, .. } | LocalProjection { expr, input, .. }
+                    if expr
+                        .iter()
+                        .all(|e| matches!(expr_arena.get(*e), AExpr::Column(_))) =>
+                {
+                    current = *input
+                }
+                _ => break,
+            }
+        }
+
+        // the scan is modified in place, so the plan doesn't change and no new pass is needed
+        match lp_arena.get_mut(current) {
+            CsvScan {
+                stop_after_n_rows,
+                predicate: None,
+                aggregate,
+                ..
+            } if aggregate.is_empty() => limit_rows(stop_after_n_rows, n_rows),
+            #[cfg(feature = "parquet")]
+            ParquetScan {
+                stop_after_n_rows,
+                predicate: None,
+                aggregate,
+                ..
+            } if aggregate.is_empty() => limit_rows(stop_after_n_rows, n_rows),
+            _ => {}
+        }
+        None
+    }
+}
@@ -1,9 +1,20 @@
 use crate::logical_plan::Context;
 use crate::prelude::*;
+use crate::utils::expr_to_root_column_exprs;
+use polars_core::prelude::*;
 use polars_core::utils::get_supertype;
 
 pub struct TypeCoercionRule {}
 
+/// The type both operands are cast to. A null operand, e.g. a null literal or an unbound
+/// parameter, gets the type of the other operand.
+fn coercion_type(left: &DataType, right: &DataType) -> Option<DataType> {
+    match (left, right) {
+        (DataType::Null, dt) | (dt, DataType::Null) => Some(dt.clone()),
+        _ => get_supertype(left, right).ok(),
+    }
+}
+
 impl OptimizationRule for TypeCoercionRule {
     fn optimize_expr(
         &self,
@@ -24,15 +35,15 @@ impl OptimizationRule for TypeCoercionRule {
                 let falsy = expr_arena.get(falsy_node);
                 let type_true = truthy
                     .get_type(input_schema, Context::Other, expr_arena)
-                    .expect("could not dtype");
+                    .ok()?;
                 let type_false = falsy
                     .get_type(input_schema, Context::Other, expr_arena)
-                    .expect("could not dtype");
+                    .ok()?;
 
                 if type_true == type_false {
                     None
                 } else {
-                    let st = get_supertype(&type_true, &type_false).expect("supertype");
+                    let st = coercion_type(&type_true, &type_false)?;
                     let new_node_truthy = expr_arena.add(AExpr::Cast {
                         expr: truthy_node,
                         data_type: st.clone(),
@@ -60,15 +71,14 @@ impl OptimizationRule for TypeCoercionRule {
 
                 let type_left = left
                     .get_type(input_schema, Context::Other, expr_arena)
-                    .expect("could not get dtype");
+                    .ok()?;
                 let type_right = right
                     .get_type(input_schema, Context::Other, expr_arena)
-                    .expect("could not get dtype");
+                    .ok()?;
                 if type_left == type_right {
                     None
                 } else {
-                    let st = get_supertype(&type_left, &type_right)
-                        .expect("could not find supertype of binary expr");
+                    let st = coercion_type(&type_left, &type_right)?;
                     let new_node_left = expr_arena.add(AExpr::Cast {
                         expr: node_left,
                         data_type: st.clone(),
@@ -89,3 +99,130 @@ impl OptimizationRule for TypeCoercionRule {
         }
     }
 }
+
+/// Resolve the fields of the expressions against the schema of their input, so a missing column
+/// or a type mismatch is reported before the plan executes.
+fn check_exprs<'a>(
+    exprs: impl IntoIterator<Item = &'a Expr>,
+    schema: &Schema,
+    ctxt: Context,
+) -> Result<()> {
+    for expr in exprs {
+        let roots = expr_to_root_column_exprs(expr);
+        // wildcards are resolved by the executor
+        if roots.iter().any(|e| matches!(e, Expr::Wildcard)) {
+            continue;
+        }
+        check_columns(
+            roots.iter().filter_map(|e| match e {
+                Expr::Column(name) => Some(name.as_str()),
+                _ => None,
+            }),
+            schema,
+        )?;
+        expr.to_field(schema, ctxt).map_err(|e| {
+            PolarsError::InvalidOperation(format!("{:?} in expression {:?}", e, expr).into())
+        })?;
+    }
+    Ok(())
+}
+
+fn check_columns<'a>(names: impl IntoIterator<Item = &'a str>, schema: &Schema) -> Result<()> {
+    for name in names {
+        if schema.field_with_name(name).is_err() {
+            let columns = schema
+                .fields()
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect::<Vec<_>>();
+            return Err(PolarsError::NotFound(
+                format!("column '{}' not found in {:?}", name, columns).into(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that every expression in the plan can be resolved against the schema of its input.
+pub(crate) fn check_plan(logical_plan: &LogicalPlan) -> Result<()> {
+    use LogicalPlan::*;
+    match logical_plan {
+        Slice { input, .. } | Cache { input, .. } | Udf { input, .. } => check_plan(input),
+        Selection { predicate, input } => {
+            check_plan(input)?;
+            check_exprs(std::iter::once(predicate), input.schema(), Context::Other)
+        }
+        Projection { expr, input, .. } | LocalProjection { expr, input, .. } => {
+            check_plan(input)?;
+            check_exprs(expr, input.schema(), Context::Other)
+        }
+        HStack { input, exprs, .. } => {
+            check_plan(input)?;
+            check_exprs(exprs, input.schema(), Context::Other)
+        }
+        Aggregate {
+            input, keys, aggs, ..
+        } => {
+            check_plan(input)?;
+            check_exprs(keys.iter(), input.schema(), Context::Other)?;
+            check_exprs(aggs, input.schema(), Context::Aggregation)
+        }
+        Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            ..
+        } => {
+            check_plan(input_left)?;
+            check_plan(input_right)?;
+            if left_on.len() != right_on.len() {
+                return Err(PolarsError::ShapeMisMatch(
+                    format!(
+                        "the number of left keys: {} does not match the number of right keys: {}",
+                        left_on.len(),
+                        right_on.len()
+                    )
+                    .into(),
+                ));
+            }
+            if left_on.is_empty() {
+                return Err(PolarsError::ValueError(
+                    "a join needs at least one key".into(),
+                ));
+            }
+            check_exprs(left_on, input_left.schema(), Context::Other)?;
+            check_exprs(right_on, input_right.schema(), Context::Other)
+        }
+        Sort {
+            input, by_column, ..
+        } => {
+            check_plan(input)?;
+            check_columns(std::iter::once(by_column.as_str()), input.schema())
+        }
+        Explode { input, columns } => {
+            check_plan(input)?;
+            check_columns(columns.iter().map(|s| s.as_str()), input.schema())
+        }
+        Distinct { input, subset, .. } => {
+            check_plan(input)?;
+            match subset.as_ref() {
+                Some(subset) => check_columns(subset.iter().map(|s| s.as_str()), input.schema()),
+                None => Ok(()),
+            }
+        }
+        Melt {
+            input,
+            id_vars,
+            value_vars,
+            ..
+        } => {
+            check_plan(input)?;
+            check_columns(
+                id_vars.iter().chain(value_vars.iter()).map(|s| s.as_str()),
+                input.schema(),
+            )
+        }
+        _ => Ok(()),
+    }
+}
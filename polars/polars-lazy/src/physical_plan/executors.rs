@@ -2,14 +2,40 @@ use super::*;
 use crate::logical_plan::{Context, FETCH_ROWS};
 use crate::utils::rename_expr_root_name;
 use itertools::Itertools;
+use polars_core::config;
 use polars_core::utils::{accumulate_dataframes_vertical, num_cpus, split_df};
-use polars_core::{frame::hash_join::JoinType, POOL};
+use polars_core::{
+    frame::hash_join::JoinType,
+    progress::{CancellationToken, Progress},
+    POOL,
+};
 use polars_io::prelude::*;
 use polars_io::{csv::CsvEncoding, ScanAggregation};
 use rayon::prelude::*;
 use std::io::{Read, Seek};
 use std::mem;
 
+/// The key of a scanned file in the cache. Scans of the same file with another projection,
+/// predicate or row limit read other data, so they don't share the cached DataFrame.
+fn scan_cache_key(
+    path: &str,
+    with_columns: &Option<Vec<String>>,
+    predicate: &Option<Arc<dyn PhysicalExpr>>,
+    stop_after_n_rows: Option<usize>,
+) -> String {
+    let mut key = path.to_string();
+    if let Some(n_rows) = stop_after_n_rows {
+        key.push_str(&format!("[..{}]", n_rows));
+    }
+    if let Some(columns) = with_columns {
+        key.push_str(&format!("{:?}", columns));
+    }
+    if let Some(predicate) = predicate {
+        key.push_str(&format!("{:?}", predicate.as_expression()));
+    }
+    key
+}
+
 trait FinishScanOps {
     /// Read the file and create the DataFrame. Used from lazy execution
     fn finish_with_scan_ops(
@@ -38,8 +64,6 @@ impl<'a, R: 'static + Read + Seek + Sync + Send> FinishScanOps for CsvReader<'a,
     }
 }
 
-const POLARS_VERBOSE: &str = "POLARS_VERBOSE";
-
 fn set_n_rows(stop_after_n_rows: Option<usize>) -> Option<usize> {
     let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
     match fetch_rows {
@@ -66,13 +90,12 @@ impl Executor for CacheExec {
         // cache miss
         let df = self.input.execute(cache)?;
 
+        if config::verbose() {
+            println!("cache set {:?}", self.key);
+        }
         let mut guard = cache.lock().unwrap();
         let key = std::mem::take(&mut self.key);
         guard.insert(key, df.clone());
-
-        if std::env::var(POLARS_VERBOSE).is_ok() {
-            println!("cache set {:?}", self.key);
-        }
         Ok(df)
     }
 }
@@ -114,10 +137,12 @@ impl ParquetExec {
 #[cfg(feature = "parquet")]
 impl Executor for ParquetExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
-        let cache_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", self.path, predicate.as_expression()),
-            None => self.path.to_string(),
-        };
+        let cache_key = scan_cache_key(
+            &self.path,
+            &self.with_columns,
+            &self.predicate,
+            self.stop_after_n_rows,
+        );
         if self.cache {
             let guard = cache.lock().unwrap();
             // cache hit
@@ -167,7 +192,7 @@ impl Executor for ParquetExec {
             let mut guard = cache.lock().unwrap();
             guard.insert(cache_key, df.clone());
         }
-        if std::env::var(POLARS_VERBOSE).is_ok() {
+        if config::verbose() {
             println!("parquet {:?} read", self.path);
         }
 
@@ -187,6 +212,7 @@ pub struct CsvExec {
     predicate: Option<Arc<dyn PhysicalExpr>>,
     aggregate: Vec<ScanAggregation>,
     cache: bool,
+    cancellation_token: Option<CancellationToken>,
 }
 
 impl CsvExec {
@@ -203,6 +229,7 @@ impl CsvExec {
         predicate: Option<Arc<dyn PhysicalExpr>>,
         aggregate: Vec<ScanAggregation>,
         cache: bool,
+        cancellation_token: Option<CancellationToken>,
     ) -> Self {
         CsvExec {
             path,
@@ -216,16 +243,19 @@ impl CsvExec {
             predicate,
             aggregate,
             cache,
+            cancellation_token,
         }
     }
 }
 
 impl Executor for CsvExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
-        let cache_key = match &self.predicate {
-            Some(predicate) => format!("{}{:?}", self.path, predicate.as_expression()),
-            None => self.path.to_string(),
-        };
+        let cache_key = scan_cache_key(
+            &self.path,
+            &self.with_columns,
+            &self.predicate,
+            self.stop_after_n_rows,
+        );
         if self.cache {
             let guard = cache.lock().unwrap();
             // cache hit
@@ -258,7 +288,8 @@ impl Executor for CsvExec {
             .with_skip_rows(self.skip_rows)
             .with_stop_after_n_rows(stop_after_n_rows)
             .with_columns(with_columns)
-            .with_encoding(CsvEncoding::LossyUtf8);
+            .with_encoding(CsvEncoding::LossyUtf8)
+            .with_cancellation_token(self.cancellation_token.clone());
 
         let aggregate = if self.aggregate.is_empty() {
             None
@@ -272,7 +303,7 @@ impl Executor for CsvExec {
             let mut guard = cache.lock().unwrap();
             guard.insert(cache_key, df.clone());
         }
-        if std::env::var(POLARS_VERBOSE).is_ok() {
+        if config::verbose() {
             println!("csv {:?} read", self.path);
         }
 
@@ -280,6 +311,75 @@ impl Executor for CsvExec {
     }
 }
 
+pub struct AnonymousScanExec {
+    function: Arc<dyn AnonymousScan>,
+    with_columns: Option<Vec<String>>,
+    predicate: Option<Expr>,
+    physical_predicate: Option<Arc<dyn PhysicalExpr>>,
+}
+
+impl AnonymousScanExec {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        with_columns: Option<Vec<String>>,
+        predicate: Option<Expr>,
+        physical_predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Self {
+        AnonymousScanExec {
+            function,
+            with_columns,
+            predicate,
+            physical_predicate,
+        }
+    }
+}
+
+impl Executor for AnonymousScanExec {
+    fn execute(&mut self, _: &Cache) -> Result<DataFrame> {
+        let mut options = AnonymousScanOptions::default();
+        if self.function.allows_projection_pushdown() {
+            options.with_columns = self.with_columns.clone();
+        }
+        if self.function.allows_predicate_pushdown() {
+            options.predicate = self.predicate.clone();
+        }
+
+        let batches = self.function.scan(options)?;
+        let mut df = match batches.len() {
+            0 => DataFrame::new_no_checks(
+                self.function
+                    .schema()?
+                    .fields()
+                    .iter()
+                    .map(|fld| Series::full_null(fld.name(), 0, fld.data_type()))
+                    .collect(),
+            ),
+            _ => accumulate_dataframes_vertical(batches)?,
+        };
+
+        // projection should be before selection as those are free
+        if let Some(columns) = &self.with_columns {
+            df = df.select(columns)?;
+        }
+        if let Some(predicate) = &self.physical_predicate {
+            let s = predicate.evaluate(&df)?;
+            let mask = s.bool().map_err(|_| {
+                PolarsError::Other("filter predicate was not of type boolean".into())
+            })?;
+            df = df.filter(mask)?;
+        }
+        if config::verbose() {
+            println!("anonymous scan read");
+        }
+
+        if let Some(limit) = set_n_rows(None) {
+            Ok(df.head(Some(limit)))
+        } else {
+            Ok(df)
+        }
+    }
+}
+
 pub struct FilterExec {
     predicate: Arc<dyn PhysicalExpr>,
     input: Box<dyn Executor>,
@@ -297,7 +397,7 @@ impl Executor for FilterExec {
         let s = self.predicate.evaluate(&df)?;
         let mask = s.bool().expect("filter predicate wasn't of type boolean");
         let df = df.filter(mask)?;
-        if std::env::var(POLARS_VERBOSE).is_ok() {
+        if config::verbose() {
             println!("dataframe filtered");
         }
         Ok(df)
@@ -408,7 +508,7 @@ impl Executor for StandardExec {
         let df = self.input.execute(cache)?;
 
         let df = evaluate_physical_expressions(&df, &self.expr);
-        if std::env::var(POLARS_VERBOSE).is_ok() {
+        if config::verbose() {
             println!("operation {} on dataframe finished", self.operation);
         }
         df
@@ -430,13 +530,13 @@ impl Executor for ExplodeExec {
 pub(crate) struct SortExec {
     pub(crate) input: Box<dyn Executor>,
     pub(crate) by_column: String,
-    pub(crate) reverse: bool,
+    pub(crate) options: SortOptions,
 }
 
 impl Executor for SortExec {
     fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
         let df = self.input.execute(cache)?;
-        df.sort(&self.by_column, self.reverse)
+        df.sort_with_options(&self.by_column, self.options)
     }
 }
 
@@ -462,6 +562,7 @@ pub struct GroupByExec {
     keys: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Arc<dyn PhysicalExpr>>,
     apply: Option<Arc<dyn DataFrameUdf>>,
+    maintain_order: bool,
 }
 
 impl GroupByExec {
@@ -470,12 +571,14 @@ impl GroupByExec {
         keys: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Arc<dyn PhysicalExpr>>,
         apply: Option<Arc<dyn DataFrameUdf>>,
+        maintain_order: bool,
     ) -> Self {
         Self {
             input,
             keys,
             aggs,
             apply,
+            maintain_order,
         }
     }
 }
@@ -485,8 +588,11 @@ fn groupby_helper(
     keys: Vec<Series>,
     aggs: &[Arc<dyn PhysicalExpr>],
     apply: Option<&Arc<dyn DataFrameUdf>>,
+    maintain_order: bool,
 ) -> Result<DataFrame> {
-    let gb = df.groupby_with_series(keys, true)?;
+    let gb = df
+        .groupby_with_series(keys, true)?
+        .maintain_order(maintain_order);
     if let Some(f) = apply {
         return gb.apply(|df| f.call_udf(df));
     }
@@ -529,7 +635,13 @@ impl Executor for GroupByExec {
             .iter()
             .map(|e| e.evaluate(&df))
             .collect::<Result<_>>()?;
-        groupby_helper(df, keys, &self.aggs, self.apply.as_ref())
+        groupby_helper(
+            df,
+            keys,
+            &self.aggs,
+            self.apply.as_ref(),
+            self.maintain_order,
+        )
     }
 }
 
@@ -539,6 +651,7 @@ pub struct PartitionGroupByExec {
     keys: Vec<Arc<dyn PhysicalExpr>>,
     phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
     aggs: Vec<Expr>,
+    planner: DefaultPlanner,
 }
 
 impl PartitionGroupByExec {
@@ -547,12 +660,14 @@ impl PartitionGroupByExec {
         keys: Vec<Arc<dyn PhysicalExpr>>,
         phys_aggs: Vec<Arc<dyn PhysicalExpr>>,
         aggs: Vec<Expr>,
+        planner: DefaultPlanner,
     ) -> Self {
         Self {
             input,
             keys,
             phys_aggs,
             aggs,
+            planner,
         }
     }
 }
@@ -580,7 +695,7 @@ impl Executor for PartitionGroupByExec {
             let frac = cat_map.len() as f32 / ca.len() as f32;
             // TODO! proper benchmark which boundary should be chosen.
             if frac > 0.3 {
-                return groupby_helper(original_df, keys, &self.phys_aggs, None);
+                return groupby_helper(original_df, keys, &self.phys_aggs, None, false);
             }
         }
 
@@ -601,10 +716,12 @@ impl Executor for PartitionGroupByExec {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let planner = DefaultPlanner {};
         let outer_phys_aggs = aggs_and_names
             .iter()
-            .map(|(e, _)| planner.create_physical_expr(e.clone(), Context::Aggregation))
+            .map(|(e, _)| {
+                self.planner
+                    .create_physical_expr(e.clone(), Context::Aggregation)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         let n_threads = num_cpus::get();
@@ -698,6 +815,8 @@ pub struct JoinExec {
     left_on: Vec<Arc<dyn PhysicalExpr>>,
     right_on: Vec<Arc<dyn PhysicalExpr>>,
     parallel: bool,
+    suffix: String,
+    validation: Option<JoinValidation>,
 }
 
 impl JoinExec {
@@ -708,6 +827,8 @@ impl JoinExec {
         left_on: Vec<Arc<dyn PhysicalExpr>>,
         right_on: Vec<Arc<dyn PhysicalExpr>>,
         parallel: bool,
+        suffix: String,
+        validation: Option<JoinValidation>,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -716,6 +837,8 @@ impl JoinExec {
             left_on,
             right_on,
             parallel,
+            suffix,
+            validation,
         }
     }
 }
@@ -748,20 +871,30 @@ impl Executor for JoinExec {
         let df_left = df_left?;
         let df_right = df_right?;
 
-        let left_names = self
+        // the keys may be computed, so we join on the evaluated keys and not on the columns
+        let left_on = self
             .left_on
             .iter()
-            .map(|e| e.evaluate(&df_left).map(|s| s.name().to_string()))
+            .map(|e| e.evaluate(&df_left))
             .collect::<Result<Vec<_>>>()?;
 
-        let right_names = self
+        let right_on = self
             .right_on
             .iter()
-            .map(|e| e.evaluate(&df_right).map(|s| s.name().to_string()))
+            .map(|e| e.evaluate(&df_right))
             .collect::<Result<Vec<_>>>()?;
 
-        let df = df_left.join(&df_right, &left_names, &right_names, self.how);
-        if std::env::var(POLARS_VERBOSE).is_ok() {
+        if let Some(validation) = self.validation {
+            validation.validate(&left_on, &right_on)?;
+        }
+        let df = df_left.join_with_series_and_suffix(
+            &df_right,
+            &left_on,
+            &right_on,
+            self.how,
+            &self.suffix,
+        );
+        if config::verbose() {
             println!("{:?} join dataframes finished", self.how);
         };
         df
@@ -783,24 +916,30 @@ impl Executor for StackExec {
         let mut df = self.input.execute(cache)?;
         let height = df.height();
 
-        let res: Result<_> = self.expr.iter().try_for_each(|expr| {
-            let s = expr.evaluate(&df).map(|series| {
-                // literal series. Should be whole column size
-                if series.len() == 1 && height > 1 {
-                    series.expand_at_index(0, height)
-                } else {
-                    series
-                }
-            })?;
+        // all expressions are evaluated on the input, so they don't see the columns added by
+        // each other, like in the schema of the HStack node.
+        let columns = self
+            .expr
+            .par_iter()
+            .map(|expr| {
+                expr.evaluate(&df).map(|series| {
+                    // literal series. Should be whole column size
+                    if series.len() == 1 && height > 1 {
+                        series.expand_at_index(0, height)
+                    } else {
+                        series
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
+        for s in columns {
             let name = s.name().to_string();
             df.replace_or_add(&name, s)?;
-            if std::env::var(POLARS_VERBOSE).is_ok() {
+            if config::verbose() {
                 println!("added column {} to dataframe", name);
             }
-            Ok(())
-        });
-        let _ = res?;
+        }
         Ok(df)
     }
 }
@@ -841,3 +980,37 @@ impl Executor for UdfExec {
         self.function.call_udf(df)
     }
 }
+
+/// Reports the execution of the wrapped executor to the global
+/// [ProgressHandler](polars_core::progress::ProgressHandler).
+/// The execution is aborted when the cancellation token is cancelled.
+pub(crate) struct ProgressExec {
+    input: Box<dyn Executor>,
+    operation: &'static str,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl ProgressExec {
+    pub(crate) fn new(
+        input: Box<dyn Executor>,
+        operation: &'static str,
+        cancellation_token: Option<CancellationToken>,
+    ) -> Self {
+        Self {
+            input,
+            operation,
+            cancellation_token,
+        }
+    }
+}
+
+impl Executor for ProgressExec {
+    fn execute(&mut self, cache: &Cache) -> Result<DataFrame> {
+        let progress = Progress::start(self.operation, None)
+            .with_cancellation_token(self.cancellation_token.clone());
+        progress.check_cancelled()?;
+        let df = self.input.execute(cache)?;
+        progress.update(df.height())?;
+        Ok(df)
+    }
+}
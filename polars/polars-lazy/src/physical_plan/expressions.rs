@@ -76,6 +76,11 @@ impl PhysicalExpr for LiteralExpr {
                 let timestamp = naive_datetime_to_date64(ndt);
                 Date64Chunked::full("literal", timestamp, 1).into_series()
             }
+            Param(name) => {
+                return Err(PolarsError::ValueError(
+                    format!("parameter '{}' is not bound to a value", name).into(),
+                ))
+            }
         };
         Ok(s)
     }
@@ -100,6 +105,7 @@ impl PhysicalExpr for LiteralExpr {
             Range { data_type, .. } => Field::new(name, data_type.clone()),
             #[cfg(feature = "temporal")]
             DateTime(_) => Field::new(name, DataType::Date64),
+            Param(_) => Field::new(name, DataType::Null),
         };
         Ok(field)
     }
@@ -139,7 +145,7 @@ impl PhysicalExpr for BinaryExpr {
         let left = &lhs;
         let right = &rhs;
 
-        match self.op {
+        let mut out = match self.op {
             Operator::Gt => Ok(ChunkCompare::<&Series>::gt(left, right).into_series()),
             Operator::GtEq => Ok(ChunkCompare::<&Series>::gt_eq(left, right).into_series()),
             Operator::Lt => Ok(ChunkCompare::<&Series>::lt(left, right).into_series()),
@@ -156,10 +162,13 @@ impl PhysicalExpr for BinaryExpr {
             Operator::Like => todo!(),
             Operator::NotLike => todo!(),
             Operator::Modulus => Ok(left % right),
-        }
+        }?;
+        // the result is named after the left operand, as in the schema of the expression
+        out.rename(lhs.name());
+        Ok(out)
     }
-    fn to_field(&self, _input_schema: &Schema) -> Result<Field> {
-        todo!()
+    fn to_field(&self, input_schema: &Schema) -> Result<Field> {
+        self.expr.to_field(input_schema, Context::Other)
     }
 }
 
@@ -633,12 +642,38 @@ impl PhysicalExpr for TernaryExpr {
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
         let mask_series = self.predicate.evaluate(df)?;
         let mask = mask_series.bool()?;
-        let truthy = self.truthy.evaluate(df)?;
-        let falsy = self.falsy.evaluate(df)?;
+        let mut truthy = self.truthy.evaluate(df)?;
+        let mut falsy = self.falsy.evaluate(df)?;
+        // a null literal evaluates to a boolean Series, so it is replaced with a null value of the
+        // type of the other branch. The type coercion may have cast it already.
+        fn is_null_lit(e: &Expr) -> bool {
+            match e {
+                Expr::Literal(LiteralValue::Null) => true,
+                Expr::Cast { expr, .. } => is_null_lit(expr),
+                _ => false,
+            }
+        }
+        let (truthy_expr, falsy_expr) = match &self.expr {
+            Expr::Ternary { truthy, falsy, .. } => (&**truthy, &**falsy),
+            _ => unreachable!(),
+        };
+        if is_null_lit(truthy_expr) {
+            truthy = Series::full_null(truthy.name(), 1, falsy.dtype());
+            let mut out = truthy.zip_with(&mask, &falsy)?;
+            out.rename(falsy.name());
+            return Ok(out);
+        }
+        if is_null_lit(falsy_expr) {
+            falsy = Series::full_null(falsy.name(), 1, truthy.dtype());
+        }
         truthy.zip_with(&mask, &falsy)
     }
     fn to_field(&self, input_schema: &Schema) -> Result<Field> {
-        self.truthy.to_field(input_schema)
+        let field = self.truthy.to_field(input_schema)?;
+        match field.data_type() {
+            DataType::Null => self.falsy.to_field(input_schema),
+            _ => Ok(field),
+        }
     }
 }
 
@@ -699,7 +734,10 @@ impl AggPhysicalExpr for ApplyExpr {
             // layer below is also an aggregation expr.
             Ok(expr) => {
                 let aggregated = expr.evaluate(df, groups)?;
-                let out = aggregated.map(|s| self.function.call_udf(s));
+                let out = aggregated.map(|s| match s.dtype() {
+                    DataType::List(_) => self.function.call_udf_groups(s),
+                    _ => self.function.call_udf(s),
+                });
                 out.transpose()
             }
             Err(_) => {
@@ -707,7 +745,7 @@ impl AggPhysicalExpr for ApplyExpr {
                 series
                     .agg_list(groups)
                     .map(|s| {
-                        let s = self.function.call_udf(s);
+                        let s = self.function.call_udf_groups(s);
                         s.map(|mut s| {
                             s.rename(series.name());
                             s
@@ -727,15 +765,32 @@ pub struct WindowExpr {
     pub(crate) out_name: Arc<String>,
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) function: Expr,
+    /// The input of the function if it is not the apply column itself.
+    pub(crate) input: Option<Arc<dyn PhysicalExpr>>,
 }
 
+/// The name of the evaluated function input in the DataFrame that is grouped by a window
+/// function.
+const WINDOW_INPUT: &str = "__POLARS_WINDOW_INPUT";
+
 impl PhysicalExpr for WindowExpr {
     // Note: this was first implemented with expression evaluation but this performed really bad.
     // Therefore we choose the groupby -> apply -> self join approach
     fn evaluate(&self, df: &DataFrame) -> Result<Series> {
-        let gb = df
-            .groupby(self.group_column.as_str())?
-            .select(self.apply_column.as_str());
+        let gb_df;
+        let gb = match &self.input {
+            Some(input) => {
+                let mut input = input.evaluate(df)?;
+                input.rename(WINDOW_INPUT);
+                gb_df = DataFrame::new(vec![df.column(&self.group_column)?.clone(), input])?;
+                gb_df
+                    .groupby(self.group_column.as_str())?
+                    .select(WINDOW_INPUT)
+            }
+            None => df
+                .groupby(self.group_column.as_str())?
+                .select(self.apply_column.as_str()),
+        };
 
         let out = match &self.function {
             Expr::Udf { function, .. } => {
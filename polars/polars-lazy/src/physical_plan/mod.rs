@@ -28,6 +28,9 @@ pub trait Executor: Send + Sync {
 
 pub(crate) type Cache = Arc<Mutex<HashMap<String, DataFrame, RandomState>>>;
 
+/// Values bound to the [lit_param](crate::dsl::lit_param) placeholders of a query.
+pub(crate) type Params = Arc<HashMap<String, LiteralValue, RandomState>>;
+
 /// Take a DataFrame and evaluate the expressions.
 /// Implement this for Column, lt, eq, etc
 pub trait PhysicalExpr: Send + Sync {
@@ -1,16 +1,20 @@
 use crate::logical_plan::Context;
 use crate::physical_plan::executors::*;
+use crate::physical_plan::Params;
 use crate::prelude::*;
-use crate::utils::{agg_source_paths, expr_to_root_column_name};
+use crate::utils::{agg_source_paths, cache_key, expr_to_root_column_name, output_name};
 use ahash::RandomState;
 use itertools::Itertools;
 use polars_core::prelude::*;
-use polars_core::{frame::group_by::GroupByMethod, utils::parallel_op};
+use polars_core::{
+    frame::group_by::GroupByMethod, progress::CancellationToken, utils::parallel_op,
+};
 use polars_io::ScanAggregation;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-fn aggregate_expr_to_scan_agg(aggregate: Vec<Expr>) -> Vec<ScanAggregation> {
+fn aggregate_expr_to_scan_agg(aggregate: Vec<Expr>) -> Result<Vec<ScanAggregation>> {
+    let column = |e: &Expr| expr_to_root_column_name(e).map(|name| (*name).clone());
     aggregate
         .into_iter()
         .map(|e| {
@@ -20,41 +24,86 @@ fn aggregate_expr_to_scan_agg(aggregate: Vec<Expr>) -> Vec<ScanAggregation> {
                 expr = *e;
                 alias = Some((*name).clone())
             };
-            if let Expr::Agg(agg) = expr {
-                match agg {
-                    AggExpr::Min(e) => ScanAggregation::Min {
-                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
-                        alias,
-                    },
-                    AggExpr::Max(e) => ScanAggregation::Max {
-                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
-                        alias,
-                    },
-                    AggExpr::Sum(e) => ScanAggregation::Sum {
-                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
-                        alias,
-                    },
-                    AggExpr::First(e) => ScanAggregation::First {
-                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
-                        alias,
-                    },
-                    AggExpr::Last(e) => ScanAggregation::Last {
-                        column: (*expr_to_root_column_name(&e).unwrap()).clone(),
-                        alias,
-                    },
-                    _ => todo!(),
+            let agg = match expr {
+                Expr::Agg(agg) => agg,
+                expr => {
+                    return Err(PolarsError::InvalidOperation(
+                        format!("expected an aggregation in a scan, got {:?}", expr).into(),
+                    ))
                 }
-            } else {
-                unreachable!()
+            };
+            match agg {
+                AggExpr::Min(e) => Ok(ScanAggregation::Min {
+                    column: column(&e)?,
+                    alias,
+                }),
+                AggExpr::Max(e) => Ok(ScanAggregation::Max {
+                    column: column(&e)?,
+                    alias,
+                }),
+                AggExpr::Sum(e) => Ok(ScanAggregation::Sum {
+                    column: column(&e)?,
+                    alias,
+                }),
+                AggExpr::First(e) => Ok(ScanAggregation::First {
+                    column: column(&e)?,
+                    alias,
+                }),
+                AggExpr::Last(e) => Ok(ScanAggregation::Last {
+                    column: column(&e)?,
+                    alias,
+                }),
+                AggExpr::Count(e) => Ok(ScanAggregation::Count {
+                    column: column(&e)?,
+                    alias,
+                }),
+                agg => Err(PolarsError::InvalidOperation(
+                    format!(
+                        "aggregation {:?} is not supported in a scan",
+                        Expr::Agg(agg)
+                    )
+                    .into(),
+                )),
             }
         })
         .collect()
 }
 
-pub struct DefaultPlanner {}
+/// Name of the operation that is reported to the progress handler.
+fn operation_name(logical_plan: &LogicalPlan) -> &'static str {
+    use LogicalPlan::*;
+    match logical_plan {
+        Selection { .. } => "selection",
+        Cache { .. } => "cache",
+        CsvScan { .. } => "csv_scan",
+        LogicalPlan::AnonymousScan { .. } => "anonymous_scan",
+        #[cfg(feature = "parquet")]
+        ParquetScan { .. } => "parquet_scan",
+        DataFrameScan { .. } => "dataframe_scan",
+        LocalProjection { .. } | Projection { .. } => "projection",
+        Aggregate { .. } => "aggregate",
+        Join { .. } => "join",
+        HStack { .. } => "hstack",
+        Distinct { .. } => "distinct",
+        Sort { .. } => "sort",
+        Explode { .. } => "explode",
+        Slice { .. } => "slice",
+        Melt { .. } => "melt",
+        Udf { .. } => "udf",
+    }
+}
+
+#[derive(Clone)]
+pub struct DefaultPlanner {
+    params: Params,
+    cancellation_token: Option<CancellationToken>,
+}
 impl Default for DefaultPlanner {
     fn default() -> Self {
-        Self {}
+        Self {
+            params: Arc::new(HashMap::with_hasher(RandomState::default())),
+            cancellation_token: None,
+        }
     }
 }
 
@@ -65,6 +114,20 @@ impl PhysicalPlanner for DefaultPlanner {
 }
 
 impl DefaultPlanner {
+    /// Create a planner that replaces the [lit_param](crate::dsl::lit_param) placeholders
+    /// with the given values.
+    pub(crate) fn with_params(mut self, params: Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Abort the execution of the physical plan when the token is cancelled.
+    /// The token is checked between the nodes of the plan and between the batches of csv scans.
+    pub(crate) fn with_cancellation_token(mut self, token: Option<CancellationToken>) -> Self {
+        self.cancellation_token = token;
+        self
+    }
+
     fn create_physical_expressions(
         &self,
         exprs: Vec<Expr>,
@@ -79,6 +142,16 @@ impl DefaultPlanner {
         &self,
         logical_plan: LogicalPlan,
     ) -> Result<Box<dyn Executor>> {
+        let operation = operation_name(&logical_plan);
+        let executor = self.create_executor(logical_plan)?;
+        Ok(Box::new(ProgressExec::new(
+            executor,
+            operation,
+            self.cancellation_token.clone(),
+        )))
+    }
+
+    fn create_executor(&self, logical_plan: LogicalPlan) -> Result<Box<dyn Executor>> {
         match logical_plan {
             LogicalPlan::Melt {
                 input,
@@ -118,7 +191,7 @@ impl DefaultPlanner {
                 let predicate = predicate
                     .map(|pred| self.create_physical_expr(pred, Context::Other))
                     .map_or(Ok(None), |v| v.map(Some))?;
-                let aggregate = aggregate_expr_to_scan_agg(aggregate);
+                let aggregate = aggregate_expr_to_scan_agg(aggregate)?;
                 Ok(Box::new(CsvExec::new(
                     path,
                     schema,
@@ -131,6 +204,7 @@ impl DefaultPlanner {
                     predicate,
                     aggregate,
                     cache,
+                    self.cancellation_token.clone(),
                 )))
             }
             #[cfg(feature = "parquet")]
@@ -147,7 +221,7 @@ impl DefaultPlanner {
                     .map(|pred| self.create_physical_expr(pred, Context::Other))
                     .map_or(Ok(None), |v| v.map(Some))?;
 
-                let aggregate = aggregate_expr_to_scan_agg(aggregate);
+                let aggregate = aggregate_expr_to_scan_agg(aggregate)?;
                 Ok(Box::new(ParquetExec::new(
                     path,
                     schema,
@@ -158,6 +232,23 @@ impl DefaultPlanner {
                     cache,
                 )))
             }
+            LogicalPlan::AnonymousScan {
+                function,
+                with_columns,
+                predicate,
+                ..
+            } => {
+                let physical_predicate = predicate
+                    .clone()
+                    .map(|pred| self.create_physical_expr(pred, Context::Other))
+                    .map_or(Ok(None), |v| v.map(Some))?;
+                Ok(Box::new(AnonymousScanExec::new(
+                    function,
+                    with_columns,
+                    predicate,
+                    physical_predicate,
+                )))
+            }
             LogicalPlan::Projection { expr, input, .. } => {
                 let input = self.create_initial_physical_plan(*input)?;
                 let phys_expr = self.create_physical_expressions(expr, Context::Other)?;
@@ -185,31 +276,21 @@ impl DefaultPlanner {
             LogicalPlan::Sort {
                 input,
                 by_column,
-                reverse,
+                options,
             } => {
                 let input = self.create_initial_physical_plan(*input)?;
                 Ok(Box::new(SortExec {
                     input,
                     by_column,
-                    reverse,
+                    options,
                 }))
             }
             LogicalPlan::Explode { input, columns } => {
                 let input = self.create_initial_physical_plan(*input)?;
                 Ok(Box::new(ExplodeExec { input, columns }))
             }
-            LogicalPlan::Cache { input } => {
-                let fields = input.schema().fields();
-                // todo! fix the unique constraint in the schema. Probably in projection pushdown at joins
-                let mut unique =
-                    HashSet::with_capacity_and_hasher(fields.len(), RandomState::default());
-                // assumption of 80 characters per column name
-                let mut key = String::with_capacity(fields.len() * 80);
-                for field in fields {
-                    if unique.insert(field.name()) {
-                        key.push_str(field.name())
-                    }
-                }
+            LogicalPlan::Cache { input, id } => {
+                let key = cache_key(id);
                 let input = self.create_initial_physical_plan(*input)?;
                 Ok(Box::new(CacheExec { input, key }))
             }
@@ -231,6 +312,7 @@ impl DefaultPlanner {
                 keys,
                 aggs,
                 apply,
+                maintain_order,
                 ..
             } => {
                 let input = self.create_initial_physical_plan(*input)?;
@@ -260,6 +342,10 @@ impl DefaultPlanner {
                 if apply.is_some() {
                     partitionable = false;
                 }
+                // the partitioned groupby doesn't keep the order of the groups.
+                if maintain_order {
+                    partitionable = false;
+                }
                 let phys_keys = self.create_physical_expressions(
                     Arc::try_unwrap(keys).unwrap_or_else(|keys| (&*keys).clone()),
                     Context::Other,
@@ -268,12 +354,20 @@ impl DefaultPlanner {
                     let phys_aggs =
                         self.create_physical_expressions(aggs.clone(), Context::Aggregation)?;
                     Ok(Box::new(PartitionGroupByExec::new(
-                        input, phys_keys, phys_aggs, aggs,
+                        input,
+                        phys_keys,
+                        phys_aggs,
+                        aggs,
+                        self.clone(),
                     )))
                 } else {
                     let phys_aggs = self.create_physical_expressions(aggs, Context::Aggregation)?;
                     Ok(Box::new(GroupByExec::new(
-                        input, phys_keys, phys_aggs, apply,
+                        input,
+                        phys_keys,
+                        phys_aggs,
+                        apply,
+                        maintain_order,
                     )))
                 }
             }
@@ -283,13 +377,12 @@ impl DefaultPlanner {
                 how,
                 left_on,
                 right_on,
-                allow_par,
-                force_par,
+                options,
                 ..
             } => {
-                let parallel = if force_par {
-                    force_par
-                } else if allow_par {
+                let parallel = if options.force_parallel {
+                    true
+                } else if options.allow_parallel {
                     // check if two DataFrames come from a separate source. If they don't we hope it is cached.
                     let mut sources_left =
                         HashSet::with_capacity_and_hasher(32, RandomState::default());
@@ -308,6 +401,16 @@ impl DefaultPlanner {
                 let input_left = self.create_initial_physical_plan(*input_left)?;
                 let input_right = self.create_initial_physical_plan(*input_right)?;
                 let left_on = self.create_physical_expressions(left_on, Context::Other)?;
+                // like in the schema of the join, a right key without an output name must not
+                // remove a column of the right table, so it gets a name that isn't taken
+                let right_on = right_on
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, e)| match output_name(&e) {
+                        Ok(_) => e,
+                        Err(_) => e.alias(&format!("__POLARS_JOIN_KEY_{}", i)),
+                    })
+                    .collect();
                 let right_on = self.create_physical_expressions(right_on, Context::Other)?;
                 Ok(Box::new(JoinExec::new(
                     input_left,
@@ -316,6 +419,8 @@ impl DefaultPlanner {
                     left_on,
                     right_on,
                     parallel,
+                    options.get_suffix().to_string(),
+                    options.validation,
                 )))
             }
             LogicalPlan::HStack { input, exprs, .. } => {
@@ -355,16 +460,39 @@ impl DefaultPlanner {
                     function = *expr;
                     out_name = name;
                 } else {
-                    out_name = group_column.clone();
+                    out_name = apply_column.clone();
                 }
 
+                // the function may be applied on an expression of the root column instead of
+                // the column itself, e.g. `(col("a") * lit(2)).sum()`
+                let input = match &function {
+                    Expr::Agg(agg) => Some(agg.as_ref()),
+                    Expr::Udf { input, .. } => Some(&**input),
+                    _ => None,
+                };
+                let input = match input {
+                    Some(Expr::Column(_)) | None => None,
+                    Some(input) => Some(self.create_physical_expr(input.clone(), Context::Other)?),
+                };
+
                 Ok(Arc::new(WindowExpr {
                     group_column,
                     apply_column,
                     out_name,
                     function,
+                    input,
                 }))
             }
+            Expr::Literal(LiteralValue::Param(name)) => match self.params.get(&name) {
+                Some(value) => {
+                    let expr = Expr::Literal(value.clone());
+                    Ok(Arc::new(LiteralExpr::new(value.clone(), expr)))
+                }
+                None => Err(PolarsError::NotFound(format!(
+                    "no value bound to parameter '{}'",
+                    name
+                ))),
+            },
             Expr::Literal(value) => Ok(Arc::new(LiteralExpr::new(value, expression))),
             Expr::BinaryExpr { left, op, right } => {
                 let lhs = self.create_physical_expr(*left, ctxt)?;
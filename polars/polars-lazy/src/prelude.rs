@@ -6,14 +6,18 @@ pub use crate::{
             predicate::PredicatePushDown, projection::ProjectionPushDown,
             type_coercion::TypeCoercionRule, Optimize, *,
         },
-        DataFrameUdf, LiteralValue, LogicalPlan, LogicalPlanBuilder,
+        AnonymousScan, AnonymousScanOptions, DataFrameUdf, LiteralValue, LogicalPlan,
+        LogicalPlanBuilder,
     },
     physical_plan::{
-        executors::{CsvExec, DataFrameExec, FilterExec, GroupByExec, StandardExec},
+        executors::{
+            AnonymousScanExec, CsvExec, DataFrameExec, FilterExec, GroupByExec, StandardExec,
+        },
         expressions::*,
         planner::DefaultPlanner,
         Executor, PhysicalExpr, PhysicalPlanner,
     },
 };
 
+pub use polars_core::functions::ClosedWindow;
 pub use polars_core::utils::{Arena, Node};
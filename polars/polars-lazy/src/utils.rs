@@ -5,270 +5,25 @@ use polars_core::prelude::*;
 use std::collections::HashSet;
 use std::sync::Arc;
 
-pub(crate) fn has_aexpr(
-    current_node: Node,
-    arena: &Arena<AExpr>,
-    matching_expr: &AExpr,
-    follow_agg: bool,
-) -> bool {
-    let current_expr = arena.get(current_node);
-
-    match current_expr {
-        AExpr::Window {
-            function,
-            partition_by,
-            order_by,
-        } => {
-            if matches!(matching_expr, AExpr::Window { .. }) {
-                true
-            } else {
-                has_aexpr(*function, arena, matching_expr, follow_agg)
-                    || has_aexpr(*partition_by, arena, matching_expr, follow_agg)
-                    || order_by
-                        .map(|ob| has_aexpr(ob, arena, matching_expr, follow_agg))
-                        .unwrap_or(false)
-            }
-        }
-        AExpr::Duplicated(node) => {
-            if matches!(matching_expr, AExpr::Duplicated(_)) {
-                true
-            } else {
-                has_aexpr(*node, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Unique(node) => {
-            if matches!(matching_expr, AExpr::Unique(_)) {
-                true
-            } else {
-                has_aexpr(*node, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Explode(node) => {
-            if matches!(matching_expr, AExpr::Explode(_)) {
-                true
-            } else {
-                has_aexpr(*node, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Reverse(node) => {
-            if matches!(matching_expr, AExpr::Reverse(_)) {
-                true
-            } else {
-                has_aexpr(*node, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Alias(node, _) => {
-            if matches!(matching_expr, AExpr::Alias(_, _)) {
-                true
-            } else {
-                has_aexpr(*node, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Column(_) => {
-            matches!(matching_expr, AExpr::Column(_))
-        }
-        AExpr::Literal(_) => {
-            matches!(matching_expr, AExpr::Literal(_))
-        }
-        AExpr::BinaryExpr { left, right, .. } => {
-            if matches!(matching_expr, AExpr::BinaryExpr { .. }) {
-                true
-            } else {
-                has_aexpr(*left, arena, matching_expr, follow_agg)
-                    | has_aexpr(*right, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Not(e) => {
-            if matches!(matching_expr, AExpr::Not(_)) {
-                true
-            } else {
-                has_aexpr(*e, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::IsNotNull(e) => {
-            if matches!(matching_expr, AExpr::IsNotNull(_)) {
-                true
-            } else {
-                has_aexpr(*e, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::IsNull(e) => {
-            if matches!(matching_expr, AExpr::IsNull(_)) {
-                true
-            } else {
-                has_aexpr(*e, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Cast { expr, .. } => {
-            if matches!(matching_expr, AExpr::Cast { .. }) {
-                true
-            } else {
-                has_aexpr(*expr, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Sort { expr, .. } => {
-            if matches!(matching_expr, AExpr::Sort { .. }) {
-                true
-            } else {
-                has_aexpr(*expr, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Agg(agg) => {
-            if let AExpr::Agg(tmp_matching_expr) = matching_expr {
-                if !follow_agg {
-                    return true;
-                }
-                match agg {
-                    AAggExpr::Min(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Min(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Max(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Max(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Median(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Median(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::NUnique(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::NUnique(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::First(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::First(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Last(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Last(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Mean(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Mean(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::List(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::List(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Count(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Count(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Quantile { expr, .. } => {
-                        if matches!(tmp_matching_expr, AAggExpr::Quantile { .. }) {
-                            true
-                        } else {
-                            has_aexpr(*expr, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Sum(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Sum(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Std(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Std(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::Var(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::Var(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                    AAggExpr::AggGroups(e) => {
-                        if matches!(tmp_matching_expr, AAggExpr::AggGroups(_)) {
-                            true
-                        } else {
-                            has_aexpr(*e, arena, matching_expr, follow_agg)
-                        }
-                    }
-                }
-            } else {
-                false
-            }
-        }
-        AExpr::Ternary {
+/// Check if an expression is evaluated row by row, such that evaluating it on consecutive
+/// slices of a DataFrame and concatenating the results is the same as evaluating it on the
+/// whole DataFrame. Udfs are assumed not to be.
+pub(crate) fn is_elementwise(expr: &Expr) -> bool {
+    match expr {
+        Expr::Column(_) | Expr::Literal(_) | Expr::Wildcard => true,
+        Expr::Alias(e, _)
+        | Expr::Not(e)
+        | Expr::IsNotNull(e)
+        | Expr::IsNull(e)
+        | Expr::Except(e)
+        | Expr::Cast { expr: e, .. } => is_elementwise(e),
+        Expr::BinaryExpr { left, right, .. } => is_elementwise(left) && is_elementwise(right),
+        Expr::Ternary {
             predicate,
             truthy,
             falsy,
-        } => {
-            if matches!(matching_expr, AExpr::Ternary { .. }) {
-                true
-            } else {
-                has_aexpr(*predicate, arena, matching_expr, follow_agg)
-                    | has_aexpr(*truthy, arena, matching_expr, follow_agg)
-                    | has_aexpr(*falsy, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::BinaryFunction {
-            input_a, input_b, ..
-        } => {
-            if matches!(matching_expr, AExpr::BinaryFunction { .. }) {
-                true
-            } else {
-                has_aexpr(*input_a, arena, matching_expr, follow_agg)
-                    | has_aexpr(*input_b, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Udf { input, .. } => {
-            if matches!(matching_expr, AExpr::Udf { .. }) {
-                true
-            } else {
-                has_aexpr(*input, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Shift { input, .. } => {
-            if matches!(matching_expr, AExpr::Shift { .. }) {
-                true
-            } else {
-                has_aexpr(*input, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Slice { input, .. } => {
-            if matches!(matching_expr, AExpr::Slice { .. }) {
-                true
-            } else {
-                has_aexpr(*input, arena, matching_expr, follow_agg)
-            }
-        }
-        AExpr::Wildcard => {
-            matches!(matching_expr, AExpr::Wildcard)
-        }
+        } => is_elementwise(predicate) && is_elementwise(truthy) && is_elementwise(falsy),
+        _ => false,
     }
 }
 
@@ -871,15 +626,32 @@ pub(crate) fn rename_expr_root_name(expr: &Expr, new_name: Arc<String>) -> Resul
     }
 }
 
+/// The field of an expression. If the field cannot be resolved, e.g. because a column doesn't
+/// exist, a field of dtype Null is returned. The plan is checked before it is optimized, which
+/// reports the error, so building the plan doesn't panic.
+pub(crate) fn expr_to_field_or_null(expr: &Expr, schema: &Schema, ctxt: Context) -> Field {
+    expr.to_field(schema, ctxt).unwrap_or_else(|_| {
+        let name = output_name(expr)
+            .or_else(|_| expr_to_root_column_name(expr))
+            .map(|name| (*name).clone())
+            .unwrap_or_default();
+        Field::new(&name, DataType::Null)
+    })
+}
+
 pub(crate) fn expressions_to_schema(expr: &[Expr], schema: &Schema, ctxt: Context) -> Schema {
     let fields = expr
         .iter()
-        .map(|expr| expr.to_field(schema, ctxt))
-        .collect::<Result<Vec<_>>>()
-        .unwrap();
+        .map(|expr| expr_to_field_or_null(expr, schema, ctxt))
+        .collect();
     Schema::new(fields)
 }
 
+/// The key under which the output of a cached plan is stored.
+pub(crate) fn cache_key(id: usize) -> String {
+    format!("__POLARS_CACHE_{}", id)
+}
+
 /// Get a set of the data source paths in this LogicalPlan
 pub(crate) fn agg_source_paths(
     logical_plan: &LogicalPlan,
@@ -893,7 +665,10 @@ pub(crate) fn agg_source_paths(
         Selection { input, .. } => {
             agg_source_paths(input, paths);
         }
-        Cache { input } => {
+        Cache { input, id } => {
+            // branches that share a cache are not independent, the first one fills the cache
+            // for the other
+            paths.insert(cache_key(*id));
             agg_source_paths(input, paths);
         }
         CsvScan { path, .. } => {
@@ -904,6 +679,7 @@ pub(crate) fn agg_source_paths(
             paths.insert(path.clone());
         }
         DataFrameScan { .. } => (),
+        LogicalPlan::AnonymousScan { .. } => (),
         Projection { input, .. } => {
             agg_source_paths(input, paths);
         }
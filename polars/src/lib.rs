@@ -189,10 +189,14 @@
 //!     - Json serialization
 //! * `ipc`
 //!     - Arrow's IPC format serialization
+//! * `flight`
+//!     - Fetch and serve DataFrames over Arrow Flight
+//! * `streaming`
+//!     - Groupby over batches that don't fit in memory, see [the streaming module](polars_io::streaming)
 //! * `random`
 //!     - Generate array's with randomly sampled values
 //! * `ndarray`
-//!     - Convert from `DataFrame` to `ndarray`
+//!     - Convert between `DataFrame` and `ndarray`
 //! * `parallel`
 //!     - ChunkedArrays can be used by rayon::par_iter()
 //! * `lazy`
@@ -202,9 +206,11 @@
 //! * `object`
 //!     - Support for generic ChunkedArray's called `ObjectChunked<T>` (generic over `T`).
 //!       These will downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
+#![deny(unstable_features)]
 pub mod prelude;
 pub use polars_core::{
-    chunked_array, datatypes, doc, error, frame, functions, series, testing, toggle_string_cache,
+    chunked_array, config, datatypes, doc, error, frame, functions, series, testing,
+    toggle_string_cache, with_string_cache,
 };
 
 pub use polars_core::apply_method_all_arrow_series;
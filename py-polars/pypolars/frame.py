@@ -1075,11 +1075,28 @@ class DataFrame:
         """
         return wrap_df(self._df.null_count())
 
+    def describe(self, percentiles: "Optional[List[float]]" = None) -> "DataFrame":
+        """
+        Summary statistics of the columns.
+
+        The first column `describe` names the statistics: count, null_count, mean, std, min,
+        one row per percentile, max, unique, top and freq. Numeric columns are described by
+        floats, all other columns by strings.
+
+        Parameters
+        ----------
+        percentiles
+            Percentiles between 0 and 1 to include, defaults to [0.25, 0.5, 0.75]
+        """
+        return wrap_df(self._df.describe(percentiles))
+
     def sample(
         self,
         n: "Optional[int]" = None,
         frac: "Optional[float]" = None,
         with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: "Optional[int]" = None,
     ) -> "DataFrame":
         """
         Sample from this DataFrame by setting either `n` or `frac`
@@ -1092,10 +1109,14 @@ class DataFrame:
             Fraction between 0.0 and 1.0
         with_replacement
             Sample with replacement
+        shuffle
+            Return the sample in random order instead of the original order
+        seed
+            Seed for the random number generator, makes the sample reproducible
         """
         if n is not None:
-            return wrap_df(self._df.sample_n(n, with_replacement))
-        return wrap_df(self._df.sample_frac(frac, with_replacement))
+            return wrap_df(self._df.sample_n(n, with_replacement, shuffle, seed))
+        return wrap_df(self._df.sample_frac(frac, with_replacement, shuffle, seed))
 
 
 class GroupBy:
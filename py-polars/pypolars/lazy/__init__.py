@@ -163,6 +163,18 @@ class LazyFrame:
                 plt.imshow(img)
                 plt.show()
 
+    def explain(self, optimized: bool = True) -> str:
+        """
+        Describe the plan as an indented tree. Every node is annotated with its schema and the
+        estimated number of rows, `?` if that is not known.
+
+        Parameters
+        ----------
+        optimized
+            Describe the plan after the optimizations are applied.
+        """
+        return self._ldf.explain(optimized)
+
     def describe_optimized_plan(
         self,
         type_coercion: bool = True,
@@ -260,6 +272,13 @@ class LazyFrame:
         """
         return wrap_ldf(self._ldf.cache())
 
+    def hint_broadcast(self) -> "LazyFrame":
+        """
+        Hint that this LazyFrame is small. The joins are ordered such that a small input is
+        joined before inputs that are not known to be small.
+        """
+        return wrap_ldf(self._ldf.hint_broadcast())
+
     def filter(self, predicate: "Expr") -> "LazyFrame":
         return wrap_ldf(self._ldf.filter(predicate._pyexpr))
 
@@ -1365,6 +1365,8 @@ class Series:
         n: "Optional[int]" = None,
         frac: "Optional[float]" = None,
         with_replacement: bool = False,
+        shuffle: bool = False,
+        seed: "Optional[int]" = None,
     ) -> "DataFrame":
         """
         Sample from this Series by setting either `n` or `frac`
@@ -1377,10 +1379,14 @@ class Series:
             Fraction between 0.0 and 1.0
         with_replacement
             sample with replacement
+        shuffle
+            Return the sample in random order instead of the original order
+        seed
+            Seed for the random number generator, makes the sample reproducible
         """
         if n is not None:
-            return wrap_s(self._s.sample_n(n, with_replacement))
-        return wrap_s(self._s.sample_frac(frac, with_replacement))
+            return wrap_s(self._s.sample_n(n, with_replacement, shuffle, seed))
+        return wrap_s(self._s.sample_frac(frac, with_replacement, shuffle, seed))
 
 
 def out_to_dtype(out: Any) -> "Union[Datatype, np.ndarray]":
@@ -219,18 +219,30 @@ impl PyDataFrame {
         Ok(df.into())
     }
 
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let df = self
             .df
-            .sample_n(n, with_replacement)
+            .sample_n(n, with_replacement, shuffle, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
 
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let df = self
             .df
-            .sample_frac(frac, with_replacement)
+            .sample_frac(frac, with_replacement, shuffle, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(df.into())
     }
@@ -460,7 +472,7 @@ impl PyDataFrame {
     }
 
     pub fn is_duplicated(&self) -> PyResult<PySeries> {
-        let mask = self.df.is_unique().map_err(PyPolarsEr::from)?;
+        let mask = self.df.is_duplicated().map_err(PyPolarsEr::from)?;
         Ok(mask.into_series().into())
     }
 
@@ -659,6 +671,14 @@ impl PyDataFrame {
         let df = self.df.null_count();
         df.into()
     }
+
+    pub fn describe(&self, percentiles: Option<Vec<f64>>) -> PyResult<Self> {
+        let df = self
+            .df
+            .describe(percentiles.as_deref())
+            .map_err(PyPolarsEr::from)?;
+        Ok(df.into())
+    }
 }
 
 fn finish_groupby(gb: GroupBy, agg: &str) -> PyResult<PyDataFrame> {
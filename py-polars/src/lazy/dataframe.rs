@@ -131,6 +131,11 @@ impl PyLazyFrame {
         Ok(result)
     }
 
+    pub fn explain(&self, optimized: bool) -> PyResult<String> {
+        let result = self.ldf.explain(optimized).map_err(PyPolarsEr::from)?;
+        Ok(result)
+    }
+
     pub fn optimization_toggle(
         &self,
         type_coercion: bool,
@@ -156,6 +161,11 @@ impl PyLazyFrame {
         ldf.cache().into()
     }
 
+    pub fn hint_broadcast(&self) -> PyLazyFrame {
+        let ldf = self.ldf.clone();
+        ldf.hint_broadcast().into()
+    }
+
     pub fn collect(&self) -> PyResult<PyDataFrame> {
         let ldf = self.ldf.clone();
         let gil = Python::acquire_gil();
@@ -214,6 +224,9 @@ impl PyLazyFrame {
         let options = JoinOptions {
             allow_parallel,
             force_parallel,
+            suffix: None,
+            validation: None,
+            broadcast: false,
         };
         let left_on = left_on.into_iter().map(|pyexpr| pyexpr.inner).collect();
         let right_on = right_on.into_iter().map(|pyexpr| pyexpr.inner).collect();
@@ -8,6 +8,7 @@ use polars::chunked_array::builder::get_bitmap;
 use pyo3::types::{PyList, PyTuple};
 use pyo3::{exceptions::PyRuntimeError, prelude::*, Python};
 use std::any::Any;
+use std::fmt;
 use std::ops::{BitAnd, BitOr};
 
 #[derive(Clone, Debug)]
@@ -40,6 +41,19 @@ impl ToPyObject for ObjectValue {
     }
 }
 
+impl fmt::Display for ObjectValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gil = Python::acquire_gil();
+        let python = gil.python();
+        match self.inner.as_ref(python).str() {
+            Ok(s) => write!(f, "{}", s),
+            Err(_) => write!(f, "object"),
+        }
+    }
+}
+
+impl PolarsObject for ObjectValue {}
+
 impl Default for ObjectValue {
     fn default() -> Self {
         let gil = Python::acquire_gil();
@@ -259,7 +273,7 @@ impl PySeries {
     }
 
     pub fn chunk_lengths(&self) -> Vec<usize> {
-        self.series.chunk_lengths().clone()
+        self.series.chunk_lengths().to_vec()
     }
 
     pub fn name(&self) -> &str {
@@ -417,18 +431,30 @@ impl PySeries {
         Ok(ca.into_series().into())
     }
 
-    pub fn sample_n(&self, n: usize, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_n(
+        &self,
+        n: usize,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let s = self
             .series
-            .sample_n(n, with_replacement)
+            .sample_n(n, with_replacement, shuffle, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
 
-    pub fn sample_frac(&self, frac: f64, with_replacement: bool) -> PyResult<Self> {
+    pub fn sample_frac(
+        &self,
+        frac: f64,
+        with_replacement: bool,
+        shuffle: bool,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
         let s = self
             .series
-            .sample_frac(frac, with_replacement)
+            .sample_frac(frac, with_replacement, shuffle, seed)
             .map_err(PyPolarsEr::from)?;
         Ok(s.into())
     }
@@ -96,6 +96,16 @@ def test_null_count():
     assert df.null_count().shape == (1, 2)
 
 
+def test_describe():
+    df = DataFrame({"a": [1.0, 2.0, None], "b": ["x", "y", "y"]})
+    out = df.describe()
+    assert out.shape == (12, 3)
+    assert out["describe"].to_list()[:2] == ["count", "null_count"]
+    assert out["a"].to_list()[:2] == [2.0, 1.0]
+    assert out["b"].to_list()[-2:] == ["y", "2"]
+    assert df.describe([0.1]).height == 10
+
+
 def test_head_tail():
     df = DataFrame({"a": range(10), "b": range(10)})
     assert df.head(5).height == 5
@@ -335,3 +345,13 @@ def test_to_pandas():
     df = DataFrame({"col": Series([True, False, True])})
     print(df)
     df.shift(2).to_pandas()
+
+
+def test_sample():
+    df = DataFrame({"a": [1, 2, 3, 4, 5], "b": [1, 2, 3, 4, 5]})
+    out = df.sample(n=3, seed=0)
+    assert out.frame_equal(df.sample(n=3, seed=0))
+    # without shuffle the rows keep their order and stay aligned
+    assert out["a"].to_list() == sorted(out["a"].to_list())
+    assert out["a"].to_list() == out["b"].to_list()
+    assert df.sample(frac=1.0, shuffle=True, seed=1).height == 5
@@ -57,3 +57,19 @@ def test_binary_function():
         .collect()
     )
     assert out["binary_function"] == (out.a + out.b)
+
+
+def test_explain():
+    df = DataFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
+    explained = df.lazy().filter(col("a") > 1).select([col("a")]).explain(False)
+    lines = explained.splitlines()
+    assert lines[0].startswith("SELECT")
+    assert lines[1] == "  schema: {a: Int64}; rows: 3"
+    assert lines[2].startswith("  FILTER")
+
+
+def test_join_hint_broadcast():
+    df = DataFrame({"a": [1, 2, 3], "b": [1.0, 2.0, 3.0]})
+    small = DataFrame({"a": [1, 2], "c": ["x", "y"]})
+    out = df.lazy().join(small.lazy().hint_broadcast(), on="a").collect()
+    assert out.shape == (2, 3)